@@ -0,0 +1,448 @@
+use crate::builder::Builder;
+use crate::error::{BuildError, BuildResult};
+use crate::ide::include_dirs;
+use crate::parser::DependencyParser;
+use crate::utils::canonical_path;
+use lazy_static::lazy_static;
+use log::warn;
+use regex::Regex;
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+/// `cbuild amalgamate`: 라이브러리를 하나의 .c와 .h로 합침 (SQLite 방식)
+///
+/// 타겟의 포함 디렉토리에 있는 공개 헤더와 그 헤더가 필요로 하는 헤더를
+/// 포함 순서(의존하는 헤더가 먼저)대로 `<name>.h`에 넣고, 나머지 내부 헤더와
+/// 소스를 `<name>.c`에 넣는다. 합친 프로젝트 헤더를 가리키는 `#include`와
+/// 합친 헤더의 `#pragma once`·포함 가드는 주석으로 바뀌고, 시스템·외부 헤더
+/// 포함은 그대로 남는다.
+pub fn amalgamate(
+    project_dir: &Path,
+    target_name: &str,
+    output: Option<PathBuf>,
+    out_dir: Option<PathBuf>,
+) -> BuildResult<()> {
+    let mut builder = Builder::new(project_dir, "release", 1)?;
+    builder.set_output_dir(out_dir);
+    let targets = builder.compile_info()?;
+
+    let target = targets
+        .iter()
+        .find(|t| (t.target_type == "static" || t.target_type == "shared") && t.name == target_name)
+        .ok_or_else(|| BuildError::TargetNotFound(format!("lib:{}", target_name)))?;
+
+    let output_dir = output.unwrap_or_else(|| builder.layout().output_root().join("amalgamation"));
+    let search_dirs = include_dirs(target);
+    let root = canonical_path(project_dir);
+    let deps_dir = root.join("deps");
+    let parser = DependencyParser::new();
+
+    // 합칠 수 있는 프로젝트 헤더 (의존성 디렉토리의 헤더는 그대로 포함)
+    let is_project_header = |path: &Path| path.starts_with(&root) && !path.starts_with(&deps_dir);
+
+    let mut sources = target.sources.clone();
+    sources.sort();
+
+    // 소스에서 도달하는 헤더를 포함 순서대로 정렬
+    let mut ordered = Vec::new();
+    let mut visited = HashSet::new();
+    for source in &sources {
+        for header in parser.direct_dependencies(source, &search_dirs)? {
+            visit_header(
+                &parser,
+                &header,
+                &search_dirs,
+                &is_project_header,
+                &mut visited,
+                &mut ordered,
+            )?;
+        }
+    }
+
+    // 공개 헤더 (선언된 포함 디렉토리 안) 와 그 헤더가 필요로 하는 헤더
+    let declared_dirs: Vec<PathBuf> = search_dirs
+        .iter()
+        .map(|dir| canonical_path(dir))
+        .filter(|dir| is_project_header(dir))
+        .collect();
+    let mut public = HashSet::new();
+    for header in &ordered {
+        if declared_dirs.iter().any(|dir| header.starts_with(dir)) {
+            public.insert(header.clone());
+            for dep in parser.parse_dependencies(header, &search_dirs)? {
+                public.insert(canonical_path(&dep));
+            }
+        }
+    }
+
+    let (public_headers, private_headers): (Vec<&PathBuf>, Vec<&PathBuf>) =
+        ordered.iter().partition(|h| public.contains(*h));
+
+    if !parser.cycles().is_empty() {
+        warn!(
+            "순환 포함이 있어 일부 헤더 순서가 정확하지 않을 수 있습니다 (cbuild graph --cycles)"
+        );
+    }
+
+    let inlined: HashSet<PathBuf> = ordered.iter().cloned().collect();
+    let amalgamator = Amalgamator {
+        parser: &parser,
+        project_dir: &root,
+        search_dirs: &search_dirs,
+        inlined: &inlined,
+    };
+
+    // 헤더 파일
+    let guard = format!(
+        "{}_AMALGAMATION_H",
+        target_name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            })
+            .collect::<String>()
+    );
+    let mut header = String::new();
+    let _ = writeln!(
+        header,
+        "/* {}.h - cbuild amalgamate로 생성된 파일입니다. 직접 수정하지 마세요. */",
+        target_name
+    );
+    let _ = writeln!(header, "#ifndef {}\n#define {}\n", guard, guard);
+    for path in &public_headers {
+        amalgamator.append_file(&mut header, path)?;
+    }
+    let _ = writeln!(header, "#endif /* {} */", guard);
+
+    // 소스 파일
+    let mut source = String::new();
+    let _ = writeln!(
+        source,
+        "/* {}.c - cbuild amalgamate로 생성된 파일입니다. 직접 수정하지 마세요. */",
+        target_name
+    );
+    let _ = writeln!(source, "#include \"{}.h\"\n", target_name);
+    for path in &private_headers {
+        amalgamator.append_file(&mut source, path)?;
+    }
+    for path in &sources {
+        amalgamator.append_file(&mut source, path)?;
+    }
+
+    std::fs::create_dir_all(&output_dir).map_err(BuildError::IoError)?;
+    for (file_name, content) in [
+        (format!("{}.h", target_name), header),
+        (format!("{}.c", target_name), source),
+    ] {
+        let path = output_dir.join(file_name);
+        std::fs::write(&path, content).map_err(BuildError::IoError)?;
+        println!("생성됨: {}", path.display());
+    }
+
+    println!(
+        "헤더 {}개(공개 {}개)와 소스 {}개를 합쳤습니다.",
+        ordered.len(),
+        public_headers.len(),
+        sources.len()
+    );
+
+    Ok(())
+}
+
+/// 의존하는 헤더를 먼저 추가하는 후위 순회
+fn visit_header(
+    parser: &DependencyParser,
+    header: &Path,
+    search_dirs: &[PathBuf],
+    is_project_header: &dyn Fn(&Path) -> bool,
+    visited: &mut HashSet<PathBuf>,
+    ordered: &mut Vec<PathBuf>,
+) -> BuildResult<()> {
+    let key = canonical_path(header);
+    if !is_project_header(&key) || !visited.insert(key.clone()) {
+        return Ok(());
+    }
+
+    for dep in parser.direct_dependencies(header, search_dirs)? {
+        visit_header(
+            parser,
+            &dep,
+            search_dirs,
+            is_project_header,
+            visited,
+            ordered,
+        )?;
+    }
+
+    ordered.push(key);
+    Ok(())
+}
+
+struct Amalgamator<'a> {
+    parser: &'a DependencyParser,
+    project_dir: &'a Path,
+    search_dirs: &'a [PathBuf],
+    inlined: &'a HashSet<PathBuf>,
+}
+
+impl Amalgamator<'_> {
+    /// 파일 내용을 시작/끝 표시와 함께 추가하고 합친 헤더의 `#include`는 주석 처리
+    fn append_file(&self, out: &mut String, path: &Path) -> BuildResult<()> {
+        lazy_static! {
+            static ref INCLUDE_LINE_RE: Regex =
+                Regex::new(r#"^\s*#\s*include\s*(?:<([^>]+)>|"([^"]+)")"#).unwrap();
+            static ref PRAGMA_ONCE_RE: Regex = Regex::new(r"^\s*#\s*pragma\s+once\b").unwrap();
+        }
+
+        let content = std::fs::read_to_string(path).map_err(BuildError::IoError)?;
+        let relative = path
+            .strip_prefix(self.project_dir)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/");
+
+        let _ = writeln!(
+            out,
+            "/************** Begin file {} **************/",
+            relative
+        );
+        let _ = writeln!(out, "#line 1 \"{}\"", relative.replace('"', "\\\""));
+
+        // 합친 파일 안에서는 의미가 없는 헤더의 포함 가드 (줄 번호는 유지)
+        let lines: Vec<&str> = content.lines().collect();
+        let guard_lines = if self.inlined.contains(&canonical_path(path)) {
+            include_guard_lines(&lines)
+        } else {
+            Vec::new()
+        };
+
+        for (index, line) in lines.iter().enumerate() {
+            if guard_lines.contains(&index) || PRAGMA_ONCE_RE.is_match(line) {
+                let _ = writeln!(out, "{}", amalgamated_comment(line));
+                continue;
+            }
+
+            let inlined = INCLUDE_LINE_RE
+                .captures(line)
+                .and_then(|cap| cap.get(1).or_else(|| cap.get(2)))
+                .and_then(|name| {
+                    self.parser
+                        .resolve_header_path(name.as_str(), path, self.search_dirs)
+                })
+                .is_some_and(|header| self.inlined.contains(&canonical_path(&header)));
+
+            if inlined {
+                let _ = writeln!(out, "{}", amalgamated_comment(line));
+            } else {
+                let _ = writeln!(out, "{}", line);
+            }
+        }
+
+        let _ = writeln!(out, "/************** End of {} **************/\n", relative);
+        Ok(())
+    }
+}
+
+/// 합친 줄을 주석으로 (`#endif /* GUARD */`처럼 줄에 있는 주석이 주석을 닫지 않게)
+fn amalgamated_comment(line: &str) -> String {
+    format!(
+        "/* {} -- amalgamated */",
+        line.trim().replace("/*", "/ *").replace("*/", "* /")
+    )
+}
+
+/// 파일 전체를 감싸는 포함 가드의 `#ifndef`, `#define`, `#endif` 줄 번호 (0부터)
+///
+/// 가드 앞뒤에는 주석과 빈 줄만 올 수 있으며, 형식이 다르면 가드로 보지 않는다.
+fn include_guard_lines(lines: &[&str]) -> Vec<usize> {
+    lazy_static! {
+        static ref IFNDEF_RE: Regex =
+            Regex::new(r"^#\s*(?:ifndef\s+(\w+)|if\s+!\s*defined\s*\(?\s*(\w+)\s*\)?)$").unwrap();
+        static ref DEFINE_RE: Regex = Regex::new(r"^#\s*define\s+(\w+)$").unwrap();
+        static ref ENDIF_RE: Regex = Regex::new(r"^#\s*endif\b").unwrap();
+    }
+
+    let code = code_lines(lines);
+    let [(ifndef, ref first), (define, ref second), ..] = code[..] else {
+        return Vec::new();
+    };
+    let Some((endif, last)) = code.last() else {
+        return Vec::new();
+    };
+
+    let guard = IFNDEF_RE
+        .captures(first)
+        .and_then(|cap| cap.get(1).or_else(|| cap.get(2)))
+        .map(|name| name.as_str());
+    let defined = DEFINE_RE.captures(second).map(|cap| cap[1].to_string());
+    if guard.is_none() || guard != defined.as_deref() || !ENDIF_RE.is_match(last) {
+        return Vec::new();
+    }
+
+    // 마지막 `#endif`가 첫 `#ifndef`와 짝인지 확인 (`#ifndef A ... #endif` 뒤에
+    // 다른 블록이 이어지는 파일은 가드가 아님)
+    let mut depth = 0;
+    for (index, text) in &code {
+        if let Some(directive) = text.strip_prefix('#') {
+            let directive = directive.trim_start();
+            if directive.starts_with("if") {
+                depth += 1;
+            } else if directive.starts_with("endif") {
+                depth -= 1;
+                if depth == 0 && index != endif {
+                    return Vec::new();
+                }
+            }
+        }
+    }
+
+    vec![ifndef, define, *endif]
+}
+
+/// 주석을 제거한 뒤 내용이 남는 줄 (줄 번호, 앞뒤 공백을 뺀 내용)
+fn code_lines(lines: &[&str]) -> Vec<(usize, String)> {
+    let mut in_comment = false;
+    let mut code = Vec::new();
+
+    for (index, line) in lines.iter().enumerate() {
+        let mut text = String::new();
+        let mut rest = *line;
+        loop {
+            if in_comment {
+                match rest.find("*/") {
+                    Some(end) => {
+                        rest = &rest[end + 2..];
+                        in_comment = false;
+                    }
+                    None => break,
+                }
+            } else {
+                let block = rest.find("/*");
+                let line_comment = rest.find("//");
+                match (block, line_comment) {
+                    (Some(start), Some(slash)) if slash < start => {
+                        text.push_str(&rest[..slash]);
+                        break;
+                    }
+                    (Some(start), _) => {
+                        text.push_str(&rest[..start]);
+                        text.push(' ');
+                        rest = &rest[start + 2..];
+                        in_comment = true;
+                    }
+                    (None, Some(slash)) => {
+                        text.push_str(&rest[..slash]);
+                        break;
+                    }
+                    (None, None) => {
+                        text.push_str(rest);
+                        break;
+                    }
+                }
+            }
+        }
+
+        let text = text.trim();
+        if !text.is_empty() {
+            code.push((index, text.to_string()));
+        }
+    }
+
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn uncommented<'a>(content: &'a str, pattern: &str) -> Vec<&'a str> {
+        content
+            .lines()
+            .filter(|line| line.trim_start().starts_with(pattern))
+            .collect()
+    }
+
+    #[test]
+    fn orders_headers_and_drops_guards() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        fs::write(
+            dir.join("cbuild.toml"),
+            r#"
+[project]
+name = "mylib"
+version = "0.1.0"
+
+[build]
+compiler = "gcc"
+
+[[targets.static_lib]]
+name = "mylib"
+src = ["src/*.c"]
+include_dirs = ["include"]
+"#,
+        )
+        .unwrap();
+        fs::create_dir_all(dir.join("include")).unwrap();
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(
+            dir.join("include/mylib.h"),
+            "/* 공개 API */\n#ifndef MYLIB_H\n#define MYLIB_H\n\n#include \"types.h\"\n\nmy_int my_add(my_int a, my_int b);\n\n#endif /* MYLIB_H */\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("include/types.h"),
+            "#pragma once\n\ntypedef int my_int;\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("src/internal.h"),
+            "#if !defined(INTERNAL_H)\n#define INTERNAL_H\n#include <mylib.h>\n#define MY_TWICE(x) my_add((x), (x))\n#endif\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("src/lib.c"),
+            "#include <stdio.h>\n#include <mylib.h>\n#include \"internal.h\"\n\nmy_int my_add(my_int a, my_int b) { return a + b; }\nint my_twice(my_int x) { return MY_TWICE(x); }\n",
+        )
+        .unwrap();
+
+        let out_dir = dir.join("out");
+        amalgamate(dir, "mylib", Some(out_dir.clone()), None).unwrap();
+        let header = fs::read_to_string(out_dir.join("mylib.h")).unwrap();
+        let source = fs::read_to_string(out_dir.join("mylib.c")).unwrap();
+
+        // 의존하는 헤더가 먼저
+        let types = header.find("Begin file include/types.h").unwrap();
+        let mylib = header.find("Begin file include/mylib.h").unwrap();
+        assert!(types < mylib);
+        assert!(source.contains("Begin file src/internal.h"));
+        assert!(!source.contains("Begin file include/"));
+
+        // 합친 헤더의 가드와 #pragma once는 주석으로 남고 출력 전체의 가드만 유지
+        assert!(uncommented(&header, "#pragma once").is_empty());
+        assert_eq!(
+            uncommented(&header, "#ifndef"),
+            vec!["#ifndef MYLIB_AMALGAMATION_H"]
+        );
+        assert_eq!(uncommented(&header, "#endif").len(), 1);
+        assert!(uncommented(&source, "#if").is_empty());
+        assert!(uncommented(&source, "#endif").is_empty());
+        assert_eq!(
+            uncommented(&source, "#include"),
+            vec!["#include \"mylib.h\"", "#include <stdio.h>"]
+        );
+        assert!(source.contains("#define MY_TWICE(x)"));
+
+        let status = std::process::Command::new("gcc")
+            .arg("-fsyntax-only")
+            .arg("-Werror")
+            .arg(out_dir.join("mylib.c"))
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
+}
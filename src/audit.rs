@@ -0,0 +1,325 @@
+use crate::config::{BuildConfig, Dependency};
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use crate::retry::RetryPolicy;
+use crate::utils::{is_tool_installed, to_absolute_path};
+use crate::vcs::head_commit;
+use colored::Colorize;
+use serde::Deserialize;
+use std::cmp::Ordering;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+const OSV_QUERY_URL: &str = "https://api.osv.dev/v1/query";
+
+/// 오프라인 권고 데이터베이스의 항목 ([audit] database, JSON 배열)
+///
+/// `versions`에 있거나 `introduced` 이상 `fixed` 미만인 버전, 또는 `commits`에 있는
+/// 커밋을 쓰는 의존성이 영향을 받는다.
+#[derive(Debug, Deserialize)]
+struct Advisory {
+    id: String,
+    package: String,
+    #[serde(default)]
+    summary: String,
+    #[serde(default)]
+    aliases: Vec<String>,
+    #[serde(default)]
+    versions: Vec<String>,
+    introduced: Option<String>,
+    fixed: Option<String>,
+    #[serde(default)]
+    commits: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvResponse {
+    #[serde(default)]
+    vulns: Vec<OsvVulnerability>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvVulnerability {
+    id: String,
+    #[serde(default)]
+    summary: String,
+    #[serde(default)]
+    aliases: Vec<String>,
+}
+
+/// 발견한 취약점 하나
+struct Finding {
+    dependency: String,
+    version: String,
+    id: String,
+    aliases: Vec<String>,
+    summary: String,
+}
+
+/// `cbuild audit`: 의존성을 권고 데이터베이스와 대조해 알려진 취약점 보고
+///
+/// git 의존성은 deps/에 받은 커밋으로 OSV API(api.osv.dev)에 조회하고, 버전이나 커밋은
+/// [audit] database의 오프라인 데이터베이스와도 대조한다. `offline`이면 OSV를 조회하지
+/// 않는다. [audit] ignore와 `ignore`에 있는 ID(별칭 포함)는 보고만 하고 실패로 세지 않는다.
+/// 무시하지 않은 취약점 수를 반환한다.
+pub fn audit(project_dir: &Path, offline: bool, ignore: &[String]) -> BuildResult<usize> {
+    let config = BuildConfig::from_file(project_dir)?;
+    let advisories = match config.audit.database {
+        Some(ref database) => load_database(&to_absolute_path(Path::new(database), project_dir))?,
+        None => Vec::new(),
+    };
+    if offline && config.audit.database.is_none() {
+        return Err(BuildError::ConfigParsingError(tr!(
+            "--offline에는 [audit] database가 필요합니다",
+            "--offline requires [audit] database"
+        )));
+    }
+    let retry = RetryPolicy::from_settings(&config.retry);
+
+    let names: Vec<&String> = config.all_dependencies().map(|(name, _)| name).collect();
+    println!(
+        "{} {}",
+        "Auditing".green().bold(),
+        tr!("의존성 {}개", "{} dependencies", names.len())
+    );
+
+    let mut findings = Vec::new();
+    let mut unchecked = Vec::new();
+    for name in names {
+        let dependency = config.find_dependency(name).unwrap();
+        let version = dependency_version(dependency);
+        let commit = checkout_commit(project_dir, name, dependency);
+
+        for advisory in advisories.iter().filter(|a| a.package == *name) {
+            if advisory.affects(&version, commit.as_deref()) {
+                findings.push(Finding {
+                    dependency: name.clone(),
+                    version: version.clone(),
+                    id: advisory.id.clone(),
+                    aliases: advisory.aliases.clone(),
+                    summary: advisory.summary.clone(),
+                });
+            }
+        }
+
+        match commit {
+            Some(ref commit) if !offline => {
+                for vulnerability in query_osv(&config, &retry, commit)? {
+                    if findings
+                        .iter()
+                        .any(|f| f.dependency == *name && f.id == vulnerability.id)
+                    {
+                        continue;
+                    }
+                    findings.push(Finding {
+                        dependency: name.clone(),
+                        version: commit[..12.min(commit.len())].to_string(),
+                        id: vulnerability.id,
+                        aliases: vulnerability.aliases,
+                        summary: vulnerability.summary,
+                    });
+                }
+            }
+            // 버전만 있는 의존성은 오프라인 데이터베이스로만 확인할 수 있음
+            _ if advisories.is_empty() => unchecked.push(name.clone()),
+            _ => {}
+        }
+    }
+
+    let mut ignore: Vec<&String> = ignore.iter().collect();
+    ignore.extend(config.audit.ignore.iter().flatten());
+
+    let mut failures = 0;
+    for finding in &findings {
+        let ignored = ignore
+            .iter()
+            .any(|id| **id == finding.id || finding.aliases.contains(id));
+        let ids = std::iter::once(finding.id.as_str())
+            .chain(finding.aliases.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let label = if ignored {
+            "ignored".yellow().bold()
+        } else {
+            failures += 1;
+            "vulnerable".red().bold()
+        };
+        println!(
+            "{} {} {} ({})",
+            label, finding.dependency, finding.version, ids
+        );
+        if !finding.summary.is_empty() {
+            println!("    {}", finding.summary);
+        }
+    }
+
+    if !unchecked.is_empty() {
+        println!(
+            "{}",
+            tr!(
+                "권고 데이터베이스 없이 확인할 수 없는 의존성: {} ([audit] database 설정 또는 `cbuild dependencies`로 git 의존성 설치)",
+                "not checked without an advisory database: {} (set [audit] database or install git dependencies with `cbuild dependencies`)",
+                unchecked.join(", ")
+            )
+            .yellow()
+        );
+    }
+
+    if failures == 0 {
+        println!(
+            "{}",
+            tr!(
+                "알려진 취약점이 없습니다 (무시 {}개).",
+                "No known vulnerabilities ({} ignored).",
+                findings.len()
+            )
+            .green()
+        );
+    } else {
+        println!(
+            "\n{}",
+            tr!(
+                "취약점 {}개를 찾았습니다.",
+                "{} vulnerabilities found.",
+                failures
+            )
+            .red()
+        );
+    }
+
+    Ok(failures)
+}
+
+impl Advisory {
+    fn affects(&self, version: &str, commit: Option<&str>) -> bool {
+        if let Some(commit) = commit {
+            if self.commits.iter().any(|c| commit.starts_with(c.as_str())) {
+                return true;
+            }
+        }
+        if self.versions.iter().any(|v| v == version) {
+            return true;
+        }
+        if self.introduced.is_none() && self.fixed.is_none() {
+            return false;
+        }
+        let after_introduced = self
+            .introduced
+            .as_deref()
+            .is_none_or(|introduced| compare_versions(version, introduced) != Ordering::Less);
+        let before_fixed = self
+            .fixed
+            .as_deref()
+            .is_none_or(|fixed| compare_versions(version, fixed) == Ordering::Less);
+        after_introduced && before_fixed
+    }
+}
+
+/// 권고와 대조할 버전 (git 태그가 있으면 태그, 앞의 `v` 제외)
+fn dependency_version(dependency: &Dependency) -> String {
+    let version = dependency.tag.as_deref().unwrap_or(&dependency.version);
+    version.strip_prefix('v').unwrap_or(version).to_string()
+}
+
+/// deps/<이름>에 받은 git 의존성의 커밋
+fn checkout_commit(project_dir: &Path, name: &str, dependency: &Dependency) -> Option<String> {
+    let dir = project_dir.join("deps").join(name);
+    // 의존성 디렉토리가 git 저장소가 아니면 상위 프로젝트의 커밋이 나오므로 확인
+    if dependency.git.is_none() || !dir.join(".git").exists() {
+        return None;
+    }
+    head_commit(&dir)
+}
+
+fn load_database(path: &Path) -> BuildResult<Vec<Advisory>> {
+    let content = std::fs::read_to_string(path).map_err(BuildError::IoError)?;
+    serde_json::from_str(&content).map_err(|e| {
+        BuildError::ConfigParsingError(tr!(
+            "권고 데이터베이스 {}를 읽을 수 없습니다: {}",
+            "cannot parse advisory database {}: {}",
+            path.display(),
+            e
+        ))
+    })
+}
+
+/// OSV API에 커밋으로 조회 (curl 사용)
+fn query_osv(
+    config: &BuildConfig,
+    retry: &RetryPolicy,
+    commit: &str,
+) -> BuildResult<Vec<OsvVulnerability>> {
+    if !is_tool_installed("curl") {
+        return Err(BuildError::DownloadError(tr!(
+            "OSV 조회에 curl이 필요합니다 (--offline으로 오프라인 데이터베이스만 사용)",
+            "curl is required to query OSV (use --offline for the offline database only)"
+        )));
+    }
+
+    let body = serde_json::json!({ "commit": commit }).to_string();
+    let response = retry
+        .run(OSV_QUERY_URL, || {
+            let mut cmd = Command::new("curl");
+            cmd.args([
+                "--fail",
+                "--silent",
+                "--show-error",
+                "--header",
+                "Content-Type: application/json",
+                "--data-binary",
+                "@-",
+            ]);
+            if let Some(ref proxy) = config.download.proxy {
+                cmd.arg("--proxy").arg(proxy);
+            }
+            cmd.arg(OSV_QUERY_URL)
+                .stdin(Stdio::piped())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped());
+
+            let mut child = cmd.spawn().map_err(|e| e.to_string())?;
+            if let Some(mut stdin) = child.stdin.take() {
+                stdin
+                    .write_all(body.as_bytes())
+                    .map_err(|e| e.to_string())?;
+            }
+            let output = child.wait_with_output().map_err(|e| e.to_string())?;
+            if !output.status.success() {
+                return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+            }
+            Ok(output.stdout)
+        })
+        .map_err(BuildError::DownloadError)?;
+
+    let response: OsvResponse = serde_json::from_slice(&response).map_err(|e| {
+        BuildError::DownloadError(tr!(
+            "OSV 응답을 읽을 수 없습니다: {}",
+            "cannot parse OSV response: {}",
+            e
+        ))
+    })?;
+    Ok(response.vulns)
+}
+
+/// 점으로 구분한 버전 비교 (숫자 부분은 숫자로, 나머지는 문자열로)
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    let parts = |v: &str| -> Vec<String> {
+        v.split(['.', '-', '_'])
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    };
+    let (a, b) = (parts(a), parts(b));
+    for index in 0..a.len().max(b.len()) {
+        let x = a.get(index).map_or("0", String::as_str);
+        let y = b.get(index).map_or("0", String::as_str);
+        let ordering = match (x.parse::<u64>(), y.parse::<u64>()) {
+            (Ok(x), Ok(y)) => x.cmp(&y),
+            _ => x.cmp(y),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    Ordering::Equal
+}
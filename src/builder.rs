@@ -1,62 +1,360 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::ffi::OsString;
+use std::io::{IsTerminal, Write};
 use std::path::{Path, PathBuf};
-use std::process::Command;
-use std::sync::Arc;
+use std::process::{Command, ExitStatus, Output};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
 use colored::Colorize;
 use dashmap::DashMap;
-use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
-use log::{debug, error, info, warn};
+use log::{debug, info, warn};
 use rayon::prelude::*;
 use sha2::{Digest, Sha256};
-use walkdir::WalkDir;
 
-use crate::config::{BuildConfig, ExecutableTarget, LibraryTarget, TestTarget};
-use crate::error::{BuildError, BuildResult};
+use crate::buildlog::{self, BuildLog};
+use crate::cmdlog::{self, CommandLog};
+use crate::config::{BuildConfig, ExecutableTarget, RpathMode, GENERATED_DIR_PLACEHOLDER};
+use crate::container::Container;
+use crate::context::ProjectContext;
+use crate::crash;
+use crate::custom::{self, CustomStage};
+use crate::diagnostics::{
+    collect_warnings, emit_annotations, parse_compiler_output, warning_option, AnnotationFormat,
+};
+use crate::distributed::Distributor;
+use crate::embed::generate_embedded_sources;
+use crate::error::{BuildError, BuildResult, CommandFailure};
+use crate::executor::{Action, ActionExecutor, LocalExecutor};
+use crate::generated;
+use crate::history;
+use crate::i18n::tr;
+use crate::jobserver::JobServer;
+use crate::layout::BuildLayout;
+use crate::memory::{parse_memory_size, MemoryGovernor};
 use crate::parser::DependencyParser;
+use crate::pch::PchCache;
+use crate::progress::{CompileProgress, ProgressMode};
+use crate::remote_exec::RemoteExecutor;
+use crate::report::{self, BuildSummary, WarningRecord};
+use crate::sarif::write_sarif;
+use crate::signing;
+use crate::state::{FileHash, IncrementalState};
+use crate::toolchain::{self, OpenMpFlags, Toolchain};
+use crate::tools;
+use crate::utils::{
+    copy_file_with_dirs, get_extension, glob_in, hash_file_content, is_newer_than, is_objc_source,
+    is_tool_installed, long_path, normalize_path, page_output, path_flag, relative_path_between,
+    substitute_paths, to_absolute_path, tool_command, write_if_changed,
+};
+use crate::vcs::changed_files_since;
+
+/// IDE 및 외부 도구 통합에 사용하는 타겟별 컴파일 정보
+pub struct TargetCompileInfo {
+    pub target_type: String,
+    pub name: String,
+    pub sources: Vec<PathBuf>,
+    /// 빌드에서 사용하는 것과 동일한 컴파일 플래그
+    pub flags: Vec<String>,
+}
+
+/// 명령 단계(pgo 등)가 설정 파일과 별도로 덧붙이는 컴파일/링크 플래그
+#[derive(Debug, Clone, Default)]
+pub struct ExtraFlags {
+    pub compile: Vec<OsString>,
+    pub link: Vec<OsString>,
+    /// 플래그 외에 증분 빌드 무효화에 반영할 입력의 지문 (예: 프로파일 데이터 해시)
+    pub fingerprint: String,
+}
+
+/// 명령을 실행하지 않고 기록만 하는 빌드 상태
+struct DryRun {
+    json_path: Option<PathBuf>,
+}
+
+/// 작업 큐에 넣는 소스 파일 하나의 컴파일
+struct CompileJob<'a> {
+    target_key: &'a str,
+    target_type: &'a str,
+    target_name: &'a str,
+    source_file: &'a PathBuf,
+    /// 작은 값부터 실행 (링크에 필요한 라이브러리 -> 기타 라이브러리 -> 실행 파일 -> 테스트)
+    rank: u8,
+    size: u64,
+}
 
 pub struct Builder {
     project_dir: PathBuf,
-    build_dir: PathBuf,
-    config: Option<BuildConfig>,
+    layout: BuildLayout,
+    out_dir: Option<PathBuf>,
+    context: Arc<ProjectContext>,
+    /// 컨텍스트에서 읽은 프로젝트 설정 (생성할 때 읽으므로 항상 있음)
+    config: Arc<BuildConfig>,
+    sources: BTreeMap<String, Vec<PathBuf>>,
+    embed_include_dirs: HashMap<String, PathBuf>,
     configuration: String,
     jobs: usize,
+    jobserver: Option<JobServer>,
+    adaptive_memory: bool,
+    /// 내용 해시, 플래그 지문, 헤더 의존성으로 바뀐 소스만 컴파일 (기본 켜짐, `--force`로 끔)
     incremental: bool,
+    since: Option<String>,
+    selected_targets: Option<HashSet<String>>,
+    /// `--target`으로 지정한 타겟/별칭 이름
+    target_names: Vec<String>,
+    /// `default = false`인 타겟도 빌드 (`--all-targets`)
+    all_targets: bool,
+    annotations: AnnotationFormat,
+    sarif_path: Option<PathBuf>,
+    deny_warnings: Vec<String>,
+    toolchain: Option<Toolchain>,
+    openmp: Option<OpenMpFlags>,
+    /// [pch]로 미리 컴파일한 헤더 캐시
+    pch: Option<PchCache>,
+    extra_flags: ExtraFlags,
+    container_image: Option<String>,
+    container: Option<Container>,
+    /// `--distributed`: [distributed]의 distcc/icecc로 컴파일 분산
+    distributed: bool,
+    distributor: Option<Distributor>,
+    /// `--remote-exec`: 컴파일을 [remote_execution] 서버에서 실행
+    remote_execution: bool,
+    /// 컴파일 등 빌드 동작을 실행하는 방법 (기본: 로컬)
+    executor: Arc<dyn ActionExecutor>,
+    dry_run: Option<DryRun>,
+    progress: ProgressMode,
+    /// 증분 빌드에서 최신 상태라 다시 컴파일하지 않는 소스 수
+    cached_sources: usize,
+    compiled_sources: usize,
+    /// `cbuild report`에 쓸 이번 빌드의 경고
+    warnings: Mutex<Vec<WarningRecord>>,
+    /// 이번 빌드에서 컴파일에 실패한 소스 (증분 상태에 최신으로 기록하지 않음)
+    failed_sources: Mutex<HashSet<PathBuf>>,
+    command_log: CommandLog,
+    build_log: Option<BuildLog>,
+    /// `--compile-commands`: 프로젝트 루트에 compile_commands.json 작성
+    compile_commands: bool,
     verbose: bool,
 }
 
 impl Builder {
-    pub fn new(project_dir: &Path, configuration: &str, jobs: usize) -> Self {
-        let build_dir = project_dir.join("build").join(configuration);
+    pub fn new(project_dir: &Path, configuration: &str, jobs: usize) -> BuildResult<Self> {
+        Self::with_context(
+            Arc::new(ProjectContext::new(project_dir)),
+            configuration,
+            jobs,
+        )
+    }
 
-        Builder {
-            project_dir: project_dir.to_path_buf(),
-            build_dir,
-            config: None,
+    /// 다른 명령 단계와 설정을 공유하는 빌더 생성 (설정을 읽을 수 없으면 실패)
+    pub fn with_context(
+        context: Arc<ProjectContext>,
+        configuration: &str,
+        jobs: usize,
+    ) -> BuildResult<Self> {
+        let config = context.shared_config()?;
+        let layout = context.layout(configuration, None);
+
+        Ok(Builder {
+            project_dir: context.project_dir().to_path_buf(),
+            layout,
+            out_dir: None,
+            context,
+            config,
+            sources: BTreeMap::new(),
+            embed_include_dirs: HashMap::new(),
             configuration: configuration.to_string(),
             jobs,
-            incremental: false,
+            jobserver: JobServer::from_env(),
+            adaptive_memory: false,
+            incremental: true,
+            since: None,
+            selected_targets: None,
+            target_names: Vec::new(),
+            all_targets: false,
+            annotations: AnnotationFormat::None,
+            sarif_path: None,
+            deny_warnings: Vec::new(),
+            toolchain: None,
+            openmp: None,
+            pch: None,
+            extra_flags: ExtraFlags::default(),
+            container_image: None,
+            container: None,
+            distributed: false,
+            distributor: None,
+            remote_execution: false,
+            executor: Arc::new(LocalExecutor),
+            dry_run: None,
+            progress: ProgressMode::default(),
+            cached_sources: 0,
+            compiled_sources: 0,
+            warnings: Mutex::new(Vec::new()),
+            failed_sources: Mutex::new(HashSet::new()),
+            command_log: CommandLog::default(),
+            build_log: None,
+            compile_commands: false,
             verbose: false,
-        }
+        })
     }
 
     pub fn set_incremental(&mut self, incremental: bool) {
         self.incremental = incremental;
     }
 
+    pub fn set_compile_commands(&mut self, compile_commands: bool) {
+        self.compile_commands = compile_commands;
+    }
+
     pub fn set_verbose(&mut self, verbose: bool) {
         self.verbose = verbose;
     }
 
+    /// 설정 로드, 소스 해석, 변경 감지까지만 하고 실행할 명령을 순서대로 출력 (`--dry-run`)
+    ///
+    /// `json_path`가 있으면 명령 목록을 JSON 파일로도 저장한다. 증분 빌드 상태와
+    /// 빌드 기록은 갱신하지 않는다.
+    pub fn set_dry_run(&mut self, json_path: Option<PathBuf>) {
+        self.dry_run = Some(DryRun { json_path });
+    }
+
+    /// 모든 타겟의 컴파일/링크에 덧붙일 플래그
+    pub fn set_extra_flags(&mut self, extra_flags: ExtraFlags) {
+        self.extra_flags = extra_flags;
+    }
+
+    /// 컴파일 진행 상황 표시 방식 (`--progress`)
+    pub fn set_progress(&mut self, progress: ProgressMode) {
+        self.progress = progress;
+    }
+
+    /// 컴파일러 진단을 CI 주석 형식으로 다시 출력 (`--annotations github`)
+    pub fn set_annotations(&mut self, annotations: AnnotationFormat) {
+        self.annotations = annotations;
+    }
+
+    /// 컴파일러 진단을 SARIF 2.1 파일로 저장 (`--emit-sarif <path>`)
+    pub fn set_sarif_output(&mut self, path: Option<PathBuf>) {
+        self.sarif_path = path;
+    }
+
+    /// 지정한 경고가 발생하면 빌드 실패로 처리 (`--deny-warnings unused-variable,shadow`)
+    ///
+    /// 경고 옵션 이름은 `-W` 접두사 유무와 관계없이 받으며 `all`은 모든 경고를 뜻한다.
+    pub fn set_deny_warnings(&mut self, warnings: Vec<String>) {
+        self.deny_warnings = warnings
+            .iter()
+            .map(|w| w.trim().trim_start_matches("-W").to_string())
+            .filter(|w| !w.is_empty())
+            .collect();
+    }
+
+    /// 컴파일·링크 명령을 지정한 이미지의 컨테이너 안에서 실행 (`--container <image>`)
+    ///
+    /// 컨테이너 빌드에서는 호스트 컴파일러를 탐지하지 않으므로
+    /// 플래그 지원 여부 검사와 진단 색상 플래그를 사용하지 않는다.
+    pub fn set_container(&mut self, image: Option<String>) {
+        self.container_image = image;
+    }
+
+    /// [distributed] 설정으로 컴파일을 원격 호스트에 분산 (`--distributed`)
+    ///
+    /// [distributed] enabled = true이면 지정하지 않아도 분산한다.
+    pub fn set_distributed(&mut self, distributed: bool) {
+        self.distributed = distributed;
+    }
+
+    /// (실험적) 컴파일을 [remote_execution] 서버에서 실행 (`--remote-exec`)
+    pub fn set_remote_execution(&mut self, remote_execution: bool) {
+        self.remote_execution = remote_execution;
+    }
+
+    /// git 참조 이후 변경의 영향을 받는 타겟만 빌드 (`--since <ref>`)
+    pub fn set_since(&mut self, git_ref: Option<String>) {
+        self.since = git_ref;
+    }
+
+    /// 지정한 타겟(또는 별칭)과 링크에 필요한 라이브러리만 빌드
+    pub fn set_targets(&mut self, names: Vec<String>) {
+        self.target_names = names;
+    }
+
+    /// 기본 빌드에서 제외된 타겟(`default = false`, 예제)까지 모두 빌드
+    pub fn set_all_targets(&mut self, all_targets: bool) {
+        self.all_targets = all_targets;
+    }
+
+    /// 가용 메모리에 따라 동시 컴파일 수 조절 (`--jobs auto-mem`)
+    pub fn set_adaptive_memory(&mut self, adaptive_memory: bool) {
+        self.adaptive_memory = adaptive_memory;
+    }
+
+    /// 명령줄에서 지정한 출력 디렉토리 (build.output_dir보다 우선)
+    pub fn set_output_dir(&mut self, out_dir: Option<PathBuf>) {
+        self.out_dir = out_dir;
+        self.layout = self
+            .context
+            .layout(&self.configuration, self.out_dir.as_deref());
+    }
+
     pub fn build(&mut self) -> BuildResult<()> {
+        let start_time = Instant::now();
+        let result = self.build_targets();
+
+        if self.dry_run.is_none() {
+            self.save_summary(start_time.elapsed().as_secs_f32(), &result);
+        }
+
+        if let Some(log) = self.build_log.take() {
+            match result {
+                Ok(()) => log.line(&format!(
+                    "\nbuild finished ({:.2}s)",
+                    start_time.elapsed().as_secs_f32()
+                )),
+                Err(ref e) => {
+                    log.line(&format!(
+                        "\nbuild failed ({:.2}s): {}",
+                        start_time.elapsed().as_secs_f32(),
+                        e
+                    ));
+                    println!(
+                        "{} {}",
+                        tr!("빌드 로그:", "Build log:").yellow(),
+                        log.path().display()
+                    );
+                }
+            }
+            log.flush();
+        }
+
+        // 실패한 빌드도 원인을 추적할 수 있도록 실행한 명령을 기록
+        if self.dry_run.is_none() && self.command_log.len() > 0 {
+            if let Err(e) = self.command_log.write(&self.layout, &self.configuration) {
+                warn!(
+                    "{}",
+                    tr!(
+                        "명령 기록을 저장할 수 없습니다: {}",
+                        "cannot save command log: {}",
+                        e
+                    )
+                );
+            }
+        }
+
+        // 실패하면 호출자가 process::exit로 종료해 Drop이 실행되지 않으므로 여기서 제거
+        self.container = None;
+        result
+    }
+
+    fn build_targets(&mut self) -> BuildResult<()> {
         let start_time = Instant::now();
         info!("빌드 시작: {}", self.project_dir.display());
 
         // 설정 로드
-        self.config = Some(BuildConfig::from_file(&self.project_dir)?);
-        let config = self.config.as_ref().unwrap();
+        self.load_project()?;
+        self.open_build_log();
+        let config = self.config();
 
         println!(
             "{} {} v{}",
@@ -65,450 +363,2881 @@ impl Builder {
             config.project.version
         );
 
-        // 컴파일러 확인
-        self.check_compiler()?;
+        // 컴파일러와 아카이버 등 필요한 도구 확인
+        // (--dry-run은 컨테이너를 시작하지 않고 로컬 툴체인 기준으로 계획을 출력)
+        if let Some(image) = self
+            .container_image
+            .clone()
+            .filter(|_| self.dry_run.is_none())
+        {
+            self.start_container(&image)?;
+        } else {
+            tools::check_tools(config)?;
+            self.check_compiler()?;
+            if self.verbose {
+                toolchain::print_report(self.config(), self.toolchain.as_ref().unwrap());
+            }
+
+            // 원격 슬롯만큼 동시 작업 수를 늘리고, 슬롯이 모두 차면 로컬에서 컴파일
+            let settings = &self.config().distributed;
+            if self.distributed || settings.enabled.unwrap_or(false) {
+                let distributor = Distributor::from_config(settings, self.jobs)?;
+                self.jobs += distributor.remote_slots();
+                println!(
+                    "{} {}",
+                    tr!("분산 컴파일", "Distributing").green().bold(),
+                    distributor.describe()
+                );
+                self.distributor = Some(distributor);
+            }
+
+            if self.remote_execution {
+                let executor = RemoteExecutor::from_config(self.config(), &self.project_dir)?;
+                println!(
+                    "{} {}",
+                    tr!("원격 실행 (실험적)", "Remote execution (experimental)")
+                        .green()
+                        .bold(),
+                    executor.endpoint()
+                );
+                self.executor = Arc::new(executor);
+            }
+        }
 
         // 빌드 디렉토리 준비
-        self.prepare_build_directory()?;
+        if self.dry_run.is_none() {
+            self.prepare_build_directory()?;
+        }
+
+        // 생성된 소스를 쓸 수 있도록 컴파일 전 사용자 정의 명령 실행
+        self.run_custom_targets(CustomStage::BeforeCompile)?;
 
         // 소스 파일 해결
-        let source_files = config.resolve_source_files(&self.project_dir)?;
+        let mut source_files = self.resolve_sources()?;
+        self.check_interfaces()?;
+        self.check_dev_dependencies()?;
+        self.context.dependency_features()?;
+        if self.container.is_none() {
+            self.check_objc_compiler()?;
+        }
+
+        // 편집기용 컴파일 데이터베이스 (타겟 선택 전이라 모든 소스 포함)
+        if self.compile_commands && self.dry_run.is_none() {
+            self.write_compile_commands(&self.project_dir.join("compile_commands.json"))?;
+        }
+
+        // --target으로 지정한 타겟만 선택
+        if !self.target_names.is_empty() {
+            let mut selected = self.config().resolve_target_keys(&self.target_names)?;
+            let required = self.unbuilt_link_dependencies(&selected);
+            selected.extend(required);
+
+            let mut names: Vec<_> = selected.iter().cloned().collect();
+            names.sort();
+            println!(
+                "{}: {}",
+                tr!("선택한 타겟", "Selected targets").blue().bold(),
+                names.join(", ")
+            );
+
+            source_files.retain(|key, _| selected.contains(key));
+            self.selected_targets = Some(selected);
+        } else if !self.all_targets {
+            if let Some(selected) = self.default_targets() {
+                source_files.retain(|key, _| selected.contains(key));
+                self.selected_targets = Some(selected);
+            }
+        }
+
+        // git 참조 이후 변경의 영향을 받는 타겟만 선택
+        if let Some(git_ref) = self.since.clone() {
+            let changed = self.changed_files_since_ref(&git_ref)?;
+            let mut impacted = self.impacted_targets(&changed)?;
+            if let Some(ref selected) = self.selected_targets {
+                impacted.retain(|key| selected.contains(key));
+            }
+            if impacted.is_empty() {
+                println!(
+                    "{}",
+                    tr!(
+                        "{} 이후 변경의 영향을 받는 타겟이 없습니다.",
+                        "No targets are affected by changes since {}.",
+                        git_ref
+                    )
+                    .green()
+                );
+                return Ok(());
+            }
+
+            // 링크에 필요한 내부 라이브러리가 아직 빌드되지 않았으면 함께 빌드
+            let required = self.unbuilt_link_dependencies(&impacted);
+            impacted.extend(required);
+
+            let mut names: Vec<_> = impacted.iter().cloned().collect();
+            names.sort();
+            println!(
+                "{}: {}",
+                tr!("영향을 받는 타겟", "Affected targets").blue().bold(),
+                names.join(", ")
+            );
+
+            source_files.retain(|key, _| impacted.contains(key));
+            self.selected_targets = Some(impacted);
+        }
 
         // 파일 변경 검사 (증분 빌드)
+        let (changed_files, mut state) = self.detect_changed_files(&source_files)?;
         let changed_files = if self.incremental {
-            self.detect_changed_files(&source_files)?
+            changed_files
         } else {
-            // 증분 빌드가 아니면 모든 파일을 변경된 것으로 간주
+            // --force: 모든 파일을 변경된 것으로 간주 (다음 증분 빌드를 위해 상태는 기록)
             source_files.clone()
         };
 
-        if changed_files.is_empty() {
-            println!("{}", "모든 파일이 최신 상태입니다.".green());
-            return Ok(());
+        // 링크 입력 지문은 링크에 성공한 뒤에 기록
+        let link_fingerprints = self.link_fingerprints(&source_files);
+        let relink = link_fingerprints
+            .iter()
+            .any(|(key, fingerprint)| state.fingerprints.get(key) != Some(fingerprint));
+
+        if changed_files.is_empty() && !relink && !self.missing_outputs(&source_files) {
+            println!(
+                "{}",
+                tr!("모든 파일이 최신 상태입니다.", "All files are up to date.").green()
+            );
+            self.run_custom_targets(CustomStage::AfterLink)?;
+            return self.print_plan();
         }
 
         // 컴파일
-        self.compile_sources(&changed_files)?;
+        let count =
+            |files: &BTreeMap<String, Vec<PathBuf>>| files.values().map(Vec::len).sum::<usize>();
+        self.compiled_sources = count(&changed_files);
+        self.cached_sources = count(&source_files) - self.compiled_sources;
+        let compiled = self.compile_sources(&changed_files);
+        if self.dry_run.is_none() {
+            // 실패한 소스는 기록하지 않아 다음 빌드에서 다시 컴파일
+            self.save_incremental_state(&mut state, &changed_files)?;
+        }
+        compiled?;
+
+        if self.dry_run.is_none() && self.config().build.strict_includes.unwrap_or(false) {
+            self.check_include_policy(&source_files, &state)?;
+        }
 
         // 링크
         self.link_targets()?;
+        if self.dry_run.is_none() {
+            // 서명에 실패하면 다음 빌드에서 다시 링크하고 서명하도록 지문 기록 전에 서명
+            self.sign_artifacts(&source_files)?;
+            state.fingerprints.extend(link_fingerprints);
+            state.save(self.layout.build_dir())?;
+        }
+
+        // 빌드 결과물에 의존하는 사용자 정의 명령 실행
+        self.run_custom_targets(CustomStage::AfterLink)?;
+
+        if self.dry_run.is_some() {
+            return self.print_plan();
+        }
 
         let duration = start_time.elapsed();
+
+        // 빌드 기록 저장 (실패해도 빌드 결과에는 영향 없음)
+        let retention = self
+            .config()
+            .build
+            .history_retention
+            .unwrap_or(history::DEFAULT_RETENTION);
+        if retention > 0 {
+            match history::record_build(
+                &self.layout,
+                &self.project_dir,
+                &self.configuration,
+                duration.as_secs_f32(),
+                retention,
+            ) {
+                Ok(manifest) => debug!("빌드 기록 저장: {}", manifest.id),
+                Err(e) => warn!(
+                    "{}",
+                    tr!(
+                        "빌드 기록을 저장할 수 없습니다: {}",
+                        "cannot save build history: {}",
+                        e
+                    )
+                ),
+            }
+        }
+
         println!(
-            "{} ({}초)",
-            "빌드 완료".green().bold(),
-            duration.as_secs_f32()
+            "{} {}",
+            tr!("빌드 완료", "Build finished").green().bold(),
+            tr!("({}초)", "({}s)", duration.as_secs_f32())
         );
 
         Ok(())
     }
 
-    pub fn clean(&self) -> BuildResult<()> {
-        info!("정리 중: {}", self.build_dir.display());
+    /// 오브젝트 생성이나 링크 없이 `-fsyntax-only`로 문법만 검사
+    ///
+    /// `changed_only`가 참이면 마지막 증분 빌드 이후 변경된 파일만 검사한다.
+    pub fn check(&mut self, changed_only: bool) -> BuildResult<()> {
+        let start_time = Instant::now();
+
+        self.load_project()?;
+        let config = self.config();
+
+        println!(
+            "{} {} v{}",
+            "Checking".green().bold(),
+            config.project.name,
+            config.project.version
+        );
 
-        if self.build_dir.exists() {
-            std::fs::remove_dir_all(&self.build_dir).map_err(|e| BuildError::IoError(e))?;
-            println!("{}: {}", "정리 완료".green(), self.build_dir.display());
+        self.check_compiler()?;
+        self.prepare_build_directory()?;
+
+        let source_files = self.resolve_sources()?;
+        let source_files = if changed_only {
+            self.detect_changed_files(&source_files)?.0
         } else {
-            println!("{}: 이미 정리되어 있습니다", "정리".green());
+            source_files
+        };
+
+        let mut units: Vec<(&str, &str, &PathBuf)> = Vec::new();
+        for (target, files) in &source_files {
+            let (target_type, target_name) = target.split_once(':').unwrap_or(("", target));
+            for file in files {
+                units.push((target_type, target_name, file));
+            }
+        }
+
+        if units.is_empty() {
+            println!(
+                "{}",
+                tr!("검사할 파일이 없습니다.", "No files to check.").green()
+            );
+            return Ok(());
         }
 
+        println!(
+            "{} {}",
+            tr!("문법 검사 중", "Checking syntax").blue().bold(),
+            tr!(
+                "({}개 파일, {}개 스레드 사용)",
+                "({} files, {} threads)",
+                units.len(),
+                self.jobs
+            )
+        );
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(self.jobs.max(1))
+            .build()
+            .map_err(|e| BuildError::CompilerError(e.to_string()))?;
+
+        let governor = self.memory_governor()?;
+        let outputs: DashMap<PathBuf, String> = DashMap::new();
+        let failures: Vec<(PathBuf, String)> = pool.install(|| {
+            units
+                .par_iter()
+                .filter_map(|(target_type, target_name, source_file)| {
+                    let flags = self.compile_flags(target_type, target_name);
+                    let mut cmd = tool_command(self.source_compiler(source_file));
+                    cmd.arg("-fsyntax-only").arg(source_file);
+                    cmd.args(self.source_flags(source_file, flags));
+
+                    if self.verbose {
+                        println!("Checking: {:?}", cmd);
+                    }
+
+                    let _reservation = governor.as_ref().map(|g| g.reserve(source_file));
+
+                    let _token = match self.jobserver {
+                        Some(ref jobserver) => match jobserver.acquire() {
+                            Ok(token) => Some(token),
+                            Err(e) => return Some(((*source_file).clone(), e.to_string())),
+                        },
+                        None => None,
+                    };
+
+                    match cmd.output() {
+                        Ok(output) => {
+                            let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+                            if !stderr.is_empty() {
+                                outputs.insert((*source_file).clone(), stderr.clone());
+                            }
+
+                            if output.status.success() {
+                                None
+                            } else {
+                                Some(((*source_file).clone(), stderr))
+                            }
+                        }
+                        Err(e) => Some(((*source_file).clone(), e.to_string())),
+                    }
+                })
+                .collect()
+        });
+
+        // 보고서와 경고 출력이 실행마다 같도록 경로 순으로 정렬
+        let outputs: BTreeMap<PathBuf, String> = outputs.into_iter().collect();
+        emit_annotations(
+            self.annotations,
+            outputs.values().map(|output| output.as_str()),
+            &self.project_dir,
+        );
+        self.write_diagnostics_report(outputs.values().cloned())?;
+        let denied = self.report_warnings(outputs.values().cloned());
+
+        if !failures.is_empty() {
+            println!("\n{}", tr!("문법 오류:", "Syntax errors:").red().bold());
+            for (file, message) in &failures {
+                println!("{}: \n{}", file.display().to_string().yellow(), message);
+            }
+            return Err(BuildError::CompilerError(tr!(
+                "{}개 파일에서 문법 오류 발생",
+                "syntax errors in {} files",
+                failures.len()
+            )));
+        }
+        denied?;
+
+        println!(
+            "{} {}",
+            tr!("문법 검사 완료", "Syntax check finished")
+                .green()
+                .bold(),
+            tr!("({}초)", "({}s)", start_time.elapsed().as_secs_f32())
+        );
+
         Ok(())
     }
 
-    fn check_compiler(&self) -> BuildResult<()> {
-        let config = self.config.as_ref().unwrap();
-        let compiler = &config.build.compiler;
+    /// `cbuild verify`: 다시 빌드하지 않고 결과물이 마지막 빌드 이후 그대로인지 확인
+    ///
+    /// 소스·헤더·설정에서 다시 계산한 지문을 증분 빌드 상태와 비교해 다시 빌드해야 하는
+    /// 타겟을 찾고, 결과물의 해시를 마지막 빌드 매니페스트와 비교해 없거나 빌드 밖에서
+    /// 바뀐 파일을 찾는다. 발견한 문제 수를 반환한다.
+    pub fn verify(&mut self) -> BuildResult<usize> {
+        self.load_project()?;
+        let config = self.config();
+
+        println!(
+            "{} {} v{} ({})",
+            "Verifying".green().bold(),
+            config.project.name,
+            config.project.version,
+            self.configuration
+        );
+
+        self.check_compiler()?;
+        let mut source_files = self.resolve_sources()?;
+        if let Some(selected) = self.default_targets() {
+            source_files.retain(|key, _| selected.contains(key));
+        }
+
+        let (changed_files, state) = self.detect_changed_files(&source_files)?;
+        let link_fingerprints = self.link_fingerprints(&source_files);
+        let mut problems = 0;
+
+        let mut keys: Vec<&String> = source_files.keys().collect();
+        keys.sort();
+        for key in keys {
+            let changed = changed_files.get(key).map_or(0, Vec::len);
+            let link_key = format!("link:{}", key);
+            let reason = if changed > 0 {
+                tr!(
+                    "소스 {}개가 마지막 빌드 이후 바뀜",
+                    "{} sources changed since the last build",
+                    changed
+                )
+            } else if state.fingerprints.get(&link_key) != link_fingerprints.get(&link_key) {
+                tr!(
+                    "링크 설정 또는 소스 목록이 바뀜",
+                    "link settings or source list changed"
+                )
+            } else {
+                continue;
+            };
+            problems += 1;
+            println!("{:>10} {} ({})", "stale".yellow().bold(), key, reason);
+        }
 
-        let output = Command::new(compiler).arg("--version").output();
+        let manifest = history::list_manifests(&self.layout)?
+            .into_iter()
+            .rev()
+            .find(|manifest| manifest.configuration == self.configuration);
+        let artifacts = manifest.map(|m| m.artifacts).unwrap_or_default();
+        if artifacts.is_empty() {
+            problems += 1;
+            println!(
+                "{:>10} {}",
+                "missing".red().bold(),
+                tr!(
+                    "이 구성의 빌드 기록이 없습니다 (cbuild build로 빌드하세요)",
+                    "no recorded build for this configuration (run cbuild build)"
+                )
+            );
+        }
+        for (name, record) in &artifacts {
+            let path = self.layout.output_root().join(name);
+            if !path.exists() {
+                problems += 1;
+                println!("{:>10} {}", "missing".red().bold(), name);
+                continue;
+            }
 
-        match output {
-            Ok(output) if output.status.success() => {
-                let version = String::from_utf8_lossy(&output.stdout);
-                info!(
-                    "컴파일러: {} ({})",
-                    compiler,
-                    version.lines().next().unwrap_or("")
+            let actual = hash_file_content(&path).map_err(BuildError::IoError)?;
+            if actual != record.sha256 {
+                problems += 1;
+                println!(
+                    "{:>10} {} ({})",
+                    "modified".red().bold(),
+                    name,
+                    tr!(
+                        "기록: {}, 현재: {}",
+                        "recorded {}, found {}",
+                        &record.sha256[..12.min(record.sha256.len())],
+                        &actual[..12.min(actual.len())]
+                    )
                 );
-                Ok(())
             }
-            _ => Err(BuildError::CompilerNotFound(compiler.clone())),
         }
+
+        // [signing]: 코드 서명과 패키지의 GPG 서명 확인
+        let config = self.config();
+        if signing::is_enabled(config, &self.configuration) {
+            let mut signed: Vec<PathBuf> = Vec::new();
+            if signing::signs_binaries(&config.signing) {
+                signed.extend(self.signable_artifacts(&source_files));
+            }
+            let package_dir = self.layout.build_dir().join("package");
+            let archives: Vec<PathBuf> = if config.signing.gpg_key.is_some() {
+                std::fs::read_dir(&package_dir)
+                    .into_iter()
+                    .flatten()
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .filter(|path| get_extension(path).as_deref() == Some("appimage"))
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
+            for path in signed.iter().filter(|path| path.exists()) {
+                if let Err(reason) = signing::verify_binary(&config.signing, path) {
+                    problems += 1;
+                    println!(
+                        "{:>10} {} ({})",
+                        "unsigned".red().bold(),
+                        path.display(),
+                        reason
+                    );
+                }
+            }
+            for path in &archives {
+                if let Err(reason) = signing::verify_archive(path) {
+                    problems += 1;
+                    println!(
+                        "{:>10} {} ({})",
+                        "unsigned".red().bold(),
+                        path.display(),
+                        reason
+                    );
+                }
+            }
+        }
+
+        if problems == 0 {
+            println!(
+                "{}",
+                tr!(
+                    "결과물 {}개가 마지막 빌드와 일치합니다.",
+                    "{} artifacts match the last build.",
+                    artifacts.len()
+                )
+                .green()
+            );
+        } else {
+            println!(
+                "\n{}",
+                tr!(
+                    "문제 {}개를 찾았습니다. cbuild build로 다시 빌드하세요.",
+                    "{} problems found. Run cbuild build to rebuild.",
+                    problems
+                )
+                .red()
+            );
+        }
+
+        Ok(problems)
     }
 
-    fn prepare_build_directory(&self) -> BuildResult<()> {
-        if !self.build_dir.exists() {
-            std::fs::create_dir_all(&self.build_dir).map_err(|e| BuildError::IoError(e))?;
+    /// 빌드에 사용되는 것과 동일한 플래그로 전처리(-E) 결과 출력
+    ///
+    /// 터미널에 출력하는 경우 `$PAGER`(기본: less)를 통해 보여준다.
+    pub fn expand(
+        &mut self,
+        file: &Path,
+        target: Option<&str>,
+        use_pager: bool,
+    ) -> BuildResult<()> {
+        self.load_project()?;
+        self.check_compiler()?;
+        generated::write_headers(self.config(), &self.layout)?;
+        self.resolve_sources()?;
+
+        let (target_type, target_name, source_file) =
+            self.find_source_target(file, target, false)?;
+
+        let mut cmd = tool_command(self.source_compiler(&source_file));
+        cmd.arg("-E").arg(&source_file);
+        cmd.args(self.source_flags(&source_file, self.compile_flags(&target_type, &target_name)));
+
+        if self.verbose {
+            eprintln!("Preprocessing: {:?}", cmd);
         }
 
-        // 객체 파일 디렉토리
-        let obj_dir = self.build_dir.join("obj");
-        if !obj_dir.exists() {
-            std::fs::create_dir_all(&obj_dir).map_err(|e| BuildError::IoError(e))?;
+        let output = self
+            .run_command(&format!("{}:{}", target_type, target_name), &mut cmd)
+            .map_err(BuildError::IoError)?;
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr).to_string();
+            return Err(BuildError::CompilerError(tr!(
+                "전처리 실패: {}\n{}",
+                "preprocessing failed: {}\n{}",
+                source_file.display(),
+                error
+            )));
         }
 
-        // 라이브러리 디렉토리
-        let lib_dir = self.build_dir.join("lib");
-        if !lib_dir.exists() {
-            std::fs::create_dir_all(&lib_dir).map_err(|e| BuildError::IoError(e))?;
+        page_output(&output.stdout, use_pager).map_err(BuildError::IoError)?;
+
+        Ok(())
+    }
+
+    /// 소스 파일 하나만 빌드와 동일한 플래그로 컴파일
+    ///
+    /// 오브젝트 파일은 빌드와 같은 위치에 쓰며 진단은 모아 두지 않고 바로 출력한다.
+    /// 파일이 여러 타겟에 속하고 `target`이 없으면 터미널에서 타겟을 고르게 한다.
+    pub fn compile_file(&mut self, file: &Path, target: Option<&str>) -> BuildResult<()> {
+        let start_time = Instant::now();
+
+        self.load_project()?;
+        self.check_compiler()?;
+        self.prepare_build_directory()?;
+        self.resolve_sources()?;
+
+        let (target_type, target_name, source_file) =
+            self.find_source_target(file, target, true)?;
+        let target_key = format!("{}:{}", target_type, target_name);
+        let object_file = self.layout.object_file(&self.project_dir, &source_file);
+        if let Some(parent) = object_file.parent() {
+            std::fs::create_dir_all(parent).map_err(BuildError::IoError)?;
         }
 
-        // 실행 파일 디렉토리
-        let bin_dir = self.build_dir.join("bin");
-        if !bin_dir.exists() {
-            std::fs::create_dir_all(&bin_dir).map_err(|e| BuildError::IoError(e))?;
+        println!(
+            "{} {} ({})",
+            "Compiling".green().bold(),
+            source_file
+                .strip_prefix(&self.project_dir)
+                .unwrap_or(&source_file)
+                .display(),
+            target_key
+        );
+
+        let mut cmd =
+            self.compile_command(&target_type, &target_name, &source_file, &object_file, true);
+
+        if self.verbose {
+            println!("Compiling: {:?}", cmd);
+        }
+
+        let output = self
+            .run_command(&target_key, &mut cmd)
+            .map_err(BuildError::IoError)?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        emit_annotations(
+            self.annotations,
+            std::iter::once(&*stderr),
+            &self.project_dir,
+        );
+        eprint!("{}", stderr);
+
+        if !output.status.success() {
+            return Err(BuildError::CompileFailed(CommandFailure::new(
+                &target_key,
+                &cmd,
+                &output.stderr,
+            )));
         }
 
+        println!(
+            "{} {} {}",
+            tr!("컴파일 완료", "Compiled").green().bold(),
+            object_file
+                .strip_prefix(&self.project_dir)
+                .unwrap_or(&object_file)
+                .display(),
+            tr!("({}초)", "({}s)", start_time.elapsed().as_secs_f32())
+        );
+
         Ok(())
     }
 
-    fn detect_changed_files(
+    /// 빌드와 동일한 플래그로 타겟별 컴파일 정보 수집 (타겟 키 순서)
+    pub fn compile_info(&mut self) -> BuildResult<Vec<TargetCompileInfo>> {
+        self.load_project()?;
+        // 편집기가 생성된 헤더를 찾을 수 있도록 빌드 전에도 작성
+        generated::write_headers(self.config(), &self.layout)?;
+        self.resolve_sources()?;
+
+        // 소스 목록은 타겟 키 순서
+        Ok(self
+            .sources
+            .iter()
+            .map(|(key, sources)| {
+                let (target_type, target_name) = key.split_once(':').unwrap_or(("", key));
+                TargetCompileInfo {
+                    target_type: target_type.to_string(),
+                    name: target_name.to_string(),
+                    sources: sources.clone(),
+                    flags: self
+                        .compile_flags(target_type, target_name)
+                        .into_iter()
+                        .map(|flag| flag.to_string_lossy().to_string())
+                        .collect(),
+                }
+            })
+            .collect())
+    }
+
+    /// 프로젝트 설정 (빌더를 만들 때 읽음)
+    pub fn config(&self) -> &BuildConfig {
+        &self.config
+    }
+
+    pub fn layout(&self) -> &BuildLayout {
+        &self.layout
+    }
+
+    /// 변경된 파일의 영향을 받는 타겟과 테스트를 한 줄에 하나씩 출력
+    ///
+    /// `files`와 `since` 참조 이후 변경된 파일을 합쳐 헤더 의존성과 라이브러리
+    /// 링크 관계로 영향을 받는 타겟 키(`exe:app`, `test:unit` 등)를 계산한다.
+    pub fn affected(&mut self, files: &[PathBuf], since: Option<&str>) -> BuildResult<()> {
+        self.load_project()?;
+        self.resolve_sources()?;
+
+        let current_dir = std::env::current_dir()?;
+        let mut changed: HashSet<PathBuf> = files
+            .iter()
+            .map(|file| {
+                let path = to_absolute_path(file, &current_dir);
+                path.canonicalize().unwrap_or(path)
+            })
+            .collect();
+
+        if let Some(git_ref) = since {
+            changed.extend(self.changed_files_since_ref(git_ref)?);
+        }
+
+        let mut impacted: Vec<String> = self.impacted_targets(&changed)?.into_iter().collect();
+        impacted.sort();
+
+        if impacted.is_empty() {
+            info!("영향을 받는 타겟이 없습니다");
+        }
+
+        for key in impacted {
+            println!("{}", key);
+        }
+
+        Ok(())
+    }
+
+    /// 소스 파일이 속한 타겟 찾기
+    ///
+    /// 반환값은 (타겟 종류, 타겟 이름, 해결된 소스 경로)이며, 여러 타겟에 속한
+    /// 파일은 `target`으로 타겟을 지정해야 한다. `choose`이고 터미널에서 실행 중이면
+    /// 대신 타겟을 고르게 한다.
+    fn find_source_target(
         &self,
-        source_files: &HashMap<String, Vec<PathBuf>>,
-    ) -> BuildResult<HashMap<String, Vec<PathBuf>>> {
-        let mut changed_files = HashMap::new();
-        let hash_file = self.build_dir.join("file_hashes.json");
-
-        // 이전 해시 로드
-        let mut previous_hashes: HashMap<String, String> = if hash_file.exists() {
-            let content =
-                std::fs::read_to_string(&hash_file).map_err(|e| BuildError::IoError(e))?;
-            serde_json::from_str(&content)
-                .map_err(|e| BuildError::ConfigParsingError(e.to_string()))?
+        file: &Path,
+        target: Option<&str>,
+        choose: bool,
+    ) -> BuildResult<(String, String, PathBuf)> {
+        let wanted = to_absolute_path(file, &std::env::current_dir()?);
+        let wanted = wanted.canonicalize().unwrap_or(wanted);
+
+        let mut candidates: Vec<(String, String, PathBuf)> = Vec::new();
+        for (key, files) in &self.sources {
+            let (target_type, target_name) = key.split_once(':').unwrap_or(("", key));
+            if target.is_some_and(|t| t != target_name) {
+                continue;
+            }
+            for source in files {
+                let resolved = source.canonicalize().unwrap_or_else(|_| source.clone());
+                if resolved == wanted {
+                    candidates.push((
+                        target_type.to_string(),
+                        target_name.to_string(),
+                        source.clone(),
+                    ));
+                }
+            }
+        }
+        candidates.sort();
+
+        match candidates.len() {
+            0 => Err(BuildError::SourceNotInTarget(file.to_path_buf())),
+            1 => Ok(candidates.remove(0)),
+            _ if choose && std::io::stdin().is_terminal() && std::io::stderr().is_terminal() => {
+                let index = prompt_target(file, &candidates)?;
+                Ok(candidates.remove(index))
+            }
+            _ => Err(BuildError::AmbiguousTarget(
+                file.to_path_buf(),
+                candidates
+                    .iter()
+                    .map(|(_, name, _)| name.clone())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            )),
+        }
+    }
+
+    pub fn clean(&self) -> BuildResult<()> {
+        let layout = self
+            .context
+            .layout(&self.configuration, self.out_dir.as_deref());
+        let build_dir = layout.build_dir();
+
+        info!("정리 중: {}", build_dir.display());
+
+        if build_dir.exists() {
+            std::fs::remove_dir_all(build_dir).map_err(BuildError::IoError)?;
+            println!(
+                "{}: {}",
+                tr!("정리 완료", "Cleaned").green(),
+                build_dir.display()
+            );
         } else {
-            HashMap::new()
-        };
+            println!(
+                "{}: {}",
+                tr!("정리", "Clean").green(),
+                tr!("이미 정리되어 있습니다", "nothing to clean")
+            );
+        }
 
-        // 변경된 파일 감지 및 새 해시 계산
-        let mut new_hashes = HashMap::new();
+        Ok(())
+    }
 
-        for (target, files) in source_files {
-            let mut changed = Vec::new();
+    /// `--jobs auto-mem` 또는 `build.max_memory` 설정 시 메모리 기반 작업 조절기 생성
+    fn memory_governor(&self) -> BuildResult<Option<MemoryGovernor>> {
+        let config = self.config();
+
+        let max_memory = match config.build.max_memory {
+            Some(ref value) => Some(parse_memory_size(value)?),
+            None => None,
+        };
+
+        if !self.adaptive_memory && max_memory.is_none() {
+            return Ok(None);
+        }
+
+        let governor = MemoryGovernor::new(max_memory);
+        if let Some(ref governor) = governor {
+            println!(
+                "{}",
+                tr!(
+                    "메모리 사용량에 따라 동시 작업 수를 조절합니다 (한도: {} MiB)",
+                    "Adjusting concurrency to memory usage (limit: {} MiB)",
+                    governor.limit() / 1024 / 1024
+                )
+            );
+        }
+
+        Ok(governor)
+    }
+
+    /// 설정 파일을 읽고 출력 디렉토리 구조 결정
+    /// `cbuild report`가 읽는 빌드 요약 저장 (실패해도 빌드 결과에는 영향 없음)
+    fn save_summary(&self, duration_secs: f32, result: &BuildResult<()>) {
+        let summary = BuildSummary {
+            configuration: self.configuration.clone(),
+            timestamp: report::unix_now(),
+            duration_secs,
+            success: result.is_ok(),
+            error: result.as_ref().err().map(|e| e.to_string()),
+            compiled: self.compiled_sources,
+            cached: self.cached_sources,
+            warnings: std::mem::take(&mut *self.warnings.lock().unwrap()),
+        };
+        if let Err(e) = report::save_build_summary(&self.layout, &summary) {
+            warn!(
+                "{}",
+                tr!(
+                    "빌드 요약을 저장할 수 없습니다: {}",
+                    "cannot save build summary: {}",
+                    e
+                )
+            );
+        }
+    }
+
+    /// build/<구성>/log에 이번 빌드의 로그 파일 생성 (`--dry-run`이면 생략)
+    fn open_build_log(&mut self) {
+        let config = self.config();
+        let retention = config
+            .build
+            .log_retention
+            .unwrap_or(buildlog::DEFAULT_RETENTION);
+        if retention == 0 || self.dry_run.is_some() {
+            return;
+        }
+
+        match BuildLog::create(&self.layout, retention) {
+            Ok(log) => {
+                log.line(&format!(
+                    "project: {} v{} ({})",
+                    config.project.name, config.project.version, self.configuration
+                ));
+                self.build_log = Some(log);
+            }
+            Err(e) => warn!(
+                "{}",
+                tr!(
+                    "빌드 로그를 만들 수 없습니다: {}",
+                    "cannot create build log: {}",
+                    e
+                )
+            ),
+        }
+    }
+
+    fn load_project(&mut self) -> BuildResult<()> {
+        let config = self.config();
+        if !matches!(self.configuration.as_str(), "debug" | "release")
+            && config.profile(&self.configuration).is_none()
+        {
+            eprintln!(
+                "{} {}",
+                tr!("경고:", "warning:").yellow().bold(),
+                tr!(
+                    "빌드 구성 {}에 대한 [profiles.{}]가 없어 debug 설정으로 빌드합니다",
+                    "no [profiles.{}] for configuration {}, building with debug settings",
+                    self.configuration,
+                    self.configuration
+                )
+            );
+        }
+        self.layout = self
+            .context
+            .layout(&self.configuration, self.out_dir.as_deref());
+        Ok(())
+    }
+
+    /// 타겟별 소스 파일 해결 (glob 패턴 및 생성된 임베딩 소스 포함)
+    fn resolve_sources(&mut self) -> BuildResult<BTreeMap<String, Vec<PathBuf>>> {
+        let mut source_files = self.context.sources()?.clone();
+
+        // 리소스 임베딩 소스 생성
+        self.generate_embedded_resources(&mut source_files)?;
+        self.add_generated_sources(&mut source_files)?;
+        self.sources = source_files.clone();
+
+        Ok(source_files)
+    }
+
+    /// src의 `{generated}` 패턴에 맞는 생성된 소스 추가 (컴파일 전 사용자 정의 명령의 출력)
+    fn add_generated_sources(
+        &self,
+        source_files: &mut BTreeMap<String, Vec<PathBuf>>,
+    ) -> BuildResult<()> {
+        let config = self.config();
+        let generated_dir = self.layout.generated_dir();
+        let follow_links = config.build.follow_symlinks.unwrap_or(true);
+
+        for (key, pattern) in config.generated_source_patterns() {
+            let resolved =
+                pattern.replace(GENERATED_DIR_PLACEHOLDER, &generated_dir.to_string_lossy());
+            let paths = glob_in(&self.project_dir, &resolved, follow_links).map_err(|e| {
+                BuildError::PathError(tr!(
+                    "패턴 '{}'에 오류: {}",
+                    "invalid pattern '{}': {}",
+                    pattern,
+                    e
+                ))
+            })?;
+            if paths.is_empty() && self.dry_run.is_none() {
+                warn!(
+                    "{}",
+                    tr!(
+                        "{}의 생성된 소스 패턴에 맞는 파일이 없습니다: {}",
+                        "no generated sources match {} pattern: {}",
+                        key,
+                        pattern
+                    )
+                );
+            }
+
+            let files = source_files.entry(key).or_default();
+            for path in paths {
+                if !files.contains(&path) {
+                    files.push(path);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 컴파일러 탐지 (종류, 버전, 플래그 지원 여부는 build/.toolchain.json에 캐시)
+    fn check_compiler(&mut self) -> BuildResult<()> {
+        let config = Arc::clone(&self.config);
+        let cache_path = self.layout.toolchain_cache_path();
+        let toolchain = if self.dry_run.is_some() {
+            Toolchain::probe_read_only(&config.build.compiler, &cache_path)?
+        } else {
+            Toolchain::probe(&config.build.compiler, &cache_path)?
+        };
+        if let Some(ref c_std) = config.build.c_standard {
+            toolchain.check_standard("build.c_standard", c_std)?;
+        }
+
+        // OpenMP를 쓰는 타겟이 있으면 컴파일 전에 런타임 확인
+        if config.uses_openmp() {
+            self.openmp = Some(toolchain.openmp_flags()?);
+        }
+
+        self.pch = PchCache::new(
+            &config.pch,
+            &config.build.compiler,
+            &toolchain,
+            self.layout.pch_dir(),
+        );
+        self.toolchain = Some(toolchain);
+        Ok(())
+    }
+
+    /// 빌드 컨테이너를 시작하고 이미지에 필요한 도구가 있는지 확인
+    fn start_container(&mut self, image: &str) -> BuildResult<()> {
+        println!(
+            "{} {}",
+            tr!("컨테이너 사용:", "Using container:").blue().bold(),
+            image
+        );
+
+        let mounts = vec![self.layout.build_dir().to_path_buf()];
+        let container = Container::start(image, &self.project_dir, &mounts, self.jobs)?;
+        container.check_tools(self.config())?;
+        self.container = Some(container);
+        Ok(())
+    }
+
+    /// 외부 명령 실행 (컨테이너 빌드이면 컨테이너 안에서 실행)
+    ///
+    /// 실행한 명령은 `target`(예: "exe:app")과 함께 build/commands.log에 기록된다.
+    fn run_command(&self, target: &str, cmd: &mut Command) -> std::io::Result<Output> {
+        self.run_action(target, Action::local(cmd))
+    }
+
+    /// 빌드 동작을 실행기(로컬, 원격 실행 등)로 실행하고 명령 기록에 남김
+    fn run_action(&self, target: &str, action: Action) -> std::io::Result<Output> {
+        let Action { command, outputs } = action;
+        let mut wrapped;
+        let cmd = match self.container {
+            Some(ref container) => {
+                wrapped = container.command(command);
+                &mut wrapped
+            }
+            None => command,
+        };
+
+        // --dry-run: 실행하지 않고 기록한 뒤 성공한 것으로 처리
+        if self.dry_run.is_some() {
+            self.command_log
+                .record(target, cmd, None, std::time::Duration::ZERO);
+            return Ok(Output {
+                status: ExitStatus::default(),
+                stdout: Vec::new(),
+                stderr: Vec::new(),
+            });
+        }
+
+        let started = Instant::now();
+        let output = self.executor.execute(Action {
+            command: cmd,
+            outputs,
+        });
+        if let Some(ref log) = self.build_log {
+            log.command(target, cmd, &output, started.elapsed());
+        }
+        self.command_log.record(
+            target,
+            cmd,
+            output.as_ref().ok().map(|output| output.status),
+            started.elapsed(),
+        );
+        output
+    }
+
+    /// 링크 입력이 있는지 (`--dry-run`에서는 앞 단계가 만들었을 것으로 간주)
+    fn will_exist(&self, path: &Path) -> bool {
+        self.dry_run.is_some() || path.exists()
+    }
+
+    /// `--dry-run`에서 기록한 명령을 실행 순서대로 출력
+    fn print_plan(&self) -> BuildResult<()> {
+        let Some(ref dry_run) = self.dry_run else {
+            return Ok(());
+        };
+        let commands = self.command_log.entries();
+
+        println!(
+            "\n{} {}",
+            tr!("실행할 명령", "Planned commands").blue().bold(),
+            tr!(
+                "({}개, 실행하지 않음)",
+                "({}, not executed)",
+                commands.len()
+            )
+        );
+        for (index, planned) in commands.iter().enumerate() {
+            println!("{:>4}. {}", index + 1, cmdlog::format_command(planned));
+        }
+
+        if let Some(ref path) = dry_run.json_path {
+            let json = serde_json::to_string_pretty(&commands)
+                .map_err(|e| BuildError::PathError(e.to_string()))?;
+            write_if_changed(path, &format!("{}\n", json)).map_err(BuildError::IoError)?;
+            println!(
+                "{} {}",
+                tr!("명령 목록 저장:", "Saved plan:").green(),
+                path.display()
+            );
+        }
+        Ok(())
+    }
+
+    fn prepare_build_directory(&self) -> BuildResult<()> {
+        let build_dir = self.layout.build_dir();
+        if !build_dir.exists() {
+            std::fs::create_dir_all(build_dir).map_err(BuildError::IoError)?;
+        }
+
+        // 객체 파일 디렉토리
+        let obj_dir = self.layout.obj_dir();
+        if !obj_dir.exists() {
+            std::fs::create_dir_all(&obj_dir).map_err(BuildError::IoError)?;
+        }
+
+        // 라이브러리 디렉토리
+        let lib_dir = self.layout.lib_dir();
+        if !lib_dir.exists() {
+            std::fs::create_dir_all(&lib_dir).map_err(BuildError::IoError)?;
+        }
+
+        // 실행 파일 디렉토리
+        let bin_dir = self.layout.bin_dir();
+        if !bin_dir.exists() {
+            std::fs::create_dir_all(&bin_dir).map_err(BuildError::IoError)?;
+        }
+
+        // 생성된 헤더 디렉토리 (모든 타겟의 포함 경로)
+        generated::write_headers(self.config(), &self.layout)
+    }
+
+    /// `embed` 필드가 있는 타겟의 리소스 소스를 생성하고 소스 목록에 추가
+    fn generate_embedded_resources(
+        &mut self,
+        source_files: &mut BTreeMap<String, Vec<PathBuf>>,
+    ) -> BuildResult<()> {
+        let config = Arc::clone(&self.config);
+
+        let mut embeds: Vec<(String, &str, &Vec<String>)> = Vec::new();
+        for exe in &config.targets.executable {
+            if let Some(ref embed) = exe.embed {
+                embeds.push((format!("exe:{}", exe.name), &exe.name, embed));
+            }
+        }
+        for lib in &config.targets.static_lib {
+            if let Some(ref embed) = lib.embed {
+                embeds.push((format!("static:{}", lib.name), &lib.name, embed));
+            }
+        }
+        for lib in &config.targets.shared_lib {
+            if let Some(ref embed) = lib.embed {
+                embeds.push((format!("shared:{}", lib.name), &lib.name, embed));
+            }
+        }
+        for test in &config.targets.test {
+            if let Some(ref embed) = test.embed {
+                embeds.push((format!("test:{}", test.name), &test.name, embed));
+            }
+        }
+
+        for (key, name, patterns) in embeds {
+            let out_dir = self
+                .layout
+                .generated_dir()
+                .join("embed")
+                .join(key.replace(':', "_"));
+            let resources = generate_embedded_sources(
+                name,
+                patterns,
+                &self.project_dir,
+                &out_dir,
+                self.dry_run.is_none(),
+            )?;
+
+            source_files
+                .entry(key.clone())
+                .or_default()
+                .extend(resources.sources);
+            self.embed_include_dirs.insert(key, resources.include_dir);
+        }
+
+        Ok(())
+    }
+
+    /// git 참조 이후 변경된 파일 (빌드 디렉토리 안의 생성된 파일 제외)
+    fn changed_files_since_ref(&self, git_ref: &str) -> BuildResult<HashSet<PathBuf>> {
+        let output_root = self.layout.output_root();
+        let output_root = output_root
+            .canonicalize()
+            .unwrap_or_else(|_| output_root.to_path_buf());
+
+        Ok(changed_files_since(&self.project_dir, git_ref)?
+            .into_iter()
+            .filter(|path| !path.starts_with(&output_root))
+            .collect())
+    }
+
+    /// 변경된 파일의 영향을 받는 타겟 키 집합
+    ///
+    /// 소스 파일이 직접 변경되었거나 포함하는 헤더가 변경된 타겟, 그리고 영향을
+    /// 받는 내부 라이브러리를 링크하는 타겟이 포함된다. `cbuild.toml`이 변경되면
+    /// 모든 타겟이 영향을 받는다. `changed`는 정규화된 절대 경로여야 한다.
+    fn impacted_targets(&self, changed: &HashSet<PathBuf>) -> BuildResult<HashSet<String>> {
+        let config_path = self.project_dir.join("cbuild.toml");
+        let config_path = config_path.canonicalize().unwrap_or(config_path);
+        if changed.contains(&config_path) {
+            info!("cbuild.toml이 변경되어 모든 타겟이 영향을 받습니다");
+            return Ok(self.sources.keys().cloned().collect());
+        }
+
+        let parser = DependencyParser::new();
+        let mut impacted = HashSet::new();
+
+        for (key, files) in &self.sources {
+            let (target_type, target_name) = key.split_once(':').unwrap_or(("", key));
+            let include_dirs = self.target_include_dirs(target_type, target_name);
+
+            for file in files {
+                let resolved = file.canonicalize().unwrap_or_else(|_| file.clone());
+                if changed.contains(&resolved) {
+                    impacted.insert(key.clone());
+                    break;
+                }
+
+                // 헤더 역의존성 확인
+                let headers = parser.parse_dependencies(file, &include_dirs)?;
+                if headers
+                    .iter()
+                    .any(|h| changed.contains(&h.canonicalize().unwrap_or_else(|_| h.clone())))
+                {
+                    impacted.insert(key.clone());
+                    break;
+                }
+            }
+        }
+
+        // 영향을 받는 라이브러리를 링크하는 타겟으로 전파
+        for key in self.sources.keys() {
+            let depends_on_impacted = self.linked_libs(key).iter().any(|lib| {
+                impacted.contains(&format!("static:{}", lib))
+                    || impacted.contains(&format!("shared:{}", lib))
+            });
+            if depends_on_impacted {
+                impacted.insert(key.clone());
+            }
+        }
+
+        Ok(impacted)
+    }
+
+    /// 기본 빌드 타겟 키 (제외된 타겟이 없으면 None)
+    ///
+    /// 기본 빌드 타겟이 링크하는 라이브러리는 `default = false`여도 함께 빌드한다.
+    fn default_targets(&self) -> Option<HashSet<String>> {
+        let config = self.config();
+        let mut selected: HashSet<String> = self
+            .sources
+            .keys()
+            .filter(|key| config.is_default_target(key))
+            .cloned()
+            .collect();
+        if selected.len() == self.sources.len() {
+            return None;
+        }
+
+        let linked: Vec<String> = selected
+            .iter()
+            .flat_map(|key| self.linked_libs(key))
+            .collect();
+        for lib in linked {
+            for key in [format!("static:{}", lib), format!("shared:{}", lib)] {
+                if self.sources.contains_key(&key) {
+                    selected.insert(key);
+                }
+            }
+        }
+        Some(selected)
+    }
+
+    /// 선택된 타겟이 링크하지만 아직 빌드되지 않은 내부 라이브러리 타겟 키
+    fn unbuilt_link_dependencies(&self, selected: &HashSet<String>) -> Vec<String> {
+        let config = self.config();
+
+        selected
+            .iter()
+            .flat_map(|key| self.linked_libs(key))
+            .flat_map(|lib| {
+                let mut keys = Vec::new();
+                if config.targets.static_lib.iter().any(|l| l.name == lib)
+                    && !self.layout.static_lib_path(&lib).exists()
+                {
+                    keys.push(format!("static:{}", lib));
+                }
+                if config.targets.shared_lib.iter().any(|l| l.name == lib)
+                    && !self.layout.shared_lib_path(&lib).exists()
+                {
+                    keys.push(format!("shared:{}", lib));
+                }
+                keys
+            })
+            .collect()
+    }
+
+    /// 실행 파일/테스트 타겟이 링크하는 라이브러리 이름
+    fn linked_libs(&self, key: &str) -> Vec<String> {
+        let config = self.config();
+        let (target_type, target_name) = key.split_once(':').unwrap_or(("", key));
+
+        let libs = match target_type {
+            "exe" => config
+                .targets
+                .executable
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.libs.clone()),
+            "test" => config
+                .targets
+                .test
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.libs.clone()),
+            _ => None,
+        };
+
+        libs.unwrap_or_default()
+    }
+
+    /// `--target`이나 `--since`로 선택된 타겟인지 확인 (선택이 없으면 모든 타겟)
+    fn is_selected(&self, key: &str) -> bool {
+        self.selected_targets
+            .as_ref()
+            .is_none_or(|selected| selected.contains(key))
+    }
+
+    /// 이전 빌드의 상태와 비교해 변경된 파일 감지
+    ///
+    /// 소스 내용, 타겟의 컴파일 플래그 지문, 지난 컴파일에서 기록한 헤더 중 하나라도
+    /// 바뀌었거나 오브젝트 파일이 없으면 다시 컴파일한다. 새 상태는 저장하지 않고
+    /// 반환하며, 컴파일이 끝난 뒤 `save_incremental_state`로 기록한다.
+    fn detect_changed_files(
+        &self,
+        source_files: &BTreeMap<String, Vec<PathBuf>>,
+    ) -> BuildResult<(BTreeMap<String, Vec<PathBuf>>, IncrementalState)> {
+        let mut changed_files = BTreeMap::new();
+
+        let previous = IncrementalState::load(self.layout.build_dir())?;
+        // 다른 타겟만 빌드할 때 지워지지 않도록 이전 상태에 덮어씀
+        let mut state = previous.clone();
+        // 여러 소스가 포함하는 헤더는 한 번만 해시
+        let mut header_hashes: HashMap<String, Option<FileHash>> = HashMap::new();
+
+        for (target, files) in source_files {
+            let mut changed = Vec::new();
+
+            // 컴파일 플래그가 바뀐 타겟은 모든 소스를 다시 컴파일
+            let (target_type, target_name) = target.split_once(':').unwrap_or(("", target));
+            let fingerprint = self.flags_fingerprint(target_type, target_name);
+            let flags_changed = previous.fingerprints.get(target) != Some(&fingerprint);
+            state.fingerprints.insert(target.clone(), fingerprint);
+
+            // 헤더 의존성이 기록되지 않은 빌드된 소스(이전 형식에서 변환한 상태 등)는
+            // 포함 관계를 직접 분석해 오브젝트보다 새 헤더가 있으면 다시 컴파일
+            let unknown: Vec<PathBuf> = files
+                .iter()
+                .filter(|file| {
+                    !previous
+                        .dependencies
+                        .contains_key(file.to_string_lossy().as_ref())
+                        && self.layout.object_file(&self.project_dir, file).exists()
+                })
+                .cloned()
+                .collect();
+            let parsed = self.parse_header_dependencies(target_type, target_name, &unknown)?;
+
+            for file in files {
+                let path_str = file.to_string_lossy().to_string();
+                // --dry-run은 임베딩 소스를 생성하지 않으므로 아직 없는 소스는 변경된 것으로 간주
+                if self.dry_run.is_some() && !file.exists() {
+                    changed.push(file.clone());
+                    continue;
+                }
+                let hash = calculate_file_hash(file)?;
+
+                let parsed_changed = parsed.get(file).is_some_and(|headers| {
+                    let object_file = self.layout.object_file(&self.project_dir, file);
+                    headers
+                        .iter()
+                        .any(|header| is_newer_than(Path::new(header), &object_file))
+                });
+                if let Some(headers) = parsed.get(file) {
+                    for header in headers {
+                        header_hashes
+                            .entry(header.clone())
+                            .or_insert_with(|| calculate_file_hash(Path::new(header)).ok());
+                    }
+                    state.dependencies.insert(path_str.clone(), headers.clone());
+                }
+
+                let headers_changed = previous
+                    .dependencies
+                    .get(&path_str)
+                    .into_iter()
+                    .flatten()
+                    .any(|header| {
+                        let current = header_hashes
+                            .entry(header.clone())
+                            .or_insert_with(|| calculate_file_hash(Path::new(header)).ok());
+                        current.is_none() || previous.file_hashes.get(header) != current.as_ref()
+                    });
+
+                if flags_changed
+                    || headers_changed
+                    || parsed_changed
+                    || previous.file_hashes.get(&path_str) != Some(&hash)
+                    || !self.layout.object_file(&self.project_dir, file).exists()
+                {
+                    changed.push(file.clone());
+                }
+
+                state.file_hashes.insert(path_str, hash);
+            }
+
+            if !changed.is_empty() {
+                changed_files.insert(target.clone(), changed);
+            }
+        }
+
+        for (header, hash) in header_hashes {
+            if let Some(hash) = hash {
+                state.file_hashes.insert(header, hash);
+            }
+        }
+
+        Ok((changed_files, state))
+    }
+
+    /// 컴파일한 소스의 헤더 의존성(.d)을 반영해 증분 빌드 상태 저장
+    ///
+    /// 컴파일에 실패한 소스는 해시를 지워 다음 빌드에서 다시 컴파일되게 한다.
+    fn save_incremental_state(
+        &self,
+        state: &mut IncrementalState,
+        compiled: &BTreeMap<String, Vec<PathBuf>>,
+    ) -> BuildResult<()> {
+        let failed = self.failed_sources.lock().unwrap();
+        let cwd = std::env::current_dir().map_err(BuildError::IoError)?;
+
+        for (target, sources) in compiled {
+            let (target_type, target_name) = target.split_once(':').unwrap_or(("", target));
+            let mut depfile_headers: Vec<(PathBuf, Vec<String>)> = Vec::new();
+            let mut missing: Vec<PathBuf> = Vec::new();
+
+            for source in sources {
+                if failed.contains(source) {
+                    state.file_hashes.remove(source.to_string_lossy().as_ref());
+                    continue;
+                }
+
+                let depfile = self
+                    .layout
+                    .object_file(&self.project_dir, source)
+                    .with_extension("d");
+                match std::fs::read_to_string(&depfile) {
+                    Ok(content) => {
+                        let headers = parse_depfile(&content)
+                            .into_iter()
+                            .map(|header| cwd.join(header))
+                            .filter(|header| header != source)
+                            .map(|header| header.to_string_lossy().to_string())
+                            .collect();
+                        depfile_headers.push((source.clone(), headers));
+                    }
+                    // depfile을 쓰지 않는 컴파일러는 포함 관계를 직접 분석
+                    Err(_) => missing.push(source.clone()),
+                }
+            }
+
+            let parsed = self.parse_header_dependencies(target_type, target_name, &missing)?;
+            for (source, headers) in depfile_headers.into_iter().chain(parsed) {
+                self.record_dependencies(state, &source, headers);
+            }
+        }
+
+        state.save(self.layout.build_dir())
+    }
+
+    /// 소스의 헤더 의존성과 헤더 해시를 증분 빌드 상태에 기록
+    fn record_dependencies(
+        &self,
+        state: &mut IncrementalState,
+        source: &Path,
+        headers: Vec<String>,
+    ) {
+        for header in &headers {
+            match calculate_file_hash(Path::new(header)) {
+                Ok(hash) => {
+                    state.file_hashes.insert(header.clone(), hash);
+                }
+                // 사라진 헤더는 다음 빌드에서 변경으로 처리됨
+                Err(_) => {
+                    state.file_hashes.remove(header);
+                }
+            }
+        }
+        state
+            .dependencies
+            .insert(source.to_string_lossy().to_string(), headers);
+    }
+
+    /// depfile 없이 소스가 포함하는 프로젝트 헤더 분석 (소스 -> 헤더 절대 경로)
+    ///
+    /// 타겟의 포함 디렉토리와 생성된 헤더 디렉토리에서 찾으며, 분석 결과는 증분 빌드
+    /// 상태에 저장되어 다음 빌드에서는 다시 분석하지 않는다.
+    fn parse_header_dependencies(
+        &self,
+        target_type: &str,
+        target_name: &str,
+        sources: &[PathBuf],
+    ) -> BuildResult<HashMap<PathBuf, Vec<String>>> {
+        if sources.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut include_dirs = self.target_include_dirs(target_type, target_name);
+        if let Some(embed_dir) = self
+            .embed_include_dirs
+            .get(&format!("{}:{}", target_type, target_name))
+        {
+            include_dirs.push(embed_dir.clone());
+        }
+        include_dirs.push(self.layout.generated_dir());
+
+        let graph = DependencyParser::new().build_dependency_graph(sources, &include_dirs)?;
+        Ok(graph
+            .into_iter()
+            .map(|(source, headers)| {
+                let mut headers: Vec<String> = headers
+                    .iter()
+                    .map(|header| normalize_path(&to_absolute_path(header, &self.project_dir)))
+                    .map(|header| header.to_string_lossy().to_string())
+                    .collect();
+                headers.sort();
+                headers.dedup();
+                (source, headers)
+            })
+            .collect())
+    }
+
+    /// 타겟의 include_dirs 밖에 있는 프로젝트 헤더를 포함한 소스 보고 (`[build] strict_includes`)
+    ///
+    /// 포함한 헤더는 컴파일러가 쓴 depfile을 반영한 증분 빌드 상태에서 가져오므로 이번에
+    /// 컴파일하지 않은 소스도 검사한다. 소스와 같은 디렉토리의 헤더, 생성된 헤더,
+    /// 프로젝트 밖(시스템)과 deps/의 헤더는 허용한다.
+    fn check_include_policy(
+        &self,
+        source_files: &BTreeMap<String, Vec<PathBuf>>,
+        state: &IncrementalState,
+    ) -> BuildResult<()> {
+        let deps_dir = self.project_dir.join("deps");
+        let output_root = self.layout.output_root();
+        let mut violations: Vec<(&PathBuf, Vec<PathBuf>)> = Vec::new();
+
+        for (key, files) in source_files {
+            let (target_type, target_name) = key.split_once(':').unwrap_or(("", key));
+            let allowed: Vec<PathBuf> = self
+                .target_include_dirs(target_type, target_name)
+                .iter()
+                .map(|dir| normalize_path(dir))
+                .collect();
+
+            for file in files {
+                let Some(headers) = state.dependencies.get(file.to_string_lossy().as_ref()) else {
+                    continue;
+                };
+                let source_dir = file.parent().map(normalize_path);
+                let outside: Vec<PathBuf> = headers
+                    .iter()
+                    .map(|header| normalize_path(Path::new(header)))
+                    .filter(|header| {
+                        header.starts_with(&self.project_dir)
+                            && !header.starts_with(&deps_dir)
+                            && !header.starts_with(output_root)
+                            && header.parent() != source_dir.as_deref()
+                            && !allowed.iter().any(|dir| header.starts_with(dir))
+                    })
+                    .collect();
+                if !outside.is_empty() {
+                    violations.push((file, outside));
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            return Ok(());
+        }
+
+        println!(
+            "\n{}",
+            tr!("포함 규칙 위반:", "Include policy violations:")
+                .red()
+                .bold()
+        );
+        for (file, headers) in &violations {
+            let relative = |path: &Path| {
+                path.strip_prefix(&self.project_dir)
+                    .unwrap_or(path)
+                    .display()
+                    .to_string()
+            };
+            for header in headers {
+                println!(
+                    "{}: {}",
+                    relative(file).yellow(),
+                    tr!(
+                        "선언하지 않은 디렉토리의 헤더 {}",
+                        "includes {} from an undeclared directory",
+                        relative(header)
+                    )
+                );
+            }
+        }
+        Err(BuildError::IncludePolicyViolation(violations.len()))
+    }
+
+    /// 코드 서명할 결과물 (실행 파일, 공유 라이브러리)
+    fn signable_artifacts(&self, source_files: &BTreeMap<String, Vec<PathBuf>>) -> Vec<PathBuf> {
+        let mut artifacts: Vec<PathBuf> = source_files
+            .keys()
+            .filter_map(|key| match key.split_once(':') {
+                Some(("exe", name)) => Some(self.layout.executable_path(name)),
+                Some(("shared", name)) => Some(self.layout.shared_lib_path(name)),
+                _ => None,
+            })
+            .collect();
+        artifacts.sort();
+        artifacts
+    }
+
+    /// [signing]에 따라 링크한 실행 파일과 공유 라이브러리에 코드 서명
+    fn sign_artifacts(&self, source_files: &BTreeMap<String, Vec<PathBuf>>) -> BuildResult<()> {
+        let config = self.config();
+        if !signing::is_enabled(config, &self.configuration)
+            || !signing::signs_binaries(&config.signing)
+        {
+            return Ok(());
+        }
+
+        for artifact in self.signable_artifacts(source_files) {
+            if artifact.exists() {
+                signing::sign_binary(&config.signing, &artifact)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 선택된 타겟 중 결과물이 없는 것이 있는지 (소스가 최신이어도 링크 필요)
+    fn missing_outputs(&self, source_files: &BTreeMap<String, Vec<PathBuf>>) -> bool {
+        source_files.keys().any(|key| {
+            let output = match key.split_once(':') {
+                Some(("static", name)) => self.layout.static_lib_path(name),
+                Some(("shared", name)) => self.layout.shared_lib_path(name),
+                Some(("exe", name)) => self.layout.executable_path(name),
+                Some(("test", name)) => self.layout.test_path(name),
+                _ => return false,
+            };
+            !output.exists()
+        })
+    }
+
+    fn compile_sources(&self, source_files: &BTreeMap<String, Vec<PathBuf>>) -> BuildResult<()> {
+        if source_files.is_empty() {
+            return Ok(());
+        }
+
+        println!(
+            "{} {}",
+            tr!("컴파일 중", "Compiling").blue().bold(),
+            tr!(
+                "({}개 작업, {}개 스레드 사용)",
+                "({} jobs, {} threads)",
+                source_files.values().map(|v| v.len()).sum::<usize>(),
+                self.jobs
+            )
+        );
+        if self.jobserver.is_some() {
+            println!(
+                "{}",
+                tr!(
+                    "GNU make jobserver에 따라 동시 작업 수를 조절합니다",
+                    "Following the GNU make jobserver for concurrency"
+                )
+            );
+        }
+
+        let governor = self.memory_governor()?;
+        let logged_before = self.command_log.len();
+
+        let jobs = self.schedule_compile_jobs(source_files);
+
+        let progress = CompileProgress::new(
+            self.progress,
+            source_files
+                .iter()
+                .map(|(key, files)| (key.as_str(), files.len())),
+            self.cached_sources,
+        );
+
+        let error_map: DashMap<PathBuf, String> = DashMap::new();
+        // 성공한 컴파일의 경고 출력
+        let warning_map: DashMap<PathBuf, String> = DashMap::new();
+
+        // 우선순위 순으로 정렬한 작업 큐에서 각 작업자가 다음 작업을 가져감
+        let next_job = AtomicUsize::new(0);
+        let compile_results: Mutex<Vec<(usize, BuildResult<()>)>> = Mutex::new(Vec::new());
+        let workers = self.jobs.max(1).min(jobs.len());
+        std::thread::scope(|scope| {
+            for _ in 0..workers {
+                scope.spawn(|| loop {
+                    let index = next_job.fetch_add(1, Ordering::Relaxed);
+                    let Some(job) = jobs.get(index) else {
+                        break;
+                    };
+                    progress.start(job.target_key, job.source_file);
+                    let result = self.compile_job(job, governor.as_ref(), &error_map, &warning_map);
+                    progress.finish(job.target_key, job.source_file);
+                    compile_results.lock().unwrap().push((index, result));
+                });
+            }
+        });
+        progress.clear();
+        // 끝난 순서가 아니라 작업 순서로 정렬해 반환할 오류가 실행마다 같게 함
+        let mut compile_results = compile_results.into_inner().unwrap();
+        compile_results.sort_by_key(|(index, _)| *index);
+
+        // 병렬로 기록된 진단은 경로 순으로 정렬해 출력과 보고서가 실행마다 같게 함
+        let error_map: BTreeMap<PathBuf, String> = error_map.into_iter().collect();
+        let warning_map: BTreeMap<PathBuf, String> = warning_map.into_iter().collect();
+
+        // 병렬로 기록된 컴파일 명령은 실행할 때마다 순서가 달라지므로 정렬
+        self.command_log.sort_from(logged_before);
+
+        // CI 주석 출력 (진행 표시줄과 섞이지 않도록 컴파일이 끝난 뒤 출력)
+        emit_annotations(
+            self.annotations,
+            warning_map
+                .values()
+                .chain(error_map.values())
+                .map(|message| message.as_str()),
+            &self.project_dir,
+        );
+
+        self.write_diagnostics_report(warning_map.values().chain(error_map.values()).cloned())?;
+        let denied = self.report_warnings(warning_map.values().chain(error_map.values()).cloned());
+
+        // 컴파일 오류 출력
+        if !error_map.is_empty() {
+            println!("\n{}", tr!("컴파일 오류:", "Compile errors:").red().bold());
+            for (file, message) in &error_map {
+                println!("{}: \n{}", file.display().to_string().yellow(), message);
+            }
+        }
+
+        self.failed_sources
+            .lock()
+            .unwrap()
+            .extend(error_map.keys().cloned());
+
+        if let Some(ref distributor) = self.distributor {
+            distributor.print_stats();
+        }
+
+        // 첫 번째로 실패한 명령의 정보와 함께 실패 반환
+        if let Some(error) = compile_results.into_iter().find_map(|(_, r)| r.err()) {
+            return Err(error);
+        }
+        denied?;
+
+        println!("{}", tr!("컴파일 완료", "Compilation finished").green());
+        Ok(())
+    }
+
+    /// 소스 파일 하나를 컴파일하는 명령 (`use_pch`면 [pch] 캐시의 헤더를 미리 만들어 사용)
+    fn compile_command(
+        &self,
+        target_type: &str,
+        target_name: &str,
+        source_file: &Path,
+        object_file: &Path,
+        use_pch: bool,
+    ) -> Command {
+        let mut cmd = tool_command(self.source_compiler(source_file));
+        // 깊은 디렉토리의 오브젝트 경로는 Windows에서 MAX_PATH를 넘을 수 있음
+        cmd.arg("-c")
+            .arg(long_path(source_file))
+            .arg("-o")
+            .arg(long_path(object_file));
+        // 증분 빌드가 헤더 변경을 감지하도록 포함한 헤더 목록을 <obj>.d에 기록
+        cmd.arg("-MMD")
+            .arg("-MF")
+            .arg(long_path(&object_file.with_extension("d")));
+
+        let flags = self.source_flags(source_file, self.compile_flags(target_type, target_name));
+        if use_pch {
+            cmd.args(self.pch_args(target_type, target_name, source_file, &flags));
+        }
+        cmd.args(flags);
+        cmd
+    }
+
+    /// 빌드가 실행하는 것과 같은 컴파일 명령으로 compile_commands.json 작성 (항목 수 반환)
+    ///
+    /// 타겟 선택이나 증분 빌드와 관계없이 모든 소스를 기록한다. 미리 컴파일한 헤더는
+    /// 컴파일러마다 형식이 달라 clangd 등이 읽지 못하므로 넣지 않는다.
+    pub fn write_compile_commands(&self, path: &Path) -> BuildResult<usize> {
+        let mut entries = Vec::new();
+        for (key, files) in &self.sources {
+            let (target_type, target_name) = key.split_once(':').unwrap_or(("", key));
+            for source_file in files {
+                let object_file = self.layout.object_file(&self.project_dir, source_file);
+                let cmd = self.compile_command(
+                    target_type,
+                    target_name,
+                    source_file,
+                    &object_file,
+                    false,
+                );
+                let arguments: Vec<String> = std::iter::once(cmd.get_program())
+                    .chain(cmd.get_args())
+                    .map(|arg| arg.to_string_lossy().to_string())
+                    .collect();
+
+                entries.push(serde_json::json!({
+                    "directory": self.project_dir.to_string_lossy(),
+                    "file": source_file.to_string_lossy(),
+                    "arguments": arguments,
+                    "output": object_file.to_string_lossy(),
+                }));
+            }
+        }
+
+        let content = serde_json::to_string_pretty(&entries)
+            .map_err(|e| BuildError::ConfigParsingError(e.to_string()))?;
+        write_if_changed(path, &format!("{}\n", content)).map_err(BuildError::IoError)?;
+        Ok(entries.len())
+    }
+
+    /// `cbuild compile-commands`: 빌드하지 않고 compile_commands.json만 작성
+    pub fn compile_commands(&mut self, path: &Path) -> BuildResult<()> {
+        self.load_project()?;
+        // 생성된 헤더가 있어야 편집기가 포함을 해석할 수 있음
+        generated::write_headers(self.config(), &self.layout)?;
+        self.resolve_sources()?;
+        self.context.dependency_features()?;
+
+        let count = self.write_compile_commands(path)?;
+        println!(
+            "{} {}",
+            tr!("생성됨:", "Generated:").green().bold(),
+            tr!(
+                "{} (컴파일 명령 {}개)",
+                "{} ({} compile commands)",
+                path.display(),
+                count
+            )
+        );
+        Ok(())
+    }
+
+    /// 컴파일 작업을 실행 순서대로 정렬
+    ///
+    /// 다른 타겟이 링크하는 라이브러리를 먼저, 같은 단계에서는 큰 파일을 먼저 컴파일해
+    /// 빌드 끝에 긴 작업 하나만 남아 코어가 노는 시간을 줄인다.
+    fn schedule_compile_jobs<'a>(
+        &self,
+        source_files: &'a BTreeMap<String, Vec<PathBuf>>,
+    ) -> Vec<CompileJob<'a>> {
+        let linked: HashSet<String> = source_files
+            .keys()
+            .flat_map(|key| self.linked_libs(key))
+            .collect();
+
+        let mut jobs: Vec<CompileJob> = source_files
+            .iter()
+            .flat_map(|(key, files)| {
+                let (target_type, target_name) = key.split_once(':').unwrap_or(("", key));
+                let rank = match target_type {
+                    "static" | "shared" if linked.contains(target_name) => 0,
+                    "static" | "shared" => 1,
+                    "test" => 3,
+                    _ => 2,
+                };
+                files.iter().map(move |source_file| CompileJob {
+                    target_key: key,
+                    target_type,
+                    target_name,
+                    source_file,
+                    rank,
+                    size: std::fs::metadata(source_file).map_or(0, |m| m.len()),
+                })
+            })
+            .collect();
+
+        jobs.sort_by(|a, b| {
+            a.rank
+                .cmp(&b.rank)
+                .then_with(|| b.size.cmp(&a.size))
+                .then_with(|| a.target_key.cmp(b.target_key))
+                .then_with(|| a.source_file.cmp(b.source_file))
+        });
+        jobs
+    }
+
+    /// 소스 파일 하나 컴파일 (오류와 경고 출력은 각 맵에 저장)
+    fn compile_job(
+        &self,
+        job: &CompileJob,
+        governor: Option<&MemoryGovernor>,
+        error_map: &DashMap<PathBuf, String>,
+        warning_map: &DashMap<PathBuf, String>,
+    ) -> BuildResult<()> {
+        let source_file = job.source_file;
+
+        // 출력 경로 생성
+        let object_file = self.layout.object_file(&self.project_dir, source_file);
+
+        // 객체 파일 디렉토리 생성
+        if let Some(parent) = object_file.parent() {
+            if self.dry_run.is_none() {
+                std::fs::create_dir_all(parent).map_err(BuildError::IoError)?;
+            }
+        }
+
+        // 컴파일 플래그 설정
+        let mut cmd = self.compile_command(
+            job.target_type,
+            job.target_name,
+            source_file,
+            &object_file,
+            true,
+        );
+
+        if self.verbose {
+            println!("Compiling: {:?}", cmd);
+        }
+
+        // 메모리 여유가 생길 때까지 대기
+        let _reservation = governor.map(|g| g.reserve(source_file));
+
+        // 상위 make의 jobserver가 있으면 작업 슬롯 확보 후 실행
+        let _token = match self.jobserver {
+            Some(ref jobserver) => Some(jobserver.acquire().map_err(BuildError::IoError)?),
+            None => None,
+        };
+
+        // 컴파일 실행 (원격 슬롯이 있으면 분산, 원격 실패 시 로컬에서 다시 컴파일)
+        let remote = self
+            .distributor
+            .as_ref()
+            .and_then(|distributor| Some((distributor, distributor.acquire()?)));
+        let output = match remote {
+            Some((distributor, slot)) => {
+                let mut remote_cmd = distributor.command(&slot, &cmd);
+                let output = self.run_command(job.target_key, &mut remote_cmd);
+                if distributor.finish(slot, &output) {
+                    self.run_command(job.target_key, &mut cmd)
+                } else {
+                    output
+                }
+            }
+            None => self.run_action(
+                job.target_key,
+                Action {
+                    command: &mut cmd,
+                    outputs: Some(vec![object_file.clone(), object_file.with_extension("d")]),
+                },
+            ),
+        }
+        .map_err(BuildError::IoError)?;
+
+        if !output.status.success() {
+            let mut error_msg = String::from_utf8_lossy(&output.stderr).to_string();
+            if crash::is_compiler_crash(&output) && self.dry_run.is_none() {
+                error_msg.push_str(&self.capture_crash(job, &cmd, &output));
+            }
+            error_map.insert(source_file.clone(), error_msg);
+            return Err(BuildError::CompileFailed(CommandFailure::new(
+                job.target_key,
+                &cmd,
+                &output.stderr,
+            )));
+        }
+
+        if !output.stderr.is_empty() {
+            let warning_msg = String::from_utf8_lossy(&output.stderr).to_string();
+            warning_map.insert(source_file.clone(), warning_msg);
+        }
+
+        Ok(())
+    }
+
+    /// 컴파일러 충돌 재현 정보를 저장하고 오류 출력에 덧붙일 안내 문구 반환
+    fn capture_crash(&self, job: &CompileJob, cmd: &Command, output: &Output) -> String {
+        let run = |cmd: &mut Command| self.run_command(job.target_key, cmd);
+        match crash::capture(&self.layout, cmd, job.source_file, output, run) {
+            Ok(dir) => tr!(
+                "\n컴파일러가 비정상 종료했습니다. 재현 정보: {}\n",
+                "\nThe compiler crashed. Reproducer saved to: {}\n",
+                dir.display()
+            ),
+            Err(e) => tr!(
+                "\n컴파일러가 비정상 종료했지만 재현 정보를 저장하지 못했습니다: {}\n",
+                "\nThe compiler crashed, but the reproducer could not be saved: {}\n",
+                e
+            ),
+        }
+    }
+
+    /// 중복을 제거한 경고와 경고 종류별 개수 요약 출력
+    ///
+    /// `--deny-warnings`에 지정한 경고가 있으면 오류를 반환한다.
+    fn report_warnings(&self, outputs: impl Iterator<Item = String>) -> BuildResult<()> {
+        let outputs: Vec<String> = outputs.collect();
+        let warnings = collect_warnings(outputs.iter().map(|output| output.as_str()));
+        self.warnings
+            .lock()
+            .unwrap()
+            .extend(warnings.iter().map(WarningRecord::from));
+        if warnings.is_empty() {
+            return Ok(());
+        }
+
+        println!(
+            "\n{}",
+            tr!("컴파일 경고:", "Compiler warnings:").yellow().bold()
+        );
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        for entry in &warnings {
+            let diagnostic = &entry.diagnostic;
+            let mut location = diagnostic.file.display().to_string();
+            if let Some(line) = diagnostic.line {
+                location.push_str(&format!(":{}", line));
+            }
+            if let Some(column) = diagnostic.column {
+                location.push_str(&format!(":{}", column));
+            }
+
+            if entry.occurrences > 1 {
+                println!(
+                    "{}: {} {}",
+                    location.yellow(),
+                    diagnostic.message,
+                    tr!("({}개 파일에서 발생)", "(in {} files)", entry.occurrences)
+                );
+            } else {
+                println!("{}: {}", location.yellow(), diagnostic.message);
+            }
+
+            let option = warning_option(&diagnostic.message).unwrap_or_default();
+            *counts.entry(option).or_insert(0) += 1;
+        }
+
+        let mut summary: Vec<(&String, &usize)> = counts.iter().collect();
+        summary.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+
+        println!(
+            "\n{} {}",
+            tr!("경고 요약:", "Warning summary:").bold(),
+            tr!("(경고 {}개)", "({} warnings)", warnings.len())
+        );
+        for (option, count) in &summary {
+            let name = if option.is_empty() {
+                tr!("(기타)", "(other)")
+            } else {
+                format!("-W{}", option)
+            };
+            println!("  {:>4}  {}", count, name);
+        }
+
+        let denied: Vec<String> = summary
+            .iter()
+            .filter(|(option, _)| {
+                self.deny_warnings
+                    .iter()
+                    .any(|deny| deny == "all" || deny == *option)
+            })
+            .map(|(option, count)| {
+                if option.is_empty() {
+                    tr!("(기타) {}개", "(other) x{}", count)
+                } else {
+                    tr!("-W{} {}개", "-W{} x{}", option, count)
+                }
+            })
+            .collect();
+
+        if !denied.is_empty() {
+            return Err(BuildError::CompilerError(tr!(
+                "허용되지 않은 경고 발생: {}",
+                "denied warnings: {}",
+                denied.join(", ")
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// 타겟의 소스 파일 컴파일에 사용할 플래그 (`-c`, `-o` 및 소스 경로 제외)
+    /// `--emit-sarif`가 지정되면 컴파일러 출력의 진단을 SARIF 파일로 저장
+    fn write_diagnostics_report(&self, outputs: impl Iterator<Item = String>) -> BuildResult<()> {
+        let Some(ref sarif_path) = self.sarif_path else {
+            return Ok(());
+        };
+
+        let mut diagnostics: Vec<_> = outputs
+            .flat_map(|output| parse_compiler_output(&output))
+            .collect();
+        diagnostics.sort_by(|a, b| (&a.file, a.line, a.column).cmp(&(&b.file, b.line, b.column)));
+
+        let compiler = &self.config().build.compiler;
+        write_sarif(sarif_path, compiler, &diagnostics, &self.project_dir)?;
+        info!("SARIF 진단 저장: {}", sarif_path.display());
+
+        Ok(())
+    }
+
+    /// 타겟의 포함 디렉토리 (프로젝트 기준 절대 경로)
+    fn target_include_dirs(&self, target_type: &str, target_name: &str) -> Vec<PathBuf> {
+        let config = self.config();
+
+        let include_dirs = match target_type {
+            "exe" => config
+                .targets
+                .executable
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.include_dirs.clone())
+                .unwrap_or_default(),
+            "static" | "shared" => {
+                let libs = if target_type == "static" {
+                    &config.targets.static_lib
+                } else {
+                    &config.targets.shared_lib
+                };
+
+                libs.iter()
+                    .find(|t| t.name == target_name)
+                    .and_then(|t| t.include_dirs.clone())
+                    .unwrap_or_default()
+            }
+            "test" => config
+                .targets
+                .test
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.include_dirs.clone())
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        };
+
+        // 인터페이스 타겟의 포함 디렉토리는 타겟 자신의 것 뒤에 둔다
+        include_dirs
+            .into_iter()
+            .map(|dir| to_absolute_path(Path::new(&dir), &self.project_dir))
+            .chain(self.interface_include_dirs(target_type, target_name))
+            .collect()
+    }
+
+    /// 타겟이 사용하는 인터페이스 타겟의 포함 디렉토리
+    fn interface_include_dirs(&self, target_type: &str, target_name: &str) -> Vec<PathBuf> {
+        self.config()
+            .target_interfaces(target_type, target_name)
+            .unwrap_or_default()
+            .into_iter()
+            .flat_map(|interface| interface.include_dirs.clone().unwrap_or_default())
+            .map(|dir| to_absolute_path(Path::new(&dir), &self.project_dir))
+            .collect()
+    }
+
+    fn compile_flags(&self, target_type: &str, target_name: &str) -> Vec<OsString> {
+        let config = self.config();
+        let mut args: Vec<OsString> = Vec::new();
+
+        // 표준 설정
+        if let Some(ref c_std) = config.build.c_standard {
+            args.push(format!("-std={}", c_std).into());
+        }
+
+        // 최적화 수준과 디버그 정보 (빌드 구성의 프로파일이 우선)
+        let profile = config.profile(&self.configuration);
+        match profile.and_then(|p| p.optimization_level.as_ref()) {
+            Some(level) => args.push(level.flag().into()),
+            None => {
+                if let Some(opt_level) = config.build.optimization_level {
+                    args.push(format!("-O{}", opt_level).into());
+                }
+            }
+        }
+
+        let debug_info = profile
+            .and_then(|p| p.debug_info)
+            .or(config.build.debug_info)
+            .unwrap_or(false);
+        if debug_info {
+            args.push("-g".into());
+        }
+
+        // 경고를 오류로 처리
+        if config.build.warnings_as_errors.unwrap_or(false) {
+            args.push("-Werror".into());
+        }
+
+        // 크로스 컴파일 타겟 (zig cc, clang)
+        if let Some(ref triple) = config.build.target {
+            args.push("-target".into());
+            args.push(triple.into());
+        }
+
+        // 포함 디렉토리 추가 (strict_includes면 타겟 자신의 디렉토리는 `#include "..."`만
+        // 찾는 -iquote, 인터페이스와 프로젝트 밖 디렉토리는 경고를 내지 않는 -isystem)
+        let strict = config.build.strict_includes.unwrap_or(false);
+        let local_flag = if strict { "-iquote" } else { "-I" };
+        let interface_dirs = self.interface_include_dirs(target_type, target_name);
+        for include_path in self.target_include_dirs(target_type, target_name) {
+            let external = interface_dirs.contains(&include_path)
+                || !include_path.starts_with(&self.project_dir);
+            args.push(
+                if strict && external {
+                    "-isystem"
+                } else {
+                    local_flag
+                }
+                .into(),
+            );
+            args.push(include_path.into_os_string());
+        }
+
+        // 임베딩 리소스 헤더 디렉토리
+        if let Some(embed_dir) = self
+            .embed_include_dirs
+            .get(&format!("{}:{}", target_type, target_name))
+        {
+            args.push(local_flag.into());
+            args.push(embed_dir.clone().into_os_string());
+        }
+
+        // 생성된 헤더 디렉토리 (version.h, config.h, export 헤더, 사용자 정의 명령의 출력)
+        args.push(local_flag.into());
+        args.push(self.layout.generated_dir().into_os_string());
+
+        // 매크로 정의 추가
+        let defines: BTreeMap<String, String> = match target_type {
+            "exe" => config
+                .targets
+                .executable
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.defines.clone())
+                .unwrap_or_default(),
+            "static" | "shared" => {
+                let libs = if target_type == "static" {
+                    &config.targets.static_lib
+                } else {
+                    &config.targets.shared_lib
+                };
+
+                libs.iter()
+                    .find(|t| t.name == target_name)
+                    .and_then(|t| t.defines.clone())
+                    .unwrap_or_default()
+            }
+            "test" => config
+                .targets
+                .test
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.defines.clone())
+                .unwrap_or_default(),
+            _ => BTreeMap::new(),
+        };
+
+        // 인터페이스 타겟의 매크로 정의 (타겟 자신의 정의가 우선, 이름 순으로 전달)
+        let mut interface_defines: BTreeMap<String, String> = BTreeMap::new();
+        for interface in config
+            .target_interfaces(target_type, target_name)
+            .unwrap_or_default()
+        {
+            for (key, value) in interface.defines.iter().flatten() {
+                interface_defines
+                    .entry(key.clone())
+                    .or_insert_with(|| value.clone());
+            }
+        }
+        // 타겟이 쓰는 의존성의 기능 매크로 (인터페이스와 같은 우선순위)
+        if let Some(features) = self
+            .context
+            .dependency_features()
+            .ok()
+            .filter(|features| !features.is_empty())
+        {
+            for (name, _) in self.target_dependencies(target_type, target_name) {
+                for (key, value) in features
+                    .get(&name)
+                    .map(|f| &f.defines)
+                    .into_iter()
+                    .flatten()
+                {
+                    interface_defines
+                        .entry(key.clone())
+                        .or_insert_with(|| value.clone());
+                }
+            }
+        }
+        interface_defines.extend(defines);
+
+        for (key, value) in interface_defines {
+            if value.is_empty() {
+                args.push(format!("-D{}", key).into());
+            } else {
+                args.push(format!("-D{}={}", key, value).into());
+            }
+        }
+
+        // export 헤더가 dllexport를 고르도록 라이브러리 자신의 소스에만 정의
+        if target_type == "shared" && config.generated.export_headers.unwrap_or(false) {
+            args.push(format!("-D{}", generated::export_building_macro(target_name)).into());
+        }
+
+        // 추가 플래그 추가
+        let extra_flags: Vec<String> = match target_type {
+            "exe" => config
+                .targets
+                .executable
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.extra_flags.clone())
+                .unwrap_or_default(),
+            "static" | "shared" => {
+                let libs = if target_type == "static" {
+                    &config.targets.static_lib
+                } else {
+                    &config.targets.shared_lib
+                };
+
+                libs.iter()
+                    .find(|t| t.name == target_name)
+                    .and_then(|t| t.extra_flags.clone())
+                    .unwrap_or_default()
+            }
+            "test" => config
+                .targets
+                .test
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.extra_flags.clone())
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        };
+
+        for flag in extra_flags {
+            args.push(flag.into());
+        }
+
+        // 빌드 구성에 따른 추가 설정
+        if config.is_release_configuration(&self.configuration) {
+            args.push("-DNDEBUG".into());
+        } else {
+            args.push("-D_DEBUG".into());
+        }
+        for (key, value) in profile
+            .and_then(|p| p.defines.as_ref())
+            .into_iter()
+            .flatten()
+        {
+            if value.is_empty() {
+                args.push(format!("-D{}", key).into());
+            } else {
+                args.push(format!("-D{}={}", key, value).into());
+            }
+        }
+
+        // PIC (Position Independent Code) 옵션 - 공유 라이브러리용
+        if target_type == "shared" {
+            args.push("-fPIC".into());
+        }
+
+        // 전역 추가 플래그 (빌드 구성의 프로파일 플래그는 그 뒤)
+        let profile_flags = profile.and_then(|p| p.extra_flags.as_ref());
+        for flag in config
+            .build
+            .extra_flags
+            .iter()
+            .chain(profile_flags)
+            .flatten()
+        {
+            args.push(flag.into());
+        }
+
+        // 명령 단계가 덧붙이는 플래그 (pgo 등)
+        args.extend(self.extra_flags.compile.iter().cloned());
+
+        // 캡처한 컴파일러 출력에도 색 유지 (--no-color, NO_COLOR이면 생략)
+        if let Some(ref toolchain) = self.toolchain {
+            if colored::control::SHOULD_COLORIZE.should_colorize() {
+                if let Some(flag) = toolchain.color_diagnostics_flag() {
+                    args.push(flag.into());
+                }
+            }
+        }
+
+        // 컴파일러가 지원하지 않는 플래그 제외
+        let mut args = match self.toolchain {
+            Some(ref toolchain) => toolchain.filter_flags(args),
+            None => args,
+        };
+
+        // OpenMP 플래그는 확인을 마쳤으므로 필터 뒤에 추가 (Apple clang의 -Xpreprocessor 쌍 보존)
+        if config.target_uses_openmp(target_type, target_name) {
+            args.extend(self.openmp_flags().compile.into_iter().map(OsString::from));
+        }
+
+        args
+    }
+
+    /// [pch] 캐시의 미리 컴파일한 헤더를 쓰는 인자 (Objective-C 소스와 --dry-run 제외)
+    fn pch_args(
+        &self,
+        target_type: &str,
+        target_name: &str,
+        source: &Path,
+        flags: &[OsString],
+    ) -> Vec<OsString> {
+        let Some(ref pch) = self.pch else {
+            return Vec::new();
+        };
+        if is_objc_source(source) || self.dry_run.is_some() {
+            return Vec::new();
+        }
+
+        let target_key = format!("{}:{}", target_type, target_name);
+        pch.include_args(
+            source,
+            flags,
+            &self.flags_fingerprint(target_type, target_name),
+            |cmd| self.run_command(&target_key, cmd),
+        )
+    }
+
+    /// 크로스 컴파일 타겟과 정적 링크 인자 (공유 라이브러리는 정적 링크하지 않음)
+    fn target_link_args(&self, target_type: &str) -> Vec<OsString> {
+        let config = self.config();
+        let mut args: Vec<OsString> = Vec::new();
+
+        if let Some(ref triple) = config.build.target {
+            args.push("-target".into());
+            args.push(triple.into());
+        }
+        if config.build.static_link.unwrap_or(false) && target_type != "shared" {
+            args.push("-static".into());
+        }
+        if let Some(link_flags) = config
+            .profile(&self.configuration)
+            .and_then(|p| p.link_flags.as_ref())
+        {
+            args.extend(link_flags.iter().map(OsString::from));
+        }
+        args.extend(self.extra_flags.link.iter().cloned());
+
+        args
+    }
+
+    /// 타겟 컴파일 플래그 지문 (바뀌면 타겟의 모든 소스를 다시 컴파일)
+    ///
+    /// 터미널 여부에 따라 달라지는 색상 진단 플래그는 제외한다.
+    fn flags_fingerprint(&self, target_type: &str, target_name: &str) -> String {
+        let color_flag = self
+            .toolchain
+            .as_ref()
+            .and_then(|toolchain| toolchain.color_diagnostics_flag());
+
+        let mut fingerprint = self
+            .compile_flags(target_type, target_name)
+            .iter()
+            .map(|arg| arg.to_string_lossy())
+            .filter(|arg| Some(arg.as_ref()) != color_flag)
+            .collect::<Vec<_>>()
+            .join("\u{1f}");
+        fingerprint.push_str(&self.extra_flags.fingerprint);
+        // build.compiler를 바꾸면 플래그가 같아도 다시 컴파일
+        let config = self.config();
+        fingerprint.push('\u{1f}');
+        fingerprint.push_str(&config.build.compiler);
+        fingerprint.push('\u{1f}');
+        fingerprint.push_str(config.objc_compiler());
+        fingerprint
+    }
+
+    /// 타겟별 링크 입력 지문 (증분 상태의 `link:<타겟 키>` 항목)
+    fn link_fingerprints(
+        &self,
+        source_files: &BTreeMap<String, Vec<PathBuf>>,
+    ) -> HashMap<String, String> {
+        source_files
+            .iter()
+            .map(|(key, files)| (format!("link:{}", key), self.link_fingerprint(key, files)))
+            .collect()
+    }
+
+    /// 타겟 링크 입력의 지문
+    ///
+    /// [build]와 현재 구성의 프로필, 타겟 설정, 소스 목록에서 계산하므로 cbuild.toml에서
+    /// 링크 옵션을 바꾸거나 소스를 빼면 컴파일할 파일이 없어도 다시 링크한다.
+    fn link_fingerprint(&self, key: &str, files: &[PathBuf]) -> String {
+        let config = self.config();
+        let (target_type, target_name) = key.split_once(':').unwrap_or(("", key));
+        // serde_json::Value는 키를 정렬하므로 HashMap 필드가 있어도 지문이 실행마다 같음
+        let target = match target_type {
+            "exe" => config
+                .targets
+                .executable
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| serde_json::to_value(t).ok()),
+            "static" => config
+                .targets
+                .static_lib
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| serde_json::to_value(t).ok()),
+            "shared" => config
+                .targets
+                .shared_lib
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| serde_json::to_value(t).ok()),
+            "test" => config
+                .targets
+                .test
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| serde_json::to_value(t).ok()),
+            _ => None,
+        };
+
+        let mut hasher = Sha256::new();
+        for part in [
+            serde_json::to_value(&config.build).ok(),
+            config
+                .profiles
+                .get(&self.configuration)
+                .and_then(|profile| serde_json::to_value(profile).ok()),
+            target,
+        ] {
+            hasher.update(part.unwrap_or_default().to_string());
+            hasher.update([0x1f]);
+        }
+        let mut files: Vec<&PathBuf> = files.iter().collect();
+        files.sort();
+        for file in files {
+            hasher.update(file.to_string_lossy().as_bytes());
+            hasher.update([0x1f]);
+        }
+        hasher.update(&self.extra_flags.fingerprint);
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// 확인한 OpenMP 플래그 (컴파일러를 탐지하지 않았으면 기본 -fopenmp)
+    fn openmp_flags(&self) -> OpenMpFlags {
+        self.openmp.clone().unwrap_or_default()
+    }
+
+    /// 링크에 필요한 OpenMP 플래그
+    ///
+    /// 실행 파일과 테스트는 모든 내부 정적 라이브러리를 함께 링크하므로
+    /// OpenMP를 쓰는 정적 라이브러리가 있어도 런타임을 링크한다.
+    fn openmp_link_args(&self, target_type: &str, target_name: &str) -> Vec<String> {
+        let config = self.config();
+        let links_openmp_static_lib = target_type != "shared"
+            && config
+                .targets
+                .static_lib
+                .iter()
+                .any(|lib| config.target_uses_openmp("static", &lib.name));
+
+        if config.target_uses_openmp(target_type, target_name) || links_openmp_static_lib {
+            self.openmp_flags().link
+        } else {
+            Vec::new()
+        }
+    }
 
-            for file in files {
-                let path_str = file.to_string_lossy().to_string();
-                let hash = calculate_file_hash(file)?;
+    /// 소스 파일을 컴파일할 컴파일러 (Objective-C 소스는 build.objc_compiler)
+    fn source_compiler(&self, source: &Path) -> &str {
+        let config = self.config();
+        if is_objc_source(source) {
+            config.objc_compiler()
+        } else {
+            &config.build.compiler
+        }
+    }
 
-                if !previous_hashes.contains_key(&path_str)
-                    || previous_hashes.get(&path_str) != Some(&hash)
-                {
-                    changed.push(file.clone());
-                }
+    /// 소스 언어에 따라 타겟 컴파일 플래그 조정
+    ///
+    /// Objective-C 소스는 ARC(`build.objc_arc`, 기본 켜짐)로 컴파일하고,
+    /// Objective-C++ 소스에는 C 표준 대신 `build.cpp_standard`를 사용한다.
+    fn source_flags(&self, source: &Path, mut args: Vec<OsString>) -> Vec<OsString> {
+        if !is_objc_source(source) {
+            return args;
+        }
 
-                new_hashes.insert(path_str, hash);
+        let config = self.config();
+        if get_extension(source).as_deref() == Some("mm") {
+            args.retain(|arg| {
+                let arg = arg.to_string_lossy();
+                !arg.starts_with("-std=") || arg.contains("++")
+            });
+            if let Some(ref cpp_std) = config.build.cpp_standard {
+                args.push(format!("-std={}", cpp_std).into());
             }
+        }
+        if config.build.objc_arc.unwrap_or(true) {
+            args.push("-fobjc-arc".into());
+        }
 
-            if !changed.is_empty() {
-                changed_files.insert(target.clone(), changed);
+        args
+    }
+
+    /// 타겟과 함께 링크되는 타겟 키 (실행 파일과 테스트는 모든 내부 정적 라이브러리 포함)
+    fn linked_target_keys(&self, target_type: &str, target_name: &str) -> Vec<(String, String)> {
+        let config = self.config();
+        let mut keys = vec![(target_type.to_string(), target_name.to_string())];
+        if target_type == "exe" || target_type == "test" {
+            for lib in &config.targets.static_lib {
+                keys.push(("static".to_string(), lib.name.clone()));
             }
         }
+        keys
+    }
 
-        // 새 해시 저장
-        let json = serde_json::to_string(&new_hashes)
-            .map_err(|e| BuildError::ConfigParsingError(e.to_string()))?;
-        std::fs::write(&hash_file, json).map_err(|e| BuildError::IoError(e))?;
-
-        Ok(changed_files)
+    /// 링크에 포함되는 Objective-C 소스
+    fn linked_objc_sources(&self, target_type: &str, target_name: &str) -> Vec<&PathBuf> {
+        self.linked_target_keys(target_type, target_name)
+            .iter()
+            .filter_map(|(kind, name)| self.sources.get(&format!("{}:{}", kind, name)))
+            .flatten()
+            .filter(|source| is_objc_source(source))
+            .collect()
     }
 
-    fn compile_sources(&self, source_files: &HashMap<String, Vec<PathBuf>>) -> BuildResult<()> {
-        if source_files.is_empty() {
-            return Ok(());
+    /// 타겟을 링크할 컴파일러 드라이버 (Objective-C 오브젝트가 있으면 build.objc_compiler)
+    fn linker(&self, target_type: &str, target_name: &str) -> &str {
+        let config = self.config();
+        if self
+            .linked_objc_sources(target_type, target_name)
+            .is_empty()
+        {
+            &config.build.compiler
+        } else {
+            config.objc_compiler()
         }
+    }
 
-        let config = self.config.as_ref().unwrap();
-        let compiler = &config.build.compiler;
+    /// Objective-C 런타임과 프레임워크 링크 인자
+    fn objc_link_args(&self, target_type: &str, target_name: &str) -> Vec<String> {
+        let config = self.config();
+        let mut args = Vec::new();
 
-        println!(
-            "{} ({}개 작업, {}개 스레드 사용)",
-            "컴파일 중".blue().bold(),
-            source_files.values().map(|v| v.len()).sum::<usize>(),
-            self.jobs
-        );
+        let objc_sources = self.linked_objc_sources(target_type, target_name);
+        if !objc_sources.is_empty() {
+            if cfg!(target_os = "macos") {
+                args.push("-fobjc-link-runtime".to_string());
+            } else {
+                args.push("-lobjc".to_string());
+            }
 
-        let mp = MultiProgress::new();
-        let sty = ProgressStyle::default_bar()
-            .template("{prefix:.bold.dim} [{bar:40}] {pos}/{len} {msg}")
-            .unwrap()
-            .progress_chars("=> ");
-
-        let total_files: usize = source_files.values().map(|v| v.len()).sum();
-        let total_pb = mp.add(ProgressBar::new(total_files as u64));
-        total_pb.set_style(sty.clone());
-
-        total_pb.set_prefix("[전체]".to_string());
-
-        let error_map: Arc<DashMap<PathBuf, String>> = Arc::new(DashMap::new());
-        let total_pb_arc = Arc::new(total_pb);
-
-        // 먼저 모든 타겟과 파일 개수를 수집하고 프로그레스바 미리 생성
-        let mut target_progress_bars = Vec::new();
-        for (target_name, files) in source_files {
-            let target_parts: Vec<&str> = target_name.splitn(2, ':').collect();
-            let target_type = target_parts[0];
-            let target_name = target_parts[1];
-
-            // 이 문자열은 Vec에 저장되므로 함수가 반환될 때까지 유효함
-            let prefix = format!("[{}]", target_name);
-            let target_pb = mp.add(ProgressBar::new(files.len() as u64));
-            target_pb.set_style(sty.clone());
-            target_pb.set_prefix(prefix);
-
-            target_progress_bars.push((
-                target_name.to_string(),
-                target_type.to_string(),
-                Arc::new(target_pb),
-                files,
-            ));
+            // Objective-C++ 오브젝트는 C++ 표준 라이브러리가 필요
+            let has_objcpp = objc_sources
+                .iter()
+                .any(|source| get_extension(source).as_deref() == Some("mm"));
+            if has_objcpp && !self.linker(target_type, target_name).contains("++") {
+                args.push("-lc++".to_string());
+            }
         }
 
-        // 이제 실제 컴파일 수행
-        let compile_results: Vec<BuildResult<()>> = target_progress_bars
-            .into_par_iter() // 소유권 이전
-            .flat_map(|(target_name, target_type, target_pb_arc, files)| {
-                let error_map_arc = error_map.clone();
-                let total_pb_arc_clone = total_pb_arc.clone();
-
-                files
-                    .par_iter()
-                    .map(move |source_file| {
-                        let pb = target_pb_arc.clone();
-                        let err_map = error_map_arc.clone();
-                        let total = total_pb_arc_clone.clone();
-
-                        // 출력 경로 생성
-                        let rel_path = source_file
-                            .strip_prefix(&self.project_dir)
-                            .unwrap_or(source_file);
-                        let object_file = self
-                            .build_dir
-                            .join("obj")
-                            .join(rel_path)
-                            .with_extension("o");
-
-                        // 객체 파일 디렉토리 생성
-                        if let Some(parent) = object_file.parent() {
-                            std::fs::create_dir_all(parent).map_err(|e| BuildError::IoError(e))?;
-                        }
-
-                        // 컴파일 플래그 설정
-                        let mut cmd = Command::new(compiler);
-                        cmd.arg("-c").arg(source_file).arg("-o").arg(&object_file);
+        let mut frameworks: Vec<String> = Vec::new();
+        for (kind, name) in self.linked_target_keys(target_type, target_name) {
+            for framework in config.target_frameworks(&kind, &name) {
+                if !frameworks.contains(&framework) {
+                    frameworks.push(framework);
+                }
+            }
+        }
+        if !frameworks.is_empty() && !cfg!(target_os = "macos") {
+            warn!(
+                "{}",
+                tr!(
+                    "{}의 프레임워크는 Apple 플랫폼에서만 링크합니다: {}",
+                    "frameworks of {} are only linked on Apple platforms: {}",
+                    target_name,
+                    frameworks.join(", ")
+                )
+            );
+            return args;
+        }
+        for framework in frameworks {
+            args.push("-framework".to_string());
+            args.push(framework);
+        }
 
-                        // 표준 설정
-                        if let Some(ref c_std) = config.build.c_standard {
-                            cmd.arg(format!("-std={}", c_std));
-                        }
+        args
+    }
 
-                        // 최적화 수준
-                        if let Some(opt_level) = config.build.optimization_level {
-                            cmd.arg(format!("-O{}", opt_level));
-                        }
+    /// Objective-C 소스가 있으면 Objective-C 컴파일러가 설치되어 있는지 확인
+    fn check_objc_compiler(&self) -> BuildResult<()> {
+        let config = self.config();
+        let has_objc = self.sources.values().flatten().any(|s| is_objc_source(s));
+        let compiler = config.objc_compiler();
 
-                        // 디버그 정보
-                        if config.build.debug_info.unwrap_or(false) {
-                            cmd.arg("-g");
-                        }
+        if has_objc && compiler != config.build.compiler && !is_tool_installed(compiler) {
+            return Err(BuildError::CompilerNotFound(compiler.to_string()));
+        }
+        Ok(())
+    }
 
-                        // 경고를 오류로 처리
-                        if config.build.warnings_as_errors.unwrap_or(false) {
-                            cmd.arg("-Werror");
-                        }
+    /// 타겟이 참조하는 인터페이스 타겟이 모두 정의되어 있는지 확인
+    fn check_interfaces(&self) -> BuildResult<()> {
+        let config = self.config();
+        for key in self.sources.keys() {
+            let (target_type, target_name) = key.split_once(':').unwrap_or(("", key));
+            config.target_interfaces(target_type, target_name)?;
+        }
+        Ok(())
+    }
 
-                        // 포함 디렉토리 추가
-                        let include_dirs = match target_type.as_str() {
-                            "exe" => config
-                                .targets
-                                .executable
-                                .iter()
-                                .find(|t| t.name == target_name)
-                                .and_then(|t| t.include_dirs.clone())
-                                .unwrap_or_default(),
-                            "static" | "shared" => {
-                                let libs = if target_type == "static" {
-                                    &config.targets.static_lib
-                                } else {
-                                    &config.targets.shared_lib
-                                };
-
-                                libs.iter()
-                                    .find(|t| t.name == target_name)
-                                    .and_then(|t| t.include_dirs.clone())
-                                    .unwrap_or_default()
-                            }
-                            "test" => config
-                                .targets
-                                .test
-                                .iter()
-                                .find(|t| t.name == target_name)
-                                .and_then(|t| t.include_dirs.clone())
-                                .unwrap_or_default(),
-                            _ => Vec::new(),
-                        };
-
-                        for dir in include_dirs {
-                            let include_path = if Path::new(&dir).is_absolute() {
-                                PathBuf::from(dir)
-                            } else {
-                                self.project_dir.join(dir)
-                            };
-                            cmd.arg("-I").arg(include_path);
-                        }
+    /// 테스트 타겟이 아닌 타겟이 테스트 전용 의존성(deps/<이름>)의 소스, 포함 디렉토리,
+    /// 라이브러리 디렉토리를 쓰면 오류
+    fn check_dev_dependencies(&self) -> BuildResult<()> {
+        let config = self.config();
+        for key in self.sources.keys() {
+            let (target_type, target_name) = key.split_once(':').unwrap_or(("", key));
+            if target_type == "test" {
+                continue;
+            }
+            let used = self.target_dependencies(target_type, target_name);
+            if let Some((name, path)) = used
+                .into_iter()
+                .find(|(name, _)| config.is_dev_dependency(name))
+            {
+                return Err(BuildError::DependencyError(tr!(
+                    "타겟 {}이(가) 테스트 전용 의존성 {}을(를) 사용합니다: {} ([dependencies]로 옮기세요)",
+                    "target {} uses test-only dependency {}: {} (move it to [dependencies])",
+                    key,
+                    name,
+                    path.display()
+                )));
+            }
+        }
+        Ok(())
+    }
 
-                        // 매크로 정의 추가
-                        let defines: HashMap<String, String> = match target_type.as_str() {
-                            "exe" => config
-                                .targets
-                                .executable
-                                .iter()
-                                .find(|t| t.name == target_name)
-                                .and_then(|t| t.defines.clone())
-                                .unwrap_or_default(),
-                            "static" | "shared" => {
-                                let libs = if target_type == "static" {
-                                    &config.targets.static_lib
-                                } else {
-                                    &config.targets.shared_lib
-                                };
-
-                                libs.iter()
-                                    .find(|t| t.name == target_name)
-                                    .and_then(|t| t.defines.clone())
-                                    .unwrap_or_default()
-                            }
-                            "test" => config
-                                .targets
-                                .test
-                                .iter()
-                                .find(|t| t.name == target_name)
-                                .and_then(|t| t.defines.clone())
-                                .unwrap_or_default(),
-                            _ => HashMap::new(),
-                        };
-
-                        for (key, value) in defines {
-                            if value.is_empty() {
-                                cmd.arg(format!("-D{}", key));
-                            } else {
-                                cmd.arg(format!("-D{}={}", key, value));
-                            }
-                        }
+    /// 타겟이 소스, 포함 디렉토리, 라이브러리 디렉토리로 쓰는 의존성과 처음 찾은 경로
+    /// (deps/<이름> 아래 경로, 선언 순서)
+    fn target_dependencies(&self, target_type: &str, target_name: &str) -> Vec<(String, PathBuf)> {
+        let config = self.config();
+        let deps_dir = self.project_dir.join("deps");
+
+        let link_dirs = match target_type {
+            "exe" => config
+                .targets
+                .executable
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.link_dirs.clone()),
+            "test" => config
+                .targets
+                .test
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.link_dirs.clone()),
+            _ => None,
+        }
+        .unwrap_or_default()
+        .into_iter()
+        .map(|dir| to_absolute_path(Path::new(&dir), &self.project_dir));
+        let used = self
+            .sources
+            .get(&format!("{}:{}", target_type, target_name))
+            .into_iter()
+            .flatten()
+            .cloned()
+            .chain(self.target_include_dirs(target_type, target_name))
+            .chain(link_dirs);
+
+        let mut found: Vec<(String, PathBuf)> = Vec::new();
+        for path in used {
+            let Some(name) = path
+                .strip_prefix(&deps_dir)
+                .ok()
+                .and_then(|rel| rel.iter().next())
+                .map(|name| name.to_string_lossy().to_string())
+            else {
+                continue;
+            };
+            if config.find_dependency(&name).is_some() && !found.iter().any(|(n, _)| *n == name) {
+                found.push((name, path));
+            }
+        }
+        found
+    }
 
-                        // 추가 플래그 추가
-                        let extra_flags: Vec<String> = match target_type.as_str() {
-                            "exe" => config
-                                .targets
-                                .executable
-                                .iter()
-                                .find(|t| t.name == target_name)
-                                .and_then(|t| t.extra_flags.clone())
-                                .unwrap_or_default(),
-                            "static" | "shared" => {
-                                let libs = if target_type == "static" {
-                                    &config.targets.static_lib
-                                } else {
-                                    &config.targets.shared_lib
-                                };
-
-                                libs.iter()
-                                    .find(|t| t.name == target_name)
-                                    .and_then(|t| t.extra_flags.clone())
-                                    .unwrap_or_default()
-                            }
-                            "test" => config
-                                .targets
-                                .test
-                                .iter()
-                                .find(|t| t.name == target_name)
-                                .and_then(|t| t.extra_flags.clone())
-                                .unwrap_or_default(),
-                            _ => Vec::new(),
-                        };
-
-                        for flag in extra_flags {
-                            cmd.arg(flag);
-                        }
+    /// 해당 시점의 사용자 정의 명령 타겟을 의존 순서대로 실행
+    ///
+    /// 증분 빌드에서는 출력이 입력보다 새롭고 명령이 바뀌지 않은 타겟을 건너뛴다.
+    fn run_custom_targets(&self, stage: CustomStage) -> BuildResult<()> {
+        let config = self.config();
+        let stamp_dir = self.layout.build_dir().join("custom");
 
-                        // 빌드 구성에 따른 추가 설정
-                        if self.configuration == "release" {
-                            cmd.arg("-DNDEBUG");
-                        } else {
-                            cmd.arg("-D_DEBUG");
-                        }
+        for (target, target_stage) in custom::ordered_custom_targets(config)? {
+            if target_stage != stage {
+                continue;
+            }
 
-                        // PIC (Position Independent Code) 옵션 - 공유 라이브러리용
-                        if target_type == "shared" {
-                            cmd.arg("-fPIC");
-                        }
+            let inputs = custom::custom_inputs(config, target, &self.project_dir, &self.layout)?;
+            let stamp_path = stamp_dir.join(format!("{}.stamp", target.name));
+            if self.incremental
+                && custom::is_up_to_date(
+                    target,
+                    &inputs,
+                    &self.project_dir,
+                    &self.layout,
+                    &stamp_path,
+                )
+            {
+                debug!("사용자 정의 타겟 최신 상태: {}", target.name);
+                continue;
+            }
 
-                        // 전역 추가 플래그
-                        if let Some(ref extra_flags) = config.build.extra_flags {
-                            for flag in extra_flags {
-                                cmd.arg(flag);
-                            }
-                        }
+            let (program, args) = target.command.split_first().ok_or_else(|| {
+                BuildError::ConfigParsingError(tr!(
+                    "사용자 정의 타겟 {}에 command가 비어 있습니다",
+                    "custom target {} has an empty command",
+                    target.name
+                ))
+            })?;
+
+            for output in custom::custom_outputs(target, &self.project_dir, &self.layout) {
+                if let Some(parent) = output.parent().filter(|_| self.dry_run.is_none()) {
+                    std::fs::create_dir_all(parent).map_err(BuildError::IoError)?;
+                }
+            }
 
-                        if self.verbose {
-                            println!("Compiling: {:?}", cmd);
-                        }
+            let cwd = match target.cwd {
+                Some(ref cwd) => to_absolute_path(Path::new(cwd), &self.project_dir),
+                None => self.project_dir.clone(),
+            };
+            let generated_dir = self.layout.generated_dir();
+            let mut cmd = Command::new(program);
+            cmd.args(
+                args.iter()
+                    .map(|arg| substitute_paths(arg, &[("{generated}", &generated_dir)])),
+            )
+            .current_dir(cwd)
+            .env("CBUILD_PROJECT_DIR", &self.project_dir)
+            .env("CBUILD_BUILD_DIR", self.layout.build_dir())
+            .env("CBUILD_GENERATED_DIR", &generated_dir)
+            .env("CBUILD_CONFIGURATION", &self.configuration);
+            for (key, value) in target.env.iter().flatten() {
+                cmd.env(key, value);
+            }
 
-                        // 파일 이름 문자열 생성 및 메시지 설정
-                        let file_name = source_file
-                            .file_name()
-                            .unwrap_or_default()
-                            .to_string_lossy()
-                            .to_string();
-                        pb.set_message(file_name);
-
-                        // 컴파일 실행
-                        let output = cmd.output().map_err(|e| BuildError::IoError(e))?;
-
-                        if !output.status.success() {
-                            let error_msg = String::from_utf8_lossy(&output.stderr).to_string();
-                            err_map.insert(source_file.clone(), error_msg);
-                            return Err(BuildError::CompilerError(format!(
-                                "컴파일 실패: {}",
-                                source_file.display()
-                            )));
-                        }
+            println!(
+                "{} {}",
+                tr!("사용자 정의 명령:", "Custom command:").blue(),
+                target.name
+            );
+            if self.verbose {
+                println!("Running: {:?}", cmd);
+            }
 
-                        pb.inc(1);
-                        total.inc(1);
+            let output = self
+                .run_command(&format!("custom:{}", target.name), &mut cmd)
+                .map_err(BuildError::IoError)?;
+            if self.verbose {
+                print!("{}", String::from_utf8_lossy(&output.stdout));
+            }
+            if !output.status.success() {
+                return Err(BuildError::CustomCommandFailed(CommandFailure::new(
+                    &format!("custom:{}", target.name),
+                    &cmd,
+                    &output.stderr,
+                )));
+            }
 
-                        Ok(())
-                    })
-                    .collect::<Vec<_>>()
-            })
-            .collect();
+            if self.dry_run.is_some() {
+                continue;
+            }
 
-        // 컴파일 오류 출력
-        if !error_map.is_empty() {
-            println!("\n{}", "컴파일 오류:".red().bold());
-            for entry in error_map.iter() {
-                println!(
-                    "{}: \n{}",
-                    entry.key().display().to_string().yellow(),
-                    entry.value()
-                );
+            for output in custom::custom_outputs(target, &self.project_dir, &self.layout) {
+                if !output.exists() {
+                    warn!(
+                        "{}",
+                        tr!(
+                            "사용자 정의 타겟 {}이(가) 출력 {}을(를) 만들지 않았습니다",
+                            "custom target {} did not produce output {}",
+                            target.name,
+                            output.display()
+                        )
+                    );
+                }
             }
-            return Err(BuildError::CompilerError("컴파일 오류 발생".to_string()));
-        }
 
-        // 컴파일 성공 여부 확인
-        let has_errors = compile_results.iter().any(|r| r.is_err());
-        if has_errors {
-            return Err(BuildError::CompilerError("빌드 실패".to_string()));
+            std::fs::create_dir_all(&stamp_dir).map_err(BuildError::IoError)?;
+            std::fs::write(&stamp_path, custom::command_stamp(target))
+                .map_err(BuildError::IoError)?;
         }
 
-        println!("{}", "컴파일 완료".green());
         Ok(())
     }
 
     fn link_targets(&self) -> BuildResult<()> {
-        println!("{}", "링크 중...".blue().bold());
-
-        let config = self.config.as_ref().unwrap();
-        let compiler = &config.build.compiler;
+        println!("{}", tr!("링크 중...", "Linking...").blue().bold());
 
         // 정적 라이브러리 링크
         self.link_static_libraries()?;
@@ -522,43 +3251,58 @@ impl Builder {
         // 테스트 링크
         self.link_tests()?;
 
-        println!("{}", "링크 완료".green());
+        println!("{}", tr!("링크 완료", "Linking finished").green());
         Ok(())
     }
 
     fn link_static_libraries(&self) -> BuildResult<()> {
-        let config = self.config.as_ref().unwrap();
+        let config = self.config();
 
         if config.targets.static_lib.is_empty() {
             return Ok(());
         }
 
         for lib in &config.targets.static_lib {
-            println!("Static library: {}", lib.name);
+            if !self.is_selected(&format!("static:{}", lib.name)) {
+                continue;
+            }
+            println!(
+                "{}",
+                tr!("정적 라이브러리: {}", "Static library: {}", lib.name)
+            );
 
-            let source_files = config
-                .resolve_source_files(&self.project_dir)?
+            let source_files = self
+                .sources
                 .get(&format!("static:{}", lib.name))
                 .cloned()
                 .unwrap_or_default();
 
             if source_files.is_empty() {
-                warn!("No source files for static library: {}", lib.name);
+                warn!(
+                    "{}",
+                    tr!(
+                        "정적 라이브러리 {}에 소스 파일이 없습니다",
+                        "No source files for static library: {}",
+                        lib.name
+                    )
+                );
                 continue;
             }
 
             // 객체 파일 수집
             let mut object_files = Vec::new();
             for source in &source_files {
-                let rel_path = source.strip_prefix(&self.project_dir).unwrap_or(source);
-                let object_file = self
-                    .build_dir
-                    .join("obj")
-                    .join(rel_path)
-                    .with_extension("o");
-
-                if !object_file.exists() {
-                    warn!("Object file does not exist: {}", object_file.display());
+                let object_file = self.layout.object_file(&self.project_dir, source);
+
+                if !self.will_exist(&object_file) {
+                    warn!(
+                        "{}",
+                        tr!(
+                            "오브젝트 파일이 없습니다: {}",
+                            "Object file does not exist: {}",
+                            object_file.display()
+                        )
+                    );
                     continue;
                 }
 
@@ -566,39 +3310,52 @@ impl Builder {
             }
 
             if object_files.is_empty() {
-                warn!("No object files found for static library: {}", lib.name);
+                warn!(
+                    "{}",
+                    tr!(
+                        "정적 라이브러리 {}의 오브젝트 파일이 없습니다",
+                        "No object files found for static library: {}",
+                        lib.name
+                    )
+                );
                 continue;
             }
 
-            // 라이브러리 파일 경로
-            let lib_name = format!("lib{}.a", lib.name);
-            let lib_path = self.build_dir.join("lib").join(&lib_name);
+            // 라이브러리 파일 경로 (output_dir 하위 디렉토리 포함)
+            let lib_path = self.layout.static_lib_path(&lib.name);
+            if let Some(parent) = lib_path.parent().filter(|_| self.dry_run.is_none()) {
+                std::fs::create_dir_all(parent).map_err(BuildError::IoError)?;
+            }
 
             // 아카이버 실행
-            let mut cmd = Command::new("ar");
-            cmd.arg("rcs").arg(&lib_path);
+            let mut cmd = tool_command(tools::archiver(config));
+            cmd.arg("rcs").arg(long_path(&lib_path));
 
             for obj in &object_files {
-                cmd.arg(obj);
+                cmd.arg(long_path(obj));
             }
 
             if self.verbose {
                 println!("Archiving: {:?}", cmd);
             }
 
-            let output = cmd.output().map_err(|e| BuildError::IoError(e))?;
+            let output = self
+                .run_command(&format!("static:{}", lib.name), &mut cmd)
+                .map_err(BuildError::IoError)?;
 
             // 오류 처리 시 원본 사용 (이동되지 않음)
             if !output.status.success() {
-                let error = String::from_utf8_lossy(&output.stderr);
-                let message = format!("Failed to create static library: {} - {}", lib_name, error);
-                return Err(BuildError::LinkerError(message));
+                return Err(BuildError::LinkerError(CommandFailure::new(
+                    &format!("static:{}", lib.name),
+                    &cmd,
+                    &output.stderr,
+                )));
             }
 
-            println!(
-                "{} {}",
-                "Created static library:".green(),
-                lib_path.display()
+            self.print_artifact(
+                tr!("정적 라이브러리 생성:", "Created static library:"),
+                tr!("정적 라이브러리 생성 예정:", "Would create static library:"),
+                &lib_path,
             );
         }
 
@@ -606,39 +3363,53 @@ impl Builder {
     }
 
     fn link_shared_libraries(&self) -> BuildResult<()> {
-        let config = self.config.as_ref().unwrap();
-        let compiler = &config.build.compiler;
+        let config = self.config();
 
         if config.targets.shared_lib.is_empty() {
             return Ok(());
         }
 
         for lib in &config.targets.shared_lib {
-            println!("Shared library: {}", lib.name);
+            if !self.is_selected(&format!("shared:{}", lib.name)) {
+                continue;
+            }
+            println!(
+                "{}",
+                tr!("공유 라이브러리: {}", "Shared library: {}", lib.name)
+            );
 
-            let source_files = config
-                .resolve_source_files(&self.project_dir)?
+            let source_files = self
+                .sources
                 .get(&format!("shared:{}", lib.name))
                 .cloned()
                 .unwrap_or_default();
 
             if source_files.is_empty() {
-                warn!("No source files for shared library: {}", lib.name);
+                warn!(
+                    "{}",
+                    tr!(
+                        "공유 라이브러리 {}에 소스 파일이 없습니다",
+                        "No source files for shared library: {}",
+                        lib.name
+                    )
+                );
                 continue;
             }
 
             // 객체 파일 수집
             let mut object_files = Vec::new();
             for source in &source_files {
-                let rel_path = source.strip_prefix(&self.project_dir).unwrap_or(source);
-                let object_file = self
-                    .build_dir
-                    .join("obj")
-                    .join(rel_path)
-                    .with_extension("o");
-
-                if !object_file.exists() {
-                    warn!("Object file does not exist: {}", object_file.display());
+                let object_file = self.layout.object_file(&self.project_dir, source);
+
+                if !self.will_exist(&object_file) {
+                    warn!(
+                        "{}",
+                        tr!(
+                            "오브젝트 파일이 없습니다: {}",
+                            "Object file does not exist: {}",
+                            object_file.display()
+                        )
+                    );
                     continue;
                 }
 
@@ -646,58 +3417,67 @@ impl Builder {
             }
 
             if object_files.is_empty() {
-                warn!("No object files found for shared library: {}", lib.name);
+                warn!(
+                    "{}",
+                    tr!(
+                        "공유 라이브러리 {}의 오브젝트 파일이 없습니다",
+                        "No object files found for shared library: {}",
+                        lib.name
+                    )
+                );
                 continue;
             }
 
             // 라이브러리 파일 경로
-            let lib_name = if cfg!(target_os = "windows") {
-                format!("{}.dll", lib.name)
-            } else if cfg!(target_os = "macos") {
-                format!("lib{}.dylib", lib.name)
-            } else {
-                format!("lib{}.so", lib.name)
-            };
-
-            let lib_path = self.build_dir.join("lib").join(&lib_name);
+            let lib_name = self.layout.shared_lib_file_name(&lib.name);
+            let lib_path = self.layout.shared_lib_path(&lib.name);
+            if let Some(parent) = lib_path.parent().filter(|_| self.dry_run.is_none()) {
+                std::fs::create_dir_all(parent).map_err(BuildError::IoError)?;
+            }
 
             // 링커 실행
-            let mut cmd = Command::new(compiler);
-            cmd.arg("-shared").arg("-o").arg(&lib_path);
+            let mut cmd = tool_command(self.linker("shared", &lib.name));
+            cmd.arg("-shared").arg("-o").arg(long_path(&lib_path));
 
             for obj in &object_files {
-                cmd.arg(obj);
+                cmd.arg(long_path(obj));
             }
 
+            cmd.args(self.target_link_args("shared"));
+            cmd.args(self.openmp_link_args("shared", &lib.name));
+            cmd.args(self.objc_link_args("shared", &lib.name));
+
             // macOS 설정
             if cfg!(target_os = "macos") {
                 cmd.arg("-install_name").arg(format!("@rpath/{}", lib_name));
             }
 
-            if self.configuration == "release" {
-                if cfg!(target_os = "linux") || cfg!(target_os = "macos") {
-                    cmd.arg("-s"); // 심볼 정보 제거 (스트립)
-                }
+            if config.is_release_configuration(&self.configuration)
+                && (cfg!(target_os = "linux") || cfg!(target_os = "macos"))
+            {
+                cmd.arg("-s"); // 심볼 정보 제거 (스트립)
             }
 
             if self.verbose {
                 println!("Linking shared library: {:?}", cmd);
             }
 
-            let output = cmd.output().map_err(|e| BuildError::IoError(e))?;
+            let output = self
+                .run_command(&format!("shared:{}", lib.name), &mut cmd)
+                .map_err(BuildError::IoError)?;
 
             if !output.status.success() {
-                let error = String::from_utf8_lossy(&output.stderr);
-                return Err(BuildError::LinkerError(format!(
-                    "Failed to create shared library: {} - {}",
-                    lib_name, error
+                return Err(BuildError::LinkerError(CommandFailure::new(
+                    &format!("shared:{}", lib.name),
+                    &cmd,
+                    &output.stderr,
                 )));
             }
 
-            println!(
-                "{} {}",
-                "Created shared library:".green(),
-                lib_path.display()
+            self.print_artifact(
+                tr!("공유 라이브러리 생성:", "Created shared library:"),
+                tr!("공유 라이브러리 생성 예정:", "Would create shared library:"),
+                &lib_path,
             );
         }
 
@@ -705,39 +3485,50 @@ impl Builder {
     }
 
     fn link_executables(&self) -> BuildResult<()> {
-        let config = self.config.as_ref().unwrap();
-        let compiler = &config.build.compiler;
+        let config = self.config();
 
         if config.targets.executable.is_empty() {
             return Ok(());
         }
 
         for exe in &config.targets.executable {
-            println!("Executable: {}", exe.name);
+            if !self.is_selected(&format!("exe:{}", exe.name)) {
+                continue;
+            }
+            println!("{}", tr!("실행 파일: {}", "Executable: {}", exe.name));
 
-            let source_files = config
-                .resolve_source_files(&self.project_dir)?
+            let source_files = self
+                .sources
                 .get(&format!("exe:{}", exe.name))
                 .cloned()
                 .unwrap_or_default();
 
             if source_files.is_empty() {
-                warn!("No source files for executable: {}", exe.name);
+                warn!(
+                    "{}",
+                    tr!(
+                        "실행 파일 {}에 소스 파일이 없습니다",
+                        "No source files for executable: {}",
+                        exe.name
+                    )
+                );
                 continue;
             }
 
             // 객체 파일 수집
             let mut object_files = Vec::new();
             for source in &source_files {
-                let rel_path = source.strip_prefix(&self.project_dir).unwrap_or(source);
-                let object_file = self
-                    .build_dir
-                    .join("obj")
-                    .join(rel_path)
-                    .with_extension("o");
-
-                if !object_file.exists() {
-                    warn!("Object file does not exist: {}", object_file.display());
+                let object_file = self.layout.object_file(&self.project_dir, source);
+
+                if !self.will_exist(&object_file) {
+                    warn!(
+                        "{}",
+                        tr!(
+                            "오브젝트 파일이 없습니다: {}",
+                            "Object file does not exist: {}",
+                            object_file.display()
+                        )
+                    );
                     continue;
                 }
 
@@ -745,25 +3536,39 @@ impl Builder {
             }
 
             if object_files.is_empty() {
-                warn!("No object files found for executable: {}", exe.name);
+                warn!(
+                    "{}",
+                    tr!(
+                        "실행 파일 {}의 오브젝트 파일이 없습니다",
+                        "No object files found for executable: {}",
+                        exe.name
+                    )
+                );
                 continue;
             }
 
-            // 실행 파일 경로
-            let exe_name = if cfg!(target_os = "windows") {
-                format!("{}.exe", exe.name)
+            // 실행 파일 경로 (링크 후 처리가 있으면 링커 출력은 중간 파일)
+            let exe_path = self.layout.executable_path(&exe.name);
+            if let Some(parent) = exe_path.parent().filter(|_| self.dry_run.is_none()) {
+                std::fs::create_dir_all(parent).map_err(BuildError::IoError)?;
+            }
+            let post_link = exe.post_link.clone().unwrap_or_default();
+            let link_output = if post_link.is_empty() {
+                exe_path.clone()
             } else {
-                exe.name.clone()
+                let dir = self.layout.post_link_dir();
+                if self.dry_run.is_none() {
+                    std::fs::create_dir_all(&dir).map_err(BuildError::IoError)?;
+                }
+                dir.join(self.layout.executable_file_name(&exe.name))
             };
 
-            let exe_path = self.build_dir.join("bin").join(exe_name);
-
             // 링커 실행
-            let mut cmd = Command::new(compiler);
-            cmd.arg("-o").arg(&exe_path);
+            let mut cmd = tool_command(self.linker("exe", &exe.name));
+            cmd.arg("-o").arg(long_path(&link_output));
 
             for obj in &object_files {
-                cmd.arg(obj);
+                cmd.arg(long_path(obj));
             }
 
             // 라이브러리 경로 추가
@@ -780,96 +3585,246 @@ impl Builder {
 
             // 내부 정적 라이브러리 추가
             for static_lib in &config.targets.static_lib {
-                let lib_path = self
-                    .build_dir
-                    .join("lib")
-                    .join(format!("lib{}.a", static_lib.name));
+                let lib_path = self.layout.static_lib_path(&static_lib.name);
 
-                if lib_path.exists() {
+                if self.will_exist(&lib_path) {
                     cmd.arg(lib_path);
                 }
             }
 
             // 내부 공유 라이브러리 경로 추가
-            cmd.arg("-L").arg(self.build_dir.join("lib"));
+            for dir in self.layout.shared_lib_dirs(config) {
+                cmd.arg("-L").arg(dir);
+            }
 
             // 라이브러리 추가
             if let Some(libs) = &exe.libs {
                 for lib in libs {
-                    cmd.arg(format!("-l{}", lib));
+                    cmd.arg(format!("-l{}", self.link_lib_name(lib)));
                 }
             }
+            cmd.args(self.target_link_args("exe"));
+            cmd.args(self.openmp_link_args("exe", &exe.name));
+            cmd.args(self.objc_link_args("exe", &exe.name));
+            cmd.args(exe.link_flags.iter().flatten());
 
             // rpath 설정 (공유 라이브러리 위치 보존)
-            if cfg!(target_os = "linux") {
-                cmd.arg(format!(
-                    "-Wl,-rpath,{}",
-                    self.build_dir.join("lib").display()
-                ));
-            } else if cfg!(target_os = "macos") {
-                cmd.arg("-Wl,-rpath,@executable_path/../lib");
+            let extra_rpath = exe.rpath.clone().unwrap_or_default();
+            for arg in self.rpath_args(
+                exe_path.parent().unwrap_or(self.layout.build_dir()),
+                &extra_rpath,
+            ) {
+                cmd.arg(arg);
             }
 
-            if self.configuration == "release" {
-                if cfg!(target_os = "linux") || cfg!(target_os = "macos") {
-                    cmd.arg("-s"); // 심볼 정보 제거 (스트립)
-                }
+            // 링크 후 처리(BOLT 등)는 심볼이 필요하므로 스트립은 후처리 단계에 맡김
+            if config.is_release_configuration(&self.configuration)
+                && post_link.is_empty()
+                && (cfg!(target_os = "linux") || cfg!(target_os = "macos"))
+            {
+                cmd.arg("-s"); // 심볼 정보 제거 (스트립)
             }
 
             if self.verbose {
                 println!("Linking executable: {:?}", cmd);
             }
 
-            let output = cmd.output().map_err(|e| BuildError::IoError(e))?;
+            let output = self
+                .run_command(&format!("exe:{}", exe.name), &mut cmd)
+                .map_err(BuildError::IoError)?;
 
             if !output.status.success() {
-                let error = String::from_utf8_lossy(&output.stderr);
-                return Err(BuildError::LinkerError(format!(
-                    "Failed to create executable: {} - {}",
-                    exe.name, error
+                return Err(BuildError::LinkerError(CommandFailure::new(
+                    &format!("exe:{}", exe.name),
+                    &cmd,
+                    &output.stderr,
                 )));
             }
 
-            println!("{} {}", "Created executable:".green(), exe_path.display());
+            if !post_link.is_empty() {
+                self.run_post_link(&exe.name, &post_link, &link_output, &exe_path)?;
+            }
+
+            self.print_artifact(
+                tr!("실행 파일 생성:", "Created executable:"),
+                tr!("실행 파일 생성 예정:", "Would create executable:"),
+                &exe_path,
+            );
+
+            // 실행 파일 옆으로 런타임 파일 복사
+            if self.dry_run.is_none() {
+                self.copy_runtime_files(exe, &exe_path)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 내부 공유 라이브러리와 선언된 에셋 파일을 실행 파일 디렉토리로 복사
+    ///
+    /// `build.copy_shared_libs = true`이거나 타겟에 `[targets.executable.copy]`가
+    /// 있으면 실행 파일이 링크하는 내부 공유 라이브러리를 복사한다.
+    fn copy_runtime_files(&self, exe: &ExecutableTarget, exe_path: &Path) -> BuildResult<()> {
+        let config = self.config();
+        let exe_dir = exe_path.parent().unwrap_or(self.layout.build_dir());
+        let rules = exe.copy.as_ref();
+
+        let copy_shared_libs = rules
+            .and_then(|r| r.shared_libs)
+            .or(config.build.copy_shared_libs)
+            .unwrap_or(rules.is_some());
+
+        let mut copies: Vec<(PathBuf, PathBuf)> = Vec::new();
+
+        if copy_shared_libs {
+            let linked = exe.libs.clone().unwrap_or_default();
+            for lib in &config.targets.shared_lib {
+                if !linked.contains(&lib.name) {
+                    continue;
+                }
+                let lib_path = self.layout.shared_lib_path(&lib.name);
+                if let Some(file_name) = lib_path.file_name() {
+                    copies.push((lib_path.clone(), exe_dir.join(file_name)));
+                }
+            }
+        }
+
+        copies.extend(self.asset_files(exe, exe_dir)?);
+
+        for (src, dst) in copies {
+            if !src.exists() {
+                warn!(
+                    "{}",
+                    tr!(
+                        "런타임 파일이 없습니다: {}",
+                        "Runtime file does not exist: {}",
+                        src.display()
+                    )
+                );
+                continue;
+            }
+
+            // 이미 최신이면 건너뜀
+            if dst.exists() && !is_newer_than(&src, &dst) {
+                continue;
+            }
+
+            copy_file_with_dirs(&src, &dst).map_err(BuildError::IoError)?;
+
+            if self.verbose {
+                println!(
+                    "{}",
+                    tr!(
+                        "복사: {} -> {}",
+                        "Copied: {} -> {}",
+                        src.display(),
+                        dst.display()
+                    )
+                );
+            }
         }
 
         Ok(())
     }
 
+    /// `[targets.executable.copy]`에 선언된 에셋 파일과 실행 파일 디렉토리 기준 복사 위치
+    pub fn asset_files(
+        &self,
+        exe: &ExecutableTarget,
+        exe_dir: &Path,
+    ) -> BuildResult<Vec<(PathBuf, PathBuf)>> {
+        let mut copies = Vec::new();
+        if let Some(rules) = exe.copy.as_ref() {
+            let dest_dir = match rules.dest {
+                Some(ref dest) => exe_dir.join(dest),
+                None => exe_dir.to_path_buf(),
+            };
+
+            for pattern in rules.files.iter().flatten() {
+                let paths = glob_in(&self.project_dir, pattern, true).map_err(|e| {
+                    BuildError::PathError(tr!(
+                        "패턴 '{}'에 오류: {}",
+                        "invalid pattern '{}': {}",
+                        pattern,
+                        e
+                    ))
+                })?;
+
+                let mut matched = false;
+                for path in paths {
+                    if !path.is_file() {
+                        continue;
+                    }
+                    matched = true;
+                    if let Some(file_name) = path.file_name() {
+                        copies.push((path.clone(), dest_dir.join(file_name)));
+                    }
+                }
+
+                if !matched {
+                    warn!(
+                        "{}",
+                        tr!(
+                            "복사 패턴과 일치하는 파일이 없습니다: {}",
+                            "No files matched copy pattern: {}",
+                            pattern
+                        )
+                    );
+                }
+            }
+        }
+
+        Ok(copies)
+    }
+
     fn link_tests(&self) -> BuildResult<()> {
-        let config = self.config.as_ref().unwrap();
-        let compiler = &config.build.compiler;
+        let config = self.config();
 
         if config.targets.test.is_empty() {
             return Ok(());
         }
 
         for test in &config.targets.test {
-            println!("Test executable: {}", test.name);
+            if !self.is_selected(&format!("test:{}", test.name)) {
+                continue;
+            }
+            println!(
+                "{}",
+                tr!("테스트 실행 파일: {}", "Test executable: {}", test.name)
+            );
 
-            let source_files = config
-                .resolve_source_files(&self.project_dir)?
+            let source_files = self
+                .sources
                 .get(&format!("test:{}", test.name))
                 .cloned()
                 .unwrap_or_default();
 
             if source_files.is_empty() {
-                warn!("No source files for test: {}", test.name);
+                warn!(
+                    "{}",
+                    tr!(
+                        "테스트 {}에 소스 파일이 없습니다",
+                        "No source files for test: {}",
+                        test.name
+                    )
+                );
                 continue;
             }
 
             // 객체 파일 수집
             let mut object_files = Vec::new();
             for source in &source_files {
-                let rel_path = source.strip_prefix(&self.project_dir).unwrap_or(source);
-                let object_file = self
-                    .build_dir
-                    .join("obj")
-                    .join(rel_path)
-                    .with_extension("o");
-
-                if !object_file.exists() {
-                    warn!("Object file does not exist: {}", object_file.display());
+                let object_file = self.layout.object_file(&self.project_dir, source);
+
+                if !self.will_exist(&object_file) {
+                    warn!(
+                        "{}",
+                        tr!(
+                            "오브젝트 파일이 없습니다: {}",
+                            "Object file does not exist: {}",
+                            object_file.display()
+                        )
+                    );
                     continue;
                 }
 
@@ -877,30 +3832,31 @@ impl Builder {
             }
 
             if object_files.is_empty() {
-                warn!("No object files found for test: {}", test.name);
+                warn!(
+                    "{}",
+                    tr!(
+                        "테스트 {}의 오브젝트 파일이 없습니다",
+                        "No object files found for test: {}",
+                        test.name
+                    )
+                );
                 continue;
             }
 
             // 테스트 실행 파일 경로
-            let test_name = if cfg!(target_os = "windows") {
-                format!("{}.exe", test.name)
-            } else {
-                test.name.clone()
-            };
-
-            let test_path = self.build_dir.join("bin").join("tests").join(&test_name);
+            let test_path = self.layout.test_path(&test.name);
 
             // 테스트 디렉토리 생성
-            if let Some(parent) = test_path.parent() {
-                std::fs::create_dir_all(parent).map_err(|e| BuildError::IoError(e))?;
+            if let Some(parent) = test_path.parent().filter(|_| self.dry_run.is_none()) {
+                std::fs::create_dir_all(parent).map_err(BuildError::IoError)?;
             }
 
             // 링커 실행
-            let mut cmd = Command::new(compiler);
-            cmd.arg("-o").arg(&test_path);
+            let mut cmd = tool_command(self.linker("test", &test.name));
+            cmd.arg("-o").arg(long_path(&test_path));
 
             for obj in &object_files {
-                cmd.arg(obj);
+                cmd.arg(long_path(obj));
             }
 
             // 라이브러리 경로 추가
@@ -917,67 +3873,336 @@ impl Builder {
 
             // 내부 정적 라이브러리 추가
             for static_lib in &config.targets.static_lib {
-                let lib_path = self
-                    .build_dir
-                    .join("lib")
-                    .join(format!("lib{}.a", static_lib.name));
+                let lib_path = self.layout.static_lib_path(&static_lib.name);
 
-                if lib_path.exists() {
+                if self.will_exist(&lib_path) {
                     cmd.arg(lib_path);
                 }
             }
 
             // 내부 공유 라이브러리 경로 추가
-            cmd.arg("-L").arg(self.build_dir.join("lib"));
+            for dir in self.layout.shared_lib_dirs(config) {
+                cmd.arg("-L").arg(dir);
+            }
 
             // 라이브러리 추가
             if let Some(libs) = &test.libs {
                 for lib in libs {
-                    cmd.arg(format!("-l{}", lib));
+                    cmd.arg(format!("-l{}", self.link_lib_name(lib)));
                 }
             }
+            cmd.args(self.target_link_args("test"));
+            cmd.args(self.openmp_link_args("test", &test.name));
+            cmd.args(self.objc_link_args("test", &test.name));
 
             // rpath 설정 (공유 라이브러리 위치 보존)
-            if cfg!(target_os = "linux") {
-                cmd.arg(format!(
-                    "-Wl,-rpath,{}",
-                    self.build_dir.join("lib").display()
-                ));
-            } else if cfg!(target_os = "macos") {
-                cmd.arg("-Wl,-rpath,@executable_path/../../lib");
+            let extra_rpath = test.rpath.clone().unwrap_or_default();
+            for arg in self.rpath_args(
+                test_path.parent().unwrap_or(self.layout.build_dir()),
+                &extra_rpath,
+            ) {
+                cmd.arg(arg);
             }
 
             if self.verbose {
                 println!("Linking test: {:?}", cmd);
             }
 
-            let output = cmd.output().map_err(|e| BuildError::IoError(e))?;
+            let output = self
+                .run_command(&format!("test:{}", test.name), &mut cmd)
+                .map_err(BuildError::IoError)?;
 
             if !output.status.success() {
-                let error = String::from_utf8_lossy(&output.stderr);
-                return Err(BuildError::LinkerError(format!(
-                    "Failed to create test executable: {} - {}",
-                    test.name, error
+                return Err(BuildError::LinkerError(CommandFailure::new(
+                    &format!("test:{}", test.name),
+                    &cmd,
+                    &output.stderr,
                 )));
             }
 
-            println!(
-                "{} {}",
-                "Created test executable:".green(),
-                test_path.display()
+            self.print_artifact(
+                tr!("테스트 실행 파일 생성:", "Created test executable:"),
+                tr!(
+                    "테스트 실행 파일 생성 예정:",
+                    "Would create test executable:"
+                ),
+                &test_path,
             );
         }
 
         Ok(())
     }
+
+    /// 만든 결과물 알림 (`--dry-run`이면 만들 예정인 결과물로 표시)
+    fn print_artifact(&self, created: String, planned: String, path: &Path) {
+        if self.dry_run.is_some() {
+            println!("{} {}", planned.yellow(), path.display());
+        } else {
+            println!("{} {}", created.green(), path.display());
+        }
+    }
+
+    /// 링크 결과에 후처리 명령을 차례로 적용해 최종 실행 파일 생성
+    fn run_post_link(
+        &self,
+        exe_name: &str,
+        steps: &[Vec<String>],
+        linked: &Path,
+        final_path: &Path,
+    ) -> BuildResult<()> {
+        let mut current = linked.to_path_buf();
+
+        for (index, step) in steps.iter().enumerate() {
+            let output = if index + 1 == steps.len() {
+                final_path.to_path_buf()
+            } else {
+                let mut name = linked.as_os_str().to_owned();
+                name.push(format!(".{}", index + 1));
+                PathBuf::from(name)
+            };
+
+            // {output}이 없는 명령은 복사본을 제자리에서 수정
+            let input = if step.iter().any(|arg| arg.contains("{output}")) {
+                current.clone()
+            } else {
+                if self.dry_run.is_none() {
+                    std::fs::copy(&current, &output).map_err(BuildError::IoError)?;
+                }
+                output.clone()
+            };
+
+            let args: Vec<OsString> = step
+                .iter()
+                .map(|arg| substitute_paths(arg, &[("{input}", &input), ("{output}", &output)]))
+                .collect();
+            let (program, args) = args.split_first().ok_or_else(|| {
+                BuildError::ConfigParsingError(tr!(
+                    "실행 파일 {}의 post_link에 빈 명령이 있습니다",
+                    "post_link of executable {} has an empty command",
+                    exe_name
+                ))
+            })?;
+
+            let mut cmd = Command::new(program);
+            cmd.args(args).current_dir(&self.project_dir);
+            if self.verbose {
+                println!("Post-link: {:?}", cmd);
+            }
+
+            let result = self
+                .run_command(&format!("exe:{}", exe_name), &mut cmd)
+                .map_err(BuildError::IoError)?;
+            if !result.status.success() {
+                return Err(BuildError::CustomCommandFailed(CommandFailure::new(
+                    &format!("exe:{}", exe_name),
+                    &cmd,
+                    &result.stderr,
+                )));
+            }
+
+            current = output;
+        }
+
+        Ok(())
+    }
+
+    /// `-l`에 전달할 라이브러리 이름 (내부 공유 라이브러리는 output_name과 구성별 접미사 적용)
+    fn link_lib_name(&self, lib: &str) -> String {
+        let config = self.config();
+
+        if config.targets.shared_lib.iter().any(|l| l.name == lib) {
+            self.layout.shared_lib_link_name(lib)
+        } else {
+            lib.to_string()
+        }
+    }
+
+    /// 링크 시 전달할 rpath 인자 생성
+    ///
+    /// `binary_dir`은 링크 결과물이 놓일 디렉토리로, `origin` 모드에서
+    /// 내부 라이브러리 디렉토리까지의 상대 경로를 계산하는 데 사용된다.
+    fn rpath_args(&self, binary_dir: &Path, extra_rpath: &[String]) -> Vec<OsString> {
+        if cfg!(target_os = "windows") {
+            return Vec::new();
+        }
+
+        let config = self.config();
+        let lib_dirs = self.layout.shared_lib_dirs(config);
+        let mut entries = Vec::new();
+
+        match config.build.rpath.unwrap_or_default() {
+            RpathMode::Origin => {
+                let origin = if cfg!(target_os = "macos") {
+                    "@executable_path"
+                } else {
+                    "$ORIGIN"
+                };
+                for lib_dir in &lib_dirs {
+                    let rel = relative_path_between(binary_dir, lib_dir);
+                    if rel.as_os_str().is_empty() {
+                        entries.push(PathBuf::from(origin));
+                    } else {
+                        entries.push(Path::new(origin).join(rel));
+                    }
+                }
+            }
+            RpathMode::Absolute => entries.extend(lib_dirs.iter().cloned()),
+            RpathMode::None => {}
+        }
+
+        entries.extend(extra_rpath.iter().map(PathBuf::from));
+
+        entries
+            .iter()
+            .map(|entry| path_flag("-Wl,-rpath,", entry))
+            .collect()
+    }
+}
+
+/// 여러 타겟에 속한 소스 파일의 타겟을 터미널에서 선택 (후보 목록의 인덱스)
+fn prompt_target(file: &Path, candidates: &[(String, String, PathBuf)]) -> BuildResult<usize> {
+    eprintln!(
+        "{}",
+        tr!(
+            "{}이(가) 여러 타겟에 속합니다:",
+            "{} belongs to several targets:",
+            file.display()
+        )
+    );
+    for (index, (target_type, target_name, _)) in candidates.iter().enumerate() {
+        eprintln!("  {}) {}:{}", index + 1, target_type, target_name);
+    }
+
+    loop {
+        eprint!(
+            "{}",
+            tr!(
+                "타겟 번호 (1-{}): ",
+                "Target number (1-{}): ",
+                candidates.len()
+            )
+        );
+        std::io::stderr().flush().map_err(BuildError::IoError)?;
+
+        let mut line = String::new();
+        if std::io::stdin()
+            .read_line(&mut line)
+            .map_err(BuildError::IoError)?
+            == 0
+        {
+            return Err(BuildError::AmbiguousTarget(
+                file.to_path_buf(),
+                candidates
+                    .iter()
+                    .map(|(_, name, _)| name.clone())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ));
+        }
+        match line.trim().parse::<usize>() {
+            Ok(number) if (1..=candidates.len()).contains(&number) => return Ok(number - 1),
+            _ => continue,
+        }
+    }
 }
 
-fn calculate_file_hash(path: &Path) -> BuildResult<String> {
-    let mut file = std::fs::File::open(path).map_err(|e| BuildError::IoError(e))?;
+fn calculate_file_hash(path: &Path) -> BuildResult<FileHash> {
+    let mut file = std::fs::File::open(path).map_err(BuildError::IoError)?;
 
     let mut hasher = Sha256::new();
-    std::io::copy(&mut file, &mut hasher).map_err(|e| BuildError::IoError(e))?;
+    std::io::copy(&mut file, &mut hasher).map_err(BuildError::IoError)?;
+
+    Ok(hasher.finalize().into())
+}
+
+/// 컴파일러가 `-MMD`로 쓴 make 규칙에서 의존 파일 목록 추출
+///
+/// 줄 이어짐(`\`)과 이스케이프된 공백(`\ `), `$$`를 처리한다. 첫 항목은 소스 자신이다.
+fn parse_depfile(content: &str) -> Vec<String> {
+    // 대상과 의존 목록은 ": "로 구분 (Windows 드라이브 문자의 ':'와 구별)
+    let rest = match content.find(": ") {
+        Some(index) => &content[index + 2..],
+        None => return Vec::new(),
+    };
+
+    let mut paths = Vec::new();
+    let mut current = String::new();
+    let mut chars = rest.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.peek() {
+                Some(' ') | Some('#') => current.push(chars.next().unwrap()),
+                Some('\n') | Some('\r') => {
+                    chars.next();
+                    if !current.is_empty() {
+                        paths.push(std::mem::take(&mut current));
+                    }
+                }
+                _ => current.push('\\'),
+            },
+            '$' if chars.peek() == Some(&'$') => {
+                chars.next();
+                current.push('$');
+            }
+            c if c.is_whitespace() => {
+                if !current.is_empty() {
+                    paths.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        paths.push(current);
+    }
+    paths
+}
 
-    let result = hasher.finalize();
-    Ok(format!("{:x}", result))
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn dry_run_does_not_touch_build_tree() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        fs::write(
+            dir.join("cbuild.toml"),
+            r#"
+[project]
+name = "plan"
+version = "0.1.0"
+
+[build]
+compiler = "gcc"
+
+[generated]
+version_header = true
+
+[[targets.executable]]
+name = "app"
+src = ["src/*.c"]
+embed = ["assets/*"]
+"#,
+        )
+        .unwrap();
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/main.c"), "int main(void) { return 0; }\n").unwrap();
+        fs::create_dir_all(dir.join("assets")).unwrap();
+        fs::write(dir.join("assets/greeting.txt"), "hello\n").unwrap();
+
+        let mut builder = Builder::new(dir, "debug", 1).unwrap();
+        builder.set_dry_run(None);
+        builder.set_compile_commands(true);
+        builder.build().unwrap();
+
+        assert!(!dir.join("build").exists());
+        assert!(!dir.join("compile_commands.json").exists());
+        assert!(builder
+            .command_log
+            .entries()
+            .iter()
+            .any(|logged| logged.target == "exe:app"));
+    }
 }
@@ -0,0 +1,134 @@
+use crate::history::format_timestamp;
+use crate::layout::BuildLayout;
+use crate::utils::get_file_modification_time;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// 보관할 빌드 로그 수 기본값
+pub const DEFAULT_RETENTION: usize = 20;
+
+/// 콘솔 출력 수준과 관계없이 남기는 색상 없는 전체 빌드 로그
+///
+/// 실행한 모든 명령과 그 출력(컴파일러 stderr 전체), 소요 시간을
+/// build/<구성>/log/build-<시각>.log에 기록한다.
+pub struct BuildLog {
+    path: PathBuf,
+    file: Mutex<BufWriter<File>>,
+}
+
+impl BuildLog {
+    /// 새 로그 파일을 만들고 `retention`개를 넘는 오래된 로그 삭제
+    pub fn create(layout: &BuildLayout, retention: usize) -> std::io::Result<Self> {
+        let log_dir = layout.log_dir();
+        std::fs::create_dir_all(&log_dir)?;
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let stamp = format_timestamp(timestamp)
+            .replace(['-', ':'], "")
+            .replace(' ', "-");
+
+        // 같은 초에 시작한 빌드는 번호를 붙여 구분
+        let mut path = log_dir.join(format!("build-{}.log", stamp));
+        let mut suffix = 1;
+        while path.exists() {
+            path = log_dir.join(format!("build-{}-{}.log", stamp, suffix));
+            suffix += 1;
+        }
+
+        let file = File::create(&path)?;
+        rotate(&log_dir, retention, &path)?;
+
+        let log = BuildLog {
+            path,
+            file: Mutex::new(BufWriter::new(file)),
+        };
+        log.line(&format!(
+            "cbuild {} — {} (UTC)",
+            env!("CARGO_PKG_VERSION"),
+            format_timestamp(timestamp)
+        ));
+        log.line(&format!(
+            "args: {}",
+            std::env::args().collect::<Vec<_>>().join(" ")
+        ));
+        Ok(log)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 한 줄 기록 (기록 실패는 빌드에 영향을 주지 않도록 무시)
+    pub fn line(&self, text: &str) {
+        let mut file = self.file.lock().unwrap();
+        let _ = writeln!(file, "{}", text);
+    }
+
+    /// 실행한 명령과 종료 상태, 소요 시간, 출력 전체 기록
+    pub fn command(
+        &self,
+        target: &str,
+        cmd: &Command,
+        output: &std::io::Result<Output>,
+        duration: Duration,
+    ) {
+        // Command의 Debug 출력은 작업 디렉토리와 환경 변수 변경도 포함
+        let mut text = format!("\n[{}] {:?}\n", target, cmd);
+
+        match output {
+            Ok(output) => {
+                let status = match output.status.code() {
+                    Some(code) => format!("exit {}", code),
+                    None => "signal".to_string(),
+                };
+                text.push_str(&format!("  -> {} ({}ms)\n", status, duration.as_millis()));
+                for stream in [&output.stdout, &output.stderr] {
+                    if !stream.is_empty() {
+                        text.push_str(String::from_utf8_lossy(stream).trim_end());
+                        text.push('\n');
+                    }
+                }
+            }
+            Err(e) => text.push_str(&format!("  -> {}\n", e)),
+        }
+
+        let mut file = self.file.lock().unwrap();
+        let _ = file.write_all(text.as_bytes());
+    }
+
+    /// 남은 내용을 파일에 기록
+    pub fn flush(&self) {
+        let _ = self.file.lock().unwrap().flush();
+    }
+}
+
+/// 수정 시각 순으로 정렬해 최근 `retention`개만 남김
+fn rotate(log_dir: &Path, retention: usize, current: &Path) -> std::io::Result<()> {
+    let mut logs: Vec<PathBuf> = std::fs::read_dir(log_dir)?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("build-") && name.ends_with(".log"))
+        })
+        .collect();
+    logs.sort_by_key(|path| (get_file_modification_time(path), path.clone()));
+
+    let excess = logs.len().saturating_sub(retention.max(1));
+    for old in logs
+        .iter()
+        .filter(|path| path.as_path() != current)
+        .take(excess)
+    {
+        std::fs::remove_file(old)?;
+    }
+    Ok(())
+}
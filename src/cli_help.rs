@@ -0,0 +1,684 @@
+use crate::i18n::{lang, Lang};
+use clap::builder::StyledStr;
+use clap::Command;
+
+/// `--help`에 표시하는 명령과 인자 설명의 영어 번역 (main.rs의 doc 주석과 같은 문자열)
+///
+/// doc 주석을 바꾸면 여기도 함께 바꿔야 한다. 빠진 번역은 테스트가 알려 준다.
+const HELP_EN: &[(&str, &str)] = &[
+    (
+        "(실험적) 컴파일을 [remote_execution] 서버에서 실행",
+        "(Experimental) run compilation on the [remote_execution] server",
+    ),
+    (
+        "--dry-run의 명령 목록을 JSON 파일로 저장",
+        "Save the --dry-run command list to a JSON file",
+    ),
+    (
+        ".clang-format과 .editorconfig를 생성하지 않음",
+        "Do not create .clang-format and .editorconfig",
+    ),
+    (
+        ".gitignore를 생성하지 않음",
+        "Do not create .gitignore",
+    ),
+    (
+        "IDE/편집기 설정 파일 생성",
+        "Generate IDE/editor configuration files",
+    ),
+    (
+        "Intel 문법 사용 (x86)",
+        "Use Intel syntax (x86)",
+    ),
+    (
+        "JSON 대신 디렉토리 경로만 출력",
+        "Print only the directory path instead of JSON",
+    ),
+    (
+        "JSON으로 출력",
+        "Print as JSON",
+    ),
+    (
+        "OSV API를 조회하지 않고 [audit] database만 사용",
+        "Use only the [audit] database without querying the OSV API",
+    ),
+    (
+        "VS Code 설정 생성 (.vscode/c_cpp_properties.json, tasks.json, launch.json)",
+        "Generate VS Code settings (.vscode/c_cpp_properties.json, tasks.json, launch.json)",
+    ),
+    (
+        "[dependencies]나 [dev-dependencies]에서 의존성 삭제 (cbuild.toml의 주석과 순서 유지, 이전 내용은 .bak)",
+        "Remove a dependency from [dependencies] or [dev-dependencies] (keeps comments and order in cbuild.toml, previous content in .bak)",
+    ),
+    (
+        "[dependencies]에 의존성 추가 또는 변경 (cbuild.toml의 주석과 순서 유지, 이전 내용은 .bak)",
+        "Add or change a dependency in [dependencies] (keeps comments and order in cbuild.toml, previous content in .bak)",
+    ),
+    (
+        "[distributed]의 distcc/icecc로 컴파일을 원격 호스트에 분산",
+        "Distribute compilation to remote hosts with distcc/icecc from [distributed]",
+    ),
+    (
+        "[layout] 규칙(테스트 위치, 공개 헤더 위치, 파일 이름)을 어긴 파일 보고 (있으면 실패 종료)",
+        "Report files that break the [layout] rules (test location, public header location, file names; exits with failure if any)",
+    ),
+    (
+        "[run.aliases]에 정의된 실행 별칭",
+        "Run alias defined in [run.aliases]",
+    ),
+    (
+        "cbuild 타겟을 감싸는 IDE 프로젝트 생성 (xcode/vs)",
+        "Generate an IDE project wrapping cbuild targets (xcode/vs)",
+    ),
+    (
+        "cbuild.toml의 [toolchains.<name>]에 고정한 툴체인을 내려받아 ~/.cbuild/toolchains에 설치",
+        "Download the toolchain pinned in [toolchains.<name>] of cbuild.toml and install it into ~/.cbuild/toolchains",
+    ),
+    (
+        "clangd 설정 생성 (compile_commands.json, .clangd)",
+        "Generate clangd configuration (compile_commands.json, .clangd)",
+    ),
+    (
+        "git clone/fetch와 다운로드가 실패해도 다시 시도하지 않음 (cbuild.toml의 [retry] 무시)",
+        "Do not retry failed git clone/fetch and downloads (ignores [retry] in cbuild.toml)",
+    ),
+    (
+        "git 브랜치",
+        "git branch",
+    ),
+    (
+        "git 저장소 URL",
+        "git repository URL",
+    ),
+    (
+        "git 태그",
+        "git tag",
+    ),
+    (
+        "가장 많이 포함되는 헤더, 가장 깊은 포함 경로, 번역 단위당 평균 포함 수와 추이",
+        "Most included headers, deepest include chains, average includes per translation unit and their trend",
+    ),
+    (
+        "골든 테스트의 기대 결과 파일을 현재 출력으로 갱신",
+        "Update golden test expectation files with the current output",
+    ),
+    (
+        "공개 헤더의 단독 컴파일 가능 여부, include guard, 전이적 포함 수 검사",
+        "Check that public headers compile on their own, have include guards, and their transitive include count",
+    ),
+    (
+        "기본 빌드에서 제외된 타겟(default = false, 예제)까지 모두 빌드",
+        "Build every target, including those excluded from the default build (default = false, examples)",
+    ),
+    (
+        "기존 빌드 설정(Makefile, CMake 빌드 디렉토리)에서 cbuild.toml 생성",
+        "Generate cbuild.toml from an existing build setup (Makefile, CMake build directory)",
+    ),
+    (
+        "다른 CMake 프로젝트가 find_package()로 찾을 수 있는 <이름>Config.cmake 생성",
+        "Generate <name>Config.cmake so other CMake projects can find it with find_package()",
+    ),
+    (
+        "다시 빌드하지 않고 결과물이 최신인지, 마지막 빌드 이후 바뀌지 않았는지 확인",
+        "Check without rebuilding that outputs are up to date and unchanged since the last build",
+    ),
+    (
+        "대규모 C 프로젝트 빌드 시스템",
+        "Build system for large C projects",
+    ),
+    (
+        "두 빌드의 결과물 크기와 해시 비교",
+        "Compare output sizes and hashes of two builds",
+    ),
+    (
+        "디스어셈블할 타겟 이름",
+        "Name of the target to disassemble",
+    ),
+    (
+        "라이브러리를 하나의 .c와 .h로 합침 (배포용)",
+        "Merge a library into a single .c and .h (for distribution)",
+    ),
+    (
+        "라이선스 헤더가 없는 소스·헤더 파일 보고 (있으면 실패 종료)",
+        "Report source and header files without a license header (exits with failure if any)",
+    ),
+    (
+        "라이선스 헤더가 없는 파일 맨 앞에 헤더 추가",
+        "Prepend the license header to files that lack it",
+    ),
+    (
+        "마지막 빌드에서 실행한 외부 명령 출력 (build/commands.log)",
+        "Print external commands run by the last build (build/commands.log)",
+    ),
+    (
+        "마지막 빌드의 소요 시간, 경고, 결과물, 테스트 결과를 HTML 보고서로 저장 (build/report)",
+        "Save duration, warnings, outputs and test results of the last build as an HTML report (build/report)",
+    ),
+    (
+        "마지막 증분 빌드 이후 변경된 파일만 검사",
+        "Check only files changed since the last incremental build",
+    ),
+    (
+        "메시지 언어 (en/ko, 기본: LANG 환경 변수)",
+        "Message language (en/ko, default: LANG environment variable)",
+    ),
+    (
+        "명령을 실행하지 않고 컴파일·아카이브·링크 명령을 순서대로 출력",
+        "Print compile, archive and link commands in order without running them",
+    ),
+    (
+        "모든 테스트 타겟의 출력 형식 (exit-code/tap/unity/cmocka/greatest, 기본: 타겟의 format)",
+        "Output format for all test targets (exit-code/tap/unity/cmocka/greatest, default: the target's format)",
+    ),
+    (
+        "버전",
+        "Version",
+    ),
+    (
+        "번들 형식 (appimage/flatpak)",
+        "Bundle format (appimage/flatpak)",
+    ),
+    (
+        "변경 여부와 관계없이 모든 파일 다시 빌드 (기본은 바뀐 파일만 빌드)",
+        "Rebuild all files regardless of changes (by default only changed files are built)",
+    ),
+    (
+        "변경된 파일 목록",
+        "Changed files",
+    ),
+    (
+        "변경된 파일의 영향을 받는 타겟과 테스트 출력",
+        "Print targets and tests affected by changed files",
+    ),
+    (
+        "변환할 빌드 시스템 (make/cmake)",
+        "Build system to convert from (make/cmake)",
+    ),
+    (
+        "병렬 처리를 위한 스레드 수 또는 auto-mem (기본: build.jobs 또는 CPU 코어 수)",
+        "Number of parallel threads or auto-mem (default: build.jobs or number of CPU cores)",
+    ),
+    (
+        "비교 기준 빌드 ID",
+        "Build ID to compare from",
+    ),
+    (
+        "비교 대상 빌드 ID (기본: 가장 최근 빌드)",
+        "Build ID to compare against (default: most recent build)",
+    ),
+    (
+        "빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)",
+        "Build output directory (default: build.output_dir or <project>/build)",
+    ),
+    (
+        "빌드 구성 (debug/release 또는 [profiles]의 이름)",
+        "Build configuration (debug/release or a name from [profiles])",
+    ),
+    (
+        "빌드 구성 (debug/release 또는 [profiles]의 이름, 기본: build.default_configuration 또는 debug)",
+        "Build configuration (debug/release or a name from [profiles], default: build.default_configuration or debug)",
+    ),
+    (
+        "빌드 작업 병렬 처리를 위한 스레드 수 (기본: build.jobs 또는 CPU 코어 수)",
+        "Number of threads for parallel build jobs (default: build.jobs or number of CPU cores)",
+    ),
+    (
+        "빌드 작업 병렬 처리를 위한 스레드 수 또는 auto-mem (기본: build.jobs 또는 CPU 코어 수)",
+        "Number of threads for parallel build jobs or auto-mem (default: build.jobs or number of CPU cores)",
+    ),
+    (
+        "빌드 환경(컴파일러, 도구), cbuild.toml, 의존성 설치 상태 점검",
+        "Check the build environment (compiler, tools), cbuild.toml and installed dependencies",
+    ),
+    (
+        "빌드 후 실행 파일, 라이브러리, 공개 헤더를 설치 접두사 아래에 복사",
+        "After building, copy executables, libraries and public headers under the install prefix",
+    ),
+    (
+        "빌드 후 자동으로 실행",
+        "Run automatically after building",
+    ),
+    (
+        "빌드된 타겟의 디스어셈블 출력",
+        "Print disassembly of a built target",
+    ),
+    (
+        "빌드하면서 프로젝트 루트에 compile_commands.json 작성 (clangd, clang-tidy용)",
+        "Write compile_commands.json to the project root while building (for clangd, clang-tidy)",
+    ),
+    (
+        "빌드하지 않고 빌드와 같은 컴파일 명령으로 compile_commands.json 작성 (clangd, clang-tidy용)",
+        "Write compile_commands.json with the same compile commands as a build, without building (for clangd, clang-tidy)",
+    ),
+    (
+        "빌드할 타겟 이름, `종류:이름` 키 또는 별칭 (여러 번 지정 가능, 기본 별칭: all-tests)",
+        "Target name, `kind:name` key or alias to build (repeatable, built-in alias: all-tests)",
+    ),
+    (
+        "사용할 기능 (쉼표로 구분)",
+        "Features to enable (comma-separated)",
+    ),
+    (
+        "사용할 테스트 프레임워크 (unity/cmocka)",
+        "Test framework to use (unity/cmocka)",
+    ),
+    (
+        "색상 출력 끄기 (컴파일러 진단 포함, NO_COLOR 환경 변수와 같음)",
+        "Disable colored output (including compiler diagnostics, same as the NO_COLOR environment variable)",
+    ),
+    (
+        "생성 파일을 저장할 디렉토리 (기본: <출력 디렉토리>/amalgamation)",
+        "Directory for the generated files (default: <output directory>/amalgamation)",
+    ),
+    (
+        "생성할 IDE 프로젝트 종류 (xcode/vs)",
+        "Kind of IDE project to generate (xcode/vs)",
+    ),
+    (
+        "설치 접두사 (bin, lib, include 디렉토리 생성)",
+        "Install prefix (creates bin, lib and include directories)",
+    ),
+    (
+        "설치된 툴체인 목록",
+        "List installed toolchains",
+    ),
+    (
+        "설치된 툴체인의 컴파일러·아카이버 사용 (`cbuild toolchain install`로 설치)",
+        "Use the compiler and archiver of an installed toolchain (install with `cbuild toolchain install`)",
+    ),
+    (
+        "설치할 툴체인 이름",
+        "Name of the toolchain to install",
+    ),
+    (
+        "소스 코드와 함께 출력 (디버그 정보 필요)",
+        "Interleave source code (requires debug info)",
+    ),
+    (
+        "소스 파일 하나만 소속 타겟의 플래그로 컴파일하고 진단을 바로 출력",
+        "Compile a single source file with its target's flags and print diagnostics immediately",
+    ),
+    (
+        "소스 파일의 전처리(-E) 결과 출력",
+        "Print the preprocessed (-E) output of a source file",
+    ),
+    (
+        "소스·헤더 파일의 라이선스 헤더 검사 및 추가 ([license])",
+        "Check and add license headers in source and header files ([license])",
+    ),
+    (
+        "소스와 헤더의 포함 관계 출력",
+        "Print include relationships between sources and headers",
+    ),
+    (
+        "순환 포함 경로만 출력 (a.h -> b.h -> a.h)",
+        "Print only cyclic include chains (a.h -> b.h -> a.h)",
+    ),
+    (
+        "실패로 세지 않을 권고 ID (여러 번 지정 가능, [audit] ignore에 추가)",
+        "Advisory ID not to count as a failure (repeatable, added to [audit] ignore)",
+    ),
+    (
+        "실행 인자",
+        "Program arguments",
+    ),
+    (
+        "실행 파일과 공유 라이브러리, 에셋을 재배치 가능한 Linux 번들로 묶기",
+        "Bundle executables, shared libraries and assets into a relocatable Linux bundle",
+    ),
+    (
+        "실행되는 컴파일러 명령 출력",
+        "Print compiler commands as they run",
+    ),
+    (
+        "실행할 명령과 인자",
+        "Command and arguments to run",
+    ),
+    (
+        "실행할 예제 이름 (없으면 예제 목록 출력)",
+        "Name of the example to run (lists examples if omitted)",
+    ),
+    (
+        "쓰지 않는 오브젝트, 오래된 빌드 구성, 다운로드 캐시, 빠진 의존성 정리",
+        "Clean up unused objects, stale build configurations, the download cache and missing dependencies",
+    ),
+    (
+        "압축 파일 URL (--sha256 필요)",
+        "Archive URL (requires --sha256)",
+    ),
+    (
+        "압축 파일의 SHA-256",
+        "SHA-256 of the archive",
+    ),
+    (
+        "예제 목록 출력 또는 예제를 빌드해 실행 (예: cbuild examples demo -- --verbose)",
+        "List examples or build and run one (e.g. cbuild examples demo -- --verbose)",
+    ),
+    (
+        "예제에 전달할 인자",
+        "Arguments passed to the example",
+    ),
+    (
+        "오류로 처리할 경고 옵션 (쉼표로 구분, 예: unused-variable,shadow / all)",
+        "Warning options to treat as errors (comma-separated, e.g. unused-variable,shadow / all)",
+    ),
+    (
+        "오브젝트 생성 없이 빠르게 문법만 검사 (-fsyntax-only)",
+        "Quickly check syntax only, without producing objects (-fsyntax-only)",
+    ),
+    (
+        "의존성 다운로드 및 설치",
+        "Download and install dependencies",
+    ),
+    (
+        "의존성 업데이트",
+        "Update dependencies",
+    ),
+    (
+        "의존성 이름",
+        "Dependency name",
+    ),
+    (
+        "의존성 이름 (deps/<이름>에 설치)",
+        "Dependency name (installed into deps/<name>)",
+    ),
+    (
+        "의존성의 알려진 취약점 검사 (취약점이 있으면 실패 종료, `audit layout`은 [layout] 규칙 검사)",
+        "Check dependencies for known vulnerabilities (exits with failure if any; `audit layout` checks [layout] rules)",
+    ),
+    (
+        "이미 설치되어 있어도 다시 내려받기",
+        "Download again even if already installed",
+    ),
+    (
+        "이미 있는 cbuild.toml 덮어쓰기",
+        "Overwrite an existing cbuild.toml",
+    ),
+    (
+        "이전 버전 호환용 (증분 빌드가 기본이라 효과 없음)",
+        "Kept for backward compatibility (no effect, incremental builds are the default)",
+    ),
+    (
+        "이전 빌드 기록 목록 출력",
+        "List previous builds",
+    ),
+    (
+        "입력 파일 (make: Makefile, cmake: 빌드 디렉토리 또는 compile_commands.json, 기본: build)",
+        "Input file (make: Makefile, cmake: build directory or compile_commands.json, default: build)",
+    ),
+    (
+        "자세한 빌드 정보 출력",
+        "Print detailed build information",
+    ),
+    (
+        "전이적 포함 수 상위 몇 개를 표시할지",
+        "How many headers with the most transitive includes to show",
+    ),
+    (
+        "전처리할 소스 파일",
+        "Source file to preprocess",
+    ),
+    (
+        "전체 프로젝트 빌드",
+        "Build the whole project",
+    ),
+    (
+        crate::EXIT_CODES_HELP,
+        "Exit codes:\n  0  success\n  1  other errors (I/O, paths, git, ...)\n  2  invalid command-line arguments\n  3  configuration error (config file, unknown target)\n  4  compilation failed, compiler or build tool missing\n  5  link failed\n  6  dependency error\n  7  tests failed",
+    ),
+    (
+        "지우지 않고 정리할 항목과 확보할 용량만 출력",
+        "Only print what would be removed and how much space would be freed",
+    ),
+    (
+        "지정한 git 참조 이후 변경된 파일 사용",
+        "Use files changed since the given git ref",
+    ),
+    (
+        "지정한 git 참조 이후 변경의 영향을 받는 타겟만 빌드",
+        "Build only targets affected by changes since the given git ref",
+    ),
+    (
+        "진단을 CI 주석으로 다시 출력 (github/none, 기본: GITHUB_ACTIONS 설정 시 github)",
+        "Re-emit diagnostics as CI annotations (github/none, default: github when GITHUB_ACTIONS is set)",
+    ),
+    (
+        "진행 상황 표시 방식 (fancy/plain/none)",
+        "Progress display (fancy/plain/none)",
+    ),
+    (
+        "첫 번째 테스트 실패에서 중단",
+        "Stop at the first test failure",
+    ),
+    (
+        "최근 N개만 출력",
+        "Print only the most recent N",
+    ),
+    (
+        "출력 파일 (기본: <프로젝트>/compile_commands.json)",
+        "Output file (default: <project>/compile_commands.json)",
+    ),
+    (
+        "컴파일 명령 출력",
+        "Print compile commands",
+    ),
+    (
+        "컴파일·링크를 지정한 이미지의 docker/podman 컨테이너에서 실행",
+        "Run compilation and linking in a docker/podman container of the given image",
+    ),
+    (
+        "컴파일러 진단을 SARIF 2.1 파일로 저장",
+        "Save compiler diagnostics to a SARIF 2.1 file",
+    ),
+    (
+        "컴파일러/링커를 낮은 CPU·IO 우선순위로 실행",
+        "Run the compiler and linker with low CPU and I/O priority",
+    ),
+    (
+        "컴파일할 소스 파일",
+        "Source file to compile",
+    ),
+    (
+        "타겟 키(exe:app), 타겟 이름 또는 소스 파일 경로 (기본: 모든 명령)",
+        "Target key (exe:app), target name or source file path (default: all commands)",
+    ),
+    (
+        "타겟별 소스 파일 수, 줄 수, 객체 파일과 결과물 크기 출력",
+        "Print source file count, line count, object and output sizes per target",
+    ),
+    (
+        "테스트 실패를 CI 주석으로 출력 (github/none, 기본: GITHUB_ACTIONS 설정 시 github)",
+        "Report test failures as CI annotations (github/none, default: github when GITHUB_ACTIONS is set)",
+    ),
+    (
+        "테스트 케이스별 결과를 JUnit XML로 저장할 경로",
+        "Path to save per-test-case results as JUnit XML",
+    ),
+    (
+        "테스트 타겟 빌드 후 실행",
+        "Build and run test targets",
+    ),
+    (
+        "테스트 타겟 전용 의존성([dev-dependencies], scope = \"test\")은 설치하지 않음",
+        "Do not install test-only dependencies ([dev-dependencies], scope = \"test\")",
+    ),
+    (
+        "테스트 타겟 전용 의존성으로 [dev-dependencies]에 추가",
+        "Add to [dev-dependencies] as a test-only dependency",
+    ),
+    (
+        "테스트 프레임워크(unity/cmocka)를 추가하고 예제 테스트 타겟 생성",
+        "Add a test framework (unity/cmocka) and create an example test target",
+    ),
+    (
+        "특정 심볼만 디스어셈블",
+        "Disassemble only the given symbol",
+    ),
+    (
+        "파일이 여러 타겟에 속할 때 사용할 타겟",
+        "Target to use when the file belongs to several targets",
+    ),
+    (
+        "파일이 여러 타겟에 속할 때 사용할 타겟 (없으면 터미널에서 선택)",
+        "Target to use when the file belongs to several targets (asks in the terminal if omitted)",
+    ),
+    (
+        "패키지 레지스트리 주소 (--version은 \"^1.2\" 같은 조건도 가능)",
+        "Package registry URL (--version may be a requirement such as \"^1.2\")",
+    ),
+    (
+        "페이저를 사용하지 않고 바로 출력",
+        "Print directly without a pager",
+    ),
+    (
+        "프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)",
+        "Project directory (default: the current or nearest parent directory containing cbuild.toml)",
+    ),
+    (
+        "프로젝트 디렉토리 (기본: 현재 디렉토리)",
+        "Project directory (default: current directory)",
+    ),
+    (
+        "프로젝트 빌드 환경에서 명령 실행 (예: cbuild exec -- gdb main)",
+        "Run a command in the project's build environment (e.g. cbuild exec -- gdb main)",
+    ),
+    (
+        "프로젝트 실행",
+        "Run the project",
+    ),
+    (
+        "프로젝트 정리 (빌드 결과물 삭제)",
+        "Clean the project (remove build outputs)",
+    ),
+    (
+        "프로젝트 초기화",
+        "Initialize a project",
+    ),
+    (
+        "프로젝트가 사용하는 컴파일러·아카이버·링커와 타겟 트리플 정보 출력",
+        "Print the compiler, archiver, linker and target triple used by the project",
+    ),
+    (
+        "프로파일 기반 최적화 빌드 (계측 빌드 -> 학습 실행 -> -fprofile-use 재빌드)",
+        "Profile-guided optimization build (instrumented build -> training run -> -fprofile-use rebuild)",
+    ),
+    (
+        "합칠 라이브러리 타겟 이름",
+        "Name of the library target to merge",
+    ),
+    (
+        "헤더 파일 검사",
+        "Check header files",
+    ),
+    (
+        "헤더와 포함 경로를 상위 몇 개까지 표시할지",
+        "How many headers and include chains to show",
+    ),
+    (
+        "현재 디렉토리에서 위로 찾은 프로젝트 루트(cbuild.toml의 디렉토리)를 JSON으로 출력",
+        "Print the project root found upward from the current directory (directory of cbuild.toml) as JSON",
+    ),
+    (
+        "휴대용 툴체인 설치 및 관리",
+        "Install and manage portable toolchains",
+    ),
+];
+
+/// 메시지 언어가 영어면 명령, 하위 명령, 인자 설명을 영어로 바꿈
+pub fn localize(cmd: Command) -> Command {
+    match lang() {
+        Lang::Ko => cmd,
+        Lang::En => translate(cmd),
+    }
+}
+
+fn translate(mut cmd: Command) -> Command {
+    if let Some(about) = cmd.get_about().and_then(english) {
+        cmd = cmd.about(about);
+    }
+    if let Some(about) = cmd.get_long_about().and_then(english) {
+        cmd = cmd.long_about(about);
+    }
+    if let Some(help) = cmd.get_after_help().and_then(english) {
+        cmd = cmd.after_help(help);
+    }
+
+    let args: Vec<_> = cmd
+        .get_arguments()
+        .map(|arg| arg.get_id().clone())
+        .collect();
+    for id in args {
+        cmd = cmd.mut_arg(id, |mut arg| {
+            if let Some(help) = arg.get_help().and_then(english) {
+                arg = arg.help(help);
+            }
+            if let Some(help) = arg.get_long_help().and_then(english) {
+                arg = arg.long_help(help);
+            }
+            arg
+        });
+    }
+
+    let subcommands: Vec<String> = cmd
+        .get_subcommands()
+        .map(|sub| sub.get_name().to_string())
+        .collect();
+    for name in subcommands {
+        cmd = cmd.mut_subcommand(name, translate);
+    }
+    cmd
+}
+
+fn english(text: &StyledStr) -> Option<&'static str> {
+    let text = text.to_string();
+    HELP_EN
+        .iter()
+        .find(|(ko, _)| *ko == text)
+        .map(|(_, en)| *en)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    fn untranslated(cmd: &Command, path: &str, missing: &mut Vec<String>) {
+        let texts = [cmd.get_about(), cmd.get_long_about(), cmd.get_after_help()]
+            .into_iter()
+            .chain(
+                cmd.get_arguments()
+                    .flat_map(|arg| [arg.get_help(), arg.get_long_help()]),
+            )
+            .flatten();
+        for text in texts {
+            if english(text).is_none() {
+                missing.push(format!("{}: {}", path, text));
+            }
+        }
+        for sub in cmd.get_subcommands() {
+            untranslated(sub, &format!("{} {}", path, sub.get_name()), missing);
+        }
+    }
+
+    #[test]
+    fn every_help_text_has_english_translation() {
+        let mut missing = Vec::new();
+        untranslated(&crate::Cli::command(), "cbuild", &mut missing);
+        assert!(
+            missing.is_empty(),
+            "missing translations:\n{}",
+            missing.join("\n")
+        );
+    }
+
+    #[test]
+    fn translations_are_unique() {
+        for (index, (ko, _)) in HELP_EN.iter().enumerate() {
+            assert!(
+                HELP_EN[index + 1..].iter().all(|(other, _)| other != ko),
+                "duplicate entry: {}",
+                ko
+            );
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use crate::layout::BuildLayout;
+use crate::utils::{to_absolute_path, write_if_changed};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// 빌드 중 실행한(또는 `--dry-run`에서 실행할) 외부 명령
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoggedCommand {
+    /// 명령을 실행한 타겟 키 (예: "exe:app", "custom:gen")
+    pub target: String,
+    /// 작업 디렉토리 (지정하지 않으면 cbuild를 실행한 디렉토리)
+    pub directory: Option<PathBuf>,
+    /// 명령에 추가한 환경 변수 (값이 없으면 제거한 변수)
+    pub env: BTreeMap<String, Option<String>>,
+    pub command: Vec<String>,
+    /// 종료 코드 (`--dry-run`이거나 시그널로 종료되면 없음)
+    pub exit_code: Option<i32>,
+    pub duration_ms: u64,
+}
+
+/// build/commands.json 형식
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CommandLogFile {
+    pub configuration: String,
+    pub commands: Vec<LoggedCommand>,
+}
+
+/// 여러 스레드에서 실행한 명령을 모으는 기록
+#[derive(Debug, Default)]
+pub struct CommandLog {
+    entries: Mutex<Vec<LoggedCommand>>,
+}
+
+impl CommandLog {
+    pub fn record(
+        &self,
+        target: &str,
+        cmd: &Command,
+        status: Option<ExitStatus>,
+        duration: Duration,
+    ) {
+        let env = cmd
+            .get_envs()
+            .map(|(key, value)| {
+                (
+                    key.to_string_lossy().to_string(),
+                    value.map(|v| v.to_string_lossy().to_string()),
+                )
+            })
+            .collect();
+
+        self.entries.lock().unwrap().push(LoggedCommand {
+            target: target.to_string(),
+            directory: cmd.get_current_dir().map(Path::to_path_buf),
+            env,
+            command: std::iter::once(cmd.get_program())
+                .chain(cmd.get_args())
+                .map(|arg| arg.to_string_lossy().to_string())
+                .collect(),
+            exit_code: status.and_then(|s| s.code()),
+            duration_ms: duration.as_millis() as u64,
+        });
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    /// `start` 이후 기록을 명령줄 순서로 정렬 (병렬 컴파일 순서를 고정할 때 사용)
+    pub fn sort_from(&self, start: usize) {
+        self.entries.lock().unwrap()[start..].sort_by(|a, b| a.command.cmp(&b.command));
+    }
+
+    pub fn entries(&self) -> Vec<LoggedCommand> {
+        self.entries.lock().unwrap().clone()
+    }
+
+    /// build/commands.log(사람이 읽는 형식)와 build/commands.json 저장
+    pub fn write(&self, layout: &BuildLayout, configuration: &str) -> BuildResult<()> {
+        let commands = self.entries();
+
+        let mut text = String::new();
+        for entry in &commands {
+            let status = match entry.exit_code {
+                Some(code) => format!("exit {}", code),
+                None => "signal".to_string(),
+            };
+            text.push_str(&format!(
+                "[{}] ({}, {}ms) {}\n",
+                entry.target,
+                status,
+                entry.duration_ms,
+                format_command(entry)
+            ));
+        }
+        write_if_changed(&layout.command_log_path(), &text).map_err(BuildError::IoError)?;
+
+        let file = CommandLogFile {
+            configuration: configuration.to_string(),
+            commands,
+        };
+        let json = serde_json::to_string_pretty(&file)
+            .map_err(|e| BuildError::PathError(e.to_string()))?;
+        write_if_changed(&layout.command_log_json_path(), &format!("{}\n", json))
+            .map_err(BuildError::IoError)?;
+        Ok(())
+    }
+}
+
+/// 셸에 붙여 넣어 다시 실행할 수 있는 형식 (`cd dir && KEY=value cmd args`)
+pub fn format_command(entry: &LoggedCommand) -> String {
+    let mut parts = Vec::new();
+    if let Some(ref dir) = entry.directory {
+        parts.push(format!("cd {} &&", quote(&dir.to_string_lossy())));
+    }
+    for (key, value) in &entry.env {
+        match value {
+            Some(value) => parts.push(format!("{}={}", key, quote(value))),
+            None => parts.push(format!("-u {}", key)),
+        }
+    }
+    if entry.env.values().any(Option::is_none) {
+        parts.insert(usize::from(entry.directory.is_some()), "env".to_string());
+    }
+    parts.extend(entry.command.iter().map(|arg| quote(arg)));
+    parts.join(" ")
+}
+
+pub fn quote(arg: &str) -> String {
+    if arg.is_empty() || arg.contains(|c: char| c.is_whitespace() || "'\"$`\\;&|<>()*?".contains(c))
+    {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    } else {
+        arg.to_string()
+    }
+}
+
+/// `cbuild last-commands`: 마지막 빌드에서 실행한 명령 출력
+///
+/// `filter`는 타겟 키(exe:app), 타겟 이름(app) 또는 명령 인자로 쓰인 파일 경로이다.
+pub fn last_commands(
+    project_dir: &Path,
+    layout: &BuildLayout,
+    filter: Option<&str>,
+    json: bool,
+) -> BuildResult<()> {
+    let path = layout.command_log_json_path();
+    let content = std::fs::read_to_string(&path).map_err(|_| {
+        BuildError::PathError(tr!(
+            "명령 기록이 없습니다 (먼저 빌드하세요): {}",
+            "no command log (build first): {}",
+            path.display()
+        ))
+    })?;
+    let log: CommandLogFile = serde_json::from_str(&content)
+        .map_err(|e| BuildError::PathError(format!("{}: {}", path.display(), e)))?;
+
+    let commands: Vec<&LoggedCommand> = log
+        .commands
+        .iter()
+        .filter(|entry| filter.is_none_or(|f| matches_filter(entry, f, project_dir)))
+        .collect();
+
+    if json {
+        let json = serde_json::to_string_pretty(&commands)
+            .map_err(|e| BuildError::PathError(e.to_string()))?;
+        println!("{}", json);
+        return Ok(());
+    }
+
+    if commands.is_empty() {
+        return Err(BuildError::TargetNotFound(
+            filter.unwrap_or_default().to_string(),
+        ));
+    }
+
+    for entry in commands {
+        let status = match entry.exit_code {
+            Some(0) => "ok".green(),
+            Some(code) => format!("exit {}", code).red(),
+            None => "signal".red(),
+        };
+        println!(
+            "{} {} {}",
+            format!("[{}]", entry.target).bold(),
+            status,
+            format!("{}ms", entry.duration_ms).dimmed()
+        );
+        println!("  {}", format_command(entry));
+    }
+    Ok(())
+}
+
+fn matches_filter(entry: &LoggedCommand, filter: &str, project_dir: &Path) -> bool {
+    if entry.target == filter
+        || entry
+            .target
+            .split_once(':')
+            .is_some_and(|(_, n)| n == filter)
+    {
+        return true;
+    }
+
+    let file = to_absolute_path(Path::new(filter), project_dir);
+    entry
+        .command
+        .iter()
+        .any(|arg| arg == filter || Path::new(arg) == file)
+}
@@ -1,9 +1,10 @@
 use crate::error::{BuildError, BuildResult};
-use camino::Utf8Path;
-use glob::glob;
+use crate::i18n::tr;
+use crate::test_report::TestOutputFormat;
+use crate::utils::{deduplicate_paths, glob_in};
 use log::info;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashSet};
 use std::path::{Path, PathBuf};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -23,6 +24,53 @@ pub struct BuildSettings {
     pub debug_info: Option<bool>,
     pub warnings_as_errors: Option<bool>,
     pub extra_flags: Option<Vec<String>>,
+    pub rpath: Option<RpathMode>,
+    pub output_dir: Option<String>,
+    pub copy_shared_libs: Option<bool>,
+    pub max_memory: Option<String>,
+    /// 테스트 실행 파일을 감싸 실행할 명령 (예: "qemu-arm -L /usr/arm-linux-gnueabihf").
+    /// 크로스 컴파일한 테스트를 에뮬레이터로 실행할 때 사용한다.
+    pub test_runner: Option<String>,
+    /// 정적 라이브러리 아카이버 (기본: ar, compiler가 zig cc이면 zig ar)
+    pub archiver: Option<String>,
+    /// 크로스 컴파일 타겟 트리플 (zig cc·clang의 -target, 예: "aarch64-linux-musl")
+    pub target: Option<String>,
+    /// 실행 파일과 테스트를 정적으로 링크 (-static, musl 타겟과 함께 사용)
+    pub static_link: Option<bool>,
+    /// 모든 타겟을 OpenMP로 컴파일·링크 (타겟의 openmp가 우선)
+    pub openmp: Option<bool>,
+    /// Objective-C(.m/.mm) 소스 컴파일러 (기본: build.compiler가 clang 계열이면 그대로, 아니면 clang)
+    pub objc_compiler: Option<String>,
+    /// Objective-C 소스를 ARC(-fobjc-arc)로 컴파일 (기본: true)
+    pub objc_arc: Option<bool>,
+    /// build/<구성>/log에 보관할 빌드 로그 수 (기본: 20, 0이면 로그를 남기지 않음)
+    pub log_retention: Option<usize>,
+    /// build/.history에 보관할 빌드 기록 수 (기본: 50, 0이면 기록하지 않음)
+    pub history_retention: Option<usize>,
+    /// `-j`를 지정하지 않았을 때 병렬 작업 수 (기본: CPU 코어 수)
+    pub jobs: Option<usize>,
+    /// `-c`를 지정하지 않았을 때 빌드 구성 (기본: debug, install/package/pgo는 release)
+    pub default_configuration: Option<String>,
+    /// 바뀐 파일만 다시 빌드 (기본 true, false면 `cbuild build`가 항상 전체 빌드)
+    pub incremental: Option<bool>,
+    /// 소스 패턴이 심볼릭 링크 디렉토리 안까지 찾음 (기본 true, 순환 링크는 한 번만 방문)
+    pub follow_symlinks: Option<bool>,
+    /// 엄격한 포함 규칙: 타겟의 include_dirs는 `-iquote`, 인터페이스와 프로젝트 밖 디렉토리는
+    /// `-isystem`으로 넘기고, 선언하지 않은 디렉토리의 프로젝트 헤더를 포함하면 빌드 실패
+    pub strict_includes: Option<bool>,
+}
+
+/// 실행 파일에 공유 라이브러리 탐색 경로(rpath)를 기록하는 방식
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RpathMode {
+    /// 실행 파일 위치 기준 상대 경로 ($ORIGIN, @executable_path). 설치 후 옮겨도 동작
+    #[default]
+    Origin,
+    /// 빌드 디렉토리의 절대 경로 (명시적으로 지정할 때만)
+    Absolute,
+    /// rpath를 기록하지 않음
+    None,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -32,6 +80,24 @@ pub struct Dependency {
     pub git: Option<String>,
     pub tag: Option<String>,
     pub branch: Option<String>,
+    /// 압축 파일(.tar.gz, .zip 등) URL (git 대신 사용, `sha256` 필수)
+    pub url: Option<String>,
+    pub sha256: Option<String>,
+    /// 패키지 레지스트리 주소 (version 조건에 맞는 압축 파일을 색인에서 찾아 설치)
+    pub registry: Option<String>,
+    /// 사용 범위 (`test`면 [dev-dependencies]처럼 테스트 타겟에서만 사용)
+    pub scope: Option<DependencyScope>,
+}
+
+/// 의존성을 사용하는 타겟 범위
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum DependencyScope {
+    /// 모든 타겟
+    #[default]
+    Build,
+    /// 테스트 타겟만 (테스트 프레임워크, 벤치마크 라이브러리 등)
+    Test,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -39,10 +105,46 @@ pub struct ExecutableTarget {
     pub name: String,
     pub src: Vec<String>,
     pub include_dirs: Option<Vec<String>>,
+    /// 사용 요구사항(포함 디렉토리, 매크로)을 가져올 인터페이스 타겟
+    pub interfaces: Option<Vec<String>>,
     pub link_dirs: Option<Vec<String>>,
     pub libs: Option<Vec<String>>,
-    pub defines: Option<HashMap<String, String>>,
+    pub defines: Option<BTreeMap<String, String>>,
     pub extra_flags: Option<Vec<String>>,
+    pub rpath: Option<Vec<String>>,
+    pub copy: Option<CopyRules>,
+    pub embed: Option<Vec<String>>,
+    pub openmp: Option<bool>,
+    /// 링크할 Apple 프레임워크 (예: ["Foundation", "AppKit"])
+    pub frameworks: Option<Vec<String>>,
+    /// 결과물 파일 이름 (기본: 타겟 이름, 접두사/확장자 제외)
+    pub output_name: Option<String>,
+    /// 기본 출력 디렉토리(bin, lib, bin/tests) 아래 하위 디렉토리
+    pub output_dir: Option<String>,
+    /// false면 `--target`이나 `--all-targets`로 지정할 때만 빌드 (기본: true)
+    pub default: Option<bool>,
+    /// 링커에 전달할 추가 플래그 (예: BOLT용 "-Wl,--emit-relocs")
+    pub link_flags: Option<Vec<String>>,
+    /// 링크 후 차례로 실행할 최적화/후처리 명령 (예: llvm-bolt, strip, objcopy)
+    ///
+    /// `{input}`은 이전 단계 결과, `{output}`은 이 단계가 만들 파일로 바뀐다.
+    /// `{output}`이 없는 명령은 이전 결과의 복사본을 제자리에서 수정하는 것으로 본다.
+    /// 마지막 단계의 결과가 run/install이 사용하는 최종 실행 파일이 된다.
+    pub post_link: Option<Vec<Vec<String>>>,
+    /// `[[targets.example]]`에서 온 타겟인지
+    #[serde(skip)]
+    pub example: bool,
+}
+
+/// 링크 후 실행 파일 디렉토리로 복사할 런타임 파일 규칙
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CopyRules {
+    /// 링크하는 내부 공유 라이브러리 복사 여부 (기본: true)
+    pub shared_libs: Option<bool>,
+    /// 복사할 에셋 파일 (glob 패턴, 프로젝트 기준)
+    pub files: Option<Vec<String>>,
+    /// 실행 파일 디렉토리 기준 복사 위치
+    pub dest: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -50,8 +152,18 @@ pub struct LibraryTarget {
     pub name: String,
     pub src: Vec<String>,
     pub include_dirs: Option<Vec<String>>,
-    pub defines: Option<HashMap<String, String>>,
+    pub interfaces: Option<Vec<String>>,
+    pub defines: Option<BTreeMap<String, String>>,
     pub extra_flags: Option<Vec<String>>,
+    pub embed: Option<Vec<String>>,
+    pub openmp: Option<bool>,
+    /// 링크할 Apple 프레임워크 (정적 라이브러리는 링크하는 실행 파일에 적용)
+    pub frameworks: Option<Vec<String>>,
+    /// 결과물 파일 이름 (기본: 타겟 이름, 접두사/확장자 제외)
+    pub output_name: Option<String>,
+    /// 기본 출력 디렉토리(bin, lib, bin/tests) 아래 하위 디렉토리
+    pub output_dir: Option<String>,
+    pub default: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -59,10 +171,379 @@ pub struct TestTarget {
     pub name: String,
     pub src: Vec<String>,
     pub include_dirs: Option<Vec<String>>,
+    pub interfaces: Option<Vec<String>>,
     pub link_dirs: Option<Vec<String>>,
     pub libs: Option<Vec<String>>,
-    pub defines: Option<HashMap<String, String>>,
+    pub defines: Option<BTreeMap<String, String>>,
+    pub extra_flags: Option<Vec<String>>,
+    pub rpath: Option<Vec<String>>,
+    pub embed: Option<Vec<String>>,
+    pub env: Option<BTreeMap<String, String>>,
+    pub env_files: Option<Vec<String>>,
+    pub cwd: Option<String>,
+    pub args: Option<Vec<String>>,
+    /// 테스트 출력 형식 (tap, unity, cmocka, greatest). 지정하면 케이스별로 집계한다.
+    pub format: Option<TestOutputFormat>,
+    pub openmp: Option<bool>,
+    pub frameworks: Option<Vec<String>>,
+    pub output_name: Option<String>,
+    pub output_dir: Option<String>,
+    pub default: Option<bool>,
+}
+
+/// 소스와 결과물 없이 사용 요구사항만 가지는 인터페이스(헤더 전용) 라이브러리
+///
+/// 이 타겟을 `interfaces`에 나열한 타겟은 포함 디렉토리와 매크로 정의를 그대로 물려받는다.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct InterfaceTarget {
+    pub name: String,
+    pub include_dirs: Option<Vec<String>>,
+    pub defines: Option<BTreeMap<String, String>>,
+    /// 함께 전파할 다른 인터페이스 타겟
+    pub interfaces: Option<Vec<String>>,
+}
+
+/// 실행 파일의 출력을 저장소에 포함된 기대 결과 파일과 비교하는 골든 테스트
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct GoldenTarget {
+    pub name: String,
+    /// 실행할 실행 파일 타겟 이름
+    pub target: String,
+    pub args: Option<Vec<String>>,
+    /// 표준 입력으로 전달할 파일 (프로젝트 기준)
+    pub stdin: Option<String>,
+    /// 기대 결과 파일 (프로젝트 기준)
+    pub expected: String,
+    /// 표준 출력 대신 비교할 출력 파일 (작업 디렉토리 기준)
+    pub output_file: Option<String>,
+    /// 기대 종료 코드 (기본: 0)
+    pub exit_code: Option<i32>,
+    pub env: Option<BTreeMap<String, String>>,
+    pub cwd: Option<String>,
+}
+
+/// `cbuild build` 중에 실행되는 사용자 정의 명령 (에셋 처리, 문서 생성 등)
+///
+/// 선언한 입력이 출력보다 새롭거나 출력이 없을 때만 실행된다. 출력이 없는 타겟은
+/// 빌드할 때마다 실행된다.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CustomTarget {
+    pub name: String,
+    /// 실행할 명령과 인자 (셸을 거치지 않음)
+    pub command: Vec<String>,
+    /// 입력 파일 (glob 패턴, 프로젝트 기준)
+    #[serde(default)]
+    pub inputs: Vec<String>,
+    /// 생성되는 파일 (프로젝트 기준)
+    #[serde(default)]
+    pub outputs: Vec<String>,
+    /// 먼저 실행할 사용자 정의 타겟 이름 또는 먼저 빌드할 타겟 키 (예: "exe:app")
+    ///
+    /// 빌드 타겟에 의존하면 링크 후에 실행되고, 그렇지 않으면 컴파일 전에 실행된다.
+    pub depends: Option<Vec<String>>,
+    pub env: Option<BTreeMap<String, String>>,
+    pub cwd: Option<String>,
+}
+
+/// 여러 타겟을 한 이름으로 묶는 별칭 (`cbuild build --target <이름>`)
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AliasTarget {
+    pub name: String,
+    /// 타겟 이름, `종류:이름` 키 또는 다른 별칭
+    pub members: Vec<String>,
+}
+
+/// 경로와 패턴에서 build/<구성>/generated를 가리키는 자리 표시자
+pub const GENERATED_DIR_PLACEHOLDER: &str = "{generated}";
+
+/// `-c`와 build.default_configuration이 모두 없을 때의 빌드 구성
+pub const DEFAULT_CONFIGURATION: &str = "debug";
+
+/// 모든 테스트 타겟을 가리키는 기본 별칭
+pub const ALL_TESTS_ALIAS: &str = "all-tests";
+
+/// 빌드 구성(debug, release 등)별 설정
+///
+/// debug, release 외의 이름(`[profiles.asan]` 등)도 `-c <이름>`으로 빌드할 수 있다.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct Profile {
+    pub output_suffix: Option<String>,
+    /// 기준 구성: NDEBUG 정의와 스트립 여부를 따름 (debug | release, 기본: 구성 이름이
+    /// release면 release, 아니면 debug)
+    pub inherits: Option<String>,
+    /// build.optimization_level 대신 사용 (0-3 또는 "s", "z", "g", "fast")
+    pub optimization_level: Option<OptimizationLevel>,
+    /// build.debug_info 대신 사용
+    pub debug_info: Option<bool>,
+    /// 모든 타겟의 컴파일 플래그에 추가 (build.extra_flags 뒤)
     pub extra_flags: Option<Vec<String>>,
+    /// 모든 링크 명령에 추가 (예: -fsanitize=address)
+    pub link_flags: Option<Vec<String>>,
+    /// 모든 타겟에 추가하는 매크로 정의
+    pub defines: Option<BTreeMap<String, String>>,
+}
+
+/// 최적화 수준 (`-O<값>`)
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum OptimizationLevel {
+    Level(u8),
+    Named(String),
+}
+
+impl std::fmt::Display for OptimizationLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OptimizationLevel::Level(level) => write!(f, "{}", level),
+            OptimizationLevel::Named(name) => write!(f, "\"{}\"", name),
+        }
+    }
+}
+
+impl OptimizationLevel {
+    /// 컴파일러 플래그 (2 -> -O2, "s" -> -Os)
+    pub fn flag(&self) -> String {
+        match self {
+            OptimizationLevel::Level(level) => format!("-O{}", level),
+            OptimizationLevel::Named(name) => format!("-O{}", name),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        match self {
+            OptimizationLevel::Level(level) => *level <= 3,
+            OptimizationLevel::Named(name) => matches!(name.as_str(), "s" | "z" | "g" | "fast"),
+        }
+    }
+}
+
+/// 버전을 고정한 휴대용 툴체인 (`cbuild toolchain install <name>`)
+///
+/// 경로는 압축을 푼 디렉토리 기준이며, 압축 파일이 최상위 디렉토리 하나로
+/// 이루어져 있으면 그 디렉토리를 기준으로 한다.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ToolchainSpec {
+    /// 압축 파일 URL (.tar.gz, .tar.xz, .zip / file:// 가능)
+    pub url: String,
+    /// 압축 파일의 SHA-256
+    pub sha256: String,
+    /// C 컴파일러 (예: "bin/clang")
+    pub cc: String,
+    /// C++ 컴파일러 (build.compiler가 C++ 컴파일러일 때 사용)
+    pub cxx: Option<String>,
+    /// 아카이버 (예: "bin/llvm-ar")
+    pub ar: Option<String>,
+}
+
+/// `cbuild pgo` 프로파일 기반 최적화 설정
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct PgoSettings {
+    /// 계측 빌드로 실행할 학습 명령 (없으면 테스트 스위트 실행)
+    pub train: Option<Vec<String>>,
+    /// 프로파일 병합 도구 (기본: 컴파일러에 맞는 llvm-profdata)
+    pub profdata: Option<String>,
+}
+
+/// git clone/fetch와 다운로드처럼 일시적으로 실패할 수 있는 네트워크 작업의 재시도 설정
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct RetrySettings {
+    /// 최대 시도 횟수 (기본: 3, 1이면 재시도하지 않음)
+    pub attempts: Option<u32>,
+    /// 첫 재시도 전 대기 시간 (밀리초, 기본: 1000, 이후 두 배씩 최대 30초)
+    pub delay_ms: Option<u64>,
+}
+
+/// 파일 다운로드 설정 (툴체인, 압축 파일 의존성)
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct DownloadSettings {
+    /// 프록시 URL (없으면 HTTPS_PROXY/HTTP_PROXY/NO_PROXY 환경 변수를 따름)
+    pub proxy: Option<String>,
+    /// URL 접두사 -> 미러 접두사 (미러에서 먼저 받고 실패하면 원래 URL 사용)
+    pub mirrors: Option<BTreeMap<String, String>>,
+    /// 내려받은 압축 파일을 보관할 디렉토리 (기본: deps/.downloads, 툴체인은 ~/.cbuild/toolchains/.downloads)
+    pub cache_dir: Option<String>,
+}
+
+/// 빌드 결과물 서명 (`cbuild verify`가 서명도 확인)
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct SigningSettings {
+    /// 서명할 빌드 구성 (기본: ["release"])
+    pub configurations: Option<Vec<String>>,
+    /// `cbuild package`가 만든 패키지 옆에 분리 서명(.asc)을 만들 GPG 키
+    pub gpg_key: Option<String>,
+    /// macOS에서 실행 파일과 공유 라이브러리를 서명할 codesign 인증서 ("-"이면 애드혹 서명)
+    pub codesign_identity: Option<String>,
+    /// codesign 추가 인자 (예: ["--options", "runtime", "--timestamp"])
+    pub codesign_args: Option<Vec<String>>,
+    /// Windows에서 실행 파일과 DLL을 서명할 `signtool sign` 인자
+    pub signtool_args: Option<Vec<String>>,
+}
+
+/// `cbuild audit`의 취약점 권고 설정
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct AuditSettings {
+    /// 오프라인 권고 데이터베이스 (JSON, 프로젝트 기준 경로)
+    pub database: Option<String>,
+    /// 보고하되 실패로 세지 않을 권고 ID (CVE, GHSA, OSV ID)
+    pub ignore: Option<Vec<String>>,
+}
+
+/// `cbuild license`가 확인하고 추가하는 라이선스 헤더
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct LicenseSettings {
+    /// 헤더 템플릿 ({year}, {author}, {project} 치환, 주석 기호 없이 작성)
+    pub header: Option<String>,
+    /// 헤더 템플릿 파일 (header가 없을 때, 프로젝트 기준 경로)
+    pub header_file: Option<String>,
+    /// {author} 값 (기본: project.authors)
+    pub author: Option<String>,
+    /// 새 헤더에 넣을 {year} 값 (기본: 올해, 검사할 때는 모든 연도 허용)
+    pub year: Option<String>,
+    /// 주석 형식: "block"(/* */, 기본) 또는 "line"(//)
+    pub style: Option<String>,
+    /// 검사하지 않을 파일 (프로젝트 기준 glob 패턴)
+    pub exclude: Option<Vec<String>>,
+}
+
+/// 실험적 원격 실행 서버 (`cbuild build --remote-exec`)
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct RemoteExecutionSettings {
+    /// 서버 주소 (예: "http://build-farm:8980")
+    pub endpoint: Option<String>,
+    /// Bearer 토큰을 읽을 환경 변수 이름
+    pub token_env: Option<String>,
+}
+
+/// distcc/icecc 분산 컴파일 (`cbuild build --distributed` 또는 enabled = true)
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct DistributedSettings {
+    /// 항상 분산 컴파일 사용 (기본: --distributed를 지정할 때만)
+    pub enabled: Option<bool>,
+    /// 분산 컴파일 도구: "distcc"(기본) 또는 "icecc"
+    pub tool: Option<String>,
+    /// distcc 호스트 ("host/슬롯 수", 슬롯 기본 4, 없으면 distcc 자체 설정 사용)
+    pub hosts: Option<Vec<String>>,
+    /// icecc 스케줄러 주소 (기본: 네트워크에서 자동 탐색)
+    pub scheduler: Option<String>,
+    /// hosts가 없을 때 원격으로 보낼 동시 작업 수 (기본: 로컬 작업 수의 2배)
+    pub remote_jobs: Option<usize>,
+}
+
+/// 미리 컴파일한 헤더(PCH) 캐시 (헤더를 지정하면 사용)
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct PchSettings {
+    /// C와 C++ 소스 모두에 미리 컴파일해 포함할 헤더 (예: "stdio.h", `#include <...>` 형식)
+    pub headers: Option<Vec<String>>,
+    /// C++ 소스에만 포함할 헤더 (예: "vector", "boost/asio.hpp")
+    pub cpp_headers: Option<Vec<String>>,
+}
+
+/// build/<구성>/generated에 만드는 헤더 (이 디렉토리는 모든 타겟의 포함 경로에 추가됨)
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct GeneratedSettings {
+    /// 프로젝트 버전 매크로를 담은 version.h 생성 (<PROJECT>_VERSION 등)
+    pub version_header: Option<bool>,
+    /// 공유 라이브러리마다 심볼 공개 매크로를 담은 <이름>_export.h 생성 (<NAME>_EXPORT)
+    pub export_headers: Option<bool>,
+    /// config.h에 정의할 매크로 (값이 빈 문자열이면 값 없이 정의)
+    pub config: Option<BTreeMap<String, String>>,
+}
+
+/// `cbuild audit layout`이 검사하는 파일 배치와 이름 규칙
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct LayoutSettings {
+    /// 테스트 타겟 소스가 있어야 하는 디렉토리 (예: "test")
+    pub test_dir: Option<String>,
+    /// 라이브러리 공개 헤더가 있어야 하는 디렉토리 ({project} 치환, 예: "include/{project}")
+    pub public_header_dir: Option<String>,
+    /// 소스·헤더 파일 이름(확장자 제외)이 맞아야 하는 정규식 (예: "^[a-z][a-z0-9_]*$")
+    pub file_name: Option<String>,
+    /// 검사하지 않을 파일 (프로젝트 기준 glob 패턴)
+    pub exclude: Option<Vec<String>>,
+}
+
+/// `cbuild gc`의 정리 기준
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct GcSettings {
+    /// 이 기간(일)보다 오래 빌드하지 않은 빌드 구성 디렉토리와 다운로드 캐시 항목 삭제 (기본: 30)
+    pub max_age_days: Option<u64>,
+    /// 다운로드 캐시 최대 크기 (예: "2G", 넘으면 오래된 파일부터 삭제, 기본: 제한 없음)
+    pub max_cache_size: Option<String>,
+}
+
+/// `cbuild install`/`cbuild package`가 함께 배포할 문서와 셸 자동 완성
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct InstallSettings {
+    /// man 페이지 (glob 패턴, 확장자의 섹션 번호로 share/man/man<N>에 설치)
+    pub man: Option<Vec<String>>,
+    #[serde(default)]
+    pub completions: CompletionSettings,
+}
+
+/// 셸 자동 완성 스크립트
+///
+/// 셸별 파일을 지정하거나, `command`로 빌드한 실행 파일에서 생성한다.
+/// `command`의 첫 항목이 실행 파일 타겟 이름이면 빌드 결과물 경로로 바뀌고,
+/// `{shell}`은 bash/zsh/fish로 바뀐다.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct CompletionSettings {
+    /// 완성 대상 명령 이름 (기본: 프로젝트 이름)
+    pub name: Option<String>,
+    pub bash: Option<String>,
+    pub zsh: Option<String>,
+    pub fish: Option<String>,
+    /// 자동 완성 스크립트를 표준 출력으로 내보내는 명령
+    pub command: Option<Vec<String>>,
+}
+
+/// `cbuild package` 배포 번들 설정
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct PackageSettings {
+    /// 번들에 넣을 실행 파일 (기본: `[run] default`와 같은 규칙)
+    pub executable: Option<String>,
+    /// 역도메인 형식의 애플리케이션 ID (flatpak 필수, 기본: io.cbuild.<프로젝트 이름>)
+    pub app_id: Option<String>,
+    /// 아이콘 파일 (.png/.svg, 없으면 기본 아이콘 생성)
+    pub icon: Option<String>,
+    /// 데스크톱 항목 분류 (기본: ["Utility"])
+    pub categories: Option<Vec<String>>,
+    /// 터미널에서 실행하는 프로그램인지 (기본: true)
+    pub terminal: Option<bool>,
+    /// flatpak 런타임 버전 (기본: "23.08")
+    pub runtime_version: Option<String>,
+}
+
+/// `cbuild run` 실행 환경 설정
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct RunSettings {
+    pub default: Option<String>,
+    #[serde(default)]
+    pub aliases: BTreeMap<String, RunAlias>,
+    pub env: Option<BTreeMap<String, String>>,
+    pub env_files: Option<Vec<String>>,
+    pub cwd: Option<String>,
+    pub args: Option<Vec<String>>,
+}
+
+/// `cbuild test` 설정
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct TestSettings {
+    /// 불안정한 것으로 알려진 테스트 (타겟 이름 또는 `타겟::케이스`).
+    /// 실행은 하지만 실패해도 빌드를 실패시키지 않고 따로 보고한다.
+    #[serde(default)]
+    pub quarantine: Vec<String>,
+    /// 테스트 실행 시 메모리 누수 검사 (LeakSanitizer 또는 valgrind).
+    /// 누수가 발견되면 테스트 실패로 보고한다.
+    #[serde(default)]
+    pub leak_check: bool,
+}
+
+/// 미리 정의된 인자로 실행 파일을 실행하는 별칭 (`cbuild run --alias <name>`)
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RunAlias {
+    pub target: Option<String>,
+    pub args: Option<Vec<String>>,
+    pub env: Option<BTreeMap<String, String>>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -75,6 +556,31 @@ pub struct Targets {
     pub shared_lib: Vec<LibraryTarget>,
     #[serde(default)]
     pub test: Vec<TestTarget>,
+    #[serde(default)]
+    pub golden: Vec<GoldenTarget>,
+    #[serde(default)]
+    pub interface: Vec<InterfaceTarget>,
+    #[serde(default)]
+    pub custom: Vec<CustomTarget>,
+    #[serde(default)]
+    pub alias: Vec<AliasTarget>,
+    /// 예제 실행 파일 (기본 빌드에서 제외, `cbuild examples`로 실행)
+    #[serde(default)]
+    pub example: Vec<ExecutableTarget>,
+}
+
+impl Targets {
+    /// 예제 타겟을 기본 빌드에서 제외되고 bin/examples에 놓이는 실행 파일 타겟으로 합침
+    fn merge_examples(&mut self) {
+        for mut example in std::mem::take(&mut self.example) {
+            example.default.get_or_insert(false);
+            example
+                .output_dir
+                .get_or_insert_with(|| "examples".to_string());
+            example.example = true;
+            self.executable.push(example);
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -82,11 +588,70 @@ pub struct BuildConfig {
     pub project: ProjectInfo,
     pub build: BuildSettings,
     #[serde(default)]
-    pub dependencies: HashMap<String, Dependency>,
+    pub dependencies: BTreeMap<String, Dependency>,
+    /// 테스트 타겟에서만 쓰는 의존성 (`cbuild dependencies --no-dev`로 설치하지 않음)
+    #[serde(default, rename = "dev-dependencies")]
+    pub dev_dependencies: BTreeMap<String, Dependency>,
+    /// 이 프로젝트를 의존성으로 쓰는 쪽이 `features`로 켤 수 있는 기능 (기능 -> 매크로 정의)
+    #[serde(default)]
+    pub features: BTreeMap<String, BTreeMap<String, String>>,
     pub targets: Targets,
+    #[serde(default)]
+    pub run: RunSettings,
+    #[serde(default)]
+    pub test: TestSettings,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+    #[serde(default)]
+    pub pgo: PgoSettings,
+    #[serde(default)]
+    pub install: InstallSettings,
+    #[serde(default)]
+    pub package: PackageSettings,
+    #[serde(default)]
+    pub toolchains: BTreeMap<String, ToolchainSpec>,
+    #[serde(default)]
+    pub retry: RetrySettings,
+    #[serde(default)]
+    pub download: DownloadSettings,
+    #[serde(default)]
+    pub gc: GcSettings,
+    #[serde(default)]
+    pub signing: SigningSettings,
+    #[serde(default)]
+    pub audit: AuditSettings,
+    #[serde(default)]
+    pub license: LicenseSettings,
+    #[serde(default)]
+    pub layout: LayoutSettings,
+    #[serde(default)]
+    pub pch: PchSettings,
+    #[serde(default)]
+    pub distributed: DistributedSettings,
+    #[serde(default)]
+    pub remote_execution: RemoteExecutionSettings,
+    #[serde(default)]
+    pub generated: GeneratedSettings,
 }
 
 impl BuildConfig {
+    /// `start`부터 상위 디렉토리로 올라가며 cbuild.toml이 있는 첫 디렉토리 찾기
+    pub fn find_project_root(start: &Path) -> Option<PathBuf> {
+        start
+            .ancestors()
+            .find(|dir| dir.join("cbuild.toml").is_file())
+            .map(Path::to_path_buf)
+    }
+
+    /// 프로젝트 설정 로드
+    ///
+    /// 다음 파일을 순서대로 병합하며 뒤의 파일이 우선한다. 테이블은 키별로 병합하고
+    /// 배열과 값은 통째로 바꾼다.
+    ///
+    /// 1. 사용자 설정: `$CBUILD_CONFIG`, 없으면 `$XDG_CONFIG_HOME/cbuild/config.toml`
+    ///    (기본 `~/.config/cbuild/config.toml`). 기본 컴파일러, 다운로드 미러와 캐시 디렉토리 등
+    /// 2. `cbuild.toml`: 저장소에 커밋하는 프로젝트 설정
+    /// 3. `cbuild.local.toml`: 커밋하지 않는 이 컴퓨터 전용 재정의
     pub fn from_file(path: &Path) -> BuildResult<Self> {
         let config_path = path.join("cbuild.toml");
 
@@ -94,12 +659,58 @@ impl BuildConfig {
             return Err(BuildError::ConfigNotFound(config_path));
         }
 
-        info!("설정 파일 로드 중: {}", config_path.display());
+        let mut merged = toml::Value::Table(toml::Table::new());
+        let layers = user_config_path()
+            .into_iter()
+            .chain([config_path.clone(), path.join(LOCAL_CONFIG_FILE)]);
+        for layer in layers {
+            if layer != config_path && !layer.is_file() {
+                continue;
+            }
+            info!("설정 파일 로드 중: {}", layer.display());
+
+            let content = std::fs::read_to_string(&layer).map_err(BuildError::IoError)?;
+            let value: toml::Value = toml::from_str(&content).map_err(|e| {
+                if layer == config_path {
+                    BuildError::ConfigParsingError(e.to_string())
+                } else {
+                    BuildError::ConfigParsingError(format!("{}: {}", layer.display(), e))
+                }
+            })?;
+            merge_toml(&mut merged, value);
+        }
+
+        let mut config: BuildConfig = merged
+            .try_into()
+            .map_err(|e: toml::de::Error| BuildError::ConfigParsingError(e.to_string()))?;
+        config.targets.merge_examples();
 
-        let content = std::fs::read_to_string(&config_path).map_err(|e| BuildError::IoError(e))?;
+        if let Some((name, level)) = config.profiles.iter().find_map(|(name, profile)| {
+            profile
+                .optimization_level
+                .as_ref()
+                .filter(|level| !level.is_valid())
+                .map(|level| (name, level))
+        }) {
+            return Err(BuildError::ConfigParsingError(tr!(
+                "[profiles.{}]의 optimization_level {}이(가) 올바르지 않습니다 (0-3, \"s\", \"z\", \"g\", \"fast\")",
+                "[profiles.{}] has an invalid optimization_level {} (0-3, \"s\", \"z\", \"g\", \"fast\")",
+                name,
+                level
+            )));
+        }
 
-        let config: BuildConfig =
-            toml::from_str(&content).map_err(|e| BuildError::ConfigParsingError(e.to_string()))?;
+        if let Some(name) = config
+            .dev_dependencies
+            .keys()
+            .find(|name| config.dependencies.contains_key(*name))
+        {
+            return Err(BuildError::ConfigParsingError(tr!(
+                "의존성 {}이(가) [dependencies]와 [dev-dependencies]에 모두 있습니다",
+                "dependency {} is in both [dependencies] and [dev-dependencies]",
+                name
+            )));
+        }
 
         Ok(config)
     }
@@ -107,61 +718,427 @@ impl BuildConfig {
     pub fn resolve_source_files(
         &self,
         project_dir: &Path,
-    ) -> BuildResult<HashMap<String, Vec<PathBuf>>> {
-        let mut resolved_sources = HashMap::new();
+    ) -> BuildResult<BTreeMap<String, Vec<PathBuf>>> {
+        let mut resolved_sources = BTreeMap::new();
+        let follow_links = self.build.follow_symlinks.unwrap_or(true);
 
         // 실행 파일 소스 해결
         for target in &self.targets.executable {
-            let sources = resolve_glob_patterns(&target.src, project_dir)?;
+            let sources = resolve_glob_patterns(&target.src, project_dir, follow_links)?;
             resolved_sources.insert(format!("exe:{}", target.name), sources);
         }
 
         // 정적 라이브러리 소스 해결
         for target in &self.targets.static_lib {
-            let sources = resolve_glob_patterns(&target.src, project_dir)?;
+            let sources = resolve_glob_patterns(&target.src, project_dir, follow_links)?;
             resolved_sources.insert(format!("static:{}", target.name), sources);
         }
 
         // 공유 라이브러리 소스 해결
         for target in &self.targets.shared_lib {
-            let sources = resolve_glob_patterns(&target.src, project_dir)?;
+            let sources = resolve_glob_patterns(&target.src, project_dir, follow_links)?;
             resolved_sources.insert(format!("shared:{}", target.name), sources);
         }
 
         // 테스트 소스 해결
         for target in &self.targets.test {
-            let sources = resolve_glob_patterns(&target.src, project_dir)?;
+            let sources = resolve_glob_patterns(&target.src, project_dir, follow_links)?;
             resolved_sources.insert(format!("test:{}", target.name), sources);
         }
 
         Ok(resolved_sources)
     }
-}
 
-fn resolve_glob_patterns(patterns: &[String], base_dir: &Path) -> BuildResult<Vec<PathBuf>> {
-    let mut resolved_files = Vec::new();
+    /// src의 `{generated}` 패턴 (타겟 키, 패턴)
+    ///
+    /// 빌드 구성마다 디렉토리가 다르고 컴파일 전 사용자 정의 명령이 파일을 만들기 때문에
+    /// `resolve_source_files`는 건너뛰고 빌더가 따로 해결한다.
+    pub fn generated_source_patterns(&self) -> Vec<(String, &str)> {
+        let targets = &self.targets;
+        let sources = targets
+            .executable
+            .iter()
+            .map(|t| ("exe", &t.name, &t.src))
+            .chain(
+                targets
+                    .static_lib
+                    .iter()
+                    .map(|t| ("static", &t.name, &t.src)),
+            )
+            .chain(
+                targets
+                    .shared_lib
+                    .iter()
+                    .map(|t| ("shared", &t.name, &t.src)),
+            )
+            .chain(targets.test.iter().map(|t| ("test", &t.name, &t.src)));
 
-    for pattern in patterns {
-        let pattern_path = if Path::new(pattern).is_absolute() {
-            pattern.clone()
-        } else {
-            base_dir.join(pattern).to_string_lossy().to_string()
+        let mut patterns = Vec::new();
+        for (kind, name, src) in sources {
+            for pattern in src {
+                if pattern.contains(GENERATED_DIR_PLACEHOLDER) {
+                    patterns.push((format!("{}:{}", kind, name), pattern.as_str()));
+                }
+            }
+        }
+        patterns
+    }
+
+    /// 타겟이 OpenMP를 사용하는지 (타겟의 openmp, 없으면 build.openmp)
+    pub fn target_uses_openmp(&self, target_type: &str, target_name: &str) -> bool {
+        let targets = &self.targets;
+        let target_setting = match target_type {
+            "exe" => targets
+                .executable
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.openmp),
+            "static" => targets
+                .static_lib
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.openmp),
+            "shared" => targets
+                .shared_lib
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.openmp),
+            "test" => targets
+                .test
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.openmp),
+            _ => None,
         };
 
-        let paths = glob(&pattern_path)
-            .map_err(|e| BuildError::PathError(format!("패턴 '{}'에 오류: {}", pattern, e)))?;
+        target_setting.or(self.build.openmp).unwrap_or(false)
+    }
+
+    /// 타겟이 링크하는 Apple 프레임워크
+    pub fn target_frameworks(&self, target_type: &str, target_name: &str) -> Vec<String> {
+        let targets = &self.targets;
+        let frameworks = match target_type {
+            "exe" => targets
+                .executable
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.frameworks.clone()),
+            "static" => targets
+                .static_lib
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.frameworks.clone()),
+            "shared" => targets
+                .shared_lib
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.frameworks.clone()),
+            "test" => targets
+                .test
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.frameworks.clone()),
+            _ => None,
+        };
+
+        frameworks.unwrap_or_default()
+    }
+
+    /// 타겟 키가 `cbuild build`의 기본 빌드에 포함되는지 (`default = false`면 제외)
+    pub fn is_default_target(&self, key: &str) -> bool {
+        let (target_type, target_name) = key.split_once(':').unwrap_or(("", key));
+        let targets = &self.targets;
+        let default = match target_type {
+            "exe" => targets
+                .executable
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.default),
+            "static" => targets
+                .static_lib
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.default),
+            "shared" => targets
+                .shared_lib
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.default),
+            "test" => targets
+                .test
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.default),
+            _ => None,
+        };
+
+        default.unwrap_or(true)
+    }
+
+    /// 예제 타겟 (`[[targets.example]]`)
+    pub fn examples(&self) -> impl Iterator<Item = &ExecutableTarget> {
+        self.targets.executable.iter().filter(|t| t.example)
+    }
+
+    /// `--target`으로 지정한 이름을 빌드 타겟 키 집합으로 변환
+    ///
+    /// 이름은 `종류:이름` 키, 타겟 이름(같은 이름의 모든 종류), 별칭 순으로 해석하며
+    /// 별칭은 멤버를 재귀적으로 펼친다. `all-tests`는 별도로 정의하지 않으면 모든
+    /// 테스트 타겟을 가리킨다.
+    pub fn resolve_target_keys(&self, names: &[String]) -> BuildResult<HashSet<String>> {
+        let mut keys = HashSet::new();
+        let mut expanding = Vec::new();
+        for name in names {
+            self.expand_target_name(name, &mut expanding, &mut keys)?;
+        }
+        Ok(keys)
+    }
+
+    fn expand_target_name(
+        &self,
+        name: &str,
+        expanding: &mut Vec<String>,
+        keys: &mut HashSet<String>,
+    ) -> BuildResult<()> {
+        let targets = &self.targets;
+        let build_keys = targets
+            .executable
+            .iter()
+            .map(|t| format!("exe:{}", t.name))
+            .chain(
+                targets
+                    .static_lib
+                    .iter()
+                    .map(|t| format!("static:{}", t.name)),
+            )
+            .chain(
+                targets
+                    .shared_lib
+                    .iter()
+                    .map(|t| format!("shared:{}", t.name)),
+            )
+            .chain(targets.test.iter().map(|t| format!("test:{}", t.name)));
 
-        for path in paths {
-            match path {
-                Ok(path) => resolved_files.push(path),
-                Err(e) => return Err(BuildError::PathError(format!("경로 해결 오류: {}", e))),
+        let matched: Vec<String> = build_keys
+            .filter(|key| key == name || key.split_once(':').map(|(_, n)| n) == Some(name))
+            .collect();
+        if !matched.is_empty() {
+            keys.extend(matched);
+            return Ok(());
+        }
+
+        if let Some(alias) = targets.alias.iter().find(|a| a.name == name) {
+            if expanding.iter().any(|n| n == name) {
+                expanding.push(name.to_string());
+                return Err(BuildError::ConfigParsingError(tr!(
+                    "별칭 타겟에 순환이 있습니다: {}",
+                    "alias targets form a cycle: {}",
+                    expanding.join(" -> ")
+                )));
+            }
+            expanding.push(name.to_string());
+            for member in &alias.members {
+                self.expand_target_name(member, expanding, keys)?;
+            }
+            expanding.pop();
+            return Ok(());
+        }
+
+        if name == ALL_TESTS_ALIAS {
+            keys.extend(targets.test.iter().map(|t| format!("test:{}", t.name)));
+            return Ok(());
+        }
+
+        Err(BuildError::TargetNotFound(name.to_string()))
+    }
+
+    /// 빌드 구성의 [profiles.<이름>] 설정
+    pub fn profile(&self, configuration: &str) -> Option<&Profile> {
+        self.profiles.get(configuration)
+    }
+
+    /// `-c`를 지정하지 않았을 때의 빌드 구성 (build.default_configuration 또는 debug)
+    pub fn default_configuration(&self) -> &str {
+        self.build
+            .default_configuration
+            .as_deref()
+            .unwrap_or(DEFAULT_CONFIGURATION)
+    }
+
+    /// 릴리스 계열 빌드 구성인지 (release 또는 `inherits = "release"`인 프로파일)
+    pub fn is_release_configuration(&self, configuration: &str) -> bool {
+        match self
+            .profile(configuration)
+            .and_then(|p| p.inherits.as_deref())
+        {
+            Some(inherits) => inherits == "release",
+            None => configuration == "release",
+        }
+    }
+
+    /// [dependencies]와 [dev-dependencies]의 모든 의존성 (이름 순)
+    pub fn all_dependencies(&self) -> impl Iterator<Item = (&String, &Dependency)> {
+        let mut all: Vec<(&String, &Dependency)> = self
+            .dependencies
+            .iter()
+            .chain(self.dev_dependencies.iter())
+            .collect();
+        all.sort_by(|a, b| a.0.cmp(b.0));
+        all.into_iter()
+    }
+
+    /// 이름으로 의존성 찾기 ([dependencies] 다음 [dev-dependencies])
+    pub fn find_dependency(&self, name: &str) -> Option<&Dependency> {
+        self.dependencies
+            .get(name)
+            .or_else(|| self.dev_dependencies.get(name))
+    }
+
+    /// 테스트 타겟에서만 쓰는 의존성인지 ([dev-dependencies] 또는 `scope = "test"`)
+    pub fn is_dev_dependency(&self, name: &str) -> bool {
+        self.dev_dependencies.contains_key(name)
+            || self
+                .dependencies
+                .get(name)
+                .is_some_and(|d| d.scope == Some(DependencyScope::Test))
+    }
+
+    /// 타겟이 사용하는 인터페이스 타겟 (전이적으로 해석, 선언 순서 유지)
+    pub fn target_interfaces(
+        &self,
+        target_type: &str,
+        target_name: &str,
+    ) -> BuildResult<Vec<&InterfaceTarget>> {
+        let targets = &self.targets;
+        let direct = match target_type {
+            "exe" => targets
+                .executable
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.interfaces.clone()),
+            "static" => targets
+                .static_lib
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.interfaces.clone()),
+            "shared" => targets
+                .shared_lib
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.interfaces.clone()),
+            "test" => targets
+                .test
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.interfaces.clone()),
+            "interface" => targets
+                .interface
+                .iter()
+                .find(|t| t.name == target_name)
+                .and_then(|t| t.interfaces.clone()),
+            _ => None,
+        };
+
+        let mut resolved: Vec<&InterfaceTarget> = Vec::new();
+        let mut pending: Vec<String> = direct.unwrap_or_default();
+        pending.reverse();
+        while let Some(name) = pending.pop() {
+            if resolved.iter().any(|t| t.name == name) {
+                continue;
+            }
+            let interface = targets
+                .interface
+                .iter()
+                .find(|t| t.name == name)
+                .ok_or_else(|| BuildError::TargetNotFound(format!("interface:{}", name)))?;
+            resolved.push(interface);
+            for nested in interface.interfaces.iter().flatten().rev() {
+                pending.push(nested.clone());
             }
         }
+
+        Ok(resolved)
+    }
+
+    /// Objective-C 소스 컴파일러 (build.objc_compiler, 없으면 clang 계열 컴파일러)
+    pub fn objc_compiler(&self) -> &str {
+        match self.build.objc_compiler {
+            Some(ref compiler) => compiler,
+            None if self.build.compiler.contains("clang") => &self.build.compiler,
+            None => "clang",
+        }
+    }
+
+    /// OpenMP를 사용하는 타겟이 하나라도 있는지
+    pub fn uses_openmp(&self) -> bool {
+        let targets = &self.targets;
+        self.build.openmp.unwrap_or(false)
+            || targets.executable.iter().any(|t| t.openmp == Some(true))
+            || targets.static_lib.iter().any(|t| t.openmp == Some(true))
+            || targets.shared_lib.iter().any(|t| t.openmp == Some(true))
+            || targets.test.iter().any(|t| t.openmp == Some(true))
+    }
+}
+
+/// 커밋하지 않는 이 컴퓨터 전용 설정 파일 (`cbuild init`이 .gitignore에 추가)
+pub const LOCAL_CONFIG_FILE: &str = "cbuild.local.toml";
+
+/// 사용자 설정 파일 경로 (`BuildConfig::from_file`의 병합 순서 참고)
+pub fn user_config_path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os("CBUILD_CONFIG") {
+        return Some(PathBuf::from(path));
+    }
+    let config_home = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME")
+                .or_else(|| std::env::var_os("USERPROFILE"))
+                .map(|home| PathBuf::from(home).join(".config"))
+        })?;
+    Some(config_home.join("cbuild").join("config.toml"))
+}
+
+/// `overlay`를 `base`에 병합 (테이블은 재귀적으로, 나머지는 덮어씀)
+fn merge_toml(base: &mut toml::Value, overlay: toml::Value) {
+    match (base, overlay) {
+        (toml::Value::Table(base), toml::Value::Table(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge_toml(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+fn resolve_glob_patterns(
+    patterns: &[String],
+    base_dir: &Path,
+    follow_links: bool,
+) -> BuildResult<Vec<PathBuf>> {
+    let mut resolved_files = Vec::new();
+
+    for pattern in patterns {
+        if pattern.contains(GENERATED_DIR_PLACEHOLDER) {
+            continue;
+        }
+        let paths = glob_in(base_dir, pattern, follow_links)
+            .map_err(|e| BuildError::PathError(format!("패턴 '{}'에 오류: {}", pattern, e)))?;
+        resolved_files.extend(paths);
     }
 
-    if resolved_files.is_empty() {
+    let generated = patterns
+        .iter()
+        .any(|pattern| pattern.contains(GENERATED_DIR_PLACEHOLDER));
+    if resolved_files.is_empty() && !generated {
         return Err(BuildError::NoSourceFiles(patterns.join(", ")));
     }
 
-    Ok(resolved_files)
+    // 여러 패턴이나 심볼릭 링크로 같은 파일에 닿으면 오브젝트가 두 번 만들어지므로 한 번만 남김
+    Ok(deduplicate_paths(&resolved_files))
 }
@@ -0,0 +1,237 @@
+use crate::config::{BuildConfig, Dependency, DependencyScope};
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use crate::utils::write_if_changed;
+use std::path::{Path, PathBuf};
+use toml_edit::{Array, DocumentMut, InlineTable, Item, Table};
+
+/// 의존성 표 이름
+pub const DEPENDENCIES: &str = "dependencies";
+pub const DEV_DEPENDENCIES: &str = "dev-dependencies";
+
+/// cbuild.toml을 주석, 빈 줄, 키 순서를 유지한 채 수정
+///
+/// `save`는 결과가 설정으로 읽히는지 확인한 뒤 이전 내용을 cbuild.toml.bak에
+/// 남기고 저장한다.
+pub struct ConfigEditor {
+    path: PathBuf,
+    document: DocumentMut,
+}
+
+impl ConfigEditor {
+    pub fn open(project_dir: &Path) -> BuildResult<Self> {
+        let path = project_dir.join("cbuild.toml");
+        if !path.exists() {
+            return Err(BuildError::ConfigNotFound(path));
+        }
+        let content = std::fs::read_to_string(&path).map_err(BuildError::IoError)?;
+        let document = content
+            .parse::<DocumentMut>()
+            .map_err(|e| BuildError::ConfigParsingError(e.to_string()))?;
+
+        Ok(ConfigEditor { path, document })
+    }
+
+    /// 의존성 표(`table`)에 의존성 추가 또는 교체 (`name = { version = ..., git = ... }` 형식)
+    ///
+    /// 이미 있는 의존성은 같은 자리에서 바꾼다.
+    pub fn set_dependency(&mut self, table: &str, name: &str, dependency: &Dependency) {
+        let mut fields = InlineTable::new();
+        fields.insert("version", dependency.version.as_str().into());
+        if let Some(ref features) = dependency.features {
+            fields.insert("features", features.iter().collect::<Array>().into());
+        }
+        for (key, value) in [
+            ("git", &dependency.git),
+            ("tag", &dependency.tag),
+            ("branch", &dependency.branch),
+            ("url", &dependency.url),
+            ("sha256", &dependency.sha256),
+            ("registry", &dependency.registry),
+        ] {
+            if let Some(value) = value {
+                fields.insert(key, value.as_str().into());
+            }
+        }
+        if dependency.scope == Some(DependencyScope::Test) {
+            fields.insert("scope", "test".into());
+        }
+
+        let dependencies = self.dependencies_table(table);
+        match dependencies.get_mut(name) {
+            Some(item) => *item = toml_edit::value(fields),
+            None => {
+                dependencies.insert(name, toml_edit::value(fields));
+            }
+        }
+    }
+
+    /// 의존성 표(`table`)에서 의존성 삭제 (`[dependencies.<이름>]` 표 형식 포함), 없으면 false
+    ///
+    /// [dependencies]가 아닌 표는 비면 표 머리글도 지운다.
+    pub fn remove_dependency(&mut self, table: &str, name: &str) -> bool {
+        let removed = self
+            .document
+            .get_mut(table)
+            .and_then(|item| item.as_table_like_mut())
+            .and_then(|table| table.remove(name));
+
+        let found = match removed {
+            Some(Item::Table(removed)) => {
+                self.keep_comments(&removed);
+                true
+            }
+            Some(_) => true,
+            None => false,
+        };
+
+        let emptied = self
+            .document
+            .get(table)
+            .and_then(|item| item.as_table_like())
+            .is_some_and(|table| table.is_empty());
+        if found && emptied && table != DEPENDENCIES {
+            if let Some(Item::Table(removed)) = self.document.remove(table) {
+                self.keep_comments(&removed);
+            }
+        }
+        found
+    }
+
+    /// 삭제한 표 머리글 앞의 주석을 다음 표(없으면 문서 끝) 앞으로 옮김
+    ///
+    /// toml_edit은 표의 마지막 키 뒤에 있는 주석을 다음 표 머리글에 붙여 두므로,
+    /// 그대로 지우면 앞 표에 속한 주석까지 사라진다.
+    fn keep_comments(&mut self, removed: &Table) {
+        let Some(prefix) = removed.decor().prefix().and_then(|p| p.as_str()) else {
+            return;
+        };
+        if !prefix.contains('#') {
+            return;
+        }
+
+        let next = removed
+            .position()
+            .and_then(|position| next_table_position(self.document.as_table(), position));
+        match next.and_then(|next| table_at_position(self.document.as_table_mut(), next)) {
+            Some(table) => {
+                let own = table
+                    .decor()
+                    .prefix()
+                    .and_then(|p| p.as_str())
+                    .unwrap_or("");
+                let merged = format!("{}{}", prefix, own);
+                table.decor_mut().set_prefix(merged);
+            }
+            None => {
+                let trailing = self.document.trailing().as_str().unwrap_or("");
+                let merged = format!("{}{}", prefix, trailing);
+                self.document.set_trailing(merged);
+            }
+        }
+    }
+
+    /// 설정 끝에 TOML 조각 추가 (`cbuild test init`의 의존성과 테스트 타겟 등)
+    pub fn append(&mut self, snippet: &str) -> BuildResult<()> {
+        let content = format!("{}{}", self.document, snippet);
+        self.document = content
+            .parse::<DocumentMut>()
+            .map_err(|e| BuildError::ConfigParsingError(e.to_string()))?;
+        Ok(())
+    }
+
+    /// 수정한 설정 검증 후 저장 (내용이 같으면 아무것도 하지 않음)
+    pub fn save(self) -> BuildResult<()> {
+        let content = self.document.to_string();
+        toml::from_str::<BuildConfig>(&content).map_err(|e| {
+            BuildError::ConfigParsingError(tr!(
+                "수정한 설정이 올바르지 않아 저장하지 않았습니다: {}",
+                "edited configuration is invalid and was not saved: {}",
+                e
+            ))
+        })?;
+        write_config(&self.path, &content)
+    }
+
+    fn dependencies_table(&mut self, table: &str) -> &mut Table {
+        // 새 표는 문서 끝의 주석 예시 사이가 아니라 [dependencies] 바로 뒤에 둠
+        let position = self
+            .document
+            .get(DEPENDENCIES)
+            .and_then(|item| item.as_table())
+            .and_then(|table| table.position());
+        let item = self.document.entry(table).or_insert_with(|| {
+            let mut new_table = Table::new();
+            if let Some(position) = position {
+                new_table.set_position(position);
+            }
+            Item::Table(new_table)
+        });
+        // `dependencies = { ... }` 형식이면 표로 바꿔 한 줄에 하나씩 적음
+        if let Item::Value(toml_edit::Value::InlineTable(inline)) = item {
+            *item = Item::Table(std::mem::take(inline).into_table());
+        }
+        item.as_table_mut().expect("의존성 표는 표여야 합니다")
+    }
+}
+
+/// `after` 다음에 나오는 표 머리글의 문서 내 위치
+fn next_table_position(table: &Table, after: usize) -> Option<usize> {
+    let mut next: Option<usize> = None;
+    for (_, item) in table.iter() {
+        let children: Vec<&Table> = match item {
+            Item::Table(table) => vec![table],
+            Item::ArrayOfTables(array) => array.iter().collect(),
+            _ => continue,
+        };
+        for child in children {
+            let candidates = child
+                .position()
+                .filter(|&position| position > after)
+                .into_iter()
+                .chain(next_table_position(child, after));
+            for position in candidates {
+                next = Some(next.map_or(position, |next| next.min(position)));
+            }
+        }
+    }
+    next
+}
+
+/// 문서 내 위치가 `position`인 표
+fn table_at_position(table: &mut Table, position: usize) -> Option<&mut Table> {
+    for (_, item) in table.iter_mut() {
+        let children: Vec<&mut Table> = match item {
+            Item::Table(table) => vec![table],
+            Item::ArrayOfTables(array) => array.iter_mut().collect(),
+            _ => continue,
+        };
+        for child in children {
+            if child.position() == Some(position) {
+                return Some(child);
+            }
+            if let Some(found) = table_at_position(child, position) {
+                return Some(found);
+            }
+        }
+    }
+    None
+}
+
+/// 설정 파일 저장, 이미 있는 파일의 내용이 바뀌면 이전 내용을 `<파일>.bak`에 보관
+pub fn write_config(path: &Path, content: &str) -> BuildResult<()> {
+    if let Ok(existing) = std::fs::read_to_string(path) {
+        if existing == content {
+            return Ok(());
+        }
+        write_if_changed(&backup_path(path), &existing).map_err(BuildError::IoError)?;
+    }
+    std::fs::write(path, content).map_err(BuildError::IoError)
+}
+
+/// 설정 파일의 백업 경로 (cbuild.toml -> cbuild.toml.bak)
+fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".bak");
+    path.with_file_name(name)
+}
@@ -0,0 +1,177 @@
+use crate::config::BuildConfig;
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use crate::tools::{self, RequiredTool, ToolKind};
+use crate::utils;
+use log::{debug, warn};
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// 컴파일러가 읽는 환경 변수 (호스트에 설정되어 있으면 컨테이너로 전달)
+const FORWARDED_ENV: &[&str] = &[
+    "CPATH",
+    "C_INCLUDE_PATH",
+    "CPLUS_INCLUDE_PATH",
+    "LIBRARY_PATH",
+    "SOURCE_DATE_EPOCH",
+    "TZ",
+];
+
+/// 컴파일·링크 명령을 실행하는 컨테이너 (`--container <image>`)
+///
+/// 빌드를 시작할 때 컨테이너를 하나 띄워 두고 각 명령을 `exec`로 실행한다.
+/// 프로젝트와 빌드 디렉토리는 호스트와 같은 경로에 마운트하므로
+/// 명령줄의 경로를 바꿀 필요가 없다. 컨테이너는 빌드가 끝나면 제거된다.
+pub struct Container {
+    engine: String,
+    id: String,
+}
+
+impl Container {
+    /// 컨테이너 엔진 결정 (CBUILD_CONTAINER_ENGINE, 없으면 docker, podman 순서)
+    pub fn detect_engine() -> BuildResult<String> {
+        if let Ok(engine) = std::env::var("CBUILD_CONTAINER_ENGINE") {
+            if utils::is_tool_installed(&engine) {
+                return Ok(engine);
+            }
+            return Err(BuildError::ToolsNotFound(vec![RequiredTool {
+                program: engine,
+                kind: ToolKind::ContainerEngine,
+            }]));
+        }
+
+        ["docker", "podman"]
+            .iter()
+            .find(|engine| utils::is_tool_installed(engine))
+            .map(|engine| engine.to_string())
+            .ok_or_else(|| {
+                BuildError::ToolsNotFound(vec![RequiredTool {
+                    program: "docker".to_string(),
+                    kind: ToolKind::ContainerEngine,
+                }])
+            })
+    }
+
+    /// 이미지로 컨테이너를 시작하고 디렉토리를 같은 경로에 마운트
+    ///
+    /// `jobs`는 컨테이너가 사용할 수 있는 CPU 수로 지정한다.
+    pub fn start(
+        image: &str,
+        project_dir: &Path,
+        mounts: &[PathBuf],
+        jobs: usize,
+    ) -> BuildResult<Self> {
+        let engine = Self::detect_engine()?;
+
+        let mut cmd = Command::new(&engine);
+        cmd.args(["run", "--detach", "--rm", "--init"]);
+        cmd.arg("--workdir").arg(project_dir);
+        cmd.arg("--cpus").arg(jobs.max(1).to_string());
+
+        let mut mounted: Vec<&Path> = Vec::new();
+        for dir in std::iter::once(project_dir).chain(mounts.iter().map(|p| p.as_path())) {
+            if mounted.iter().any(|m| dir.starts_with(m)) {
+                continue;
+            }
+            let mut volume = dir.as_os_str().to_os_string();
+            volume.push(":");
+            volume.push(dir.as_os_str());
+            cmd.arg("--volume").arg(volume);
+            mounted.push(dir);
+        }
+
+        // 빌드 결과물이 root 소유가 되지 않도록 호스트 사용자로 실행
+        #[cfg(unix)]
+        {
+            if engine.contains("podman") {
+                cmd.arg("--userns=keep-id");
+            } else {
+                let (uid, gid) = unsafe { (libc::getuid(), libc::getgid()) };
+                cmd.arg("--user").arg(format!("{}:{}", uid, gid));
+            }
+        }
+
+        cmd.arg(image).args(["sleep", "infinity"]);
+        debug!("컨테이너 시작: {:?}", cmd);
+
+        let output = cmd.output().map_err(BuildError::IoError)?;
+        if !output.status.success() {
+            return Err(BuildError::ContainerError(tr!(
+                "{} 이미지로 컨테이너를 시작할 수 없습니다: {}",
+                "cannot start a container from image {}: {}",
+                image,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        let id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        debug!("컨테이너 ID: {}", id);
+        Ok(Container { engine, id })
+    }
+
+    /// 호스트에서 실행하려던 명령을 컨테이너 안에서 실행하는 명령으로 변환
+    ///
+    /// 명령에 설정된 작업 디렉토리와 환경 변수, 호스트의 컴파일러 관련 환경 변수를 전달한다.
+    pub fn command(&self, cmd: &Command) -> Command {
+        let mut exec = Command::new(&self.engine);
+        exec.args(["exec", "--interactive"]);
+
+        if let Some(dir) = cmd.get_current_dir() {
+            exec.arg("--workdir").arg(dir);
+        }
+
+        for name in FORWARDED_ENV {
+            if let Some(value) = std::env::var_os(name) {
+                exec.arg("--env").arg(env_pair(OsStr::new(name), &value));
+            }
+        }
+        for (name, value) in cmd.get_envs() {
+            if let Some(value) = value {
+                exec.arg("--env").arg(env_pair(name, value));
+            }
+        }
+
+        exec.arg(&self.id)
+            .arg(cmd.get_program())
+            .args(cmd.get_args());
+        exec
+    }
+
+    /// 빌드에 필요한 도구가 컨테이너 이미지에 있는지 확인
+    pub fn check_tools(&self, config: &BuildConfig) -> BuildResult<()> {
+        tools::check_with(tools::required_tools(config), |program| {
+            Command::new(&self.engine)
+                .args(["exec", &self.id, "sh", "-c", "command -v \"$0\""])
+                .arg(utils::split_tool_command(program).0)
+                .output()
+                .map(|output| output.status.success())
+                .unwrap_or(false)
+        })
+    }
+}
+
+impl Drop for Container {
+    fn drop(&mut self) {
+        let result = Command::new(&self.engine)
+            .args(["rm", "--force", &self.id])
+            .output();
+        if !matches!(result, Ok(ref output) if output.status.success()) {
+            warn!(
+                "{}",
+                tr!(
+                    "컨테이너 {}을(를) 제거할 수 없습니다",
+                    "cannot remove container {}",
+                    self.id
+                )
+            );
+        }
+    }
+}
+
+fn env_pair(name: &OsStr, value: &OsStr) -> std::ffi::OsString {
+    let mut pair = name.to_os_string();
+    pair.push("=");
+    pair.push(value);
+    pair
+}
@@ -0,0 +1,104 @@
+use crate::config::BuildConfig;
+use crate::error::BuildResult;
+use crate::features::{resolve_dependency_features, ResolvedFeatures};
+use crate::layout::BuildLayout;
+use crate::provision::InstalledToolchain;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+
+/// 명령 한 번을 실행하는 동안 공유하는 프로젝트 정보
+///
+/// 설정 파일과 타겟별 소스 목록은 처음 필요할 때 한 번만 해석한다. 툴체인처럼
+/// 바꿀 수 있는 값은 `Arc`로 감싸기 전에 정하고, 그 뒤에는 Builder, Runner,
+/// DependencyManager가 `Arc`를 복제해 읽기 전용으로 같은 컨텍스트를 공유한다.
+pub struct ProjectContext {
+    project_dir: PathBuf,
+    config: OnceLock<Arc<BuildConfig>>,
+    sources: OnceLock<BTreeMap<String, Vec<PathBuf>>>,
+    dependency_features: OnceLock<BTreeMap<String, ResolvedFeatures>>,
+    toolchain: Option<InstalledToolchain>,
+}
+
+impl ProjectContext {
+    pub fn new(project_dir: &Path) -> Self {
+        ProjectContext {
+            project_dir: project_dir.to_path_buf(),
+            config: OnceLock::new(),
+            sources: OnceLock::new(),
+            dependency_features: OnceLock::new(),
+            toolchain: None,
+        }
+    }
+
+    /// 설정의 컴파일러와 아카이버 대신 설치된 툴체인 사용 (`--toolchain <name>`)
+    ///
+    /// 설정을 처음 읽을 때 반영되므로 컨텍스트를 공유하기 전에 호출해야 한다.
+    pub fn set_toolchain(&mut self, toolchain: InstalledToolchain) {
+        self.toolchain = Some(toolchain);
+    }
+
+    pub fn project_dir(&self) -> &Path {
+        &self.project_dir
+    }
+
+    /// 프로젝트 설정 (처음 호출할 때 cbuild.toml 파싱)
+    pub fn config(&self) -> BuildResult<&BuildConfig> {
+        self.shared_config_ref().map(|config| config.as_ref())
+    }
+
+    /// 컨텍스트보다 오래 들고 있을 수 있는 프로젝트 설정 (Builder가 보관)
+    pub fn shared_config(&self) -> BuildResult<Arc<BuildConfig>> {
+        self.shared_config_ref().cloned()
+    }
+
+    fn shared_config_ref(&self) -> BuildResult<&Arc<BuildConfig>> {
+        if let Some(config) = self.config.get() {
+            return Ok(config);
+        }
+
+        let mut config = BuildConfig::from_file(&self.project_dir)?;
+        if let Some(ref toolchain) = self.toolchain {
+            config.build.compiler = toolchain.compiler_for(&config.build.compiler);
+            if let Some(ref ar) = toolchain.ar {
+                config.build.archiver = Some(ar.to_string_lossy().to_string());
+            }
+        }
+        Ok(self.config.get_or_init(|| Arc::new(config)))
+    }
+
+    /// 이미 로드된 설정 (아직 로드하지 않았으면 None)
+    pub fn loaded_config(&self) -> Option<&BuildConfig> {
+        self.config.get().map(|config| config.as_ref())
+    }
+
+    /// 설정의 glob 패턴을 해석한 타겟별 소스 파일 (생성된 임베딩 소스 제외)
+    pub fn sources(&self) -> BuildResult<&BTreeMap<String, Vec<PathBuf>>> {
+        if let Some(sources) = self.sources.get() {
+            return Ok(sources);
+        }
+
+        let sources = self.config()?.resolve_source_files(&self.project_dir)?;
+        Ok(self.sources.get_or_init(|| sources))
+    }
+
+    /// 의존성별로 합친 기능과 매크로 정의 (처음 호출할 때 설치된 의존성의 cbuild.toml 확인)
+    pub fn dependency_features(&self) -> BuildResult<&BTreeMap<String, ResolvedFeatures>> {
+        if let Some(features) = self.dependency_features.get() {
+            return Ok(features);
+        }
+
+        let features = resolve_dependency_features(&self.project_dir, self.config()?)?;
+        Ok(self.dependency_features.get_or_init(|| features))
+    }
+
+    /// 빌드 구성과 출력 디렉토리에 따른 빌드 결과물 경로
+    pub fn layout(&self, configuration: &str, out_dir: Option<&Path>) -> BuildLayout {
+        BuildLayout::new(
+            &self.project_dir,
+            configuration,
+            out_dir,
+            self.loaded_config(),
+        )
+    }
+}
@@ -0,0 +1,148 @@
+use crate::config::BuildConfig;
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use crate::install::public_include_dirs;
+use crate::layout::BuildLayout;
+use crate::license::project_files;
+use crate::utils::{collect_files_with_extension, get_common_header_extensions};
+use colored::Colorize;
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// `cbuild audit layout`: [layout] 규칙을 어긴 파일 보고
+///
+/// - `test_dir`: 테스트 타겟 소스는 이 디렉토리 아래에 있어야 함
+/// - `public_header_dir`: 라이브러리 공개 헤더(`cbuild install`이 설치하는 헤더)는 이 디렉토리
+///   아래에 있어야 함
+/// - `file_name`: 프로젝트의 소스·헤더 파일 이름(확장자 제외)이 정규식과 맞아야 함
+///
+/// 규칙 위반 수를 반환한다.
+pub fn check(project_dir: &Path) -> BuildResult<usize> {
+    let config = BuildConfig::from_file(project_dir)?;
+    let settings = &config.layout;
+    if settings.test_dir.is_none()
+        && settings.public_header_dir.is_none()
+        && settings.file_name.is_none()
+    {
+        return Err(BuildError::ConfigParsingError(tr!(
+            "[layout]에 검사할 규칙이 없습니다 (test_dir, public_header_dir, file_name)",
+            "[layout] has no rules to check (test_dir, public_header_dir, file_name)"
+        )));
+    }
+
+    let files = project_files(&config, project_dir, &settings.exclude, "layout")?;
+    // 파일마다 어긴 규칙 목록 (경로 순으로 출력)
+    let mut violations: BTreeMap<PathBuf, Vec<String>> = BTreeMap::new();
+
+    if let Some(ref test_dir) = settings.test_dir {
+        let test_dir = project_dir.join(test_dir);
+        for (key, sources) in config.resolve_source_files(project_dir)? {
+            let Some(target) = key.strip_prefix("test:") else {
+                continue;
+            };
+            for source in sources {
+                if files.contains(&source) && !source.starts_with(&test_dir) {
+                    violations.entry(source).or_default().push(tr!(
+                        "테스트 타겟 {}의 소스가 {} 밖에 있습니다",
+                        "source of test target {} is outside {}",
+                        target,
+                        relative(&test_dir, project_dir).display()
+                    ));
+                }
+            }
+        }
+    }
+
+    if let Some(ref header_dir) = settings.public_header_dir {
+        let header_dir = project_dir.join(header_dir.replace("{project}", &config.project.name));
+        let layout = BuildLayout::new(
+            project_dir,
+            config.default_configuration(),
+            None,
+            Some(&config),
+        );
+        let libraries = config
+            .targets
+            .static_lib
+            .iter()
+            .chain(&config.targets.shared_lib);
+        for lib in libraries {
+            for dir in public_include_dirs(project_dir, &layout, lib) {
+                for header in collect_files_with_extension(&dir, &get_common_header_extensions()) {
+                    if files.contains(&header) && !header.starts_with(&header_dir) {
+                        let messages = violations.entry(header).or_default();
+                        let message = tr!(
+                            "공개 헤더가 {} 밖에 있습니다",
+                            "public header is outside {}",
+                            relative(&header_dir, project_dir).display()
+                        );
+                        // 여러 라이브러리가 같은 include 디렉토리를 공유할 수 있음
+                        if !messages.contains(&message) {
+                            messages.push(message);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(ref pattern) = settings.file_name {
+        let regex = Regex::new(pattern)
+            .map_err(|e| BuildError::ConfigParsingError(format!("[layout] file_name: {}", e)))?;
+        for file in &files {
+            let stem = file
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default();
+            if !regex.is_match(&stem) {
+                violations.entry(file.clone()).or_default().push(tr!(
+                    "파일 이름이 {}와 맞지 않습니다",
+                    "file name does not match {}",
+                    pattern
+                ));
+            }
+        }
+    }
+
+    let count: usize = violations.values().map(Vec::len).sum();
+    for (file, messages) in &violations {
+        for message in messages {
+            println!(
+                "{} {}: {}",
+                "violation".red().bold(),
+                relative(file, project_dir).display(),
+                message
+            );
+        }
+    }
+
+    if count == 0 {
+        println!(
+            "{}",
+            tr!(
+                "파일 {}개 모두 배치 규칙을 지킵니다.",
+                "All {} files follow the layout rules.",
+                files.len()
+            )
+            .green()
+        );
+    } else {
+        println!(
+            "\n{}",
+            tr!(
+                "파일 {}개에서 규칙 위반 {}건을 찾았습니다.",
+                "Found layout violations in {} files ({} total).",
+                violations.len(),
+                count
+            )
+            .red()
+        );
+    }
+
+    Ok(count)
+}
+
+fn relative<'a>(path: &'a Path, project_dir: &Path) -> &'a Path {
+    path.strip_prefix(project_dir).unwrap_or(path)
+}
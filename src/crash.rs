@@ -0,0 +1,240 @@
+use crate::cmdlog::quote;
+use crate::layout::BuildLayout;
+use crate::utils::{get_extension, make_executable};
+use std::ffi::OsStr;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// 컴파일러가 진단을 내지 못하고 비정상 종료했는지 (시그널 종료 또는 내부 오류)
+pub fn is_compiler_crash(output: &Output) -> bool {
+    if output.status.code().is_none() {
+        return true;
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    stderr.contains("internal compiler error")
+        || stderr.contains("PLEASE submit a bug report")
+        || stderr.contains("clang frontend command failed")
+}
+
+/// 컴파일러 충돌 재현 정보를 build/crash-reports/<n>/에 저장하고 그 경로를 반환
+///
+/// 명령줄, 작업 디렉토리와 환경 변수, 컴파일러 출력, 컴파일러 버전과 함께
+/// 같은 플래그로 만든 전처리 결과(-E)와 이를 다시 컴파일하는 스크립트를 남겨
+/// 컴파일러 버그 보고에 그대로 첨부할 수 있게 한다. `run`은 컨테이너 빌드에서도
+/// 같은 환경에서 실행되도록 빌더의 명령 실행 함수를 사용한다.
+pub fn capture(
+    layout: &BuildLayout,
+    cmd: &Command,
+    source_file: &Path,
+    output: &Output,
+    run: impl Fn(&mut Command) -> std::io::Result<Output>,
+) -> std::io::Result<PathBuf> {
+    let dir = next_report_dir(&layout.crash_reports_dir())?;
+
+    let args: Vec<&OsStr> = cmd.get_args().collect();
+    let status = match output.status.code() {
+        Some(code) => format!("exit {}", code),
+        None => describe_signal(&output.status),
+    };
+
+    let mut command = String::new();
+    if let Some(cwd) = cmd.get_current_dir() {
+        let _ = writeln!(command, "cd {}", quote(&cwd.to_string_lossy()));
+    }
+    let _ = writeln!(
+        command,
+        "{}",
+        command_line(cmd.get_program(), args.iter().copied())
+    );
+    std::fs::write(dir.join("command.sh"), &command)?;
+
+    let mut environment = String::new();
+    for (key, value) in cmd.get_envs() {
+        match value {
+            Some(value) => {
+                let _ = writeln!(
+                    environment,
+                    "{}={}  (cbuild)",
+                    key.to_string_lossy(),
+                    value.to_string_lossy()
+                );
+            }
+            None => {
+                let _ = writeln!(environment, "-{}  (cbuild)", key.to_string_lossy());
+            }
+        }
+    }
+    let mut vars: Vec<(String, String)> = std::env::vars().collect();
+    vars.sort();
+    for (key, value) in vars {
+        let _ = writeln!(environment, "{}={}", key, value);
+    }
+    std::fs::write(dir.join("environment.txt"), environment)?;
+
+    std::fs::write(dir.join("stderr.txt"), &output.stderr)?;
+    if !output.stdout.is_empty() {
+        std::fs::write(dir.join("stdout.txt"), &output.stdout)?;
+    }
+
+    // 컴파일러 버전
+    let mut version_cmd = Command::new(cmd.get_program());
+    version_cmd.arg("--version");
+    let version = run(&mut version_cmd)
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+        .unwrap_or_default();
+
+    // 같은 플래그로 전처리해 재현용 단일 파일 생성
+    let preprocessed_name = format!(
+        "{}.{}",
+        source_file
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy(),
+        preprocessed_extension(source_file)
+    );
+    let preprocessed = dir.join(&preprocessed_name);
+    let mut preprocess = Command::new(cmd.get_program());
+    preprocess.args(preprocess_args(&args, &preprocessed));
+    if let Some(cwd) = cmd.get_current_dir() {
+        preprocess.current_dir(cwd);
+    }
+    for (key, value) in cmd.get_envs() {
+        match value {
+            Some(value) => preprocess.env(key, value),
+            None => preprocess.env_remove(key),
+        };
+    }
+    let preprocess_ok = run(&mut preprocess).is_ok_and(|o| o.status.success());
+
+    if preprocess_ok {
+        // 원본 소스 대신 전처리 결과를 컴파일 (포함 경로와 매크로는 이미 적용됨)
+        let reproduce_args = reproduce_args(&args, source_file, &preprocessed_name);
+        let script = format!(
+            "#!/bin/sh\n# {}\ncd \"$(dirname \"$0\")\"\n{}\n",
+            status,
+            command_line(cmd.get_program(), reproduce_args.iter().map(OsStr::new))
+        );
+        std::fs::write(dir.join("reproduce.sh"), script)?;
+        make_executable(&dir.join("reproduce.sh"))?;
+    }
+
+    let mut readme = String::new();
+    let _ = writeln!(readme, "source:   {}", source_file.display());
+    let _ = writeln!(readme, "status:   {}", status);
+    let _ = writeln!(readme, "compiler: {}", version.lines().next().unwrap_or(""));
+    let _ = writeln!(readme);
+    let _ = writeln!(readme, "command.sh       original compile command");
+    let _ = writeln!(
+        readme,
+        "environment.txt  environment (lines marked (cbuild) were set for the compiler)"
+    );
+    let _ = writeln!(readme, "stderr.txt       compiler output");
+    if preprocess_ok {
+        let _ = writeln!(readme, "{:<16} preprocessed source (-E)", preprocessed_name);
+        let _ = writeln!(
+            readme,
+            "reproduce.sh     compiles the preprocessed source with the same flags"
+        );
+    } else {
+        let _ = writeln!(
+            readme,
+            "(preprocessing failed; no reproducer was generated)"
+        );
+    }
+    if !version.is_empty() {
+        let _ = writeln!(readme, "\n{}", version);
+    }
+    std::fs::write(dir.join("README.txt"), readme)?;
+
+    Ok(dir)
+}
+
+/// build/crash-reports 아래 다음 번호의 디렉토리 생성
+fn next_report_dir(root: &Path) -> std::io::Result<PathBuf> {
+    std::fs::create_dir_all(root)?;
+    let last = std::fs::read_dir(root)?
+        .flatten()
+        .filter_map(|entry| entry.file_name().to_str()?.parse::<u32>().ok())
+        .max()
+        .unwrap_or(0);
+    let dir = root.join((last + 1).to_string());
+    std::fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+/// `-c`와 `-o <obj>`를 `-E -o <preprocessed>`로 바꾼 인자
+fn preprocess_args(args: &[&OsStr], preprocessed: &Path) -> Vec<std::ffi::OsString> {
+    let mut result = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.to_str() {
+            Some("-c") => result.push("-E".into()),
+            Some("-o") => {
+                iter.next();
+                result.push("-o".into());
+                result.push(preprocessed.as_os_str().to_os_string());
+            }
+            _ => result.push(arg.to_os_string()),
+        }
+    }
+    result
+}
+
+/// 원본 명령에서 소스를 전처리 결과로, 출력을 현재 디렉토리의 오브젝트로 바꾼 인자
+///
+/// 전처리 결과에 이미 반영된 -I/-D/-include 플래그는 제거한다.
+fn reproduce_args(args: &[&OsStr], source_file: &Path, preprocessed: &str) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let text = arg.to_string_lossy();
+        if Path::new(arg) == source_file {
+            result.push(preprocessed.to_string());
+        } else if text == "-o" {
+            iter.next();
+            result.push("-o".to_string());
+            result.push("reproduce.o".to_string());
+        } else if matches!(
+            text.as_ref(),
+            "-I" | "-D" | "-include" | "-iquote" | "-isystem"
+        ) {
+            iter.next();
+        } else if !(text.starts_with("-I") || text.starts_with("-D")) {
+            result.push(text.to_string());
+        }
+    }
+    result
+}
+
+fn command_line<'a>(program: &'a OsStr, args: impl Iterator<Item = &'a OsStr>) -> String {
+    std::iter::once(program)
+        .chain(args)
+        .map(|arg| quote(&arg.to_string_lossy()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 전처리 결과 확장자 (C: .i, C++: .ii, Objective-C: .mi, Objective-C++: .mii)
+fn preprocessed_extension(source_file: &Path) -> &'static str {
+    match get_extension(source_file).as_deref() {
+        Some("c") => "i",
+        Some("m") => "mi",
+        Some("mm") => "mii",
+        _ => "ii",
+    }
+}
+
+#[cfg(unix)]
+fn describe_signal(status: &std::process::ExitStatus) -> String {
+    use std::os::unix::process::ExitStatusExt;
+    match status.signal() {
+        Some(signal) => format!("signal {}", signal),
+        None => "signal".to_string(),
+    }
+}
+
+#[cfg(not(unix))]
+fn describe_signal(_status: &std::process::ExitStatus) -> String {
+    "signal".to_string()
+}
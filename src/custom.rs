@@ -0,0 +1,199 @@
+use crate::config::{BuildConfig, CustomTarget};
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use crate::layout::BuildLayout;
+use crate::utils::{get_file_modification_time, glob_in, substitute_paths, to_absolute_path};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// 사용자 정의 명령 타겟의 실행 시점
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CustomStage {
+    /// 소스 해석과 컴파일 전 (생성된 소스/헤더를 빌드에 사용할 수 있음)
+    BeforeCompile,
+    /// 모든 타겟을 링크한 후 (빌드 결과물을 입력으로 사용)
+    AfterLink,
+}
+
+/// 의존 순서대로 정렬한 사용자 정의 타겟과 각 타겟의 실행 시점
+///
+/// `depends`의 `종류:이름` 항목은 빌드 타겟, 그 외는 다른 사용자 정의 타겟을 가리킨다.
+/// 빌드 타겟에 직접 또는 간접으로 의존하는 타겟은 링크 후에 실행된다.
+pub fn ordered_custom_targets(
+    config: &BuildConfig,
+) -> BuildResult<Vec<(&CustomTarget, CustomStage)>> {
+    let mut ordered = Vec::new();
+    let mut stages: HashMap<&str, CustomStage> = HashMap::new();
+    let mut visiting = Vec::new();
+
+    for target in &config.targets.custom {
+        visit(config, target, &mut visiting, &mut stages, &mut ordered)?;
+    }
+
+    Ok(ordered)
+}
+
+fn visit<'a>(
+    config: &'a BuildConfig,
+    target: &'a CustomTarget,
+    visiting: &mut Vec<&'a str>,
+    stages: &mut HashMap<&'a str, CustomStage>,
+    ordered: &mut Vec<(&'a CustomTarget, CustomStage)>,
+) -> BuildResult<CustomStage> {
+    if let Some(stage) = stages.get(target.name.as_str()) {
+        return Ok(*stage);
+    }
+    if visiting.contains(&target.name.as_str()) {
+        visiting.push(&target.name);
+        return Err(BuildError::ConfigParsingError(tr!(
+            "사용자 정의 타겟의 의존 관계에 순환이 있습니다: {}",
+            "custom targets have a dependency cycle: {}",
+            visiting.join(" -> ")
+        )));
+    }
+    visiting.push(&target.name);
+
+    let mut stage = CustomStage::BeforeCompile;
+    for dep in target.depends.iter().flatten() {
+        if dep.contains(':') {
+            if !is_build_target(config, dep) {
+                return Err(BuildError::TargetNotFound(dep.clone()));
+            }
+            stage = CustomStage::AfterLink;
+            continue;
+        }
+
+        let dep_target = config
+            .targets
+            .custom
+            .iter()
+            .find(|t| &t.name == dep)
+            .ok_or_else(|| BuildError::TargetNotFound(format!("custom:{}", dep)))?;
+        if visit(config, dep_target, visiting, stages, ordered)? == CustomStage::AfterLink {
+            stage = CustomStage::AfterLink;
+        }
+    }
+
+    visiting.pop();
+    stages.insert(&target.name, stage);
+    ordered.push((target, stage));
+    Ok(stage)
+}
+
+/// `종류:이름` 키가 설정에 있는 빌드 타겟인지
+fn is_build_target(config: &BuildConfig, key: &str) -> bool {
+    let Some((kind, name)) = key.split_once(':') else {
+        return false;
+    };
+    let targets = &config.targets;
+    match kind {
+        "exe" => targets.executable.iter().any(|t| t.name == name),
+        "static" => targets.static_lib.iter().any(|t| t.name == name),
+        "shared" => targets.shared_lib.iter().any(|t| t.name == name),
+        "test" => targets.test.iter().any(|t| t.name == name),
+        _ => false,
+    }
+}
+
+/// 타겟의 입력 파일: `inputs` 패턴에 해당하는 파일, 의존하는 빌드 타겟의 결과물,
+/// 의존하는 사용자 정의 타겟의 출력
+pub fn custom_inputs(
+    config: &BuildConfig,
+    target: &CustomTarget,
+    project_dir: &Path,
+    layout: &BuildLayout,
+) -> BuildResult<Vec<PathBuf>> {
+    let mut inputs = Vec::new();
+    for pattern in &target.inputs {
+        let paths = glob_in(project_dir, pattern, true)
+            .map_err(|e| BuildError::PathError(format!("패턴 '{}'에 오류: {}", pattern, e)))?;
+        inputs.extend(paths.into_iter().filter(|p| p.is_file()));
+    }
+
+    for dep in target.depends.iter().flatten() {
+        match dep.split_once(':') {
+            Some((kind, name)) => inputs.push(match kind {
+                "exe" => layout.executable_path(name),
+                "static" => layout.static_lib_path(name),
+                "shared" => layout.shared_lib_path(name),
+                _ => layout.test_path(name),
+            }),
+            None => {
+                if let Some(dep_target) = config.targets.custom.iter().find(|t| &t.name == dep) {
+                    inputs.extend(custom_outputs(dep_target, project_dir, layout));
+                }
+            }
+        }
+    }
+
+    inputs.sort();
+    inputs.dedup();
+    Ok(inputs)
+}
+
+/// 타겟의 출력 파일 절대 경로 (`{generated}`는 build/<구성>/generated)
+pub fn custom_outputs(
+    target: &CustomTarget,
+    project_dir: &Path,
+    layout: &BuildLayout,
+) -> Vec<PathBuf> {
+    let generated_dir = layout.generated_dir();
+    target
+        .outputs
+        .iter()
+        .map(|output| {
+            let output = substitute_paths(output, &[("{generated}", &generated_dir)]);
+            to_absolute_path(Path::new(&output), project_dir)
+        })
+        .collect()
+}
+
+/// 명령, 작업 디렉토리, 환경 변수를 기록한 문자열 (바뀌면 다시 실행)
+pub fn command_stamp(target: &CustomTarget) -> String {
+    let mut env: Vec<_> = target.env.iter().flatten().collect();
+    env.sort();
+
+    let mut stamp = target.command.join("\0");
+    stamp.push('\n');
+    stamp.push_str(target.cwd.as_deref().unwrap_or(""));
+    for (key, value) in env {
+        stamp.push_str(&format!("\n{}={}", key, value));
+    }
+    stamp
+}
+
+/// 출력이 모두 있고 입력과 명령이 마지막 실행 이후 바뀌지 않았는지
+pub fn is_up_to_date(
+    target: &CustomTarget,
+    inputs: &[PathBuf],
+    project_dir: &Path,
+    layout: &BuildLayout,
+    stamp_path: &Path,
+) -> bool {
+    let outputs = custom_outputs(target, project_dir, layout);
+    if outputs.is_empty() {
+        return false;
+    }
+
+    match std::fs::read_to_string(stamp_path) {
+        Ok(stamp) if stamp == command_stamp(target) => {}
+        _ => return false,
+    }
+
+    let oldest_output = match outputs
+        .iter()
+        .map(|output| get_file_modification_time(output))
+        .collect::<Option<Vec<_>>>()
+        .and_then(|times| times.into_iter().min())
+    {
+        Some(time) => time,
+        None => return false,
+    };
+
+    inputs
+        .iter()
+        .all(|input| match get_file_modification_time(input) {
+            Some(time) => time <= oldest_output,
+            None => false,
+        })
+}
@@ -1,43 +1,138 @@
-use crate::config::BuildConfig;
+use crate::config::{BuildConfig, Dependency};
+use crate::config_edit::{ConfigEditor, DEPENDENCIES, DEV_DEPENDENCIES};
+use crate::context::ProjectContext;
+use crate::download::{extract, file_name_from_url, single_top_level_dir, Downloader};
 use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use crate::lockfile::{self, LockedDependency, Lockfile, Resolutions};
+use crate::registry::{self, RegistryRelease};
+use crate::retry::RetryPolicy;
+use crate::semver::{Version, VersionReq};
 use log::{info, warn};
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::Arc;
+
+/// 압축 파일 의존성을 설치할 때 사용한 체크섬 기록 (deps/<name>/ 안)
+const ARCHIVE_MARKER: &str = ".cbuild-sha256";
+
+/// `cbuild dependencies add`: cbuild.toml의 [dependencies]에 의존성 추가 또는 변경
+///
+/// `dev`면 [dev-dependencies]에 넣고, 다른 표에 같은 이름이 있으면 그쪽에서 옮긴다.
+pub fn add_dependency(
+    project_dir: &Path,
+    name: &str,
+    dependency: &Dependency,
+    dev: bool,
+) -> BuildResult<()> {
+    let replaced = BuildConfig::from_file(project_dir)?
+        .find_dependency(name)
+        .is_some();
+
+    let (table, other) = if dev {
+        (DEV_DEPENDENCIES, DEPENDENCIES)
+    } else {
+        (DEPENDENCIES, DEV_DEPENDENCIES)
+    };
+    let mut editor = ConfigEditor::open(project_dir)?;
+    editor.remove_dependency(other, name);
+    editor.set_dependency(table, name, dependency);
+    editor.save()?;
+
+    if replaced {
+        println!("{}", tr!("의존성 {} 변경", "Updated dependency {}", name));
+    } else {
+        println!("{}", tr!("의존성 {} 추가", "Added dependency {}", name));
+    }
+    println!(
+        "{}",
+        tr!(
+            "'cbuild dependencies'로 설치하세요.",
+            "Run 'cbuild dependencies' to install it."
+        )
+    );
+    Ok(())
+}
+
+/// `cbuild dependencies remove`: cbuild.toml의 [dependencies]나 [dev-dependencies]에서 의존성 삭제
+///
+/// 이미 설치된 deps/<이름>은 그대로 둔다.
+pub fn remove_dependency(project_dir: &Path, name: &str) -> BuildResult<()> {
+    let mut editor = ConfigEditor::open(project_dir)?;
+    let removed = editor.remove_dependency(DEPENDENCIES, name);
+    if !(editor.remove_dependency(DEV_DEPENDENCIES, name) || removed) {
+        return Err(BuildError::DependencyError(tr!(
+            "의존성 {}이(가) 설정에 없습니다",
+            "dependency {} is not in the configuration",
+            name
+        )));
+    }
+    editor.save()?;
+
+    println!("{}", tr!("의존성 {} 삭제", "Removed dependency {}", name));
+    Ok(())
+}
 
 pub struct DependencyManager {
-    project_dir: PathBuf,
     deps_dir: PathBuf,
-    config: Option<BuildConfig>,
+    context: Arc<ProjectContext>,
+    /// 테스트 타겟 전용 의존성도 설치/업데이트 (기본: true)
+    include_dev: bool,
 }
 
 impl DependencyManager {
     pub fn new(project_dir: &Path) -> Self {
-        let deps_dir = project_dir.join("deps");
+        Self::with_context(Arc::new(ProjectContext::new(project_dir)))
+    }
+
+    /// 빌드 단계와 설정을 공유하는 의존성 관리자 생성
+    pub fn with_context(context: Arc<ProjectContext>) -> Self {
+        let deps_dir = context.project_dir().join("deps");
 
         DependencyManager {
-            project_dir: project_dir.to_path_buf(),
             deps_dir,
-            config: None,
+            context,
+            include_dev: true,
         }
     }
 
+    /// [dev-dependencies]와 `scope = "test"` 의존성 포함 여부 (`--no-dev`면 false)
+    pub fn set_include_dev(&mut self, include_dev: bool) {
+        self.include_dev = include_dev;
+    }
+
+    /// 설치/업데이트할 의존성 (이름 순)
+    fn selected_dependencies<'a>(
+        &self,
+        config: &'a BuildConfig,
+    ) -> Vec<(&'a String, &'a Dependency)> {
+        config
+            .all_dependencies()
+            .filter(|(name, _)| self.include_dev || !config.is_dev_dependency(name))
+            .collect()
+    }
+
     pub fn install(&mut self) -> BuildResult<()> {
         self.load_config()?;
 
-        let config = self.config.as_ref().unwrap();
-        if config.dependencies.is_empty() {
+        let config = self.context.loaded_config().unwrap();
+        let dependencies = self.selected_dependencies(config);
+        if dependencies.is_empty() {
             println!("No dependencies to install.");
-            return Ok(());
+            return self.write_lockfile(&Resolutions::default());
         }
 
         println!("Installing dependencies...");
 
         // 의존성 디렉토리 생성
         if !self.deps_dir.exists() {
-            std::fs::create_dir_all(&self.deps_dir).map_err(|e| BuildError::IoError(e))?;
+            std::fs::create_dir_all(&self.deps_dir).map_err(BuildError::IoError)?;
         }
 
-        for (name, dep) in &config.dependencies {
+        let locked = Lockfile::load(self.context.project_dir())?.unwrap_or_default();
+        let mut resolutions = Resolutions::default();
+
+        for (name, dep) in dependencies {
             println!("Processing dependency: {}", name);
 
             let dep_dir = self.deps_dir.join(name);
@@ -52,12 +147,24 @@ impl DependencyManager {
             }
 
             if let Some(ref git) = dep.git {
-                self.install_git_dependency(name, git, &dep.branch, &dep.tag, &dep_dir)?;
+                let tag = self.resolve_version_tag(name, dep, locked.find(name))?;
+                let tag = tag.or_else(|| dep.tag.clone());
+                self.install_git_dependency(name, git, &dep.branch, &tag, &dep_dir)?;
+                resolutions.tags.extend(tag.map(|tag| (name.clone(), tag)));
+            } else if let Some(ref registry) = dep.registry {
+                let release =
+                    self.resolve_registry_release(name, registry, dep, locked.find(name))?;
+                let sha256 = Some(release.sha256.clone());
+                self.install_archive_dependency(name, &release.url, &sha256, &dep_dir)?;
+                resolutions.releases.insert(name.clone(), release);
+            } else if let Some(ref url) = dep.url {
+                self.install_archive_dependency(name, url, &dep.sha256, &dep_dir)?;
             } else {
                 warn!("Dependency {} has no source specified, skipping", name);
             }
         }
 
+        self.write_lockfile(&resolutions)?;
         println!("Dependencies installed successfully.");
         Ok(())
     }
@@ -65,23 +172,50 @@ impl DependencyManager {
     pub fn update(&mut self) -> BuildResult<()> {
         self.load_config()?;
 
-        let config = self.config.as_ref().unwrap();
-        if config.dependencies.is_empty() {
+        let config = self.context.loaded_config().unwrap();
+        let dependencies = self.selected_dependencies(config);
+        if dependencies.is_empty() {
             println!("No dependencies to update.");
-            return Ok(());
+            return self.write_lockfile(&Resolutions::default());
         }
 
         println!("Updating dependencies...");
 
-        for (name, dep) in &config.dependencies {
+        let mut resolutions = Resolutions::default();
+        for (name, dep) in dependencies {
             println!("Updating dependency: {}", name);
 
             let dep_dir = self.deps_dir.join(name);
+            // 업데이트는 잠금 파일을 쓰지 않고 조건에 맞는 가장 높은 태그나 버전을 다시 찾음
+            let tag = match dep.git {
+                Some(_) => self.resolve_version_tag(name, dep, None)?,
+                None => None,
+            };
+            let tag = tag.or_else(|| dep.tag.clone());
+            resolutions
+                .tags
+                .extend(tag.clone().map(|tag| (name.clone(), tag)));
+
+            let release = match dep.registry {
+                Some(ref registry) if dep.git.is_none() => {
+                    Some(self.resolve_registry_release(name, registry, dep, None)?)
+                }
+                _ => None,
+            };
+            let archive = match release {
+                Some(ref release) => Some((release.url.clone(), Some(release.sha256.clone()))),
+                None => dep.url.clone().map(|url| (url, dep.sha256.clone())),
+            };
+            resolutions
+                .releases
+                .extend(release.map(|release| (name.clone(), release)));
 
             if !dep_dir.exists() {
                 info!("Dependency {} not installed, installing fresh copy", name);
                 if let Some(ref git) = dep.git {
-                    self.install_git_dependency(name, git, &dep.branch, &dep.tag, &dep_dir)?;
+                    self.install_git_dependency(name, git, &dep.branch, &tag, &dep_dir)?;
+                } else if let Some((ref url, ref sha256)) = archive {
+                    self.install_archive_dependency(name, url, sha256, &dep_dir)?;
                 } else {
                     warn!("Dependency {} has no source specified, skipping", name);
                 }
@@ -89,12 +223,23 @@ impl DependencyManager {
             }
 
             if let Some(ref git) = dep.git {
-                self.update_git_dependency(name, git, &dep.branch, &dep.tag, &dep_dir)?;
+                self.update_git_dependency(name, git, &dep.branch, &tag, &dep_dir)?;
+            } else if let Some((ref url, ref sha256)) = archive {
+                // 체크섬이 바뀐 경우에만 다시 내려받음
+                let installed =
+                    std::fs::read_to_string(dep_dir.join(ARCHIVE_MARKER)).unwrap_or_default();
+                if sha256.as_deref().map(str::to_lowercase) != Some(installed.trim().to_string()) {
+                    std::fs::remove_dir_all(&dep_dir).map_err(BuildError::IoError)?;
+                    self.install_archive_dependency(name, url, sha256, &dep_dir)?;
+                } else {
+                    info!("Dependency {} is up to date", name);
+                }
             } else {
                 warn!("Dependency {} has no source specified, skipping", name);
             }
         }
 
+        self.write_lockfile(&resolutions)?;
         println!("Dependencies updated successfully.");
         Ok(())
     }
@@ -120,15 +265,64 @@ impl DependencyManager {
             cmd.arg("--branch").arg(tag);
         }
 
-        let output = cmd.output().map_err(|e| BuildError::IoError(e))?;
+        self.retry_policy()
+            .run(&format!("git clone {}", git_url), || {
+                let output = cmd.output().map_err(|e| e.to_string())?;
+                if !output.status.success() {
+                    // 실패한 clone이 남긴 디렉토리는 다음 시도 전에 지움
+                    if dep_dir.exists() {
+                        let _ = std::fs::remove_dir_all(dep_dir);
+                    }
+                    return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+                }
+                Ok(())
+            })
+            .map_err(|error| {
+                BuildError::DependencyError(format!(
+                    "Failed to clone git repository for {}: {}",
+                    name, error
+                ))
+            })?;
 
-        if !output.status.success() {
-            let error = String::from_utf8_lossy(&output.stderr);
-            return Err(BuildError::DependencyError(format!(
-                "Failed to clone git repository for {}: {}",
-                name, error
-            )));
-        }
+        println!("Dependency {} installed successfully", name);
+        Ok(())
+    }
+
+    /// 압축 파일을 내려받아 체크섬을 확인하고 deps/<name>에 풀기
+    fn install_archive_dependency(
+        &self,
+        name: &str,
+        url: &str,
+        sha256: &Option<String>,
+        dep_dir: &Path,
+    ) -> BuildResult<()> {
+        let sha256 = sha256.as_deref().ok_or_else(|| {
+            BuildError::DependencyError(format!(
+                "Dependency {} downloads {} but has no sha256",
+                name, url
+            ))
+        })?;
+
+        let config = self.context.loaded_config().unwrap();
+        let downloader = Downloader::from_config(config);
+        let archive = downloader.cache_path(
+            &self.deps_dir.join(".downloads"),
+            &format!("{}-{}", name, file_name_from_url(url)),
+        );
+        downloader.fetch(url, sha256, &archive)?;
+
+        let staging = tempfile::Builder::new()
+            .prefix(&format!(".{}-", name))
+            .tempdir_in(&self.deps_dir)
+            .map_err(BuildError::IoError)?;
+        extract(&archive, staging.path())?;
+
+        // 최상위 디렉토리 하나로 된 압축 파일은 그 디렉토리를 의존성 루트로 사용
+        let contents =
+            single_top_level_dir(staging.path())?.unwrap_or_else(|| staging.path().to_path_buf());
+        std::fs::rename(&contents, dep_dir).map_err(BuildError::IoError)?;
+        std::fs::write(dep_dir.join(ARCHIVE_MARKER), sha256.to_lowercase())
+            .map_err(BuildError::IoError)?;
 
         println!("Dependency {} installed successfully", name);
         Ok(())
@@ -151,7 +345,7 @@ impl DependencyManager {
             .arg("get-url")
             .arg("origin");
 
-        let output = cmd.output().map_err(|e| BuildError::IoError(e))?;
+        let output = cmd.output().map_err(BuildError::IoError)?;
 
         if !output.status.success() {
             let error = String::from_utf8_lossy(&output.stderr);
@@ -177,7 +371,7 @@ impl DependencyManager {
                 .arg("origin")
                 .arg(git_url);
 
-            let output = cmd.output().map_err(|e| BuildError::IoError(e))?;
+            let output = cmd.output().map_err(BuildError::IoError)?;
 
             if !output.status.success() {
                 let error = String::from_utf8_lossy(&output.stderr);
@@ -190,17 +384,25 @@ impl DependencyManager {
 
         // 변경사항 가져오기
         let mut cmd = Command::new("git");
-        cmd.current_dir(dep_dir).arg("fetch").arg("--all");
-
-        let output = cmd.output().map_err(|e| BuildError::IoError(e))?;
-
-        if !output.status.success() {
-            let error = String::from_utf8_lossy(&output.stderr);
-            return Err(BuildError::DependencyError(format!(
-                "Failed to fetch updates for {}: {}",
-                name, error
-            )));
-        }
+        cmd.current_dir(dep_dir)
+            .arg("fetch")
+            .arg("--all")
+            .arg("--tags");
+
+        self.retry_policy()
+            .run(&format!("git fetch {}", name), || {
+                let output = cmd.output().map_err(|e| e.to_string())?;
+                if !output.status.success() {
+                    return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+                }
+                Ok(())
+            })
+            .map_err(|error| {
+                BuildError::DependencyError(format!(
+                    "Failed to fetch updates for {}: {}",
+                    name, error
+                ))
+            })?;
 
         // 브랜치나 태그로 체크아웃
         let checkout_target = if let Some(ref tag) = tag {
@@ -216,7 +418,7 @@ impl DependencyManager {
             .arg("checkout")
             .arg(&checkout_target);
 
-        let output = cmd.output().map_err(|e| BuildError::IoError(e))?;
+        let output = cmd.output().map_err(BuildError::IoError)?;
 
         if !output.status.success() {
             let error = String::from_utf8_lossy(&output.stderr);
@@ -248,7 +450,7 @@ impl DependencyManager {
             .arg("--hard")
             .arg("HEAD");
 
-        let output = cmd.output().map_err(|e| BuildError::IoError(e))?;
+        let output = cmd.output().map_err(BuildError::IoError)?;
 
         if !output.status.success() {
             let error = String::from_utf8_lossy(&output.stderr);
@@ -262,10 +464,171 @@ impl DependencyManager {
         Ok(())
     }
 
-    fn load_config(&mut self) -> BuildResult<()> {
-        if self.config.is_none() {
-            self.config = Some(BuildConfig::from_file(&self.project_dir)?);
+    /// cbuild.toml의 [retry] 설정 (`--no-retry`면 한 번만 시도)
+    fn retry_policy(&self) -> RetryPolicy {
+        let settings = self
+            .context
+            .loaded_config()
+            .map(|config| config.retry.clone())
+            .unwrap_or_default();
+        RetryPolicy::from_settings(&settings)
+    }
+
+    /// 설치 결과와 합친 기능, 버전 조건으로 고른 태그와 레지스트리 버전을 cbuild.lock에 기록
+    fn write_lockfile(&self, resolutions: &Resolutions) -> BuildResult<()> {
+        let config = self.context.loaded_config().unwrap();
+        let features = self.context.dependency_features()?;
+        lockfile::write_lockfile(self.context.project_dir(), config, features, resolutions)
+    }
+
+    /// 레지스트리 색인에서 version 조건에 맞는 버전 선택
+    ///
+    /// 잠금 파일에 같은 조건과 레지스트리로 고른 버전이 있으면 그 버전을 쓴다.
+    fn resolve_registry_release(
+        &self,
+        name: &str,
+        registry: &str,
+        dependency: &Dependency,
+        locked: Option<&LockedDependency>,
+    ) -> BuildResult<RegistryRelease> {
+        let config = self.context.loaded_config().unwrap();
+        let package = registry::fetch_package(&Downloader::from_config(config), registry, name)?;
+
+        let source = format!("registry+{}", registry);
+        let locked_version = locked
+            .filter(|locked| locked.version == dependency.version && locked.source == source)
+            .and_then(|locked| locked.resolved.as_deref());
+        let release = registry::select_release(&package, &dependency.version, locked_version)?;
+
+        let mut details: Vec<&str> = Vec::new();
+        details.extend(release.license.as_deref());
+        details.extend(release.description.as_deref());
+        if details.is_empty() {
+            println!("{} {} -> {}", name, dependency.version, release.version);
+        } else {
+            println!(
+                "{} {} -> {} ({})",
+                name,
+                dependency.version,
+                release.version,
+                details.join(", ")
+            );
+        }
+        if release.yanked {
+            warn!(
+                "{} {} was yanked from the registry (kept because it is locked)",
+                name, release.version
+            );
+        }
+        Ok(release)
+    }
+
+    /// git 의존성의 version이 버전 조건(`^1.2` 등)이면 체크아웃할 태그 결정
+    ///
+    /// tag나 branch를 지정했거나 version이 `1.2.3`처럼 조건이 아니면 None.
+    /// 잠금 파일에 같은 조건으로 고른 태그가 있으면 원격 저장소를 조회하지 않고 그대로 쓴다.
+    fn resolve_version_tag(
+        &self,
+        name: &str,
+        dependency: &Dependency,
+        locked: Option<&LockedDependency>,
+    ) -> BuildResult<Option<String>> {
+        let Some(ref git_url) = dependency.git else {
+            return Ok(None);
+        };
+        if dependency.tag.is_some()
+            || dependency.branch.is_some()
+            || !VersionReq::is_requirement(&dependency.version)
+        {
+            return Ok(None);
+        }
+        let requirement = VersionReq::parse(&dependency.version).ok_or_else(|| {
+            BuildError::DependencyError(tr!(
+                "의존성 {}의 버전 조건 '{}'을(를) 해석할 수 없습니다 (예: \"^1.2\", \"~1.2.3\", \">=1.0, <2\")",
+                "dependency {} has an invalid version requirement '{}' (e.g. \"^1.2\", \"~1.2.3\", \">=1.0, <2\")",
+                name,
+                dependency.version
+            ))
+        })?;
+
+        if let Some(tag) = locked
+            .filter(|locked| locked.version == dependency.version)
+            .and_then(|locked| locked.tag.as_ref())
+            .filter(|tag| Version::from_tag(tag).is_some_and(|v| requirement.matches(&v)))
+        {
+            info!("Using locked tag {} for {}", tag, name);
+            return Ok(Some(tag.clone()));
+        }
+
+        let output = self
+            .retry_policy()
+            .run(&format!("git ls-remote {}", git_url), || {
+                let output = Command::new("git")
+                    .args(["ls-remote", "--tags", "--refs", git_url])
+                    .output()
+                    .map_err(|e| e.to_string())?;
+                if !output.status.success() {
+                    return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+                }
+                Ok(String::from_utf8_lossy(&output.stdout).to_string())
+            })
+            .map_err(|error| {
+                BuildError::DependencyError(tr!(
+                    "{}의 태그 목록을 가져올 수 없습니다: {}",
+                    "cannot list tags of {}: {}",
+                    name,
+                    error
+                ))
+            })?;
+
+        let mut versions: Vec<(Version, String)> = output
+            .lines()
+            .filter_map(|line| line.split('\t').nth(1)?.strip_prefix("refs/tags/"))
+            .filter_map(|tag| Some((Version::from_tag(tag)?, tag.to_string())))
+            .collect();
+        versions.sort();
+
+        match versions.iter().rev().find(|(v, _)| requirement.matches(v)) {
+            Some((version, tag)) => {
+                println!(
+                    "{}",
+                    tr!(
+                        "{} {} -> 태그 {} ({})",
+                        "{} {} -> tag {} ({})",
+                        name,
+                        dependency.version,
+                        tag,
+                        version
+                    )
+                );
+                Ok(Some(tag.clone()))
+            }
+            None => {
+                let available: Vec<&str> = versions
+                    .iter()
+                    .rev()
+                    .take(5)
+                    .map(|(_, tag)| tag.as_str())
+                    .collect();
+                let available = if available.is_empty() {
+                    tr!("버전 태그 없음", "no version tags")
+                } else {
+                    available.join(", ")
+                };
+                Err(BuildError::DependencyError(tr!(
+                    "의존성 {}: '{}'을(를) 만족하는 태그가 {}에 없습니다 (최근 태그: {})",
+                    "dependency {}: no tag satisfies '{}' in {} (latest tags: {})",
+                    name,
+                    dependency.version,
+                    git_url,
+                    available
+                )))
+            }
         }
+    }
+
+    fn load_config(&self) -> BuildResult<()> {
+        self.context.config()?;
         Ok(())
     }
 
@@ -274,7 +637,7 @@ impl DependencyManager {
 
         let mut include_paths = Vec::new();
 
-        for dep_name in self.config.as_ref().unwrap().dependencies.keys() {
+        for dep_name in self.context.loaded_config().unwrap().dependencies.keys() {
             let dep_dir = self.deps_dir.join(dep_name);
 
             if !dep_dir.exists() {
@@ -299,14 +662,13 @@ impl DependencyManager {
                     let dep_dir_ref = dep_dir.clone();
                     for entry in std::fs::read_dir(&lib_dir)
                         .unwrap_or_else(|_| std::fs::read_dir(&dep_dir_ref).unwrap())
+                        .flatten()
                     {
-                        if let Ok(entry) = entry {
-                            let entry_path = entry.path();
-                            if entry_path.is_dir() {
-                                let include_dir = entry_path.join("include");
-                                if include_dir.exists() && include_dir.is_dir() {
-                                    include_paths.push(include_dir);
-                                }
+                        let entry_path = entry.path();
+                        if entry_path.is_dir() {
+                            let include_dir = entry_path.join("include");
+                            if include_dir.exists() && include_dir.is_dir() {
+                                include_paths.push(include_dir);
                             }
                         }
                     }
@@ -322,7 +684,7 @@ impl DependencyManager {
 
         let mut lib_paths = Vec::new();
 
-        for dep_name in self.config.as_ref().unwrap().dependencies.keys() {
+        for dep_name in self.context.loaded_config().unwrap().dependencies.keys() {
             let dep_dir = self.deps_dir.join(dep_name);
 
             if !dep_dir.exists() {
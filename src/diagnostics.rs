@@ -0,0 +1,328 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// 컴파일러 진단 심각도
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+}
+
+/// GCC/Clang 형식(`file:line:col: severity: message`)에서 파싱한 진단
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub file: PathBuf,
+    pub line: Option<u32>,
+    pub column: Option<u32>,
+    pub severity: Severity,
+    pub message: String,
+}
+
+/// 진단을 CI 시스템이 인식하는 형식으로 다시 출력하는 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnnotationFormat {
+    #[default]
+    None,
+    /// GitHub Actions 워크플로 명령 (`::error file=...::message`)
+    Github,
+}
+
+impl AnnotationFormat {
+    /// GitHub Actions에서 실행 중이면 자동으로 github 형식 사용
+    pub fn from_env() -> Self {
+        if std::env::var("GITHUB_ACTIONS").is_ok_and(|v| v == "true") {
+            AnnotationFormat::Github
+        } else {
+            AnnotationFormat::None
+        }
+    }
+}
+
+impl std::str::FromStr for AnnotationFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "github" => Ok(AnnotationFormat::Github),
+            "none" => Ok(AnnotationFormat::None),
+            _ => Err(format!("github 또는 none이어야 합니다: {}", s)),
+        }
+    }
+}
+
+/// 컴파일러 stderr 출력에서 진단 목록 추출
+///
+/// 진단 형식이 아닌 줄(소스 인용, `In function` 등)은 무시한다.
+/// 색상 진단(`-fdiagnostics-color=always`)의 ANSI escape 코드는 제거하고 해석한다.
+pub fn parse_compiler_output(output: &str) -> Vec<Diagnostic> {
+    lazy_static! {
+        static ref DIAGNOSTIC_RE: Regex =
+            Regex::new(r"^(.+?):(\d+):(?:(\d+):)?\s*(fatal error|error|warning|note):\s*(.*)$")
+                .unwrap();
+    }
+
+    strip_ansi(output)
+        .lines()
+        .filter_map(|line| {
+            let cap = DIAGNOSTIC_RE.captures(line)?;
+            let severity = match &cap[4] {
+                "warning" => Severity::Warning,
+                "note" => Severity::Note,
+                _ => Severity::Error,
+            };
+
+            Some(Diagnostic {
+                file: PathBuf::from(&cap[1]),
+                line: cap[2].parse().ok(),
+                column: cap.get(3).and_then(|c| c.as_str().parse().ok()),
+                severity,
+                message: cap[5].to_string(),
+            })
+        })
+        .collect()
+}
+
+/// 경고 메시지 끝의 경고 옵션 이름 (`... [-Wunused-variable]` → `unused-variable`)
+pub fn warning_option(message: &str) -> Option<String> {
+    lazy_static! {
+        static ref OPTION_RE: Regex = Regex::new(r"\[-W(?:error=)?([\w+\-=]+?)=?\]$").unwrap();
+    }
+
+    OPTION_RE
+        .captures(message.trim_end())
+        .map(|cap| cap[1].to_string())
+}
+
+/// 중복을 제거한 진단과 같은 진단이 나온 번역 단위 수
+pub struct WarningEntry {
+    pub diagnostic: Diagnostic,
+    pub occurrences: usize,
+}
+
+/// 중복 판단 기준: (오류 여부, 파일, 줄, 열, 메시지)
+type DiagnosticKey = (bool, PathBuf, Option<u32>, Option<u32>, String);
+
+/// 여러 번역 단위의 컴파일러 출력에서 진단을 모아 중복 제거 (note 제외)
+///
+/// 공유 헤더의 경고는 헤더를 포함하는 번역 단위마다 반복되므로
+/// 심각도, 파일, 위치, 메시지가 같은 진단은 하나로 합친다.
+fn unique_diagnostics<'a>(outputs: impl Iterator<Item = &'a str>) -> Vec<WarningEntry> {
+    let mut unique: BTreeMap<DiagnosticKey, WarningEntry> = BTreeMap::new();
+
+    for output in outputs {
+        for diagnostic in parse_compiler_output(output) {
+            if diagnostic.severity == Severity::Note {
+                continue;
+            }
+            let key = (
+                diagnostic.severity == Severity::Error,
+                diagnostic.file.clone(),
+                diagnostic.line,
+                diagnostic.column,
+                diagnostic.message.clone(),
+            );
+            unique
+                .entry(key)
+                .or_insert(WarningEntry {
+                    diagnostic,
+                    occurrences: 0,
+                })
+                .occurrences += 1;
+        }
+    }
+
+    unique.into_values().collect()
+}
+
+/// 여러 번역 단위의 컴파일러 출력에서 경고를 모아 중복 제거
+pub fn collect_warnings<'a>(outputs: impl Iterator<Item = &'a str>) -> Vec<WarningEntry> {
+    unique_diagnostics(outputs)
+        .into_iter()
+        .filter(|entry| entry.diagnostic.severity == Severity::Warning)
+        .collect()
+}
+
+/// 터미널 색상/서식 escape 코드 제거
+pub fn strip_ansi(text: &str) -> String {
+    lazy_static! {
+        static ref ANSI_RE: Regex = Regex::new(r"\x1b\[[0-9;]*[A-Za-z]").unwrap();
+    }
+
+    ANSI_RE.replace_all(text, "").to_string()
+}
+
+/// 컴파일러 출력의 오류와 경고를 지정한 형식의 주석으로 출력
+///
+/// 여러 번역 단위의 출력은 경고 요약과 같은 기준으로 중복을 제거해 주석 수 제한을
+/// 아낀다.
+pub fn emit_annotations<'a>(
+    format: AnnotationFormat,
+    outputs: impl Iterator<Item = &'a str>,
+    base_dir: &Path,
+) {
+    if format == AnnotationFormat::None {
+        return;
+    }
+
+    for entry in unique_diagnostics(outputs) {
+        emit_annotation(format, &entry.diagnostic, base_dir);
+    }
+}
+
+/// 진단 하나를 주석으로 출력 (note는 출력하지 않음)
+pub fn emit_annotation(format: AnnotationFormat, diagnostic: &Diagnostic, base_dir: &Path) {
+    let command = match diagnostic.severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Note => return,
+    };
+
+    match format {
+        AnnotationFormat::Github => {
+            let file = annotation_path(&diagnostic.file, base_dir);
+            let mut properties = format!("file={}", escape_property(&file));
+            if let Some(line) = diagnostic.line {
+                properties.push_str(&format!(",line={}", line));
+            }
+            if let Some(column) = diagnostic.column {
+                properties.push_str(&format!(",col={}", column));
+            }
+
+            println!(
+                "::{} {}::{}",
+                command,
+                properties,
+                escape_data(&diagnostic.message)
+            );
+        }
+        AnnotationFormat::None => {}
+    }
+}
+
+/// 파일 위치가 없는 오류 주석 출력 (예: 테스트 실패)
+pub fn emit_error(format: AnnotationFormat, title: &str, message: &str) {
+    if format == AnnotationFormat::Github {
+        println!(
+            "::error title={}::{}",
+            escape_property(title),
+            escape_data(message)
+        );
+    }
+}
+
+/// GitHub는 저장소 루트 기준 경로를 요구하므로 GITHUB_WORKSPACE 기준으로 변환
+fn annotation_path(file: &Path, base_dir: &Path) -> String {
+    let absolute = if file.is_absolute() {
+        file.to_path_buf()
+    } else {
+        base_dir.join(file)
+    };
+
+    let root = std::env::var_os("GITHUB_WORKSPACE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| base_dir.to_path_buf());
+
+    absolute
+        .strip_prefix(&root)
+        .unwrap_or(&absolute)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // gcc -Wall -c a.c / b.c (두 소스 모두 util.h 포함)
+    const A_OUTPUT: &str = "\
+In file included from a.c:1:
+util.h: In function 'helper':
+util.h:1:32: warning: unused variable 'unused' [-Wunused-variable]
+    1 | static int helper(int x) { int unused; return x; }
+      |                                ^~~~~~
+";
+    const B_OUTPUT: &str = "\
+In file included from b.c:1:
+util.h: In function 'helper':
+util.h:1:32: warning: unused variable 'unused' [-Wunused-variable]
+    1 | static int helper(int x) { int unused; return x; }
+      |                                ^~~~~~
+b.c: In function 'b':
+b.c:2:19: warning: unused variable 'y' [-Wunused-variable]
+    2 | int b(void) { int y; return helper(2); }
+      |                   ^
+";
+    // gcc -Wall -Werror=unused-variable -c b.c
+    const B_WERROR_OUTPUT: &str = "\
+In file included from b.c:1:
+util.h: In function 'helper':
+util.h:1:32: error: unused variable 'unused' [-Werror=unused-variable]
+    1 | static int helper(int x) { int unused; return x; }
+      |                                ^~~~~~
+cc1: some warnings being treated as errors
+";
+
+    #[test]
+    fn header_warnings_are_merged_across_translation_units() {
+        let entries = unique_diagnostics([A_OUTPUT, B_OUTPUT].into_iter());
+        let summary: Vec<_> = entries
+            .iter()
+            .map(|e| {
+                (
+                    e.diagnostic.file.to_string_lossy().to_string(),
+                    e.diagnostic.line,
+                    e.occurrences,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("b.c".to_string(), Some(2), 1),
+                ("util.h".to_string(), Some(1), 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn errors_are_kept_apart_from_warnings() {
+        let entries = unique_diagnostics([A_OUTPUT, B_WERROR_OUTPUT].into_iter());
+        assert_eq!(entries.len(), 2);
+        assert!(entries.iter().all(|e| e.occurrences == 1));
+
+        let warnings = collect_warnings([A_OUTPUT, B_WERROR_OUTPUT].into_iter());
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].diagnostic.severity, Severity::Warning);
+    }
+
+    #[test]
+    fn warning_option_names() {
+        assert_eq!(
+            warning_option("unused variable 'y' [-Wunused-variable]").as_deref(),
+            Some("unused-variable")
+        );
+        assert_eq!(
+            warning_option("unused variable 'y' [-Werror=unused-variable]").as_deref(),
+            Some("unused-variable")
+        );
+        assert_eq!(
+            warning_option("format '%d' expects argument of type 'int' [-Wformat=]").as_deref(),
+            Some("format")
+        );
+        assert_eq!(warning_option("'x' is used uninitialized"), None);
+    }
+}
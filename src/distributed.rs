@@ -0,0 +1,254 @@
+use crate::config::DistributedSettings;
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use crate::utils::is_tool_installed;
+use colored::Colorize;
+use std::collections::BTreeMap;
+use std::process::{Command, Output};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// distcc 호스트에 슬롯 수를 지정하지 않았을 때의 동시 작업 수 (distcc 기본값과 같음)
+const DEFAULT_HOST_SLOTS: usize = 4;
+
+/// 연속으로 이만큼 실패한 호스트에는 이번 빌드에서 더 보내지 않음
+const MAX_CONSECUTIVE_FAILURES: usize = 3;
+
+/// 분산 컴파일 도구
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tool {
+    Distcc,
+    Icecc,
+}
+
+impl Tool {
+    fn program(self) -> &'static str {
+        match self {
+            Tool::Distcc => "distcc",
+            Tool::Icecc => "icecc",
+        }
+    }
+}
+
+/// 컴파일을 보낼 호스트 (icecc나 호스트 목록이 없는 distcc는 도구 이름의 호스트 하나)
+struct Host {
+    name: String,
+    /// distcc에 넘길 호스트 지정 (DISTCC_HOSTS), 없으면 도구 설정을 그대로 사용
+    spec: Option<String>,
+    slots: usize,
+}
+
+#[derive(Default)]
+struct HostStats {
+    compiled: usize,
+    fallbacks: usize,
+    time: Duration,
+}
+
+/// 호스트별 동시 작업 수와 통계
+struct State {
+    active: Vec<usize>,
+    /// 호스트별 연속 원격 실패 수
+    failures: Vec<usize>,
+    stats: BTreeMap<String, HostStats>,
+}
+
+/// 컴파일 명령을 distcc/icecc로 원격 호스트에 보내는 분산 컴파일 설정
+///
+/// 호스트 슬롯이 남아 있으면 명령 앞에 도구를 붙여 원격으로 보내고, 슬롯이 모두 차
+/// 있으면 로컬에서 컴파일한다. 원격 컴파일이 도구 자체의 이유(연결 실패 등)로 실패하면
+/// 같은 파일을 로컬에서 다시 컴파일한다.
+pub struct Distributor {
+    tool: Tool,
+    hosts: Vec<Host>,
+    scheduler: Option<String>,
+    state: Mutex<State>,
+}
+
+/// 원격으로 보낸 컴파일 (끝나면 `Distributor::finish`로 반환)
+pub struct RemoteSlot {
+    host: usize,
+    started: Instant,
+}
+
+impl Distributor {
+    /// [distributed] 설정으로 생성 (`local_jobs`는 호스트 목록이 없을 때 원격 슬롯 수 기본값)
+    pub fn from_config(settings: &DistributedSettings, local_jobs: usize) -> BuildResult<Self> {
+        let tool = match settings.tool.as_deref().unwrap_or("distcc") {
+            "distcc" => Tool::Distcc,
+            "icecc" => Tool::Icecc,
+            other => {
+                return Err(BuildError::ConfigParsingError(tr!(
+                    "[distributed] tool은 distcc 또는 icecc여야 합니다: {}",
+                    "[distributed] tool must be distcc or icecc: {}",
+                    other
+                )))
+            }
+        };
+        if !is_tool_installed(tool.program()) {
+            return Err(BuildError::ToolchainError(tr!(
+                "분산 컴파일에 필요한 {}을(를) 찾을 수 없습니다",
+                "{} is required for distributed compilation but was not found",
+                tool.program()
+            )));
+        }
+
+        let hosts: Vec<Host> = match settings.hosts {
+            Some(ref hosts) if tool == Tool::Distcc && !hosts.is_empty() => {
+                hosts.iter().map(|spec| parse_host(spec)).collect()
+            }
+            _ => vec![Host {
+                name: settings
+                    .scheduler
+                    .clone()
+                    .unwrap_or_else(|| tool.program().to_string()),
+                spec: None,
+                slots: settings.remote_jobs.unwrap_or(local_jobs * 2).max(1),
+            }],
+        };
+
+        Ok(Distributor {
+            tool,
+            state: Mutex::new(State {
+                active: vec![0; hosts.len()],
+                failures: vec![0; hosts.len()],
+                stats: BTreeMap::new(),
+            }),
+            hosts,
+            scheduler: settings.scheduler.clone(),
+        })
+    }
+
+    /// 모든 호스트의 원격 슬롯 수 (로컬 작업 수에 더해 동시 작업 수를 늘림)
+    pub fn remote_slots(&self) -> usize {
+        self.hosts.iter().map(|host| host.slots).sum()
+    }
+
+    /// 도구와 호스트별 슬롯 수 (예: "distcc (build1/8, build2/4)")
+    pub fn describe(&self) -> String {
+        let hosts = self
+            .hosts
+            .iter()
+            .map(|host| format!("{}/{}", host.name, host.slots))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("{} ({})", self.tool.program(), hosts)
+    }
+
+    /// 여유 슬롯이 가장 많은 호스트의 슬롯 확보 (모두 차 있으면 None, 로컬에서 컴파일)
+    ///
+    /// 연속으로 실패한 호스트는 응답하지 않는 것으로 보고 건너뛴다.
+    pub fn acquire(&self) -> Option<RemoteSlot> {
+        let mut state = self.state.lock().unwrap();
+        let host = (0..self.hosts.len())
+            .filter(|&index| state.active[index] < self.hosts[index].slots)
+            .filter(|&index| state.failures[index] < MAX_CONSECUTIVE_FAILURES)
+            .max_by_key(|&index| self.hosts[index].slots - state.active[index])?;
+        state.active[host] += 1;
+        Some(RemoteSlot {
+            host,
+            started: Instant::now(),
+        })
+    }
+
+    /// `cmd`를 `slot`의 호스트로 보내는 명령 (원래 명령은 로컬 재시도에 사용)
+    pub fn command(&self, slot: &RemoteSlot, cmd: &Command) -> Command {
+        let mut remote = Command::new(self.tool.program());
+        remote.arg(cmd.get_program()).args(cmd.get_args());
+        for (key, value) in cmd.get_envs() {
+            match value {
+                Some(value) => remote.env(key, value),
+                None => remote.env_remove(key),
+            };
+        }
+        if let Some(dir) = cmd.get_current_dir() {
+            remote.current_dir(dir);
+        }
+
+        match self.tool {
+            Tool::Distcc => {
+                // 실패를 직접 확인하고 로컬에서 다시 컴파일하도록 distcc의 자체 대체 끔
+                remote.env("DISTCC_FALLBACK", "0");
+                if let Some(ref spec) = self.hosts[slot.host].spec {
+                    remote.env("DISTCC_HOSTS", spec);
+                }
+            }
+            Tool::Icecc => {
+                if let Some(ref scheduler) = self.scheduler {
+                    remote.env("USE_SCHEDULER", scheduler);
+                }
+            }
+        }
+        remote
+    }
+
+    /// 원격 컴파일 결과 기록, 로컬에서 다시 컴파일해야 하면 true
+    ///
+    /// 컴파일러는 오류에 1(충돌은 4)을 반환하고 distcc/icecc는 자체 실패에 100 이상을
+    /// 반환하므로, 실행하지 못했거나 100 이상이면 원격 실패로 본다.
+    pub fn finish(&self, slot: RemoteSlot, output: &std::io::Result<Output>) -> bool {
+        let remote_failed = match output {
+            Ok(output) => output.status.code().is_none_or(|code| code >= 100),
+            Err(_) => true,
+        };
+
+        let mut state = self.state.lock().unwrap();
+        state.active[slot.host] -= 1;
+        state.failures[slot.host] = if remote_failed {
+            state.failures[slot.host] + 1
+        } else {
+            0
+        };
+        let stats = state
+            .stats
+            .entry(self.hosts[slot.host].name.clone())
+            .or_default();
+        stats.time += slot.started.elapsed();
+        if remote_failed {
+            stats.fallbacks += 1;
+        } else {
+            stats.compiled += 1;
+        }
+        remote_failed
+    }
+
+    /// 호스트별 원격 컴파일 수, 로컬 대체 수, 평균 시간 출력
+    pub fn print_stats(&self) {
+        let state = self.state.lock().unwrap();
+        if state.stats.is_empty() {
+            return;
+        }
+
+        println!("{}", tr!("분산 컴파일:", "Distributed compilation:").bold());
+        for (host, stats) in &state.stats {
+            let count = (stats.compiled + stats.fallbacks).max(1) as u32;
+            let line = tr!(
+                "  {}: 원격 {}개, 로컬 대체 {}개, 평균 {}초",
+                "  {}: {} remote, {} fell back to local, {}s average",
+                host,
+                stats.compiled,
+                stats.fallbacks,
+                format!("{:.2}", (stats.time / count).as_secs_f32())
+            );
+            if stats.fallbacks > 0 {
+                println!("{}", line.yellow());
+            } else {
+                println!("{}", line);
+            }
+        }
+    }
+}
+
+/// distcc 호스트 지정 ("host", "host/8", "host:3632/8,lzo")
+fn parse_host(spec: &str) -> Host {
+    let address = spec.split_once(',').map_or(spec, |(address, _)| address);
+    let (name, slots) = match address.rsplit_once('/') {
+        Some((name, slots)) => (name, slots.parse().unwrap_or(DEFAULT_HOST_SLOTS)),
+        None => (address, DEFAULT_HOST_SLOTS),
+    };
+    Host {
+        name: name.to_string(),
+        spec: Some(spec.to_string()),
+        slots: slots.max(1),
+    }
+}
@@ -0,0 +1,370 @@
+use crate::config::{BuildConfig, Dependency};
+use crate::error::BuildError;
+use crate::hints::hint;
+use crate::i18n::tr;
+use crate::layout::BuildLayout;
+use crate::toolchain::Toolchain;
+use crate::tools::{required_tools, ToolKind};
+use crate::utils::{display_width, is_tool_installed, tool_command};
+use colored::Colorize;
+use std::path::Path;
+use std::process::Command;
+
+/// 진단 항목 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Pass,
+    /// 빌드는 가능하지만 일부 기능을 쓸 수 없음
+    Warn,
+    Fail,
+}
+
+struct Check {
+    name: String,
+    status: Status,
+    detail: String,
+    fix: Option<String>,
+}
+
+impl Check {
+    fn pass(name: impl Into<String>, detail: impl Into<String>) -> Self {
+        Check {
+            name: name.into(),
+            status: Status::Pass,
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+
+    fn problem(
+        status: Status,
+        name: impl Into<String>,
+        detail: impl Into<String>,
+        fix: Option<String>,
+    ) -> Self {
+        Check {
+            name: name.into(),
+            status,
+            detail: detail.into(),
+            fix,
+        }
+    }
+}
+
+/// `cbuild doctor`: 빌드 환경과 프로젝트 설정을 점검하고 실패한 항목 수를 반환
+///
+/// 컴파일러·아카이버·git·pkg-config 설치 여부와 버전, cbuild.toml 유효성,
+/// 의존성이 설정한 태그·브랜치로 설치되어 있는지 확인하고 해결 방법을 함께 출력한다.
+pub fn doctor(project_dir: &Path) -> usize {
+    let mut checks = Vec::new();
+
+    let config = match BuildConfig::from_file(project_dir) {
+        Ok(config) => {
+            checks.push(Check::pass(
+                "cbuild.toml",
+                tr!(
+                    "{} {} (타겟 {}개)",
+                    "{} {} ({} targets)",
+                    config.project.name,
+                    config.project.version,
+                    config.targets.executable.len()
+                        + config.targets.static_lib.len()
+                        + config.targets.shared_lib.len()
+                        + config.targets.test.len()
+                ),
+            ));
+            Some(config)
+        }
+        Err(e) => {
+            checks.push(Check::problem(
+                Status::Fail,
+                "cbuild.toml",
+                e.to_string(),
+                hint(&e),
+            ));
+            None
+        }
+    };
+
+    if let Some(ref config) = config {
+        check_tools(project_dir, config, &mut checks);
+    }
+    check_optional_tools(config.as_ref(), &mut checks);
+    if let Some(ref config) = config {
+        check_dependencies(project_dir, config, &mut checks);
+    }
+
+    print_report(&checks)
+}
+
+/// 컴파일러(버전 포함)와 타겟에 필요한 빌드 도구
+fn check_tools(project_dir: &Path, config: &BuildConfig, checks: &mut Vec<Check>) {
+    let layout = BuildLayout::new(
+        project_dir,
+        config.default_configuration(),
+        None,
+        Some(config),
+    );
+
+    for tool in required_tools(config) {
+        let name = tool.kind.to_string();
+        if tool.kind == ToolKind::Compiler {
+            match Toolchain::probe(&tool.program, &layout.toolchain_cache_path()) {
+                Ok(toolchain) => checks.push(Check::pass(
+                    name,
+                    format!("{} ({})", toolchain.version_line(), toolchain.family()),
+                )),
+                Err(e) => checks.push(Check::problem(Status::Fail, name, e.to_string(), hint(&e))),
+            }
+            continue;
+        }
+
+        if is_tool_installed(&tool.program) {
+            checks.push(Check::pass(name, tool.program.clone()));
+        } else {
+            let program = tool.program.clone();
+            let error = BuildError::ToolsNotFound(vec![tool]);
+            checks.push(Check::problem(
+                Status::Fail,
+                name,
+                tr!("{}을(를) 찾을 수 없습니다", "{} not found", program),
+                hint(&error),
+            ));
+        }
+    }
+}
+
+/// git(git 의존성이 있으면 필수)과 pkg-config
+fn check_optional_tools(config: Option<&BuildConfig>, checks: &mut Vec<Check>) {
+    let needs_git = config.is_some_and(|c| c.all_dependencies().any(|(_, d)| d.git.is_some()));
+    let tools = [
+        (
+            "git",
+            if needs_git {
+                Status::Fail
+            } else {
+                Status::Warn
+            },
+            tr!(
+                "git 의존성 설치, --since, 빌드 기록에 필요",
+                "needed for git dependencies, --since and build history"
+            ),
+        ),
+        (
+            "pkg-config",
+            Status::Warn,
+            tr!(
+                "시스템 라이브러리 탐색에 사용",
+                "used to locate system libraries"
+            ),
+        ),
+    ];
+
+    for (program, status, purpose) in tools {
+        match tool_version(program) {
+            Some(version) => checks.push(Check::pass(program, version)),
+            None => checks.push(Check::problem(
+                status,
+                program,
+                tr!(
+                    "{}을(를) 찾을 수 없습니다 ({})",
+                    "{} not found ({})",
+                    program,
+                    purpose
+                ),
+                Some(tr!(
+                    "패키지 관리자로 {}을(를) 설치하세요",
+                    "install {} with your package manager",
+                    program
+                )),
+            )),
+        }
+    }
+}
+
+fn tool_version(program: &str) -> Option<String> {
+    if !is_tool_installed(program) {
+        return None;
+    }
+    let output = tool_command(program).arg("--version").output().ok()?;
+    let text = String::from_utf8_lossy(&output.stdout);
+    Some(text.lines().next().unwrap_or(program).trim().to_string())
+}
+
+/// 의존성이 deps/에 설치되어 있고 설정한 태그·브랜치와 일치하는지
+fn check_dependencies(project_dir: &Path, config: &BuildConfig, checks: &mut Vec<Check>) {
+    for (name, dep) in config.all_dependencies() {
+        let label = tr!("의존성 {}", "dependency {}", name);
+        let dep_dir = project_dir.join("deps").join(name);
+
+        if dep.git.is_none() && dep.registry.is_none() && dep.url.is_none() {
+            checks.push(Check::problem(
+                Status::Warn,
+                label,
+                tr!(
+                    "소스(git, registry, url)가 지정되지 않음",
+                    "no source (git, registry, url) specified"
+                ),
+                Some(tr!(
+                    "cbuild.toml의 [dependencies.{}]에 git, registry 또는 url을 지정하세요",
+                    "set git, registry or url in [dependencies.{}] of cbuild.toml",
+                    name
+                )),
+            ));
+            continue;
+        }
+        if !dep_dir.exists() {
+            checks.push(Check::problem(
+                Status::Fail,
+                label,
+                tr!("설치되지 않음", "not installed"),
+                Some(tr!(
+                    "`cbuild dependencies`로 설치하세요",
+                    "install it with `cbuild dependencies`"
+                )),
+            ));
+            continue;
+        }
+
+        if dep.git.is_none() {
+            let source = dep
+                .registry
+                .as_deref()
+                .or(dep.url.as_deref())
+                .unwrap_or_default();
+            checks.push(Check::pass(
+                label,
+                tr!("압축 파일 ({})", "archive ({})", source),
+            ));
+            continue;
+        }
+
+        checks.push(match dependency_revision(&dep_dir, dep) {
+            Ok(detail) => Check::pass(label, detail),
+            Err(detail) => Check::problem(
+                Status::Fail,
+                label,
+                detail,
+                Some(tr!(
+                    "`cbuild dependencies --update`로 고정한 리비전을 체크아웃하세요",
+                    "check out the pinned revision with `cbuild dependencies --update`"
+                )),
+            ),
+        });
+    }
+}
+
+/// 설치된 의존성의 HEAD가 태그(또는 원격 브랜치)와 같으면 Ok(설명), 다르면 Err(설명)
+fn dependency_revision(dep_dir: &Path, dep: &Dependency) -> Result<String, String> {
+    let head = git(dep_dir, &["rev-parse", "HEAD"]).ok_or_else(|| {
+        tr!(
+            "git 저장소가 아닙니다: {}",
+            "not a git repository: {}",
+            dep_dir.display()
+        )
+    })?;
+    let short = &head[..head.len().min(10)];
+
+    let (expected_ref, expected) = match (&dep.tag, &dep.branch) {
+        (Some(tag), _) => (
+            tag.clone(),
+            git(dep_dir, &["rev-parse", &format!("{}^{{commit}}", tag)]),
+        ),
+        (None, Some(branch)) => {
+            let remote = format!("origin/{}", branch);
+            let commit = git(dep_dir, &["rev-parse", &remote]);
+            (remote, commit)
+        }
+        (None, None) => return Ok(short.to_string()),
+    };
+
+    match expected {
+        Some(expected) if expected == head => Ok(format!("{} ({})", expected_ref, short)),
+        Some(expected) => Err(tr!(
+            "HEAD {}이(가) {}({})와 다릅니다",
+            "HEAD {} differs from {} ({})",
+            short,
+            expected_ref,
+            &expected[..expected.len().min(10)]
+        )),
+        None => Err(tr!(
+            "{}을(를) 찾을 수 없습니다",
+            "{} not found",
+            expected_ref
+        )),
+    }
+}
+
+fn git(dir: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// 색상 보고서 출력 후 실패 항목 수 반환
+fn print_report(checks: &[Check]) -> usize {
+    let width = checks
+        .iter()
+        .map(|c| display_width(&c.name))
+        .max()
+        .unwrap_or(0);
+
+    for check in checks {
+        let mark = match check.status {
+            Status::Pass => "✓".green().bold(),
+            Status::Warn => "!".yellow().bold(),
+            Status::Fail => "✗".red().bold(),
+        };
+        let padding = width - display_width(&check.name);
+        println!(
+            "{} {}{}  {}",
+            mark,
+            check.name.bold(),
+            " ".repeat(padding),
+            check.detail
+        );
+        if let Some(ref fix) = check.fix {
+            println!(
+                "  {}{} {}",
+                " ".repeat(width),
+                tr!("해결:", "fix:").cyan(),
+                fix
+            );
+        }
+    }
+
+    let failures = checks.iter().filter(|c| c.status == Status::Fail).count();
+    let warnings = checks.iter().filter(|c| c.status == Status::Warn).count();
+    println!();
+    if failures == 0 {
+        println!(
+            "{}",
+            tr!(
+                "문제 없음 (경고 {}개)",
+                "No problems found ({} warnings)",
+                warnings
+            )
+            .green()
+            .bold()
+        );
+    } else {
+        println!(
+            "{}",
+            tr!(
+                "문제 {}개, 경고 {}개",
+                "{} problems, {} warnings",
+                failures,
+                warnings
+            )
+            .red()
+            .bold()
+        );
+    }
+    failures
+}
@@ -0,0 +1,316 @@
+use crate::config::{BuildConfig, DownloadSettings};
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use crate::retry::RetryPolicy;
+use crate::utils;
+use colored::Colorize;
+use indicatif::{ProgressBar, ProgressStyle};
+use log::{debug, info};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+/// 체크섬을 확인하는 파일 다운로드 (툴체인, 압축 파일 의존성에서 공통 사용)
+///
+/// curl 또는 wget으로 `<dest>.part`에 내려받고, 중단된 다운로드는 이어 받으며,
+/// SHA-256이 일치할 때만 `dest`로 옮긴다. 프록시는 도구가 읽는 환경 변수
+/// (HTTPS_PROXY, HTTP_PROXY, NO_PROXY)를 따르고 cbuild.toml의 [download]로 지정할 수도 있다.
+/// [download]의 미러가 URL과 맞으면 미러에서 먼저 받는다.
+pub struct Downloader {
+    retry: RetryPolicy,
+    proxy: Option<String>,
+    /// (URL 접두사, 미러 접두사), 긴 접두사 우선
+    mirrors: Vec<(String, String)>,
+    cache_dir: Option<PathBuf>,
+}
+
+impl Downloader {
+    pub fn new(settings: &DownloadSettings, retry: RetryPolicy) -> Self {
+        let mut mirrors: Vec<(String, String)> = settings
+            .mirrors
+            .iter()
+            .flatten()
+            .map(|(prefix, mirror)| (prefix.clone(), mirror.clone()))
+            .collect();
+        mirrors.sort_by_key(|(prefix, _)| std::cmp::Reverse(prefix.len()));
+
+        Downloader {
+            retry,
+            proxy: settings.proxy.clone(),
+            mirrors,
+            cache_dir: settings.cache_dir.as_deref().map(expand_home),
+        }
+    }
+
+    /// 프로젝트 설정의 [download]와 [retry]를 사용하는 다운로더
+    pub fn from_config(config: &BuildConfig) -> Self {
+        Self::new(&config.download, RetryPolicy::from_settings(&config.retry))
+    }
+
+    /// 내려받은 파일을 둘 디렉토리 ([download] cache_dir가 없으면 `default_dir`)
+    pub fn cache_dir(&self, default_dir: &Path) -> PathBuf {
+        self.cache_dir
+            .as_deref()
+            .unwrap_or(default_dir)
+            .to_path_buf()
+    }
+
+    /// 내려받은 파일을 둘 경로
+    pub fn cache_path(&self, default_dir: &Path, file_name: &str) -> PathBuf {
+        self.cache_dir(default_dir).join(file_name)
+    }
+
+    /// `url`을 `dest`로 내려받고 SHA-256 확인
+    ///
+    /// `dest`가 이미 있고 체크섬이 같으면 다시 내려받지 않는다.
+    pub fn fetch(&self, url: &str, sha256: &str, dest: &Path) -> BuildResult<()> {
+        if sha256.len() != 64 || !sha256.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(BuildError::DownloadError(tr!(
+                "{}의 sha256이 올바른 SHA-256 값이 아닙니다: \"{}\"",
+                "sha256 for {} is not a valid SHA-256 digest: \"{}\"",
+                url,
+                sha256
+            )));
+        }
+
+        if dest.exists() && sha256_file(dest)?.eq_ignore_ascii_case(sha256) {
+            info!("다운로드 캐시 사용: {}", dest.display());
+            return Ok(());
+        }
+
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent).map_err(BuildError::IoError)?;
+        }
+
+        let tool = download_tool()?;
+
+        let part = partial_path(dest);
+        let sources = self.sources(url);
+        let mut errors = Vec::new();
+        let mut downloaded = false;
+        for (index, source) in sources.iter().enumerate() {
+            println!("{} {}", "Downloading".green().bold(), source);
+            let result = self.retry.run(source, || {
+                match self.transfer(tool, source, &part, true) {
+                    // 서버가 이어 받기를 지원하지 않거나 남은 파일이 맞지 않으면 처음부터 다시 받음
+                    Err(ref error) if is_resume_error(error) => {
+                        let _ = std::fs::remove_file(&part);
+                        self.transfer(tool, source, &part, false)
+                    }
+                    result => result,
+                }
+            });
+            match result {
+                Ok(()) => {
+                    downloaded = true;
+                    break;
+                }
+                Err(error) => {
+                    // 미러에서 받다 만 파일은 다른 서버에서 이어 받지 않음
+                    if index + 1 < sources.len() {
+                        let _ = std::fs::remove_file(&part);
+                    }
+                    errors.push(format!("{}: {}", source, error));
+                }
+            }
+        }
+        if !downloaded {
+            return Err(BuildError::DownloadError(tr!(
+                "{}을(를) 내려받을 수 없습니다: {}",
+                "cannot download {}: {}",
+                url,
+                errors.join("\n")
+            )));
+        }
+
+        let actual = sha256_file(&part)?;
+        if !actual.eq_ignore_ascii_case(sha256) {
+            let _ = std::fs::remove_file(&part);
+            return Err(BuildError::DownloadError(tr!(
+                "{}의 체크섬이 일치하지 않습니다 (예상: {}, 실제: {})",
+                "checksum mismatch for {} (expected {}, got {})",
+                url,
+                sha256,
+                actual
+            )));
+        }
+        info!("체크섬 확인: {}", actual);
+
+        std::fs::rename(&part, dest).map_err(BuildError::IoError)
+    }
+
+    /// 체크섬 없이 작은 텍스트 파일(레지스트리 색인 등)을 내려받아 내용 반환
+    ///
+    /// 캐시하지 않으며 미러, 프록시, 재시도 설정은 `fetch`와 같다.
+    pub fn fetch_text(&self, url: &str) -> BuildResult<String> {
+        let tool = download_tool()?;
+        let file = tempfile::NamedTempFile::new().map_err(BuildError::IoError)?;
+
+        let mut errors = Vec::new();
+        for source in self.sources(url) {
+            debug!("다운로드: {}", source);
+            match self
+                .retry
+                .run(&source, || self.transfer(tool, &source, file.path(), false))
+            {
+                Ok(()) => {
+                    return std::fs::read_to_string(file.path()).map_err(BuildError::IoError);
+                }
+                Err(error) => errors.push(format!("{}: {}", source, error)),
+            }
+        }
+        Err(BuildError::DownloadError(tr!(
+            "{}을(를) 내려받을 수 없습니다: {}",
+            "cannot download {}: {}",
+            url,
+            errors.join("\n")
+        )))
+    }
+
+    /// 내려받을 URL 목록 (맞는 미러가 있으면 미러 먼저)
+    fn sources(&self, url: &str) -> Vec<String> {
+        let mirror = self.mirrors.iter().find_map(|(prefix, mirror)| {
+            url.strip_prefix(prefix.as_str())
+                .map(|rest| format!("{}{}", mirror, rest))
+        });
+        mirror.into_iter().chain([url.to_string()]).collect()
+    }
+
+    /// 다운로드 도구를 한 번 실행하고 받은 크기를 진행 표시줄로 보여줌
+    fn transfer(&self, tool: &str, url: &str, part: &Path, resume: bool) -> Result<(), String> {
+        let mut cmd = Command::new(tool);
+        if tool == "curl" {
+            cmd.args(["--fail", "--location", "--silent", "--show-error"]);
+            if resume {
+                cmd.args(["--continue-at", "-"]);
+            }
+            if let Some(ref proxy) = self.proxy {
+                cmd.arg("--proxy").arg(proxy);
+            }
+            cmd.arg("--output").arg(part).arg(url);
+        } else {
+            cmd.arg("--quiet");
+            if resume {
+                cmd.arg("--continue");
+            }
+            if let Some(ref proxy) = self.proxy {
+                cmd.arg("-e").arg(format!("http_proxy={}", proxy));
+                cmd.arg("-e").arg(format!("https_proxy={}", proxy));
+            }
+            cmd.arg("--output-document").arg(part).arg(url);
+        }
+        cmd.stdout(Stdio::null()).stderr(Stdio::piped());
+
+        debug!("다운로드: {:?}", cmd);
+        let mut child = cmd.spawn().map_err(|e| e.to_string())?;
+
+        // 터미널이 아니면 indicatif가 표시하지 않음
+        let bar = ProgressBar::new_spinner();
+        bar.set_style(
+            ProgressStyle::with_template("  {spinner} {bytes} ({bytes_per_sec}) {msg}")
+                .unwrap_or_else(|_| ProgressStyle::default_spinner()),
+        );
+        bar.set_message(file_name_from_url(url));
+        while child.try_wait().map_err(|e| e.to_string())?.is_none() {
+            bar.set_position(std::fs::metadata(part).map(|m| m.len()).unwrap_or(0));
+            bar.tick();
+            std::thread::sleep(Duration::from_millis(100));
+        }
+        bar.finish_and_clear();
+
+        let output = child.wait_with_output().map_err(|e| e.to_string())?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+        Ok(())
+    }
+}
+
+/// 설치된 다운로드 도구 (curl 우선, 없으면 wget)
+fn download_tool() -> BuildResult<&'static str> {
+    if utils::is_tool_installed("curl") {
+        Ok("curl")
+    } else if utils::is_tool_installed("wget") {
+        Ok("wget")
+    } else {
+        Err(BuildError::DownloadError(tr!(
+            "파일을 내려받으려면 curl 또는 wget이 필요합니다",
+            "curl or wget is required to download files"
+        )))
+    }
+}
+
+/// 이어 받기에 실패한 오류인지 (HTTP 416, curl 33)
+fn is_resume_error(error: &str) -> bool {
+    error.contains("error: 416") || error.contains("(33)") || error.contains("416 Requested Range")
+}
+
+/// 앞의 `~/`를 홈 디렉토리로 바꿈
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+fn partial_path(dest: &Path) -> PathBuf {
+    let mut name = dest.file_name().unwrap_or_default().to_os_string();
+    name.push(".part");
+    dest.with_file_name(name)
+}
+
+/// URL의 마지막 경로 요소 (압축 형식 판별과 캐시 파일 이름에 사용)
+pub fn file_name_from_url(url: &str) -> String {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    path.rsplit('/')
+        .find(|part| !part.is_empty())
+        .unwrap_or("download")
+        .to_string()
+}
+
+pub fn sha256_file(path: &Path) -> BuildResult<String> {
+    let mut file = std::fs::File::open(path).map_err(BuildError::IoError)?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher).map_err(BuildError::IoError)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// 압축 해제 (.zip은 unzip, 나머지는 tar)
+pub fn extract(archive: &Path, dest: &Path) -> BuildResult<()> {
+    let name = archive.to_string_lossy().to_lowercase();
+    let mut cmd = if name.ends_with(".zip") {
+        let mut cmd = Command::new("unzip");
+        cmd.arg("-q").arg(archive).arg("-d").arg(dest);
+        cmd
+    } else {
+        let mut cmd = Command::new("tar");
+        cmd.arg("-xf").arg(archive).arg("-C").arg(dest);
+        cmd
+    };
+
+    let output = cmd.output().map_err(BuildError::IoError)?;
+    if !output.status.success() {
+        return Err(BuildError::DownloadError(tr!(
+            "{}의 압축을 풀 수 없습니다: {}",
+            "cannot extract {}: {}",
+            archive.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    Ok(())
+}
+
+/// 압축을 푼 디렉토리에 최상위 디렉토리 하나만 있으면 그 경로
+pub fn single_top_level_dir(dir: &Path) -> BuildResult<Option<PathBuf>> {
+    let entries: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map_err(BuildError::IoError)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .collect();
+
+    match entries.as_slice() {
+        [only] if only.is_dir() => Ok(Some(only.clone())),
+        _ => Ok(None),
+    }
+}
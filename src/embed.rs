@@ -0,0 +1,165 @@
+use crate::error::{BuildError, BuildResult};
+use crate::utils::{glob_in, is_newer_than, write_if_changed};
+use log::{debug, warn};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+/// 타겟에 포함될 리소스 소스 생성 결과
+pub struct EmbeddedResources {
+    /// 컴파일 대상에 추가할 생성된 C 소스 파일
+    pub sources: Vec<PathBuf>,
+    /// 접근자 헤더가 위치한 디렉토리 (포함 디렉토리로 추가됨)
+    pub include_dir: PathBuf,
+}
+
+/// `embed` 패턴에 해당하는 에셋 파일을 C 바이트 배열 소스로 변환
+///
+/// 에셋마다 하나의 .c 파일을 만들고, 이름으로 리소스를 찾는 인덱스 소스와
+/// `<target>_embed.h` 헤더를 생성한다. 에셋이 생성된 소스보다 새로울 때만
+/// 다시 생성하므로 증분 빌드에서 변경되지 않은 에셋은 재컴파일되지 않는다.
+/// `write`가 false이면(`--dry-run`) 파일을 쓰지 않고 생성될 경로만 돌려준다.
+pub fn generate_embedded_sources(
+    target_name: &str,
+    patterns: &[String],
+    project_dir: &Path,
+    out_dir: &Path,
+    write: bool,
+) -> BuildResult<EmbeddedResources> {
+    if write {
+        std::fs::create_dir_all(out_dir).map_err(BuildError::IoError)?;
+    }
+
+    let mut assets = Vec::new();
+    for pattern in patterns {
+        let paths = glob_in(project_dir, pattern, true)
+            .map_err(|e| BuildError::PathError(format!("패턴 '{}'에 오류: {}", pattern, e)))?;
+
+        let before = assets.len();
+        assets.extend(paths.into_iter().filter(|p| p.is_file()));
+
+        if assets.len() == before {
+            warn!("No files matched embed pattern: {}", pattern);
+        }
+    }
+    assets.sort();
+    assets.dedup();
+
+    let prefix = sanitize_identifier(target_name);
+    let mut sources = Vec::new();
+    let mut symbols = Vec::new();
+
+    for asset in &assets {
+        let rel_path = asset.strip_prefix(project_dir).unwrap_or(asset);
+        let resource_name = rel_path.to_string_lossy().replace('\\', "/");
+        let symbol = sanitize_identifier(&resource_name);
+        let source_path = out_dir.join(format!("{}.c", symbol));
+
+        if write && (!source_path.exists() || is_newer_than(asset, &source_path)) {
+            debug!("Embedding {} as {}", asset.display(), symbol);
+            let data = std::fs::read(asset).map_err(BuildError::IoError)?;
+            write_if_changed(&source_path, &render_asset_source(&symbol, &data))
+                .map_err(BuildError::IoError)?;
+        }
+
+        sources.push(source_path);
+        symbols.push((resource_name, symbol));
+    }
+
+    // 헤더와 인덱스는 에셋 목록이 바뀔 때만 내용이 달라짐
+    let header_path = out_dir.join(format!("{}_embed.h", target_name));
+    let index_path = out_dir.join(format!("{}_embed_index.c", prefix));
+    if write {
+        write_if_changed(&header_path, &render_header(&prefix, &symbols))
+            .map_err(BuildError::IoError)?;
+        write_if_changed(&index_path, &render_index(target_name, &prefix, &symbols))
+            .map_err(BuildError::IoError)?;
+    }
+    sources.push(index_path);
+
+    Ok(EmbeddedResources {
+        sources,
+        include_dir: out_dir.to_path_buf(),
+    })
+}
+
+/// 파일 경로를 C 식별자로 변환 (예: assets/shader.glsl -> assets_shader_glsl)
+pub fn sanitize_identifier(name: &str) -> String {
+    let mut ident: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+
+    ident
+}
+
+fn render_asset_source(symbol: &str, data: &[u8]) -> String {
+    let mut out = String::new();
+    out.push_str("/* cbuild에서 자동 생성된 파일입니다. 수정하지 마세요. */\n");
+    out.push_str("#include <stddef.h>\n\n");
+    let _ = writeln!(out, "const unsigned char {}[] = {{", symbol);
+
+    for chunk in data.chunks(16) {
+        out.push_str("   ");
+        for byte in chunk {
+            let _ = write!(out, " 0x{:02x},", byte);
+        }
+        out.push('\n');
+    }
+
+    // 텍스트 에셋을 문자열로 바로 쓸 수 있도록 NUL 종료 (크기에는 포함하지 않음)
+    out.push_str("    0x00\n};\n");
+    let _ = writeln!(out, "const size_t {}_size = {};", symbol, data.len());
+    out
+}
+
+fn render_header(prefix: &str, symbols: &[(String, String)]) -> String {
+    let guard = format!("{}_EMBED_H", prefix.to_uppercase());
+    let mut out = String::new();
+    out.push_str("/* cbuild에서 자동 생성된 파일입니다. 수정하지 마세요. */\n");
+    let _ = writeln!(out, "#ifndef {}\n#define {}\n", guard, guard);
+    out.push_str("#include <stddef.h>\n\n");
+    out.push_str("#ifdef __cplusplus\nextern \"C\" {\n#endif\n\n");
+
+    for (resource_name, symbol) in symbols {
+        let _ = writeln!(out, "/* {} */", resource_name);
+        let _ = writeln!(out, "extern const unsigned char {}[];", symbol);
+        let _ = writeln!(out, "extern const size_t {}_size;\n", symbol);
+    }
+
+    out.push_str("/* 리소스 이름(프로젝트 기준 경로)으로 데이터 조회, 없으면 NULL */\n");
+    let _ = writeln!(
+        out,
+        "const unsigned char *{}_embed_get(const char *name, size_t *size);\n",
+        prefix
+    );
+    out.push_str("#ifdef __cplusplus\n}\n#endif\n\n");
+    let _ = writeln!(out, "#endif /* {} */", guard);
+    out
+}
+
+fn render_index(target_name: &str, prefix: &str, symbols: &[(String, String)]) -> String {
+    let mut out = String::new();
+    out.push_str("/* cbuild에서 자동 생성된 파일입니다. 수정하지 마세요. */\n");
+    let _ = writeln!(out, "#include \"{}_embed.h\"", target_name);
+    out.push_str("#include <string.h>\n\n");
+    let _ = writeln!(
+        out,
+        "const unsigned char *{}_embed_get(const char *name, size_t *size) {{",
+        prefix
+    );
+    out.push_str("    (void)name;\n");
+
+    for (resource_name, symbol) in symbols {
+        let _ = writeln!(out, "    if (strcmp(name, \"{}\") == 0) {{", resource_name);
+        let _ = writeln!(out, "        if (size) *size = {}_size;", symbol);
+        let _ = writeln!(out, "        return {};", symbol);
+        out.push_str("    }\n");
+    }
+
+    out.push_str("    if (size) *size = 0;\n    return NULL;\n}\n");
+    out
+}
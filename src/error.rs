@@ -1,37 +1,274 @@
+use crate::cmdlog::quote;
+use crate::diagnostics::strip_ansi;
+use crate::i18n::tr;
+use crate::tools::RequiredTool;
 use std::path::PathBuf;
-use thiserror::Error;
+use std::process::Command;
 
-#[derive(Error, Debug)]
-pub enum BuildError {
-    #[error("IO 오류: {0}")]
-    IoError(#[from] std::io::Error),
+/// 실패한 외부 명령(컴파일러, 링커, 아카이버)의 정보
+///
+/// 오류 메시지에 어떤 타겟의 어떤 명령이 실패했는지 보여주고
+/// 컴파일러 출력을 바탕으로 해결 방법을 제안하는 데 사용한다.
+#[derive(Debug)]
+pub struct CommandFailure {
+    /// 실패한 타겟 (`exe:app`, `static:core` 등)
+    pub target: String,
+    /// 실행한 명령줄
+    pub command: String,
+    /// 표준 오류 출력 (색상 escape 코드 제거)
+    pub stderr: String,
+}
 
-    #[error("설정 파싱 오류: {0}")]
-    ConfigParsingError(String),
+impl CommandFailure {
+    pub fn new(target: &str, cmd: &Command, stderr: &[u8]) -> Box<Self> {
+        let command = std::iter::once(cmd.get_program())
+            .chain(cmd.get_args())
+            .map(|arg| quote(&arg.to_string_lossy()))
+            .collect::<Vec<_>>()
+            .join(" ");
 
-    #[error("컴파일러 오류: {0}")]
-    CompilerError(String),
+        Box::new(CommandFailure {
+            target: target.to_string(),
+            command,
+            stderr: strip_ansi(&String::from_utf8_lossy(stderr)),
+        })
+    }
 
-    #[error("링커 오류: {0}")]
-    LinkerError(String),
+    /// 표준 오류 출력의 앞부분 (최대 `lines`줄)
+    pub fn excerpt(&self, lines: usize) -> String {
+        self.stderr
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .take(lines)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
 
-    #[error("의존성 오류: {0}")]
-    DependencyError(String),
+    /// 첫 번째 오류 줄 (없으면 첫 줄)
+    pub fn first_error(&self) -> &str {
+        self.stderr
+            .lines()
+            .find(|line| line.contains("error"))
+            .or_else(|| self.stderr.lines().find(|line| !line.trim().is_empty()))
+            .unwrap_or("")
+    }
+}
 
-    #[error("경로 오류: {0}")]
+#[derive(Debug)]
+pub enum BuildError {
+    IoError(std::io::Error),
+    ConfigParsingError(String),
+    CompilerError(String),
+    CompileFailed(Box<CommandFailure>),
+    LinkerError(Box<CommandFailure>),
+    CustomCommandFailed(Box<CommandFailure>),
+    DependencyError(String),
+    GitError(String),
     PathError(String),
-
-    #[error("컴파일러 {0}이(가) 설치되지 않았습니다")]
     CompilerNotFound(String),
-
-    #[error("프로젝트 설정 파일 {0}을(를) 찾을 수 없습니다")]
+    ToolsNotFound(Vec<RequiredTool>),
+    OpenMpNotFound(String),
+    ContainerError(String),
+    ToolchainNotInstalled(String),
+    ToolchainError(String),
+    DownloadError(String),
     ConfigNotFound(PathBuf),
-
-    #[error("타겟 {0}에 대한 소스 파일을 찾을 수 없습니다")]
     NoSourceFiles(String),
-
-    #[error("실행 파일 {0}을(를) 찾을 수 없습니다")]
     ExecutableNotFound(PathBuf),
+    TargetNotFound(String),
+    RunAliasNotFound(String),
+    SourceNotInTarget(PathBuf),
+    AmbiguousTarget(PathBuf, String),
+    TestFailure(String),
+    /// 타겟의 include_dirs 밖 헤더를 포함한 소스 수 (`[build] strict_includes`)
+    IncludePolicyViolation(usize),
+}
+
+impl std::fmt::Display for BuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            BuildError::IoError(e) => tr!("IO 오류: {}", "I/O error: {}", e),
+            BuildError::ConfigParsingError(msg) => {
+                tr!("설정 파싱 오류: {}", "config parse error: {}", msg)
+            }
+            BuildError::CompilerError(msg) => tr!("컴파일러 오류: {}", "compiler error: {}", msg),
+            BuildError::CompileFailed(failure) => tr!(
+                "컴파일 실패 ({}): {}",
+                "compilation failed ({}): {}",
+                failure.target,
+                failure.first_error()
+            ),
+            BuildError::LinkerError(failure) => tr!(
+                "링커 오류 ({}):\n{}",
+                "linker error ({}):\n{}",
+                failure.target,
+                failure.excerpt(10)
+            ),
+            BuildError::CustomCommandFailed(failure) => tr!(
+                "사용자 정의 명령 실패 ({}):\n{}",
+                "custom command failed ({}):\n{}",
+                failure.target,
+                failure.excerpt(10)
+            ),
+            BuildError::DependencyError(msg) => {
+                tr!("의존성 오류: {}", "dependency error: {}", msg)
+            }
+            BuildError::GitError(msg) => tr!("git 오류: {}", "git error: {}", msg),
+            BuildError::PathError(msg) => tr!("경로 오류: {}", "path error: {}", msg),
+            BuildError::CompilerNotFound(compiler) => tr!(
+                "컴파일러 {}이(가) 설치되지 않았습니다",
+                "compiler {} is not installed",
+                compiler
+            ),
+            BuildError::ToolsNotFound(tools) => {
+                let list: Vec<String> = tools
+                    .iter()
+                    .map(|tool| format!("  - {} ({})", tool.program, tool.kind))
+                    .collect();
+                tr!(
+                    "빌드에 필요한 도구를 찾을 수 없습니다:\n{}",
+                    "tools required for the build are not installed:\n{}",
+                    list.join("\n")
+                )
+            }
+            BuildError::OpenMpNotFound(compiler) => tr!(
+                "컴파일러 {}에서 OpenMP를 사용할 수 없습니다 (omp.h 또는 OpenMP 런타임 없음)",
+                "OpenMP is not available with compiler {} (omp.h or the OpenMP runtime is missing)",
+                compiler
+            ),
+            BuildError::ContainerError(msg) => {
+                tr!("컨테이너 오류: {}", "container error: {}", msg)
+            }
+            BuildError::ToolchainNotInstalled(name) => tr!(
+                "툴체인 {}이(가) 설치되지 않았습니다",
+                "toolchain {} is not installed",
+                name
+            ),
+            BuildError::ToolchainError(msg) => tr!("툴체인 오류: {}", "toolchain error: {}", msg),
+            BuildError::DownloadError(msg) => tr!("다운로드 오류: {}", "download error: {}", msg),
+            BuildError::ConfigNotFound(path) => tr!(
+                "프로젝트 설정 파일 {}을(를) 찾을 수 없습니다",
+                "project config file {} not found",
+                path.display()
+            ),
+            BuildError::NoSourceFiles(target) => tr!(
+                "타겟 {}에 대한 소스 파일을 찾을 수 없습니다",
+                "no source files found for target {}",
+                target
+            ),
+            BuildError::ExecutableNotFound(path) => tr!(
+                "실행 파일 {}을(를) 찾을 수 없습니다",
+                "executable {} not found",
+                path.display()
+            ),
+            BuildError::TargetNotFound(target) => tr!(
+                "타겟 {}을(를) 찾을 수 없습니다",
+                "target {} not found",
+                target
+            ),
+            BuildError::RunAliasNotFound(alias) => tr!(
+                "실행 별칭 {}이(가) 정의되지 않았습니다",
+                "run alias {} is not defined",
+                alias
+            ),
+            BuildError::SourceNotInTarget(path) => tr!(
+                "파일 {}이(가) 어떤 타겟에도 속하지 않습니다",
+                "file {} does not belong to any target",
+                path.display()
+            ),
+            BuildError::AmbiguousTarget(path, targets) => tr!(
+                "파일 {}이(가) 여러 타겟에 속합니다 ({}) - --target으로 지정하세요",
+                "file {} belongs to several targets ({}) - choose one with --target",
+                path.display(),
+                targets
+            ),
+            BuildError::TestFailure(msg) => tr!("테스트 실패: {}", "test failure: {}", msg),
+            BuildError::IncludePolicyViolation(count) => tr!(
+                "소스 {}개가 타겟의 include_dirs 밖의 헤더를 포함합니다",
+                "{} sources include headers outside their target's include_dirs",
+                count
+            ),
+        };
+
+        write!(f, "{}", message)
+    }
+}
+
+impl std::error::Error for BuildError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BuildError::IoError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for BuildError {
+    fn from(e: std::io::Error) -> Self {
+        BuildError::IoError(e)
+    }
+}
+
+/// 프로세스 종료 코드
+///
+/// 래퍼 스크립트와 CI가 실패 종류에 따라 분기할 수 있도록 오류 분류마다
+/// 다른 값을 사용한다. 2는 clap이 잘못된 명령줄 인자에 사용한다.
+pub mod exit_code {
+    /// 그 외 오류 (입출력, 경로, git 등)
+    pub const FAILURE: i32 = 1;
+    /// 설정 파일이 없거나 잘못됨, 존재하지 않는 타겟
+    pub const CONFIG: i32 = 3;
+    /// 컴파일 실패 또는 컴파일러·빌드 도구 없음
+    pub const COMPILE: i32 = 4;
+    /// 링크 실패
+    pub const LINK: i32 = 5;
+    /// 의존성 해석·설치 실패
+    pub const DEPENDENCY: i32 = 6;
+    /// 테스트 실패
+    pub const TEST: i32 = 7;
+}
+
+impl BuildError {
+    /// 외부 명령 실패로 생긴 오류이면 그 명령의 정보
+    pub fn command_failure(&self) -> Option<&CommandFailure> {
+        match self {
+            BuildError::CompileFailed(failure)
+            | BuildError::LinkerError(failure)
+            | BuildError::CustomCommandFailed(failure) => Some(failure),
+            _ => None,
+        }
+    }
+
+    /// 오류 분류에 해당하는 프로세스 종료 코드
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            BuildError::ConfigParsingError(_)
+            | BuildError::ConfigNotFound(_)
+            | BuildError::NoSourceFiles(_)
+            | BuildError::TargetNotFound(_)
+            | BuildError::RunAliasNotFound(_)
+            | BuildError::SourceNotInTarget(_)
+            | BuildError::AmbiguousTarget(..)
+            | BuildError::ToolchainNotInstalled(_) => exit_code::CONFIG,
+            BuildError::CompilerError(_)
+            | BuildError::CompileFailed(_)
+            | BuildError::CompilerNotFound(_)
+            | BuildError::ToolsNotFound(_)
+            | BuildError::OpenMpNotFound(_)
+            | BuildError::IncludePolicyViolation(_) => exit_code::COMPILE,
+            BuildError::LinkerError(_) => exit_code::LINK,
+            BuildError::DependencyError(_) => exit_code::DEPENDENCY,
+            BuildError::TestFailure(_) => exit_code::TEST,
+            BuildError::IoError(_)
+            | BuildError::GitError(_)
+            | BuildError::PathError(_)
+            | BuildError::ContainerError(_)
+            | BuildError::ToolchainError(_)
+            | BuildError::DownloadError(_)
+            | BuildError::ExecutableNotFound(_)
+            | BuildError::CustomCommandFailed(_) => exit_code::FAILURE,
+        }
+    }
 }
 
 pub type BuildResult<T> = Result<T, BuildError>;
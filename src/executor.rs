@@ -0,0 +1,36 @@
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+/// 빌더가 실행하는 명령 하나 (컴파일, 아카이브, 링크 등)
+pub struct Action<'a> {
+    pub command: &'a mut Command,
+    /// 다른 곳에서 실행해도 되는 동작의 출력 파일 (None이면 항상 이 컴퓨터에서 실행)
+    ///
+    /// 원격 실행기는 실행 후 이 파일들을 내려받는다. 지금은 컴파일만 지정한다.
+    pub outputs: Option<Vec<PathBuf>>,
+}
+
+impl<'a> Action<'a> {
+    /// 이 컴퓨터에서만 실행하는 동작
+    pub fn local(command: &'a mut Command) -> Self {
+        Action {
+            command,
+            outputs: None,
+        }
+    }
+}
+
+/// 빌드 동작을 실행하는 방법 (로컬 프로세스, 원격 실행 서버 등)
+pub trait ActionExecutor: Send + Sync {
+    /// 동작을 실행하고 종료 상태와 출력 반환
+    fn execute(&self, action: Action) -> std::io::Result<Output>;
+}
+
+/// 명령을 이 컴퓨터에서 바로 실행
+pub struct LocalExecutor;
+
+impl ActionExecutor for LocalExecutor {
+    fn execute(&self, action: Action) -> std::io::Result<Output> {
+        action.command.output()
+    }
+}
@@ -0,0 +1,115 @@
+use crate::config::BuildConfig;
+use crate::embed::sanitize_identifier;
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use log::warn;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+/// 의존성 하나에 대해 합친 기능과 그 기능이 켜는 매크로 정의
+#[derive(Debug, Clone, Default)]
+pub struct ResolvedFeatures {
+    pub features: BTreeSet<String>,
+    pub defines: BTreeMap<String, String>,
+}
+
+/// 프로젝트와 설치된 의존성이 요청한 기능을 의존성별로 합침
+///
+/// 같은 의존성을 프로젝트와 다른 의존성(deps/<이름>/cbuild.toml의 [dependencies])이
+/// 함께 쓰면 요청한 기능의 합집합을 켠다. 의존성이 cbuild 프로젝트이고 [features]를
+/// 선언했으면 그 매크로 정의를 쓰고, 아니면 기능마다 `<의존성>_FEATURE_<기능>=1`을 정의한다.
+pub fn resolve_dependency_features(
+    project_dir: &Path,
+    config: &BuildConfig,
+) -> BuildResult<BTreeMap<String, ResolvedFeatures>> {
+    let deps_dir = project_dir.join("deps");
+    let mut requested: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    let mut manifests: BTreeMap<String, Option<BuildConfig>> = BTreeMap::new();
+
+    let mut pending: Vec<String> = Vec::new();
+    for (name, dependency) in config.all_dependencies() {
+        requested
+            .entry(name.clone())
+            .or_default()
+            .extend(dependency.features.iter().flatten().cloned());
+        pending.push(name.clone());
+    }
+
+    // 설치된 의존성의 cbuild.toml을 따라가며 기능 요청을 모음
+    while let Some(name) = pending.pop() {
+        if manifests.contains_key(&name) {
+            continue;
+        }
+        let manifest = dependency_manifest(&deps_dir.join(&name), &name);
+        if let Some(ref manifest) = manifest {
+            for (child, dependency) in &manifest.dependencies {
+                requested
+                    .entry(child.clone())
+                    .or_default()
+                    .extend(dependency.features.iter().flatten().cloned());
+                pending.push(child.clone());
+            }
+        }
+        manifests.insert(name, manifest);
+    }
+
+    let mut resolved = BTreeMap::new();
+    for (name, features) in requested {
+        if features.is_empty() {
+            continue;
+        }
+        let declared = manifests
+            .get(&name)
+            .and_then(|m| m.as_ref())
+            .map(|m| &m.features)
+            .filter(|declared| !declared.is_empty());
+
+        let mut defines = BTreeMap::new();
+        for feature in &features {
+            match declared {
+                Some(declared) => {
+                    let feature_defines = declared.get(feature).ok_or_else(|| {
+                        BuildError::DependencyError(tr!(
+                            "의존성 {}에 기능 {}이(가) 없습니다 (사용 가능: {})",
+                            "dependency {} has no feature {} (available: {})",
+                            name,
+                            feature,
+                            declared.keys().cloned().collect::<Vec<_>>().join(", ")
+                        ))
+                    })?;
+                    defines.extend(feature_defines.clone());
+                }
+                None => {
+                    defines.insert(feature_macro(&name, feature), "1".to_string());
+                }
+            }
+        }
+        resolved.insert(name, ResolvedFeatures { features, defines });
+    }
+
+    Ok(resolved)
+}
+
+/// 기능 매크로 이름 (libcurl, ssl -> LIBCURL_FEATURE_SSL)
+fn feature_macro(dependency: &str, feature: &str) -> String {
+    format!(
+        "{}_FEATURE_{}",
+        sanitize_identifier(dependency).to_uppercase(),
+        sanitize_identifier(feature).to_uppercase()
+    )
+}
+
+/// 설치된 의존성의 cbuild.toml (cbuild 프로젝트가 아니거나 읽을 수 없으면 None)
+fn dependency_manifest(dep_dir: &Path, name: &str) -> Option<BuildConfig> {
+    let content = std::fs::read_to_string(dep_dir.join("cbuild.toml")).ok()?;
+    match toml::from_str(&content) {
+        Ok(manifest) => Some(manifest),
+        Err(e) => {
+            warn!(
+                "의존성 {}의 cbuild.toml을 읽을 수 없어 기능 선언을 무시합니다: {}",
+                name, e
+            );
+            None
+        }
+    }
+}
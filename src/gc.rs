@@ -0,0 +1,330 @@
+use crate::context::ProjectContext;
+use crate::download::Downloader;
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use crate::layout::BuildLayout;
+use crate::memory::parse_memory_size;
+use crate::provision;
+use crate::utils::{format_size, get_extension};
+use colored::Colorize;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use walkdir::WalkDir;
+
+/// [gc] max_age_days 기본값
+const DEFAULT_MAX_AGE_DAYS: u64 = 30;
+
+/// 지울 파일 또는 디렉토리
+struct Removal {
+    path: PathBuf,
+    size: u64,
+    reason: String,
+}
+
+/// `cbuild gc`: 빌드 디렉토리와 캐시에서 더 이상 쓰지 않는 파일 삭제
+///
+/// 다음 항목을 찾아 지우고 확보한 용량을 보고한다. `dry_run`이면 목록만 출력한다.
+///
+/// - 현재 소스 목록에 없는 소스의 오브젝트 파일(.o, .d)
+/// - [gc] max_age_days보다 오래 빌드하지 않은 빌드 구성 디렉토리 (기본 구성 제외)
+/// - [gc] max_age_days보다 오래된 미리 컴파일한 헤더 ([pch])
+/// - 기간이 지났거나 [gc] max_cache_size를 넘는 다운로드 캐시 파일 (오래된 것부터)
+/// - cbuild.toml의 [dependencies]에서 빠진 deps/ 아래 의존성
+pub fn gc(context: &ProjectContext, out_dir: Option<&Path>, dry_run: bool) -> BuildResult<()> {
+    let config = context.config()?;
+    let project_dir = context.project_dir();
+    let max_age =
+        Duration::from_secs(config.gc.max_age_days.unwrap_or(DEFAULT_MAX_AGE_DAYS) * 24 * 60 * 60);
+    let max_cache_size = config
+        .gc
+        .max_cache_size
+        .as_deref()
+        .map(parse_memory_size)
+        .transpose()?;
+    let now = SystemTime::now();
+    let mut removals = Vec::new();
+
+    // 빌드 구성 디렉토리와 그 안의 오브젝트 파일
+    let default_configuration = config.default_configuration();
+    let output_root = context
+        .layout(default_configuration, out_dir)
+        .output_root()
+        .to_path_buf();
+    for (name, dir) in configuration_dirs(&output_root) {
+        if name != default_configuration && age(last_build_time(&dir), now) > max_age {
+            removals.push(Removal {
+                size: dir_size(&dir),
+                path: dir,
+                reason: tr!(
+                    "{}일 넘게 빌드하지 않은 구성",
+                    "configuration not built for over {} days",
+                    max_age.as_secs() / (24 * 60 * 60)
+                ),
+            });
+            continue;
+        }
+        removals.extend(orphaned_objects(context, &context.layout(&name, out_dir))?);
+    }
+
+    // 컴파일러나 플래그가 바뀌어 더 이상 쓰지 않는 미리 컴파일한 헤더
+    let pch_dir = context.layout(default_configuration, out_dir).pch_dir();
+    for entry in std::fs::read_dir(&pch_dir).into_iter().flatten().flatten() {
+        let modified = entry.metadata().and_then(|m| m.modified()).ok();
+        if age(modified, now) > max_age {
+            removals.push(Removal {
+                size: dir_size(&entry.path()),
+                path: entry.path(),
+                reason: tr!(
+                    "{}일 넘게 만들지 않은 PCH",
+                    "PCH not rebuilt for over {} days",
+                    max_age.as_secs() / (24 * 60 * 60)
+                ),
+            });
+        }
+    }
+
+    // [dependencies]에서 빠진 의존성
+    let deps_dir = project_dir.join("deps");
+    if let Ok(entries) = std::fs::read_dir(&deps_dir) {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with('.') || config.find_dependency(&name).is_some() {
+                continue;
+            }
+            removals.push(Removal {
+                size: dir_size(&entry.path()),
+                path: entry.path(),
+                reason: tr!(
+                    "cbuild.toml에 없는 의존성",
+                    "dependency no longer in cbuild.toml"
+                ),
+            });
+        }
+    }
+
+    // 다운로드 캐시 (프로젝트 의존성, 툴체인)
+    let downloader = Downloader::from_config(config);
+    let mut cache_dirs = vec![downloader.cache_dir(&deps_dir.join(".downloads"))];
+    if let Ok(toolchains_dir) = provision::toolchains_dir() {
+        let dir = downloader.cache_dir(&toolchains_dir.join(".downloads"));
+        if !cache_dirs.contains(&dir) {
+            cache_dirs.push(dir);
+        }
+    }
+    for dir in &cache_dirs {
+        removals.extend(expired_downloads(dir, now, max_age, max_cache_size));
+    }
+
+    if removals.is_empty() {
+        println!(
+            "{}",
+            tr!("정리할 파일이 없습니다.", "Nothing to collect.").green()
+        );
+        return Ok(());
+    }
+
+    let label = if dry_run { "Would remove" } else { "Removing" };
+    let mut reclaimed = 0;
+    for removal in &removals {
+        println!(
+            "{} {} ({}, {})",
+            label.yellow().bold(),
+            removal
+                .path
+                .strip_prefix(project_dir)
+                .unwrap_or(&removal.path)
+                .display(),
+            format_size(removal.size),
+            removal.reason
+        );
+        if dry_run {
+            continue;
+        }
+
+        let result = if removal.path.is_dir() {
+            std::fs::remove_dir_all(&removal.path)
+        } else {
+            std::fs::remove_file(&removal.path)
+        };
+        result.map_err(BuildError::IoError)?;
+        reclaimed += removal.size;
+    }
+
+    if dry_run {
+        let total: u64 = removals.iter().map(|removal| removal.size).sum();
+        println!(
+            "{}",
+            tr!(
+                "{}개 항목, {}를 확보할 수 있습니다 (--dry-run)",
+                "{} entries, {} can be reclaimed (--dry-run)",
+                removals.len(),
+                format_size(total)
+            )
+        );
+    } else {
+        for (_, dir) in configuration_dirs(&output_root) {
+            remove_empty_dirs(&dir.join("obj"));
+        }
+        println!(
+            "{} {}",
+            tr!("정리 완료", "Collected").green().bold(),
+            tr!(
+                "({}개 항목, {} 확보)",
+                "({} entries, {} reclaimed)",
+                removals.len(),
+                format_size(reclaimed)
+            )
+        );
+    }
+
+    Ok(())
+}
+
+/// 출력 디렉토리 아래의 빌드 구성 디렉토리 (이름, 경로)
+///
+/// 증분 빌드 상태나 오브젝트 디렉토리가 있는 디렉토리만 빌드 구성으로 본다.
+fn configuration_dirs(output_root: &Path) -> Vec<(String, PathBuf)> {
+    let mut dirs: Vec<(String, PathBuf)> = std::fs::read_dir(output_root)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| {
+            (
+                entry.file_name().to_string_lossy().to_string(),
+                entry.path(),
+            )
+        })
+        .filter(|(name, path)| {
+            !name.starts_with('.')
+                && (path.join("state.bin").is_file() || path.join("obj").is_dir())
+        })
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+/// 현재 소스 목록과 생성된 소스에 대응하지 않는 오브젝트와 의존성 파일
+fn orphaned_objects(context: &ProjectContext, layout: &BuildLayout) -> BuildResult<Vec<Removal>> {
+    let project_dir = context.project_dir();
+    let obj_dir = layout.obj_dir();
+    if !obj_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let generated = WalkDir::new(layout.generated_dir())
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.into_path());
+    let mut expected = HashSet::new();
+    for source in context
+        .sources()?
+        .values()
+        .flatten()
+        .cloned()
+        .chain(generated)
+    {
+        let object = layout.object_file(project_dir, &source);
+        expected.insert(object.with_extension("d"));
+        expected.insert(object);
+    }
+
+    Ok(WalkDir::new(&obj_dir)
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry.file_type().is_file())
+        .filter(|entry| matches!(get_extension(entry.path()).as_deref(), Some("o" | "d")))
+        .filter(|entry| !expected.contains(entry.path()))
+        .map(|entry| Removal {
+            size: entry.metadata().map_or(0, |m| m.len()),
+            path: entry.into_path(),
+            reason: tr!("소스가 없는 오브젝트", "object without a source"),
+        })
+        .collect())
+}
+
+/// 기간이 지났거나 크기 제한을 넘는 다운로드 캐시 파일 (오래된 것부터)
+fn expired_downloads(
+    dir: &Path,
+    now: SystemTime,
+    max_age: Duration,
+    max_size: Option<u64>,
+) -> Vec<Removal> {
+    let mut files: Vec<(SystemTime, u64, PathBuf)> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok()?;
+            metadata.is_file().then(|| {
+                (
+                    metadata.modified().unwrap_or(now),
+                    metadata.len(),
+                    entry.path(),
+                )
+            })
+        })
+        .collect();
+    files.sort();
+
+    let mut total: u64 = files.iter().map(|(_, size, _)| size).sum();
+    let mut removals = Vec::new();
+    for (modified, size, path) in files {
+        let reason = if age(Some(modified), now) > max_age {
+            tr!("기간이 지난 다운로드 캐시", "expired download cache entry")
+        } else if max_size.is_some_and(|max| total > max) {
+            tr!(
+                "다운로드 캐시 크기 제한 초과",
+                "download cache over size limit"
+            )
+        } else {
+            continue;
+        };
+        total -= size;
+        removals.push(Removal { path, size, reason });
+    }
+    removals
+}
+
+/// 빌드 구성 디렉토리를 마지막으로 빌드한 시각 (상태 파일, 빌드 요약, 디렉토리 중 최신)
+fn last_build_time(dir: &Path) -> Option<SystemTime> {
+    [
+        dir.to_path_buf(),
+        dir.join("state.bin"),
+        dir.join("summary.json"),
+    ]
+    .iter()
+    .filter_map(|path| std::fs::metadata(path).ok()?.modified().ok())
+    .max()
+}
+
+fn age(time: Option<SystemTime>, now: SystemTime) -> Duration {
+    time.and_then(|time| now.duration_since(time).ok())
+        .unwrap_or_default()
+}
+
+fn dir_size(path: &Path) -> u64 {
+    WalkDir::new(path)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.metadata().ok())
+        .filter(|metadata| metadata.is_file())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+/// 오브젝트를 지우고 남은 빈 디렉토리 제거 (`dir` 자체는 유지)
+fn remove_empty_dirs(dir: &Path) {
+    for entry in WalkDir::new(dir)
+        .min_depth(1)
+        .contents_first(true)
+        .into_iter()
+        .flatten()
+    {
+        if entry.file_type().is_dir() {
+            // 비어 있지 않으면 실패하므로 무시
+            let _ = std::fs::remove_dir(entry.path());
+        }
+    }
+}
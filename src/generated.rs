@@ -0,0 +1,122 @@
+use crate::config::BuildConfig;
+use crate::embed::sanitize_identifier;
+use crate::error::{BuildError, BuildResult};
+use crate::layout::BuildLayout;
+use crate::utils::write_if_changed;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+const NOTICE: &str = "/* cbuild에서 자동 생성된 파일입니다. 수정하지 마세요. */\n";
+
+/// [generated] 설정의 헤더를 build/<구성>/generated에 작성
+///
+/// 내용이 같으면 파일을 다시 쓰지 않으므로, 헤더를 포함한 소스는 내용이 바뀔 때만
+/// 증분 빌드에서 다시 컴파일된다.
+pub fn write_headers(config: &BuildConfig, layout: &BuildLayout) -> BuildResult<()> {
+    let dir = layout.generated_dir();
+    std::fs::create_dir_all(&dir).map_err(BuildError::IoError)?;
+    let settings = &config.generated;
+
+    if settings.version_header.unwrap_or(false) {
+        write_if_changed(&dir.join("version.h"), &render_version_header(config))
+            .map_err(BuildError::IoError)?;
+    }
+
+    if let Some(ref defines) = settings.config {
+        write_if_changed(
+            &dir.join("config.h"),
+            &render_config_header(config, defines),
+        )
+        .map_err(BuildError::IoError)?;
+    }
+
+    if settings.export_headers.unwrap_or(false) {
+        for lib in &config.targets.shared_lib {
+            write_if_changed(
+                &dir.join(format!("{}_export.h", lib.name)),
+                &render_export_header(&lib.name),
+            )
+            .map_err(BuildError::IoError)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// 공유 라이브러리 자신을 컴파일할 때 정의하는 매크로 (export 헤더가 dllexport를 고름)
+pub fn export_building_macro(lib_name: &str) -> String {
+    format!("{}_BUILDING", macro_prefix(lib_name))
+}
+
+fn macro_prefix(name: &str) -> String {
+    sanitize_identifier(name).to_uppercase()
+}
+
+fn render_version_header(config: &BuildConfig) -> String {
+    let prefix = macro_prefix(&config.project.name);
+    let version = &config.project.version;
+    // "1.2.3-beta.1" -> 1, 2, 3 (없거나 숫자가 아닌 부분은 0)
+    let mut numbers = version
+        .split(['-', '+'])
+        .next()
+        .unwrap_or_default()
+        .split('.')
+        .map(|part| part.parse::<u64>().unwrap_or(0));
+
+    let mut out = String::from(NOTICE);
+    let _ = writeln!(
+        out,
+        "#ifndef {0}_VERSION_H\n#define {0}_VERSION_H\n",
+        prefix
+    );
+    let _ = writeln!(out, "#define {}_VERSION \"{}\"", prefix, version);
+    for name in ["MAJOR", "MINOR", "PATCH"] {
+        let _ = writeln!(
+            out,
+            "#define {}_VERSION_{} {}",
+            prefix,
+            name,
+            numbers.next().unwrap_or(0)
+        );
+    }
+    let _ = writeln!(out, "\n#endif /* {}_VERSION_H */", prefix);
+    out
+}
+
+fn render_config_header(config: &BuildConfig, defines: &BTreeMap<String, String>) -> String {
+    let guard = format!("{}_CONFIG_H", macro_prefix(&config.project.name));
+    let mut out = String::from(NOTICE);
+    let _ = writeln!(out, "#ifndef {0}\n#define {0}\n", guard);
+    for (key, value) in defines {
+        if value.is_empty() {
+            let _ = writeln!(out, "#define {}", key);
+        } else {
+            let _ = writeln!(out, "#define {} {}", key, value);
+        }
+    }
+    let _ = writeln!(out, "\n#endif /* {} */", guard);
+    out
+}
+
+fn render_export_header(lib_name: &str) -> String {
+    let prefix = macro_prefix(lib_name);
+    let mut out = String::from(NOTICE);
+    let _ = writeln!(out, "#ifndef {0}_EXPORT_H\n#define {0}_EXPORT_H\n", prefix);
+    let _ = writeln!(
+        out,
+        "#if defined(_WIN32) || defined(__CYGWIN__)\n\
+         #  ifdef {0}_BUILDING\n\
+         #    define {0}_EXPORT __declspec(dllexport)\n\
+         #  else\n\
+         #    define {0}_EXPORT __declspec(dllimport)\n\
+         #  endif\n\
+         #elif defined(__GNUC__) && __GNUC__ >= 4\n\
+         #  define {0}_EXPORT __attribute__((visibility(\"default\")))\n\
+         #else\n\
+         #  define {0}_EXPORT\n\
+         #endif",
+        prefix
+    );
+    let _ = writeln!(out, "\n#endif /* {}_EXPORT_H */", prefix);
+    out
+}
@@ -0,0 +1,102 @@
+use crate::builder::Builder;
+use crate::context::ProjectContext;
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use crate::ide::include_dirs;
+use crate::parser::DependencyParser;
+use colored::Colorize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// `cbuild graph`: 소스와 헤더의 포함 관계 출력
+///
+/// 기본으로는 각 파일이 직접 포함하는 프로젝트 헤더를 나열하고,
+/// `cycles_only`이면 순환 포함 경로만 출력한다. 순환이 있으면 오류를 반환한다.
+pub fn print_include_graph(
+    context: Arc<ProjectContext>,
+    configuration: &str,
+    out_dir: Option<PathBuf>,
+    cycles_only: bool,
+) -> BuildResult<()> {
+    let project_dir = context.project_dir();
+    let mut builder = Builder::with_context(context.clone(), configuration, 1)?;
+    builder.set_output_dir(out_dir);
+    let targets = builder.compile_info()?;
+
+    let parser = DependencyParser::new();
+    let mut edges: BTreeMap<PathBuf, BTreeSet<PathBuf>> = BTreeMap::new();
+
+    for target in &targets {
+        let search_dirs = include_dirs(target);
+
+        for source in &target.sources {
+            // 전체 탐색으로 순환 기록
+            parser.parse_dependencies(source, &search_dirs)?;
+
+            if cycles_only {
+                continue;
+            }
+
+            // 소스에서 도달하는 파일마다 직접 포함 관계 수집
+            let mut pending = vec![source.clone()];
+            while let Some(file) = pending.pop() {
+                if edges.contains_key(&file) {
+                    continue;
+                }
+                let headers = parser.direct_dependencies(&file, &search_dirs)?;
+                pending.extend(headers.iter().cloned());
+                edges.insert(file, headers.into_iter().collect());
+            }
+        }
+    }
+
+    let relative = |path: &Path| {
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        let root = project_dir
+            .canonicalize()
+            .unwrap_or_else(|_| project_dir.to_path_buf());
+        path.strip_prefix(&root)
+            .unwrap_or(&path)
+            .display()
+            .to_string()
+    };
+
+    if !cycles_only {
+        for (file, headers) in &edges {
+            println!("{}", relative(file));
+            for header in headers {
+                println!("  -> {}", relative(header));
+            }
+        }
+    }
+
+    let cycles = parser.cycles();
+    if cycles.is_empty() {
+        if cycles_only {
+            println!("{}", tr!("순환 포함이 없습니다.", "No include cycles."));
+        }
+        return Ok(());
+    }
+
+    if !cycles_only {
+        println!();
+    }
+    println!("{}", tr!("순환 포함:", "Include cycles:").red().bold());
+    for cycle in &cycles {
+        println!(
+            "  {}",
+            cycle
+                .iter()
+                .map(|p| relative(p))
+                .collect::<Vec<_>>()
+                .join(" -> ")
+        );
+    }
+
+    Err(BuildError::DependencyError(tr!(
+        "순환 포함 {}개 발견",
+        "include cycles found: {}",
+        cycles.len()
+    )))
+}
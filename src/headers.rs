@@ -0,0 +1,292 @@
+use crate::builder::{Builder, TargetCompileInfo};
+use crate::context::ProjectContext;
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use crate::ide::include_dirs;
+use crate::parser::DependencyParser;
+use crate::utils::{collect_files_with_extension, get_common_header_extensions, tool_command};
+use colored::Colorize;
+use lazy_static::lazy_static;
+use rayon::prelude::*;
+use regex::Regex;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::Arc;
+
+/// 헤더 하나의 검사 결과
+struct HeaderReport {
+    path: PathBuf,
+    /// 단독 컴파일 실패 시 컴파일러 출력
+    compile_error: Option<String>,
+    has_guard: bool,
+    /// 전이적으로 포함하는 프로젝트 헤더 수
+    transitive_includes: usize,
+}
+
+/// `cbuild headers check`: 공개 헤더 위생 검사
+///
+/// 타겟의 포함 디렉토리에 있는 프로젝트 헤더마다 해당 헤더 하나만 포함한
+/// 번역 단위를 컴파일해 단독으로 컴파일되는지 확인하고, include guard나
+/// `#pragma once`가 없는 헤더와 다른 헤더를 가장 많이 끌어오는 헤더를 보고한다.
+/// 단독 컴파일 실패나 guard 누락이 있으면 오류를 반환한다.
+pub fn check_headers(
+    context: Arc<ProjectContext>,
+    configuration: &str,
+    out_dir: Option<PathBuf>,
+    top: usize,
+) -> BuildResult<()> {
+    let project_dir = context.project_dir();
+    let mut builder = Builder::with_context(context.clone(), configuration, 1)?;
+    builder.set_output_dir(out_dir);
+    let targets = builder.compile_info()?;
+    let config = builder.config();
+    let compiler = config.build.compiler.clone();
+    let cpp_standard = config.build.cpp_standard.clone();
+
+    // 검사할 헤더와 헤더가 속한 타겟 (의존성과 빌드 출력 디렉토리는 제외)
+    let excluded = [
+        project_dir.join("deps"),
+        builder.layout().output_root().to_path_buf(),
+    ];
+    let mut headers: Vec<(PathBuf, &TargetCompileInfo)> = Vec::new();
+    for target in &targets {
+        for dir in include_dirs(target) {
+            if !dir.starts_with(project_dir) || excluded.iter().any(|ex| dir.starts_with(ex)) {
+                continue;
+            }
+            for header in collect_files_with_extension(&dir, &get_common_header_extensions()) {
+                if !headers.iter().any(|(path, _)| *path == header) {
+                    headers.push((header, target));
+                }
+            }
+        }
+    }
+    headers.sort_by(|a, b| a.0.cmp(&b.0));
+
+    if headers.is_empty() {
+        println!("{}", tr!("검사할 헤더가 없습니다.", "No headers to check."));
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        tr!("헤더 {}개 검사 중", "Checking headers: {}", headers.len())
+            .blue()
+            .bold()
+    );
+
+    let parser = DependencyParser::new();
+    let reports: Vec<HeaderReport> = headers
+        .par_iter()
+        .map(|(header, target)| {
+            let content = std::fs::read_to_string(header).unwrap_or_default();
+            let search_dirs = include_dirs(target);
+
+            HeaderReport {
+                path: header.clone(),
+                compile_error: compile_standalone(
+                    &compiler,
+                    header,
+                    target,
+                    cpp_standard.as_deref(),
+                    project_dir,
+                ),
+                has_guard: has_include_guard(&content),
+                transitive_includes: parser
+                    .parse_dependencies(header, &search_dirs)
+                    .map(|deps| deps.len())
+                    .unwrap_or(0),
+            }
+        })
+        .collect();
+
+    let relative = |path: &Path| {
+        path.strip_prefix(project_dir)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    };
+
+    // 단독 컴파일 실패
+    let not_self_contained: Vec<&HeaderReport> = reports
+        .iter()
+        .filter(|r| r.compile_error.is_some())
+        .collect();
+    for report in &not_self_contained {
+        println!(
+            "{} {}",
+            "FAIL".red(),
+            tr!(
+                "{}: 단독으로 컴파일되지 않습니다",
+                "{}: does not compile on its own",
+                relative(&report.path)
+            )
+        );
+        for line in report
+            .compile_error
+            .as_deref()
+            .unwrap_or("")
+            .lines()
+            .take(5)
+        {
+            println!("    {}", line);
+        }
+    }
+
+    // include guard 누락
+    let missing_guard: Vec<&HeaderReport> = reports.iter().filter(|r| !r.has_guard).collect();
+    for report in &missing_guard {
+        println!(
+            "{} {}",
+            "WARN".yellow(),
+            tr!(
+                "{}: include guard 또는 #pragma once가 없습니다",
+                "{}: no include guard or #pragma once",
+                relative(&report.path)
+            )
+        );
+    }
+
+    // 전이적 포함이 많은 헤더
+    let mut heaviest: Vec<&HeaderReport> = reports
+        .iter()
+        .filter(|r| r.transitive_includes > 0)
+        .collect();
+    heaviest.sort_by(|a, b| {
+        b.transitive_includes
+            .cmp(&a.transitive_includes)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    if !heaviest.is_empty() {
+        println!(
+            "\n{}",
+            tr!(
+                "전이적으로 포함하는 헤더가 많은 헤더:",
+                "Headers with the most transitive includes:"
+            )
+            .bold()
+        );
+        for report in heaviest.iter().take(top) {
+            println!(
+                "  {:>4}  {}",
+                report.transitive_includes,
+                relative(&report.path)
+            );
+        }
+    }
+
+    println!(
+        "\n{}",
+        tr!(
+            "헤더 {}개: 단독 컴파일 실패 {}개, guard 누락 {}개",
+            "headers: {}, not self-contained: {}, missing guards: {}",
+            reports.len(),
+            not_self_contained.len(),
+            missing_guard.len()
+        )
+    );
+
+    if !not_self_contained.is_empty() || !missing_guard.is_empty() {
+        return Err(BuildError::CompilerError(tr!(
+            "단독 컴파일 실패 {}개, guard 누락 {}개",
+            "not self-contained: {}, missing guards: {}",
+            not_self_contained.len(),
+            missing_guard.len()
+        )));
+    }
+
+    Ok(())
+}
+
+/// 헤더 하나만 포함한 번역 단위를 문법 검사만 하도록 컴파일 (실패 시 컴파일러 출력)
+fn compile_standalone(
+    compiler: &str,
+    header: &Path,
+    target: &TargetCompileInfo,
+    cpp_standard: Option<&str>,
+    project_dir: &Path,
+) -> Option<String> {
+    let is_cpp = header.extension().and_then(|e| e.to_str()) != Some("h");
+
+    let mut cmd = tool_command(compiler);
+    cmd.current_dir(project_dir)
+        .arg("-fsyntax-only")
+        .arg("-x")
+        .arg(if is_cpp { "c++" } else { "c" });
+
+    for flag in &target.flags {
+        // C++ 헤더에는 C 표준 대신 C++ 표준 적용
+        if is_cpp && flag.starts_with("-std=") {
+            continue;
+        }
+        cmd.arg(flag);
+    }
+    if is_cpp {
+        if let Some(cpp_std) = cpp_standard {
+            cmd.arg(format!("-std={}", cpp_std));
+        }
+    }
+
+    cmd.arg("-")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    let mut child = match cmd.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            return Some(tr!(
+                "컴파일러 실행 실패: {}",
+                "failed to run compiler: {}",
+                e
+            ))
+        }
+    };
+
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = writeln!(stdin, "#include \"{}\"", header.to_string_lossy());
+    }
+
+    match child.wait_with_output() {
+        Ok(output) if output.status.success() => None,
+        Ok(output) => Some(String::from_utf8_lossy(&output.stderr).to_string()),
+        Err(e) => Some(e.to_string()),
+    }
+}
+
+/// include guard(`#ifndef X` / `#define X` ... `#endif`) 또는 `#pragma once` 확인
+fn has_include_guard(content: &str) -> bool {
+    lazy_static! {
+        static ref COMMENT_RE: Regex = Regex::new(r"(?s)/\*.*?\*/|//[^\n]*").unwrap();
+        static ref PRAGMA_ONCE_RE: Regex = Regex::new(r"^#\s*pragma\s+once\b").unwrap();
+        static ref IFNDEF_RE: Regex =
+            Regex::new(r"^#\s*(?:ifndef\s+(\w+)|if\s+!\s*defined\s*\(?\s*(\w+)\s*\)?)").unwrap();
+        static ref DEFINE_RE: Regex = Regex::new(r"^#\s*define\s+(\w+)").unwrap();
+        static ref ENDIF_RE: Regex = Regex::new(r"^#\s*endif\b").unwrap();
+    }
+
+    let stripped = COMMENT_RE.replace_all(content, "");
+    let lines: Vec<&str> = stripped
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .collect();
+
+    if lines.iter().any(|line| PRAGMA_ONCE_RE.is_match(line)) {
+        return true;
+    }
+
+    let (Some(first), Some(second), Some(last)) = (lines.first(), lines.get(1), lines.last())
+    else {
+        return false;
+    };
+
+    let guard = IFNDEF_RE
+        .captures(first)
+        .and_then(|cap| cap.get(1).or_else(|| cap.get(2)))
+        .map(|m| m.as_str());
+    let defined = DEFINE_RE.captures(second).map(|cap| cap[1].to_string());
+
+    matches!((guard, defined), (Some(g), Some(d)) if g == d) && ENDIF_RE.is_match(last)
+}
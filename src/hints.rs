@@ -0,0 +1,288 @@
+use crate::error::{BuildError, CommandFailure};
+use crate::i18n::tr;
+use crate::tools::{is_zig, RequiredTool, ToolKind};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// 오류를 해결하는 방법 제안 (제안할 내용이 없으면 None)
+pub fn hint(error: &BuildError) -> Option<String> {
+    match error {
+        BuildError::CompilerNotFound(compiler) => Some(install_hint(compiler)),
+        BuildError::ToolsNotFound(tools) => Some(
+            tools
+                .iter()
+                .map(tool_install_hint)
+                .collect::<Vec<_>>()
+                .join("; "),
+        ),
+        BuildError::OpenMpNotFound(compiler) => Some(openmp_hint(compiler)),
+        BuildError::ToolchainNotInstalled(name) => Some(tr!(
+            "`cbuild toolchain install {}`로 설치하세요 (cbuild.toml의 [toolchains.{}]에 정의 필요)",
+            "install it with `cbuild toolchain install {}` (requires [toolchains.{}] in cbuild.toml)",
+            name,
+            name
+        )),
+        BuildError::ConfigNotFound(_) => Some(tr!(
+            "현재 디렉토리와 상위 디렉토리에 cbuild.toml이 없습니다. `cbuild init`으로 프로젝트를 만들거나 -d로 프로젝트 디렉토리를 지정하세요",
+            "no cbuild.toml in the current directory or its parents; run `cbuild init` to create a project, or pass -d with the project directory"
+        )),
+        BuildError::NoSourceFiles(target) => Some(tr!(
+            "cbuild.toml에서 {}의 src 패턴이 실제 파일과 일치하는지 확인하세요",
+            "check that the src patterns of {} in cbuild.toml match existing files",
+            target
+        )),
+        BuildError::ExecutableNotFound(_) => Some(tr!(
+            "`cbuild build`로 먼저 빌드하세요",
+            "build it first with `cbuild build`"
+        )),
+        BuildError::TargetNotFound(_) => Some(tr!(
+            "cbuild.toml의 [[targets.*]] 항목에 있는 name을 확인하세요",
+            "check the name fields of the [[targets.*]] entries in cbuild.toml"
+        )),
+        BuildError::RunAliasNotFound(alias) => Some(tr!(
+            "cbuild.toml에 [run.aliases.{}] 항목을 추가하세요",
+            "add a [run.aliases.{}] table to cbuild.toml",
+            alias
+        )),
+        BuildError::SourceNotInTarget(_) => Some(tr!(
+            "파일이 어떤 타겟의 src 패턴에 포함되도록 cbuild.toml을 수정하세요",
+            "add the file to the src patterns of a target in cbuild.toml"
+        )),
+        BuildError::IncludePolicyViolation(_) => Some(tr!(
+            "헤더가 있는 디렉토리를 타겟의 include_dirs에 추가하거나 헤더를 선언한 디렉토리로 옮기세요",
+            "add the header's directory to the target's include_dirs or move the header into a declared directory"
+        )),
+        BuildError::CompileFailed(failure) => compile_hint(failure),
+        BuildError::LinkerError(failure) => link_hint(failure),
+        _ => None,
+    }
+}
+
+/// 컴파일러 설치 방법 제안
+fn install_hint(compiler: &str) -> String {
+    tr!(
+        "{}을(를) 설치하거나 ({}) cbuild.toml의 build.compiler를 설치된 컴파일러로 바꾸세요",
+        "install {} ({}) or set build.compiler in cbuild.toml to an installed compiler",
+        compiler,
+        install_command(compiler, ToolKind::Compiler)
+    )
+}
+
+/// OpenMP 런타임 설치 방법 제안
+fn openmp_hint(compiler: &str) -> String {
+    if cfg!(target_os = "macos") {
+        tr!(
+            "Apple clang은 별도의 libomp가 필요합니다: brew install libomp (또는 build.compiler를 gcc로 변경)",
+            "Apple clang needs a separate libomp: brew install libomp (or set build.compiler to gcc)"
+        )
+    } else if compiler.contains("clang") {
+        tr!(
+            "LLVM OpenMP 런타임을 설치하세요 (예: sudo apt install libomp-dev, sudo dnf install libomp-devel)",
+            "install the LLVM OpenMP runtime (e.g. sudo apt install libomp-dev, sudo dnf install libomp-devel)"
+        )
+    } else {
+        tr!(
+            "{}의 OpenMP 런타임(libgomp)을 설치하거나 cbuild.toml에서 openmp를 끄세요",
+            "install the OpenMP runtime (libgomp) for {} or turn off openmp in cbuild.toml",
+            compiler
+        )
+    }
+}
+
+/// 빌드 도구 설치 방법 제안
+fn tool_install_hint(tool: &RequiredTool) -> String {
+    match tool.kind {
+        ToolKind::Compiler => install_hint(&tool.program),
+        ToolKind::ContainerEngine => tr!(
+            "docker 또는 podman을 설치하거나 ({}) CBUILD_CONTAINER_ENGINE으로 엔진을 지정하세요",
+            "install docker or podman ({}) or choose an engine with CBUILD_CONTAINER_ENGINE",
+            install_command(&tool.program, tool.kind)
+        ),
+        ToolKind::Archiver | ToolKind::ProfileMerger => tr!(
+            "{}을(를) 설치하세요 ({})",
+            "install {} ({})",
+            tool.program,
+            install_command(&tool.program, tool.kind)
+        ),
+    }
+}
+
+/// 운영체제와 배포판에 맞는 설치 명령
+fn install_command(program: &str, kind: ToolKind) -> String {
+    let is_clang = program.contains("clang");
+    let is_cpp = program.contains("++");
+
+    // zig는 배포판 패키지가 오래된 경우가 많아 공식 배포본을 안내
+    if is_zig(program) && kind != ToolKind::ContainerEngine {
+        return if cfg!(target_os = "macos") {
+            "brew install zig".to_string()
+        } else {
+            tr!(
+                "https://ziglang.org/download 에서 받아 PATH에 추가",
+                "download from https://ziglang.org/download and add it to PATH"
+            )
+        };
+    }
+
+    if cfg!(target_os = "macos") {
+        match kind {
+            ToolKind::Compiler if !is_clang => "brew install gcc".to_string(),
+            ToolKind::Compiler | ToolKind::Archiver | ToolKind::ProfileMerger => {
+                "xcode-select --install".to_string()
+            }
+            ToolKind::ContainerEngine => "brew install --cask docker".to_string(),
+        }
+    } else if cfg!(target_os = "windows") {
+        match kind {
+            ToolKind::Compiler if is_clang => "winget install LLVM.LLVM".to_string(),
+            ToolKind::Compiler => {
+                "winget install MSYS2.MSYS2 && pacman -S mingw-w64-ucrt-x86_64-gcc".to_string()
+            }
+            ToolKind::Archiver => {
+                "winget install MSYS2.MSYS2 && pacman -S mingw-w64-ucrt-x86_64-binutils".to_string()
+            }
+            ToolKind::ContainerEngine => "winget install Docker.DockerDesktop".to_string(),
+            ToolKind::ProfileMerger => "winget install LLVM.LLVM".to_string(),
+        }
+    } else {
+        linux_install_command(kind, is_clang, is_cpp)
+    }
+}
+
+/// /etc/os-release의 배포판 정보로 패키지 관리자 명령 결정
+fn linux_install_command(kind: ToolKind, is_clang: bool, is_cpp: bool) -> String {
+    let os_release = std::fs::read_to_string("/etc/os-release").unwrap_or_default();
+    let ids: Vec<&str> = os_release
+        .lines()
+        .filter_map(|line| {
+            line.strip_prefix("ID=")
+                .or_else(|| line.strip_prefix("ID_LIKE="))
+        })
+        .flat_map(|value| value.trim_matches('"').split_whitespace())
+        .collect();
+    let is = |id: &str| ids.contains(&id);
+    // 아카이버(ar), 컨테이너 엔진, llvm-profdata는 모든 배포판에서 패키지 이름이 같다
+    let common = match kind {
+        ToolKind::Compiler => None,
+        ToolKind::Archiver => Some("binutils"),
+        ToolKind::ContainerEngine => Some("podman"),
+        ToolKind::ProfileMerger => Some("llvm"),
+    };
+
+    if is("debian") || is("ubuntu") {
+        let package = match (common, is_clang) {
+            (Some(package), _) => package,
+            (None, true) => "clang",
+            (None, false) => "build-essential",
+        };
+        format!("sudo apt install {}", package)
+    } else if is("fedora") || is("rhel") || is("centos") {
+        let package = match (common, is_clang, is_cpp) {
+            (Some(package), _, _) => package,
+            (None, true, _) => "clang",
+            (None, false, true) => "gcc-c++",
+            (None, false, false) => "gcc",
+        };
+        format!("sudo dnf install {}", package)
+    } else if is("arch") {
+        let package = match (common, is_clang) {
+            (Some(package), _) => package,
+            (None, true) => "clang",
+            (None, false) => "gcc",
+        };
+        format!("sudo pacman -S {}", package)
+    } else if is("alpine") {
+        let package = match (common, is_clang) {
+            (Some(package), _) => package,
+            (None, true) => "clang",
+            (None, false) => "build-base",
+        };
+        format!("sudo apk add {}", package)
+    } else if is("opensuse") || is("suse") {
+        let package = match (common, is_clang, is_cpp) {
+            (Some(package), _, _) => package,
+            (None, true, _) => "clang",
+            (None, false, true) => "gcc-c++",
+            (None, false, false) => "gcc",
+        };
+        format!("sudo zypper install {}", package)
+    } else {
+        tr!(
+            "배포판의 패키지 관리자로 설치",
+            "use your distribution's package manager"
+        )
+    }
+}
+
+/// 컴파일러 출력으로 원인 추정
+fn compile_hint(failure: &CommandFailure) -> Option<String> {
+    lazy_static! {
+        static ref MISSING_HEADER_RE: Regex =
+            Regex::new(r"fatal error: ([^:\s]+): No such file or directory|fatal error: '([^']+)' file not found")
+                .unwrap();
+        static ref UNKNOWN_OPTION_RE: Regex =
+            Regex::new(r"unrecognized command[- ]line option '([^']+)'|unknown argument: '([^']+)'")
+                .unwrap();
+    }
+
+    if let Some(cap) = MISSING_HEADER_RE.captures(&failure.stderr) {
+        let header = cap.get(1).or_else(|| cap.get(2)).unwrap().as_str();
+        return Some(tr!(
+            "{}이(가) 있는 디렉토리를 {}의 include_dirs에 추가하거나, 외부 라이브러리라면 `cbuild dependencies`로 설치하세요",
+            "add the directory containing {} to include_dirs of {}, or install the library with `cbuild dependencies`",
+            header,
+            failure.target
+        ));
+    }
+
+    if let Some(cap) = UNKNOWN_OPTION_RE.captures(&failure.stderr) {
+        let option = cap.get(1).or_else(|| cap.get(2)).unwrap().as_str();
+        return Some(tr!(
+            "컴파일러가 {} 옵션을 지원하지 않습니다. build.extra_flags에서 제거하거나 컴파일러를 업데이트하세요",
+            "the compiler does not support {}; remove it from build.extra_flags or upgrade the compiler",
+            option
+        ));
+    }
+
+    None
+}
+
+/// 링커 출력으로 원인 추정
+fn link_hint(failure: &CommandFailure) -> Option<String> {
+    lazy_static! {
+        static ref MISSING_LIB_RE: Regex =
+            Regex::new(r"cannot find -l([^\s:]+)|library not found for -l([^\s:]+)").unwrap();
+        static ref UNDEFINED_RE: Regex =
+            Regex::new(r"undefined reference to [`']([^']+)'|Undefined symbols for architecture")
+                .unwrap();
+    }
+
+    if let Some(cap) = MISSING_LIB_RE.captures(&failure.stderr) {
+        let lib = cap.get(1).or_else(|| cap.get(2)).unwrap().as_str();
+        return Some(tr!(
+            "lib{}이(가) 있는 디렉토리를 {}의 link_dirs에 추가하거나 libs에서 이름을 확인하세요",
+            "add the directory containing lib{} to link_dirs of {}, or check the name in libs",
+            lib,
+            failure.target
+        ));
+    }
+
+    if let Some(cap) = UNDEFINED_RE.captures(&failure.stderr) {
+        let symbol = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+        return Some(tr!(
+            "정의되지 않은 심볼 {}: 정의한 소스가 src 패턴에 포함되어 있는지, 필요한 라이브러리가 {}의 libs에 있는지 확인하세요",
+            "undefined symbol {}: make sure the defining source is in the src patterns and the library is listed in libs of {}",
+            symbol,
+            failure.target
+        ));
+    }
+
+    if failure.stderr.contains("multiple definition of") {
+        return Some(tr!(
+            "헤더에 정의한 함수나 변수는 static inline 또는 extern 선언으로 바꾸세요",
+            "functions or variables defined in headers should be static inline or declared extern"
+        ));
+    }
+
+    None
+}
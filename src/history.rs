@@ -0,0 +1,325 @@
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use crate::layout::BuildLayout;
+use crate::utils::hash_file_content;
+use crate::vcs::head_commit;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use walkdir::WalkDir;
+
+/// build/.history에 보관하는 기본 빌드 기록 수
+pub const DEFAULT_RETENTION: usize = 50;
+
+/// 빌드 한 번의 기록 (`build/.history/<id>.json`)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BuildManifest {
+    pub id: u64,
+    /// 빌드 완료 시각 (UNIX 초)
+    pub timestamp: u64,
+    pub configuration: String,
+    pub git_commit: Option<String>,
+    pub duration_secs: f32,
+    /// 출력 디렉토리 기준 경로 -> 결과물 정보
+    pub artifacts: BTreeMap<String, ArtifactRecord>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ArtifactRecord {
+    pub size: u64,
+    pub sha256: String,
+}
+
+/// 빌드 결과물(lib, bin)의 해시와 크기를 매니페스트로 기록
+///
+/// 기록한 뒤 가장 최근 `retention`개만 남기고 오래된 매니페스트는 삭제한다.
+pub fn record_build(
+    layout: &BuildLayout,
+    project_dir: &Path,
+    configuration: &str,
+    duration_secs: f32,
+    retention: usize,
+) -> BuildResult<BuildManifest> {
+    let history_dir = layout.history_dir();
+    std::fs::create_dir_all(&history_dir).map_err(BuildError::IoError)?;
+
+    let mut artifacts = BTreeMap::new();
+    for dir in [layout.lib_dir(), layout.bin_dir()] {
+        for entry in WalkDir::new(&dir).into_iter().flatten() {
+            if !entry.file_type().is_file() {
+                continue;
+            }
+
+            let path = entry.path();
+            let name = path
+                .strip_prefix(layout.output_root())
+                .unwrap_or(path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+            let sha256 = hash_file_content(path).map_err(BuildError::IoError)?;
+
+            artifacts.insert(name, ArtifactRecord { size, sha256 });
+        }
+    }
+
+    let id = manifest_ids(layout)?.last().map_or(1, |(id, _)| id + 1);
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let manifest = BuildManifest {
+        id,
+        timestamp,
+        configuration: configuration.to_string(),
+        git_commit: head_commit(project_dir),
+        duration_secs,
+        artifacts,
+    };
+
+    let content = serde_json::to_string_pretty(&manifest)
+        .map_err(|e| BuildError::ConfigParsingError(e.to_string()))?;
+    std::fs::write(manifest_path(layout, id), content).map_err(BuildError::IoError)?;
+    prune(layout, retention)?;
+
+    Ok(manifest)
+}
+
+/// 가장 최근 `retention`개(최소 1개)를 넘는 오래된 매니페스트 삭제
+fn prune(layout: &BuildLayout, retention: usize) -> BuildResult<()> {
+    let manifests = manifest_ids(layout)?;
+    let excess = manifests.len().saturating_sub(retention.max(1));
+    for (_, path) in manifests.iter().take(excess) {
+        std::fs::remove_file(path).map_err(BuildError::IoError)?;
+    }
+    Ok(())
+}
+
+/// 저장된 매니페스트의 id와 경로 (id 순서, 파일 이름으로 판단해 내용은 읽지 않음)
+fn manifest_ids(layout: &BuildLayout) -> BuildResult<Vec<(u64, PathBuf)>> {
+    let history_dir = layout.history_dir();
+    if !history_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut ids = Vec::new();
+    for entry in std::fs::read_dir(&history_dir).map_err(BuildError::IoError)? {
+        let path = entry.map_err(BuildError::IoError)?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        if let Some(id) = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.parse::<u64>().ok())
+        {
+            ids.push((id, path));
+        }
+    }
+
+    ids.sort();
+    Ok(ids)
+}
+
+/// 저장된 매니페스트를 id 순서로 로드 (읽을 수 없는 파일은 건너뜀)
+pub fn list_manifests(layout: &BuildLayout) -> BuildResult<Vec<BuildManifest>> {
+    let history_dir = layout.history_dir();
+    if !history_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut manifests = Vec::new();
+    for entry in std::fs::read_dir(&history_dir).map_err(BuildError::IoError)? {
+        let path = entry.map_err(BuildError::IoError)?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+
+        if let Some(manifest) = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<BuildManifest>(&content).ok())
+        {
+            manifests.push(manifest);
+        }
+    }
+
+    manifests.sort_by_key(|m| m.id);
+    Ok(manifests)
+}
+
+/// `cbuild history`: 이전 빌드 목록 출력
+pub fn print_history(layout: &BuildLayout, limit: Option<usize>) -> BuildResult<()> {
+    let manifests = list_manifests(layout)?;
+
+    if manifests.is_empty() {
+        println!("{}", tr!("빌드 기록이 없습니다.", "No build history."));
+        return Ok(());
+    }
+
+    let skip = limit.map_or(0, |limit| manifests.len().saturating_sub(limit));
+
+    println!(
+        "{:>5}  {:<20}  {:<10}  {:<12}  {:>8}  {:>10}",
+        "ID",
+        tr!("시각 (UTC)", "Time (UTC)"),
+        tr!("구성", "Config"),
+        tr!("커밋", "Commit"),
+        tr!("결과물", "Artifacts"),
+        tr!("소요 시간", "Duration")
+    );
+    for manifest in manifests.iter().skip(skip) {
+        let commit = manifest
+            .git_commit
+            .as_deref()
+            .map(|c| &c[..c.len().min(12)])
+            .unwrap_or("-");
+        println!(
+            "{:>5}  {:<20}  {:<10}  {:<12}  {:>8}  {:>9.2}s",
+            manifest.id,
+            format_timestamp(manifest.timestamp),
+            manifest.configuration,
+            commit,
+            manifest.artifacts.len(),
+            manifest.duration_secs
+        );
+    }
+
+    Ok(())
+}
+
+/// `cbuild diff-artifacts`: 두 빌드의 결과물 크기와 해시 비교
+///
+/// `against`가 없으면 가장 최근 빌드와 비교한다.
+pub fn diff_artifacts(layout: &BuildLayout, id: u64, against: Option<u64>) -> BuildResult<()> {
+    let manifests = list_manifests(layout)?;
+
+    let find = |id: u64| {
+        manifests.iter().find(|m| m.id == id).ok_or_else(|| {
+            BuildError::PathError(tr!(
+                "빌드 기록 {}을(를) 찾을 수 없습니다",
+                "build {} not found in history",
+                id
+            ))
+        })
+    };
+
+    let old = find(id)?;
+    let new = match against {
+        Some(against) => find(against)?,
+        None => manifests.last().ok_or_else(|| {
+            BuildError::PathError(tr!("빌드 기록이 없습니다", "no build history"))
+        })?,
+    };
+
+    println!(
+        "{}",
+        tr!("빌드 {} -> 빌드 {}", "build {} -> build {}", old.id, new.id)
+    );
+
+    let mut names: Vec<&String> = old.artifacts.keys().chain(new.artifacts.keys()).collect();
+    names.sort();
+    names.dedup();
+
+    let mut unchanged = 0;
+    for name in names {
+        match (old.artifacts.get(name), new.artifacts.get(name)) {
+            (Some(before), Some(after)) if before == after => unchanged += 1,
+            (Some(before), Some(after)) => {
+                let delta = after.size as i64 - before.size as i64;
+                let delta = if delta > 0 {
+                    format!("+{}", delta).red()
+                } else {
+                    delta.to_string().green()
+                };
+                println!(
+                    "  {} {}: {}",
+                    "~".yellow(),
+                    name,
+                    tr!(
+                        "{} -> {} 바이트 ({})",
+                        "{} -> {} bytes ({})",
+                        before.size,
+                        after.size,
+                        delta
+                    )
+                );
+            }
+            (None, Some(after)) => {
+                println!(
+                    "  {} {}: {}",
+                    "+".green(),
+                    name,
+                    tr!("{} 바이트", "{} bytes", after.size)
+                )
+            }
+            (Some(before), None) => {
+                println!(
+                    "  {} {}: {}",
+                    "-".red(),
+                    name,
+                    tr!("{} 바이트", "{} bytes", before.size)
+                )
+            }
+            (None, None) => {}
+        }
+    }
+
+    println!("{}", tr!("변경 없음: {}개", "Unchanged: {}", unchanged));
+    Ok(())
+}
+
+fn manifest_path(layout: &BuildLayout, id: u64) -> PathBuf {
+    layout.history_dir().join(format!("{}.json", id))
+}
+
+/// UNIX 초를 `YYYY-MM-DD HH:MM:SS` 형식(UTC)으로 변환
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = (timestamp / 86400) as i64;
+    let secs = timestamp % 86400;
+
+    // 그레고리력 날짜 계산 (days from civil의 역변환)
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_only_recent_manifests() {
+        let tmp = tempfile::tempdir().unwrap();
+        let layout = BuildLayout::new(tmp.path(), "debug", None, None);
+        std::fs::create_dir_all(layout.bin_dir()).unwrap();
+        std::fs::write(layout.bin_dir().join("app"), "binary").unwrap();
+
+        for _ in 0..4 {
+            record_build(&layout, tmp.path(), "debug", 0.5, 2).unwrap();
+        }
+
+        let manifests = list_manifests(&layout).unwrap();
+        let ids: Vec<u64> = manifests.iter().map(|m| m.id).collect();
+        assert_eq!(ids, vec![3, 4]);
+        assert!(manifests[1].artifacts.contains_key("debug/bin/app"));
+    }
+}
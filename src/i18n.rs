@@ -0,0 +1,70 @@
+use std::sync::OnceLock;
+
+/// 사용자에게 보여주는 메시지 언어
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lang {
+    Ko,
+    En,
+}
+
+impl Lang {
+    /// LC_ALL, LC_MESSAGES, LANG 순서로 언어 결정
+    ///
+    /// 설정되지 않았거나 C/POSIX 로캘이면 기존 출력과 같은 한국어를 사용한다.
+    pub fn from_env() -> Self {
+        let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .unwrap_or_default();
+
+        if locale.is_empty() || locale == "C" || locale == "POSIX" || locale.starts_with("ko") {
+            Lang::Ko
+        } else {
+            Lang::En
+        }
+    }
+}
+
+impl std::str::FromStr for Lang {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ko" => Ok(Lang::Ko),
+            "en" => Ok(Lang::En),
+            _ => Err(format!("en 또는 ko여야 합니다: {}", s)),
+        }
+    }
+}
+
+static LANG: OnceLock<Lang> = OnceLock::new();
+
+/// 명령줄에서 지정한 언어 (`--lang`, 메시지를 출력하기 전에 호출)
+pub fn set_lang(lang: Lang) {
+    let _ = LANG.set(lang);
+}
+
+/// 현재 메시지 언어
+pub fn lang() -> Lang {
+    *LANG.get_or_init(Lang::from_env)
+}
+
+/// 현재 언어에 맞는 메시지 생성
+///
+/// 한국어와 영어 형식 문자열을 나란히 적어 두는 방식의 메시지 목록으로,
+/// 두 문자열은 같은 인자를 같은 순서로 사용해야 한다.
+///
+/// ```ignore
+/// println!("{}", tr!("{}개 파일 컴파일 중", "Compiling {} files", count));
+/// ```
+macro_rules! tr {
+    ($ko:literal, $en:literal $(, $arg:expr)* $(,)?) => {
+        match $crate::i18n::lang() {
+            $crate::i18n::Lang::Ko => format!($ko $(, $arg)*),
+            $crate::i18n::Lang::En => format!($en $(, $arg)*),
+        }
+    };
+}
+
+pub(crate) use tr;
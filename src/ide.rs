@@ -0,0 +1,271 @@
+use crate::builder::{Builder, TargetCompileInfo};
+use crate::context::ProjectContext;
+use crate::error::{BuildError, BuildResult};
+use crate::utils::{split_tool_command, write_if_changed};
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// `cbuild ide vscode`: .vscode 설정 파일 생성
+///
+/// 타겟마다 IntelliSense 구성을 만들고, cbuild를 호출하는 빌드 작업과
+/// 실행 파일별 디버그 실행 구성을 작성한다.
+pub fn generate_vscode(
+    context: Arc<ProjectContext>,
+    configuration: &str,
+    out_dir: Option<PathBuf>,
+) -> BuildResult<()> {
+    let project_dir = context.project_dir();
+    let mut builder = Builder::with_context(context.clone(), configuration, 1)?;
+    builder.set_output_dir(out_dir);
+    let targets = builder.compile_info()?;
+    let config = builder.config();
+
+    let vscode_dir = project_dir.join(".vscode");
+    std::fs::create_dir_all(&vscode_dir).map_err(BuildError::IoError)?;
+
+    let compiler_path = which::which(split_tool_command(&config.build.compiler).0)
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|_| config.build.compiler.clone());
+
+    // IntelliSense 구성 (타겟별)
+    let configurations: Vec<Value> = targets
+        .iter()
+        .map(|target| {
+            let mut entry = json!({
+                "name": target.name,
+                "includePath": include_dirs(target)
+                    .iter()
+                    .map(|dir| workspace_path(dir, project_dir))
+                    .collect::<Vec<_>>(),
+                "defines": defines(target),
+                "compilerPath": compiler_path,
+                "compilerArgs": other_flags(target),
+            });
+            if let Some(ref c_std) = config.build.c_standard {
+                entry["cStandard"] = json!(c_std);
+            }
+            if let Some(ref cpp_std) = config.build.cpp_standard {
+                entry["cppStandard"] = json!(cpp_std);
+            }
+            entry
+        })
+        .collect();
+
+    let properties = json!({
+        "configurations": configurations,
+        "version": 4,
+    });
+
+    // 빌드 작업
+    let cli = cli_name();
+    let task = |label: &str, args: &[&str], group: Value| {
+        json!({
+            "label": label,
+            "type": "shell",
+            "command": cli,
+            "args": args,
+            "options": { "cwd": "${workspaceFolder}" },
+            "group": group,
+            "problemMatcher": ["$gcc"],
+        })
+    };
+    let tasks = json!({
+        "version": "2.0.0",
+        "tasks": [
+            task(
+                "cbuild: build",
+                &["build", "-c", configuration],
+                json!({ "kind": "build", "isDefault": true }),
+            ),
+            task("cbuild: build release", &["build", "-c", "release"], json!("build")),
+            task("cbuild: check", &["check", "-c", configuration], json!("build")),
+            task("cbuild: clean", &["clean"], json!("none")),
+        ],
+    });
+
+    // 실행 파일별 디버그 구성
+    let debugger = if cfg!(target_os = "macos") {
+        "lldb"
+    } else {
+        "gdb"
+    };
+    let run_args = config.run.args.clone().unwrap_or_default();
+    let launch_configurations: Vec<Value> = config
+        .targets
+        .executable
+        .iter()
+        .map(|exe| {
+            json!({
+                "name": format!("Debug {}", exe.name),
+                "type": "cppdbg",
+                "request": "launch",
+                "program": workspace_path(&builder.layout().executable_path(&exe.name), project_dir),
+                "args": run_args,
+                "cwd": "${workspaceFolder}",
+                "MIMode": debugger,
+                "preLaunchTask": "cbuild: build",
+            })
+        })
+        .collect();
+    let launch = json!({
+        "version": "0.2.0",
+        "configurations": launch_configurations,
+    });
+
+    for (file_name, content) in [
+        ("c_cpp_properties.json", properties),
+        ("tasks.json", tasks),
+        ("launch.json", launch),
+    ] {
+        let path = vscode_dir.join(file_name);
+        let content = serde_json::to_string_pretty(&content)
+            .map_err(|e| BuildError::ConfigParsingError(e.to_string()))?;
+        write_if_changed(&path, &content).map_err(BuildError::IoError)?;
+        println!("생성됨: {}", path.display());
+    }
+
+    Ok(())
+}
+
+/// `cbuild ide clangd`: compile_commands.json과 .clangd 생성
+///
+/// compile_commands.json은 빌드와 같은 명령으로 각 소스의 플래그를 제공하고,
+/// .clangd는 데이터베이스에 없는 헤더도 같은 디렉토리 소스가 속한 타겟의
+/// 포함 디렉토리와 매크로 정의로 분석되도록 디렉토리별 플래그를 추가한다.
+pub fn generate_clangd(
+    context: Arc<ProjectContext>,
+    configuration: &str,
+    out_dir: Option<PathBuf>,
+) -> BuildResult<()> {
+    let project_dir = context.project_dir();
+    let mut builder = Builder::with_context(context.clone(), configuration, 1)?;
+    builder.set_output_dir(out_dir);
+    let targets = builder.compile_info()?;
+
+    let database_path = project_dir.join("compile_commands.json");
+    builder.write_compile_commands(&database_path)?;
+    println!("생성됨: {}", database_path.display());
+
+    let mut content = String::from("# cbuild ide clangd로 생성된 파일입니다.\n");
+    content.push_str("CompileFlags:\n  CompilationDatabase: .\n");
+
+    // 디렉토리별 플래그 (여러 타겟이 공유하는 디렉토리는 먼저 나온 타겟 기준)
+    let mut claimed = std::collections::HashSet::new();
+    for target in &targets {
+        let mut add_flags: Vec<String> = include_dirs(target)
+            .iter()
+            .map(|dir| format!("-I{}", dir.to_string_lossy()))
+            .collect();
+        add_flags.extend(defines(target).iter().map(|define| format!("-D{}", define)));
+        if add_flags.is_empty() {
+            continue;
+        }
+
+        let mut dirs: Vec<String> = target
+            .sources
+            .iter()
+            .filter_map(|source| source.parent())
+            .filter_map(|dir| dir.strip_prefix(project_dir).ok())
+            .map(|dir| dir.to_string_lossy().replace('\\', "/"))
+            .filter(|dir| !dir.is_empty())
+            .collect();
+        dirs.sort();
+        dirs.dedup();
+        dirs.retain(|dir| claimed.insert(dir.clone()));
+        if dirs.is_empty() {
+            continue;
+        }
+
+        let pattern = dirs
+            .iter()
+            .map(|dir| format!("{}/.*", regex::escape(dir)))
+            .collect::<Vec<_>>()
+            .join("|");
+
+        content.push_str("---\n");
+        content.push_str(&format!("# {}:{}\n", target.target_type, target.name));
+        content.push_str(&format!("If:\n  PathMatch: {}\n", yaml_quote(&pattern)));
+        content.push_str("CompileFlags:\n  Add:\n");
+        for flag in add_flags {
+            content.push_str(&format!("    - {}\n", yaml_quote(&flag)));
+        }
+    }
+
+    let clangd_path = project_dir.join(".clangd");
+    write_if_changed(&clangd_path, &content).map_err(BuildError::IoError)?;
+    println!("생성됨: {}", clangd_path.display());
+
+    Ok(())
+}
+
+/// YAML 작은따옴표 문자열
+fn yaml_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// 포함 디렉토리를 지정하는 플래그 (strict_includes면 -iquote, -isystem)
+const INCLUDE_FLAGS: [&str; 3] = ["-I", "-iquote", "-isystem"];
+
+/// 컴파일 플래그에서 포함 디렉토리 추출 (`-I dir`, `-Idir`, `-iquote dir`, `-isystem dir`)
+pub fn include_dirs(target: &TargetCompileInfo) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    let mut flags = target.flags.iter();
+
+    while let Some(flag) = flags.next() {
+        if INCLUDE_FLAGS.contains(&flag.as_str()) {
+            if let Some(dir) = flags.next() {
+                dirs.push(PathBuf::from(dir));
+            }
+        } else if let Some(dir) = flag.strip_prefix("-I") {
+            dirs.push(PathBuf::from(dir));
+        }
+    }
+
+    dirs
+}
+
+/// 컴파일 플래그에서 매크로 정의 추출 (`-DNAME`, `-DNAME=VALUE`)
+pub fn defines(target: &TargetCompileInfo) -> Vec<String> {
+    target
+        .flags
+        .iter()
+        .filter_map(|flag| flag.strip_prefix("-D"))
+        .map(|define| define.to_string())
+        .collect()
+}
+
+/// 포함 디렉토리와 매크로 정의를 제외한 나머지 플래그
+fn other_flags(target: &TargetCompileInfo) -> Vec<String> {
+    let mut others = Vec::new();
+    let mut flags = target.flags.iter();
+
+    while let Some(flag) = flags.next() {
+        if INCLUDE_FLAGS.contains(&flag.as_str()) {
+            flags.next();
+        } else if !flag.starts_with("-I") && !flag.starts_with("-D") {
+            others.push(flag.clone());
+        }
+    }
+
+    others
+}
+
+/// 프로젝트 내부 경로는 `${workspaceFolder}` 기준으로 표시
+fn workspace_path(path: &Path, project_dir: &Path) -> String {
+    match path.strip_prefix(project_dir) {
+        Ok(relative) => format!(
+            "${{workspaceFolder}}/{}",
+            relative.to_string_lossy().replace('\\', "/")
+        ),
+        Err(_) => path.to_string_lossy().replace('\\', "/"),
+    }
+}
+
+/// 현재 실행 중인 cbuild 실행 파일 이름 (작업 명령에 사용)
+pub fn cli_name() -> String {
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.file_stem().map(|s| s.to_string_lossy().to_string()))
+        .unwrap_or_else(|| "cbuild".to_string())
+}
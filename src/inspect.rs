@@ -0,0 +1,98 @@
+use crate::context::ProjectContext;
+use crate::error::{BuildError, BuildResult};
+use crate::utils::{is_tool_installed, page_output};
+use log::info;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// 디스어셈블 출력 옵션
+pub struct DisasmOptions {
+    pub symbol: Option<String>,
+    pub intel_syntax: bool,
+    pub with_source: bool,
+    pub use_pager: bool,
+}
+
+/// 빌드된 타겟 결과물을 objdump/llvm-objdump로 디스어셈블
+pub fn disassemble(
+    context: &ProjectContext,
+    configuration: &str,
+    out_dir: Option<PathBuf>,
+    target: &str,
+    options: &DisasmOptions,
+) -> BuildResult<()> {
+    let config = context.config()?;
+    let layout = context.layout(configuration, out_dir.as_deref());
+
+    let artifact = layout
+        .target_artifact(config, target)
+        .ok_or_else(|| BuildError::TargetNotFound(target.to_string()))?;
+
+    if !artifact.exists() {
+        return Err(BuildError::ExecutableNotFound(artifact));
+    }
+
+    let tool = select_objdump(&config.build.compiler)?;
+    let is_llvm = tool.starts_with("llvm");
+
+    let mut cmd = Command::new(&tool);
+    cmd.arg("-d").arg("-C");
+
+    if options.with_source {
+        cmd.arg("-S");
+    }
+
+    if options.intel_syntax {
+        if is_llvm {
+            cmd.arg("--x86-asm-syntax=intel");
+        } else {
+            cmd.arg("-M").arg("intel");
+        }
+    }
+
+    if let Some(ref symbol) = options.symbol {
+        if is_llvm {
+            cmd.arg(format!("--disassemble-symbols={}", symbol));
+        } else {
+            cmd.arg(format!("--disassemble={}", symbol));
+        }
+    }
+
+    cmd.arg(&artifact);
+    info!("Disassembling: {:?}", cmd);
+
+    let output = cmd.output().map_err(BuildError::IoError)?;
+    if !output.status.success() {
+        return Err(BuildError::CompilerError(format!(
+            "{} 실행 실패: {}",
+            tool,
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+
+    page_output(&output.stdout, options.use_pager).map_err(BuildError::IoError)
+}
+
+/// 컴파일러 계열에 맞는 objdump 선택 (clang이면 llvm-objdump 우선)
+fn select_objdump(compiler: &str) -> BuildResult<String> {
+    let mut candidates = Vec::new();
+
+    if compiler.contains("clang") {
+        candidates.push("llvm-objdump".to_string());
+    }
+
+    // 교차 컴파일러(arm-none-eabi-gcc 등)는 같은 접두사의 objdump 사용
+    if let Some(prefix) = compiler.strip_suffix("gcc") {
+        if !prefix.is_empty() {
+            candidates.push(format!("{}objdump", prefix));
+        }
+    }
+
+    candidates.push("objdump".to_string());
+    candidates.push("llvm-objdump".to_string());
+
+    candidates
+        .into_iter()
+        .find(|tool| is_tool_installed(tool))
+        .ok_or_else(|| BuildError::CompilerNotFound("objdump".to_string()))
+}
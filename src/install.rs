@@ -0,0 +1,377 @@
+use crate::builder::Builder;
+use crate::config::{BuildConfig, LibraryTarget};
+use crate::error::{BuildError, BuildResult, CommandFailure};
+use crate::i18n::tr;
+use crate::layout::BuildLayout;
+use crate::utils::{
+    collect_files_with_extension, copy_file_with_dirs, get_common_header_extensions, glob_in,
+    to_absolute_path, write_if_changed,
+};
+use colored::Colorize;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// 설치한 라이브러리 타겟 (CMake 패키지 설정 생성에 사용)
+struct InstalledLibrary {
+    name: String,
+    shared: bool,
+    /// 설치 접두사 기준 경로 (예: lib/libfoo.a)
+    file: String,
+}
+
+/// `cbuild install`: 빌드 후 결과물과 공개 헤더를 설치 접두사 아래에 복사
+///
+/// 실행 파일은 `bin`, 라이브러리는 `lib`에 `output_name`이 적용된 파일 이름으로 두고
+/// (`output_dir` 하위 디렉토리는 빌드 출력에만 적용), 라이브러리 포함 디렉토리의 헤더는
+/// 디렉토리 구조를 유지해 `include`에 둔다. `cmake_config`가 참이면 다른 CMake
+/// 프로젝트가 `find_package(<프로젝트 이름>)`으로 찾을 수 있도록
+/// `lib/cmake/<이름>/<이름>Config.cmake`와 버전 파일을 생성한다.
+/// `[install]`의 man 페이지와 셸 자동 완성은 `share` 아래에 둔다.
+pub fn install(
+    project_dir: &Path,
+    configuration: &str,
+    out_dir: Option<PathBuf>,
+    prefix: &Path,
+    cmake_config: bool,
+) -> BuildResult<()> {
+    let mut builder = Builder::new(project_dir, configuration, num_cpus::get())?;
+    builder.set_output_dir(out_dir);
+    builder.build()?;
+
+    let config = builder.config();
+    let layout = builder.layout();
+    let prefix = to_absolute_path(prefix, &std::env::current_dir()?);
+
+    println!(
+        "{} {} -> {}",
+        "Installing".green().bold(),
+        config.project.name,
+        prefix.display()
+    );
+
+    for target in &config.targets.executable {
+        // 기본 빌드에서 제외된 타겟(예제 등)은 설치하지 않음
+        if !config.is_default_target(&format!("exe:{}", target.name)) {
+            continue;
+        }
+        let file_name = layout.executable_file_name(&target.name);
+        install_file(
+            &layout.executable_path(&target.name),
+            &prefix.join("bin").join(&file_name),
+        )?;
+    }
+
+    let mut libraries = Vec::new();
+    let mut header_dirs = Vec::new();
+    let libs = config
+        .targets
+        .static_lib
+        .iter()
+        .map(|lib| (lib, false))
+        .chain(config.targets.shared_lib.iter().map(|lib| (lib, true)));
+    for (lib, shared) in libs {
+        let key = format!("{}:{}", if shared { "shared" } else { "static" }, lib.name);
+        if !config.is_default_target(&key) {
+            continue;
+        }
+        let (file_name, built) = if shared {
+            (
+                layout.shared_lib_file_name(&lib.name),
+                layout.shared_lib_path(&lib.name),
+            )
+        } else {
+            (
+                layout.static_lib_file_name(&lib.name),
+                layout.static_lib_path(&lib.name),
+            )
+        };
+        install_file(&built, &prefix.join("lib").join(&file_name))?;
+        libraries.push(InstalledLibrary {
+            name: lib.name.clone(),
+            shared,
+            file: format!("lib/{}", file_name),
+        });
+
+        for dir in public_include_dirs(project_dir, layout, lib) {
+            if !header_dirs.contains(&dir) {
+                header_dirs.push(dir);
+            }
+        }
+    }
+
+    let mut header_count = 0;
+    for dir in &header_dirs {
+        let mut headers = collect_files_with_extension(dir, &get_common_header_extensions());
+        headers.sort();
+        for header in headers {
+            let relative = header.strip_prefix(dir).unwrap_or(&header);
+            copy_file_with_dirs(&header, &prefix.join("include").join(relative))?;
+            header_count += 1;
+        }
+    }
+    if header_count > 0 {
+        println!(
+            "  {}",
+            tr!(
+                "헤더 {}개 -> include",
+                "{} headers -> include",
+                header_count
+            )
+        );
+    }
+
+    for (src, relative) in distribution_files(config, layout, project_dir)? {
+        install_file(&src, &prefix.join(relative))?;
+    }
+
+    if cmake_config {
+        if libraries.is_empty() {
+            // CMake 패키지로 내보낼 라이브러리 타겟이 없음
+            return Err(BuildError::TargetNotFound("lib:*".to_string()));
+        }
+        write_cmake_package(config, &prefix, &libraries, header_count > 0)?;
+    }
+
+    println!("{}", tr!("설치 완료", "Install finished").green().bold());
+    Ok(())
+}
+
+fn install_file(src: &Path, dst: &Path) -> BuildResult<()> {
+    if !src.exists() {
+        return Err(BuildError::PathError(tr!(
+            "설치할 결과물이 없습니다: {}",
+            "artifact to install does not exist: {}",
+            src.display()
+        )));
+    }
+    copy_file_with_dirs(src, dst)?;
+    println!("  {}", dst.display());
+    Ok(())
+}
+
+/// `[install]`에 선언된 man 페이지와 셸 자동 완성 스크립트
+///
+/// (원본 파일, 설치 접두사 기준 경로) 목록을 반환한다. `command`로 생성하는 자동 완성은
+/// 빌드 출력의 generated/completions에 먼저 만든다.
+pub fn distribution_files(
+    config: &BuildConfig,
+    layout: &BuildLayout,
+    project_dir: &Path,
+) -> BuildResult<Vec<(PathBuf, PathBuf)>> {
+    let mut files = Vec::new();
+
+    for pattern in config.install.man.iter().flatten() {
+        let paths = glob_in(project_dir, pattern, true).map_err(|e| {
+            BuildError::PathError(tr!(
+                "패턴 '{}'에 오류: {}",
+                "invalid pattern '{}': {}",
+                pattern,
+                e
+            ))
+        })?;
+        for path in paths.into_iter().filter(|p| p.is_file()) {
+            let section = man_section(&path).ok_or_else(|| {
+                BuildError::PathError(tr!(
+                    "man 페이지 확장자에 섹션 번호가 없습니다: {}",
+                    "man page extension has no section number: {}",
+                    path.display()
+                ))
+            })?;
+            let file_name = path.file_name().unwrap().to_owned();
+            files.push((
+                path.clone(),
+                Path::new("share/man")
+                    .join(format!("man{}", section))
+                    .join(file_name),
+            ));
+        }
+    }
+
+    let completions = &config.install.completions;
+    let command_name = completions
+        .name
+        .clone()
+        .unwrap_or_else(|| config.project.name.clone());
+    let shells = [
+        ("bash", &completions.bash),
+        ("zsh", &completions.zsh),
+        ("fish", &completions.fish),
+    ];
+    for (shell, file) in shells {
+        let src = match (file, &completions.command) {
+            (Some(file), _) => to_absolute_path(Path::new(file), project_dir),
+            (None, Some(command)) => generate_completion(config, layout, command, shell)?,
+            (None, None) => continue,
+        };
+        let relative = match shell {
+            "bash" => PathBuf::from("share/bash-completion/completions").join(&command_name),
+            "zsh" => PathBuf::from("share/zsh/site-functions").join(format!("_{}", command_name)),
+            _ => PathBuf::from("share/fish/vendor_completions.d")
+                .join(format!("{}.fish", command_name)),
+        };
+        files.push((src, relative));
+    }
+
+    Ok(files)
+}
+
+/// man 페이지 확장자의 섹션 번호 (tool.1 -> 1, tool.3p -> 3)
+fn man_section(path: &Path) -> Option<char> {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(|ext| ext.chars().next())
+        .filter(|c| c.is_ascii_digit())
+}
+
+/// 자동 완성 생성 명령을 실행해 출력을 파일로 저장
+fn generate_completion(
+    config: &BuildConfig,
+    layout: &BuildLayout,
+    command: &[String],
+    shell: &str,
+) -> BuildResult<PathBuf> {
+    let args: Vec<String> = command
+        .iter()
+        .map(|arg| arg.replace("{shell}", shell))
+        .collect();
+    let (program, args) = args.split_first().ok_or_else(|| {
+        BuildError::ConfigParsingError(tr!(
+            "[install.completions] command가 비어 있습니다",
+            "[install.completions] command is empty"
+        ))
+    })?;
+
+    // 실행 파일 타겟 이름이면 빌드 결과물 실행
+    let program = if config.targets.executable.iter().any(|t| &t.name == program) {
+        layout.executable_path(program)
+    } else {
+        PathBuf::from(program)
+    };
+
+    let mut cmd = Command::new(&program);
+    cmd.args(args);
+    let output = cmd.output().map_err(BuildError::IoError)?;
+    if !output.status.success() {
+        return Err(BuildError::CustomCommandFailed(CommandFailure::new(
+            &format!("completions:{}", shell),
+            &cmd,
+            &output.stderr,
+        )));
+    }
+
+    let path = layout
+        .generated_dir()
+        .join("completions")
+        .join(format!("{}.{}", config.project.name, shell));
+    write_if_changed(&path, &String::from_utf8_lossy(&output.stdout))
+        .map_err(BuildError::IoError)?;
+    Ok(path)
+}
+
+/// 라이브러리의 공개 헤더 디렉토리 (프로젝트 안, 의존성과 빌드 출력 디렉토리 제외)
+pub fn public_include_dirs(
+    project_dir: &Path,
+    layout: &BuildLayout,
+    lib: &LibraryTarget,
+) -> Vec<PathBuf> {
+    let excluded = [project_dir.join("deps"), layout.output_root().to_path_buf()];
+
+    lib.include_dirs
+        .iter()
+        .flatten()
+        .map(|dir| to_absolute_path(Path::new(dir), project_dir))
+        .filter(|dir| dir.starts_with(project_dir) && dir.is_dir())
+        .filter(|dir| !excluded.iter().any(|ex| dir.starts_with(ex)))
+        .collect()
+}
+
+/// `<이름>Config.cmake`와 `<이름>ConfigVersion.cmake` 생성
+fn write_cmake_package(
+    config: &BuildConfig,
+    prefix: &Path,
+    libraries: &[InstalledLibrary],
+    has_headers: bool,
+) -> BuildResult<()> {
+    let package = &config.project.name;
+    let package_dir = prefix.join("lib/cmake").join(package);
+
+    let config_path = package_dir.join(format!("{}Config.cmake", package));
+    write_if_changed(
+        &config_path,
+        &render_config(package, libraries, has_headers),
+    )?;
+    println!("  {}", config_path.display());
+
+    let version_path = package_dir.join(format!("{}ConfigVersion.cmake", package));
+    write_if_changed(&version_path, &render_version(&config.project.version))?;
+    println!("  {}", version_path.display());
+
+    Ok(())
+}
+
+/// 라이브러리마다 `<패키지>::<타겟>` IMPORTED 타겟을 정의하는 패키지 설정
+///
+/// 설치 위치를 옮겨도 동작하도록 경로는 이 파일 위치(`lib/cmake/<이름>`) 기준으로 계산한다.
+fn render_config(package: &str, libraries: &[InstalledLibrary], has_headers: bool) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "# {} CMake package config (generated by cbuild install)",
+        package
+    );
+    out.push_str(
+        "get_filename_component(_cbuild_prefix \"${CMAKE_CURRENT_LIST_DIR}/../../..\" ABSOLUTE)\n",
+    );
+
+    let mut targets = Vec::new();
+    for lib in libraries {
+        let target = format!("{}::{}", package, lib.name);
+        let kind = if lib.shared { "SHARED" } else { "STATIC" };
+        let _ = writeln!(out, "\nif(NOT TARGET {})", target);
+        let _ = writeln!(out, "  add_library({} {} IMPORTED)", target, kind);
+        let _ = writeln!(out, "  set_target_properties({} PROPERTIES", target);
+        let _ = writeln!(
+            out,
+            "    IMPORTED_LOCATION \"${{_cbuild_prefix}}/{}\"",
+            lib.file
+        );
+        if has_headers {
+            out.push_str("    INTERFACE_INCLUDE_DIRECTORIES \"${_cbuild_prefix}/include\"\n");
+        }
+        out.push_str("  )\nendif()\n");
+        targets.push(target);
+    }
+
+    out.push('\n');
+    if has_headers {
+        let _ = writeln!(
+            out,
+            "set({}_INCLUDE_DIRS \"${{_cbuild_prefix}}/include\")",
+            package
+        );
+    }
+    let _ = writeln!(out, "set({}_LIBRARIES {})", package, targets.join(" "));
+    out.push_str("unset(_cbuild_prefix)\n");
+    out
+}
+
+/// 주 버전이 같고 요청한 버전 이상이면 호환으로 보는 버전 파일 (SameMajorVersion)
+fn render_version(version: &str) -> String {
+    let major = version.split('.').next().unwrap_or(version);
+    format!(
+        r#"set(PACKAGE_VERSION "{version}")
+
+if(PACKAGE_FIND_VERSION VERSION_GREATER PACKAGE_VERSION)
+  set(PACKAGE_VERSION_COMPATIBLE FALSE)
+elseif(NOT PACKAGE_FIND_VERSION_MAJOR STREQUAL "{major}")
+  set(PACKAGE_VERSION_COMPATIBLE FALSE)
+else()
+  set(PACKAGE_VERSION_COMPATIBLE TRUE)
+  if(PACKAGE_FIND_VERSION STREQUAL PACKAGE_VERSION)
+    set(PACKAGE_VERSION_EXACT TRUE)
+  endif()
+endif()
+"#
+    )
+}
@@ -0,0 +1,150 @@
+use log::{debug, warn};
+use std::fs::File;
+use std::io::{Read, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// GNU make jobserver 클라이언트
+///
+/// 상위 make가 `MAKEFLAGS`로 jobserver를 전달하면 동시에 실행하는 컴파일
+/// 작업마다 토큰을 받아 실행해 중첩 빌드가 CPU를 과도하게 점유하지 않게 한다.
+/// 프로세스에는 기본적으로 하나의 암시적 토큰이 주어진다.
+pub struct JobServer {
+    read: File,
+    write: File,
+    implicit_available: AtomicBool,
+}
+
+/// 작업 슬롯 토큰. 해제되면 jobserver에 반환된다.
+pub struct JobToken<'a> {
+    server: &'a JobServer,
+    byte: Option<u8>,
+}
+
+impl JobServer {
+    /// `MAKEFLAGS` 환경 변수에서 jobserver 정보를 찾아 연결
+    pub fn from_env() -> Option<Self> {
+        let makeflags = std::env::var("MAKEFLAGS").ok()?;
+        let auth = parse_jobserver_auth(&makeflags)?;
+
+        match connect(&auth) {
+            Some(server) => {
+                debug!("GNU make jobserver 연결: {}", auth);
+                Some(server)
+            }
+            None => {
+                warn!(
+                    "jobserver({})에 연결할 수 없습니다. 레시피에 '+'를 붙였는지 확인하세요",
+                    auth
+                );
+                None
+            }
+        }
+    }
+
+    /// 작업 슬롯 획득 (사용 가능한 토큰이 없으면 대기)
+    pub fn acquire(&self) -> std::io::Result<JobToken<'_>> {
+        if self
+            .implicit_available
+            .compare_exchange(true, false, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+        {
+            return Ok(JobToken {
+                server: self,
+                byte: None,
+            });
+        }
+
+        let mut buf = [0u8; 1];
+        loop {
+            match (&self.read).read(&mut buf) {
+                Ok(1) => {
+                    return Ok(JobToken {
+                        server: self,
+                        byte: Some(buf[0]),
+                    })
+                }
+                Ok(_) => {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::UnexpectedEof,
+                        "jobserver가 닫혔습니다",
+                    ))
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+impl Drop for JobToken<'_> {
+    fn drop(&mut self) {
+        match self.byte {
+            Some(byte) => {
+                if let Err(e) = (&self.server.write).write_all(&[byte]) {
+                    warn!("jobserver 토큰 반환 실패: {}", e);
+                }
+            }
+            None => self
+                .server
+                .implicit_available
+                .store(true, Ordering::Release),
+        }
+    }
+}
+
+/// `--jobserver-auth=R,W`, `--jobserver-auth=fifo:PATH`, `--jobserver-fds=R,W` 추출
+fn parse_jobserver_auth(makeflags: &str) -> Option<String> {
+    makeflags
+        .split_whitespace()
+        .filter_map(|flag| {
+            flag.strip_prefix("--jobserver-auth=")
+                .or_else(|| flag.strip_prefix("--jobserver-fds="))
+        })
+        .next_back()
+        .map(|auth| auth.to_string())
+}
+
+#[cfg(unix)]
+fn connect(auth: &str) -> Option<JobServer> {
+    use std::os::unix::io::BorrowedFd;
+
+    let (read, write) = if let Some(path) = auth.strip_prefix("fifo:") {
+        let read = File::options().read(true).write(true).open(path).ok()?;
+        let write = read.try_clone().ok()?;
+        (read, write)
+    } else {
+        let (r, w) = auth.split_once(',')?;
+        let r: i32 = r.parse().ok()?;
+        let w: i32 = w.parse().ok()?;
+
+        // 상위 make가 파일 디스크립터를 넘겨주지 않은 경우 (레시피에 '+' 없음)
+        if r < 0 || w < 0 || !fd_is_open(r) || !fd_is_open(w) {
+            return None;
+        }
+
+        // SAFETY: 열려 있는 디스크립터임을 확인했고, 상속된 디스크립터는 닫지 않도록
+        // 빌려서 복제한다. 복제본은 바로 OwnedFd가 소유하므로 두 번째 복제가
+        // 실패해도 첫 번째 디스크립터가 새지 않는다.
+        let (r, w) = unsafe { (BorrowedFd::borrow_raw(r), BorrowedFd::borrow_raw(w)) };
+        let read = r.try_clone_to_owned().ok()?;
+        let write = w.try_clone_to_owned().ok()?;
+        (File::from(read), File::from(write))
+    };
+
+    Some(JobServer {
+        read,
+        write,
+        implicit_available: AtomicBool::new(true),
+    })
+}
+
+#[cfg(unix)]
+fn fd_is_open(fd: i32) -> bool {
+    // SAFETY: F_GETFD는 디스크립터 상태만 조회한다.
+    unsafe { libc::fcntl(fd, libc::F_GETFD) != -1 }
+}
+
+#[cfg(not(unix))]
+fn connect(_auth: &str) -> Option<JobServer> {
+    None
+}
@@ -0,0 +1,391 @@
+use crate::config::BuildConfig;
+use crate::utils::{simplified_path, to_absolute_path};
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf, Prefix};
+
+/// 빌드 결과물 디렉토리 구조
+///
+/// 모든 모듈은 `project_dir/build`를 직접 조합하지 않고 이 구조체를 통해
+/// 객체 파일, 라이브러리, 실행 파일 경로를 얻는다.
+#[derive(Debug, Clone)]
+pub struct BuildLayout {
+    output_root: PathBuf,
+    build_dir: PathBuf,
+    suffix: String,
+    /// 타겟 키("exe:name" 등)별 결과물 이름/하위 디렉토리 재정의
+    outputs: HashMap<String, TargetOutput>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct TargetOutput {
+    name: Option<String>,
+    dir: Option<String>,
+}
+
+impl BuildLayout {
+    /// 출력 디렉토리 결정: 명령줄 `--out-dir` > `build.output_dir` > `project_dir/build`
+    pub fn new(
+        project_dir: &Path,
+        configuration: &str,
+        out_dir: Option<&Path>,
+        config: Option<&BuildConfig>,
+    ) -> Self {
+        let output_root = out_dir
+            .map(Path::to_path_buf)
+            .or_else(|| {
+                config
+                    .and_then(|c| c.build.output_dir.as_ref())
+                    .map(PathBuf::from)
+            })
+            .map(|dir| to_absolute_path(&dir, project_dir))
+            .unwrap_or_else(|| project_dir.join("build"));
+
+        let build_dir = output_root.join(configuration);
+
+        // 빌드 구성별 결과물 이름 접미사 (예: debug -> app_d)
+        let suffix = config
+            .and_then(|c| c.profiles.get(configuration))
+            .and_then(|p| p.output_suffix.clone())
+            .unwrap_or_default();
+
+        let mut outputs = HashMap::new();
+        if let Some(config) = config {
+            let targets = &config.targets;
+            let entries = targets
+                .executable
+                .iter()
+                .map(|t| ("exe", &t.name, &t.output_name, &t.output_dir))
+                .chain(
+                    targets
+                        .static_lib
+                        .iter()
+                        .map(|t| ("static", &t.name, &t.output_name, &t.output_dir)),
+                )
+                .chain(
+                    targets
+                        .shared_lib
+                        .iter()
+                        .map(|t| ("shared", &t.name, &t.output_name, &t.output_dir)),
+                )
+                .chain(
+                    targets
+                        .test
+                        .iter()
+                        .map(|t| ("test", &t.name, &t.output_name, &t.output_dir)),
+                );
+            for (kind, name, output_name, output_dir) in entries {
+                if output_name.is_some() || output_dir.is_some() {
+                    outputs.insert(
+                        format!("{}:{}", kind, name),
+                        TargetOutput {
+                            name: output_name.clone(),
+                            dir: output_dir.clone(),
+                        },
+                    );
+                }
+            }
+        }
+
+        BuildLayout {
+            output_root,
+            build_dir,
+            suffix,
+            outputs,
+        }
+    }
+
+    /// 모든 빌드 구성을 포함하는 최상위 출력 디렉토리
+    pub fn output_root(&self) -> &Path {
+        &self.output_root
+    }
+
+    /// 빌드 기록(매니페스트) 디렉토리 (예: build/.history)
+    pub fn history_dir(&self) -> PathBuf {
+        self.output_root.join(".history")
+    }
+
+    /// `cbuild stats headers` 측정 기록, 한 줄에 한 번 (예: build/.history/header-stats.jsonl)
+    pub fn header_stats_path(&self) -> PathBuf {
+        self.history_dir().join("header-stats.jsonl")
+    }
+
+    /// 빌드 구성 사이에 공유하는 미리 컴파일한 헤더 디렉토리 (예: build/.pch)
+    pub fn pch_dir(&self) -> PathBuf {
+        self.output_root.join(".pch")
+    }
+
+    /// 컴파일러 탐지 결과 캐시 (예: build/.toolchain.json)
+    pub fn toolchain_cache_path(&self) -> PathBuf {
+        self.output_root.join(".toolchain.json")
+    }
+
+    /// 마지막 빌드에서 실행한 명령 기록 (예: build/commands.log)
+    pub fn command_log_path(&self) -> PathBuf {
+        self.output_root.join("commands.log")
+    }
+
+    /// `cbuild last-commands`가 읽는 명령 기록 (예: build/commands.json)
+    pub fn command_log_json_path(&self) -> PathBuf {
+        self.output_root.join("commands.json")
+    }
+
+    /// 현재 빌드 구성의 출력 디렉토리 (예: build/debug)
+    pub fn build_dir(&self) -> &Path {
+        &self.build_dir
+    }
+
+    pub fn obj_dir(&self) -> PathBuf {
+        self.build_dir.join("obj")
+    }
+
+    pub fn lib_dir(&self) -> PathBuf {
+        self.build_dir.join("lib")
+    }
+
+    pub fn bin_dir(&self) -> PathBuf {
+        self.build_dir.join("bin")
+    }
+
+    pub fn test_bin_dir(&self) -> PathBuf {
+        self.bin_dir().join("tests")
+    }
+
+    /// 마지막 빌드 요약 (예: build/debug/summary.json)
+    pub fn build_summary_path(&self) -> PathBuf {
+        self.build_dir.join("summary.json")
+    }
+
+    /// 마지막 테스트 실행 결과 (예: build/debug/test-results.json)
+    pub fn test_results_path(&self) -> PathBuf {
+        self.build_dir.join("test-results.json")
+    }
+
+    /// 컴파일러 충돌 재현 정보 디렉토리 (예: build/crash-reports)
+    pub fn crash_reports_dir(&self) -> PathBuf {
+        self.output_root.join("crash-reports")
+    }
+
+    /// `cbuild report`의 HTML 보고서 디렉토리 (예: build/report)
+    pub fn report_dir(&self) -> PathBuf {
+        self.output_root.join("report")
+    }
+
+    /// 빌드 로그 디렉토리 (예: build/debug/log)
+    pub fn log_dir(&self) -> PathBuf {
+        self.build_dir.join("log")
+    }
+
+    /// 빌드 중 생성되는 소스/헤더 디렉토리
+    pub fn generated_dir(&self) -> PathBuf {
+        self.build_dir.join("generated")
+    }
+
+    /// 링크 후 처리(post_link) 전 링커 출력과 단계별 중간 결과 디렉토리
+    pub fn post_link_dir(&self) -> PathBuf {
+        self.build_dir.join("post_link")
+    }
+
+    /// 접미사가 적용된 결과물 기본 이름
+    pub fn artifact_name(&self, name: &str) -> String {
+        format!("{}{}", name, self.suffix)
+    }
+
+    /// `output_name`과 접미사가 적용된 타겟 결과물 기본 이름 (kind: exe, static, shared, test)
+    fn output_name(&self, kind: &str, name: &str) -> String {
+        let name = self
+            .outputs
+            .get(&format!("{}:{}", kind, name))
+            .and_then(|o| o.name.as_deref())
+            .unwrap_or(name);
+        self.artifact_name(name)
+    }
+
+    /// `output_dir`이 적용된 타겟 결과물 디렉토리
+    fn output_dir(&self, kind: &str, name: &str, base: PathBuf) -> PathBuf {
+        match self
+            .outputs
+            .get(&format!("{}:{}", kind, name))
+            .and_then(|o| o.dir.as_deref())
+        {
+            Some(dir) => base.join(dir),
+            None => base,
+        }
+    }
+
+    /// 공유 라이브러리 링크 이름 (`-l<이름>`)
+    pub fn shared_lib_link_name(&self, name: &str) -> String {
+        self.output_name("shared", name)
+    }
+
+    /// 공유 라이브러리가 놓이는 디렉토리 목록 (lib과 `output_dir` 하위 디렉토리)
+    pub fn shared_lib_dirs(&self, config: &BuildConfig) -> Vec<PathBuf> {
+        let mut dirs = vec![self.lib_dir()];
+        for lib in &config.targets.shared_lib {
+            let dir = self.output_dir("shared", &lib.name, self.lib_dir());
+            if !dirs.contains(&dir) {
+                dirs.push(dir);
+            }
+        }
+        dirs
+    }
+
+    /// 정적 라이브러리 파일 이름 (예: libfoo_d.a)
+    pub fn static_lib_file_name(&self, name: &str) -> String {
+        format!("lib{}.a", self.output_name("static", name))
+    }
+
+    /// 플랫폼별 공유 라이브러리 파일 이름 (예: libfoo.so, libfoo.dylib, foo.dll)
+    pub fn shared_lib_file_name(&self, name: &str) -> String {
+        let name = self.output_name("shared", name);
+        if cfg!(target_os = "windows") {
+            format!("{}.dll", name)
+        } else if cfg!(target_os = "macos") {
+            format!("lib{}.dylib", name)
+        } else {
+            format!("lib{}.so", name)
+        }
+    }
+
+    /// 플랫폼별 실행 파일 이름
+    pub fn executable_file_name(&self, name: &str) -> String {
+        executable_name(self.output_name("exe", name))
+    }
+
+    /// 플랫폼별 테스트 실행 파일 이름
+    pub fn test_file_name(&self, name: &str) -> String {
+        executable_name(self.output_name("test", name))
+    }
+
+    pub fn static_lib_path(&self, name: &str) -> PathBuf {
+        self.output_dir("static", name, self.lib_dir())
+            .join(self.static_lib_file_name(name))
+    }
+
+    pub fn shared_lib_path(&self, name: &str) -> PathBuf {
+        self.output_dir("shared", name, self.lib_dir())
+            .join(self.shared_lib_file_name(name))
+    }
+
+    pub fn executable_path(&self, name: &str) -> PathBuf {
+        self.output_dir("exe", name, self.bin_dir())
+            .join(self.executable_file_name(name))
+    }
+
+    pub fn test_path(&self, name: &str) -> PathBuf {
+        self.output_dir("test", name, self.test_bin_dir())
+            .join(self.test_file_name(name))
+    }
+
+    /// 타겟 이름으로 빌드 결과물 경로 찾기 (실행 파일, 라이브러리, 테스트 순)
+    pub fn target_artifact(&self, config: &BuildConfig, name: &str) -> Option<PathBuf> {
+        let targets = &config.targets;
+
+        if targets.executable.iter().any(|t| t.name == name) {
+            Some(self.executable_path(name))
+        } else if targets.shared_lib.iter().any(|t| t.name == name) {
+            Some(self.shared_lib_path(name))
+        } else if targets.static_lib.iter().any(|t| t.name == name) {
+            Some(self.static_lib_path(name))
+        } else if targets.test.iter().any(|t| t.name == name) {
+            Some(self.test_path(name))
+        } else {
+            None
+        }
+    }
+
+    /// 소스 파일에 대응하는 객체 파일 경로
+    ///
+    /// 프로젝트 밖의 소스(출력 디렉토리의 생성 파일 등)는 `obj/_external` 아래에
+    /// 절대 경로 구조 그대로 배치한다. Windows에서는 드라이브 문자도 경로에 넣어
+    /// 다른 드라이브의 같은 경로가 겹치지 않게 하고, `\\?\` 접두사 유무와 관계없이
+    /// 같은 오브젝트 경로를 만든다.
+    pub fn object_file(&self, project_dir: &Path, source: &Path) -> PathBuf {
+        let project_dir = simplified_path(project_dir);
+        let source = simplified_path(source);
+        match source.strip_prefix(&project_dir) {
+            Ok(rel_path) => self.obj_dir().join(rel_path).with_extension("o"),
+            Err(_) => {
+                let mut path = self.obj_dir().join("_external");
+                for component in source.components() {
+                    match component {
+                        Component::Prefix(prefix) => match prefix.kind() {
+                            Prefix::Disk(drive) | Prefix::VerbatimDisk(drive) => {
+                                path.push((drive as char).to_ascii_lowercase().to_string())
+                            }
+                            Prefix::UNC(server, share) | Prefix::VerbatimUNC(server, share) => {
+                                path.push(server);
+                                path.push(share);
+                            }
+                            _ => {}
+                        },
+                        Component::Normal(part) => path.push(part),
+                        _ => {}
+                    }
+                }
+                path.with_extension("o")
+            }
+        }
+    }
+}
+
+fn executable_name(name: String) -> String {
+    if cfg!(target_os = "windows") {
+        format!("{}.exe", name)
+    } else {
+        name
+    }
+}
+
+#[cfg(all(test, windows))]
+mod tests {
+    use super::*;
+
+    fn layout() -> BuildLayout {
+        BuildLayout::new(Path::new(r"C:\proj"), "debug", None, None)
+    }
+
+    #[test]
+    fn object_file_ignores_verbatim_prefix() {
+        let layout = layout();
+        let expected = PathBuf::from(r"C:\proj\build\debug\obj\src\main.o");
+        assert_eq!(
+            layout.object_file(Path::new(r"C:\proj"), Path::new(r"C:\proj\src\main.c")),
+            expected
+        );
+        assert_eq!(
+            layout.object_file(Path::new(r"\\?\C:\proj"), Path::new(r"C:\proj\src\main.c")),
+            expected
+        );
+        assert_eq!(
+            layout.object_file(Path::new(r"C:\proj"), Path::new(r"\\?\C:\proj\src\main.c")),
+            expected
+        );
+    }
+
+    #[test]
+    fn object_file_accepts_forward_slashes() {
+        let layout = layout();
+        assert_eq!(
+            layout.object_file(Path::new(r"C:\proj"), Path::new("C:/proj/src/util.c")),
+            PathBuf::from(r"C:\proj\build\debug\obj\src\util.o")
+        );
+    }
+
+    #[test]
+    fn external_objects_keep_drive_and_share() {
+        let layout = layout();
+        let external = PathBuf::from(r"C:\proj\build\debug\obj\_external");
+        assert_eq!(
+            layout.object_file(Path::new(r"C:\proj"), Path::new(r"D:\vendor\x.c")),
+            external.join(r"d\vendor\x.o")
+        );
+        assert_eq!(
+            layout.object_file(Path::new(r"C:\proj"), Path::new(r"\\?\D:\vendor\x.c")),
+            external.join(r"d\vendor\x.o")
+        );
+        assert_eq!(
+            layout.object_file(Path::new(r"C:\proj"), Path::new(r"\\server\share\x.c")),
+            external.join(r"server\share\x.o")
+        );
+    }
+}
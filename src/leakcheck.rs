@@ -0,0 +1,142 @@
+use crate::config::{BuildConfig, TestTarget};
+use crate::i18n::tr;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::path::Path;
+use std::process::Command;
+
+/// 누수 검사 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeakTool {
+    /// AddressSanitizer/LeakSanitizer로 빌드된 실행 파일 (환경 변수로 활성화)
+    Sanitizer,
+    /// valgrind memcheck로 감싸 실행
+    Valgrind,
+}
+
+/// valgrind가 누수를 발견했을 때 사용할 종료 코드
+const VALGRIND_EXIT_CODE: i32 = 97;
+
+/// `[test] leak_check = true`일 때 테스트 타겟에 사용할 누수 검사 방식 결정
+///
+/// 빌드 구성의 프로파일 플래그를 포함해 새니타이저 플래그로 빌드된 테스트는
+/// LeakSanitizer를 쓰고, 그 외에는 valgrind가 있으면 valgrind로 감싼다.
+/// 검사할 수 없으면 건너뛰는 이유를 Err로 돌려준다.
+pub fn leak_tool(
+    config: &BuildConfig,
+    configuration: &str,
+    test: &TestTarget,
+) -> Result<LeakTool, String> {
+    let profile = config.profile(configuration);
+    let flags = config
+        .build
+        .extra_flags
+        .iter()
+        .flatten()
+        .chain(test.extra_flags.iter().flatten())
+        .chain(
+            profile
+                .and_then(|p| p.extra_flags.as_ref())
+                .into_iter()
+                .flatten(),
+        )
+        .chain(
+            profile
+                .and_then(|p| p.link_flags.as_ref())
+                .into_iter()
+                .flatten(),
+        );
+
+    if flags
+        .filter_map(|f| f.strip_prefix("-fsanitize="))
+        .any(|list| {
+            list.split(',')
+                .any(|sanitizer| sanitizer == "address" || sanitizer == "leak")
+        })
+    {
+        return Ok(LeakTool::Sanitizer);
+    }
+
+    // 에뮬레이터(`build.test_runner`)로 실행하는 경우에는 valgrind를 사용할 수 없음
+    if config.build.test_runner.is_some() {
+        return Err(tr!(
+            "test_runner로 실행하는 테스트는 valgrind 누수 검사를 할 수 없습니다",
+            "valgrind leak checks are not available for tests run through test_runner"
+        ));
+    }
+
+    if which::which("valgrind").is_err() {
+        return Err(tr!(
+            "새니타이저 없이 빌드되었고 valgrind를 찾을 수 없습니다",
+            "built without a sanitizer and valgrind was not found"
+        ));
+    }
+
+    Ok(LeakTool::Valgrind)
+}
+
+/// 누수 검사를 적용한 테스트 실행 명령 생성
+pub fn leak_check_command(tool: LeakTool, program: &Path) -> Command {
+    match tool {
+        LeakTool::Sanitizer => {
+            let mut cmd = Command::new(program);
+            cmd.env(
+                "ASAN_OPTIONS",
+                append_option("ASAN_OPTIONS", "detect_leaks=1"),
+            );
+            cmd.env(
+                "LSAN_OPTIONS",
+                append_option("LSAN_OPTIONS", "print_suppressions=0"),
+            );
+            cmd
+        }
+        LeakTool::Valgrind => {
+            let mut cmd = Command::new("valgrind");
+            cmd.arg("--leak-check=full")
+                .arg("--errors-for-leak-kinds=definite")
+                .arg(format!("--error-exitcode={}", VALGRIND_EXIT_CODE))
+                .arg(program);
+            cmd
+        }
+    }
+}
+
+/// 테스트 출력에서 누수 요약 추출 (누수가 없으면 None)
+pub fn parse_leak_summary(tool: LeakTool, output: &str) -> Option<String> {
+    lazy_static! {
+        static ref SANITIZER_RE: Regex = Regex::new(
+            r"SUMMARY: (?:AddressSanitizer|LeakSanitizer): (\d+) byte\(s\) leaked in (\d+) allocation\(s\)"
+        )
+        .unwrap();
+        static ref VALGRIND_RE: Regex =
+            Regex::new(r"definitely lost: ([\d,]+) bytes in ([\d,]+) blocks").unwrap();
+    }
+
+    match tool {
+        LeakTool::Sanitizer => {
+            let cap = SANITIZER_RE.captures(output)?;
+            Some(format!(
+                "LeakSanitizer: {} bytes leaked in {} allocations",
+                &cap[1], &cap[2]
+            ))
+        }
+        LeakTool::Valgrind => {
+            let cap = VALGRIND_RE.captures(output)?;
+            if cap[1].replace(',', "") == "0" {
+                return None;
+            }
+            Some(format!(
+                "valgrind: {} bytes definitely lost in {} blocks",
+                &cap[1], &cap[2]
+            ))
+        }
+    }
+}
+
+/// 기존 환경 변수의 새니타이저 옵션 뒤에 옵션 추가 (사용자 설정 유지)
+fn append_option(var: &str, option: &str) -> String {
+    match std::env::var(var) {
+        Ok(existing) if !existing.is_empty() => format!("{}:{}", existing, option),
+        _ => option.to_string(),
+    }
+}
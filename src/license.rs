@@ -0,0 +1,233 @@
+use crate::config::{BuildConfig, LicenseSettings};
+use crate::error::{BuildError, BuildResult};
+use crate::history::format_timestamp;
+use crate::i18n::tr;
+use crate::layout::BuildLayout;
+use crate::utils::{is_header_file, is_source_file, to_absolute_path};
+use colored::Colorize;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use walkdir::WalkDir;
+
+/// 검사할 때 연도 자리에 넣는 표시 (정규식으로 바꿈)
+const YEAR_MARKER: &str = "\u{0}YEAR\u{0}";
+
+/// 설정에서 만든 라이선스 헤더
+struct LicenseHeader {
+    /// 새 파일에 넣을 주석 (현재 연도)
+    text: String,
+    /// 연도와 관계없이 헤더가 있는지 확인하는 정규식
+    pattern: Regex,
+}
+
+impl LicenseHeader {
+    fn from_config(config: &BuildConfig, project_dir: &Path) -> BuildResult<Self> {
+        let settings = &config.license;
+        let template = match (&settings.header, &settings.header_file) {
+            (Some(header), _) => header.clone(),
+            (None, Some(file)) => {
+                std::fs::read_to_string(to_absolute_path(Path::new(file), project_dir))
+                    .map_err(BuildError::IoError)?
+            }
+            (None, None) => {
+                return Err(BuildError::ConfigParsingError(tr!(
+                    "[license]에 header 또는 header_file이 필요합니다",
+                    "[license] requires header or header_file"
+                )))
+            }
+        };
+
+        let author = settings
+            .author
+            .clone()
+            .or_else(|| config.project.authors.as_ref().map(|a| a.join(", ")))
+            .unwrap_or_default();
+        let template = template
+            .trim_end()
+            .replace("{author}", &author)
+            .replace("{project}", &config.project.name);
+
+        let year = settings.year.clone().unwrap_or_else(current_year);
+        let text = comment(&template.replace("{year}", &year), settings);
+
+        // 연도는 2019, 2019-2024, 2019, 2021 같은 형식을 모두 허용
+        let marked = comment(&template.replace("{year}", YEAR_MARKER), settings);
+        let pattern = format!(
+            "^\u{feff}?{}",
+            regex::escape(&marked)
+                .replace(YEAR_MARKER, r"\d{4}(?:\s*[-,]\s*\d{4})*")
+                .replace('\n', r"\r?\n")
+        );
+        let pattern = Regex::new(&pattern)
+            .map_err(|e| BuildError::ConfigParsingError(format!("[license] header: {}", e)))?;
+
+        Ok(LicenseHeader { text, pattern })
+    }
+
+    fn is_present(&self, content: &str) -> bool {
+        self.pattern.is_match(content)
+    }
+}
+
+/// `cbuild license check`: 라이선스 헤더가 없는 소스와 헤더 파일 목록 출력
+///
+/// 헤더가 없는 파일 수를 반환한다.
+pub fn check(project_dir: &Path) -> BuildResult<usize> {
+    let config = BuildConfig::from_file(project_dir)?;
+    let header = LicenseHeader::from_config(&config, project_dir)?;
+
+    let files = project_files(&config, project_dir, &config.license.exclude, "license")?;
+    let mut missing = 0;
+    for file in &files {
+        let content = std::fs::read_to_string(file).unwrap_or_default();
+        if !header.is_present(&content) {
+            missing += 1;
+            println!(
+                "{} {}",
+                "missing".red().bold(),
+                file.strip_prefix(project_dir).unwrap_or(file).display()
+            );
+        }
+    }
+
+    if missing == 0 {
+        println!(
+            "{}",
+            tr!(
+                "파일 {}개 모두 라이선스 헤더가 있습니다.",
+                "All {} files have the license header.",
+                files.len()
+            )
+            .green()
+        );
+    } else {
+        println!(
+            "\n{}",
+            tr!(
+                "파일 {}개 중 {}개에 라이선스 헤더가 없습니다 (cbuild license apply로 추가).",
+                "{} of {} files are missing the license header (add with cbuild license apply).",
+                files.len(),
+                missing
+            )
+            .red()
+        );
+    }
+
+    Ok(missing)
+}
+
+/// `cbuild license apply`: 라이선스 헤더가 없는 파일 맨 앞에 헤더 추가
+pub fn apply(project_dir: &Path) -> BuildResult<()> {
+    let config = BuildConfig::from_file(project_dir)?;
+    let header = LicenseHeader::from_config(&config, project_dir)?;
+
+    let mut updated = 0;
+    for file in project_files(&config, project_dir, &config.license.exclude, "license")? {
+        let content = std::fs::read_to_string(&file).map_err(BuildError::IoError)?;
+        if header.is_present(&content) {
+            continue;
+        }
+
+        let body = content.strip_prefix('\u{feff}').unwrap_or(&content);
+        let newline = if body.contains("\r\n") { "\r\n" } else { "\n" };
+        let mut new_content = String::new();
+        if body.len() != content.len() {
+            new_content.push('\u{feff}');
+        }
+        new_content.push_str(&header.text.replace('\n', newline));
+        new_content.push_str(newline);
+        new_content.push_str(newline);
+        new_content.push_str(body.trim_start_matches(['\r', '\n']));
+        std::fs::write(&file, new_content).map_err(BuildError::IoError)?;
+
+        updated += 1;
+        println!(
+            "{} {}",
+            "Updated".green().bold(),
+            file.strip_prefix(project_dir).unwrap_or(&file).display()
+        );
+    }
+
+    println!(
+        "{}",
+        tr!(
+            "파일 {}개에 라이선스 헤더를 추가했습니다.",
+            "Added the license header to {} files.",
+            updated
+        )
+        .green()
+    );
+    Ok(())
+}
+
+/// 프로젝트의 C/C++ 소스와 헤더 (빌드 출력, deps/, 숨김 디렉토리, `exclude` 패턴 제외)
+///
+/// `exclude`는 프로젝트 기준 glob 패턴이며 `section`은 오류 메시지에 쓸 설정 이름이다.
+pub fn project_files(
+    config: &BuildConfig,
+    project_dir: &Path,
+    exclude: &Option<Vec<String>>,
+    section: &str,
+) -> BuildResult<Vec<PathBuf>> {
+    let exclude = exclude
+        .iter()
+        .flatten()
+        .map(|pattern| glob::Pattern::new(pattern))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| BuildError::ConfigParsingError(format!("[{}] exclude: {}", section, e)))?;
+    let output_root = BuildLayout::new(
+        project_dir,
+        config.default_configuration(),
+        None,
+        Some(config),
+    )
+    .output_root()
+    .to_path_buf();
+    let deps_dir = project_dir.join("deps");
+
+    let mut files: Vec<PathBuf> = WalkDir::new(project_dir)
+        .follow_links(config.build.follow_symlinks.unwrap_or(true))
+        .into_iter()
+        .filter_entry(|entry| {
+            let path = entry.path();
+            let hidden = entry.depth() > 0 && entry.file_name().to_string_lossy().starts_with('.');
+            !hidden && path != output_root && path != deps_dir
+        })
+        .flatten()
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.into_path())
+        .filter(|path| is_source_file(path) || is_header_file(path))
+        .filter(|path| {
+            let relative = path.strip_prefix(project_dir).unwrap_or(path);
+            !exclude.iter().any(|pattern| pattern.matches_path(relative))
+        })
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// 템플릿을 C 주석으로 감쌈 (`style = "line"`이면 `//`, 기본은 블록 주석)
+fn comment(template: &str, settings: &LicenseSettings) -> String {
+    let line_style = settings.style.as_deref() == Some("line");
+    let mut lines: Vec<String> = Vec::new();
+    if !line_style {
+        lines.push("/*".to_string());
+    }
+    for line in template.lines() {
+        let prefix = if line_style { "//" } else { " *" };
+        lines.push(format!("{} {}", prefix, line).trim_end().to_string());
+    }
+    if !line_style {
+        lines.push(" */".to_string());
+    }
+    lines.join("\n")
+}
+
+fn current_year() -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    format_timestamp(now)[..4].to_string()
+}
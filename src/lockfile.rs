@@ -0,0 +1,173 @@
+use crate::config::BuildConfig;
+use crate::error::{BuildError, BuildResult};
+use crate::features::ResolvedFeatures;
+use crate::i18n::tr;
+use crate::registry::RegistryRelease;
+use crate::utils::write_if_changed;
+use crate::vcs::head_commit;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// 설치한 의존성의 정확한 출처를 기록하는 파일 (저장소에 커밋)
+pub const LOCKFILE: &str = "cbuild.lock";
+
+const LOCKFILE_VERSION: u32 = 1;
+
+/// cbuild.lock 내용
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct Lockfile {
+    pub version: u32,
+    #[serde(default, rename = "dependency")]
+    pub dependencies: Vec<LockedDependency>,
+}
+
+/// 설치된 의존성 하나 (`[[dependency]]`)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LockedDependency {
+    pub name: String,
+    pub version: String,
+    /// `git+<저장소 URL>` 또는 압축 파일 URL
+    pub source: String,
+    /// 체크아웃한 git 태그 (tag 또는 version 조건으로 고른 태그)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    /// 레지스트리에서 고른 버전
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved: Option<String>,
+    /// git 의존성의 체크아웃 커밋
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rev: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+    /// 프로젝트와 다른 의존성의 요청을 합친 기능
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub features: Vec<String>,
+    /// [dev-dependencies] 또는 `scope = "test"`
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub dev: bool,
+}
+
+impl Lockfile {
+    /// cbuild.lock 읽기 (없으면 None)
+    pub fn load(project_dir: &Path) -> BuildResult<Option<Self>> {
+        let path = project_dir.join(LOCKFILE);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path).map_err(BuildError::IoError)?;
+        toml::from_str(&content).map(Some).map_err(|e| {
+            BuildError::DependencyError(tr!(
+                "{}을(를) 읽을 수 없습니다: {}",
+                "cannot parse {}: {}",
+                LOCKFILE,
+                e
+            ))
+        })
+    }
+
+    pub fn find(&self, name: &str) -> Option<&LockedDependency> {
+        self.dependencies.iter().find(|d| d.name == name)
+    }
+}
+
+/// 이번 설치/업데이트에서 정한 의존성별 출처
+#[derive(Debug, Default)]
+pub struct Resolutions {
+    /// 체크아웃한 git 태그
+    pub tags: BTreeMap<String, String>,
+    /// 레지스트리에서 고른 버전
+    pub releases: BTreeMap<String, RegistryRelease>,
+}
+
+/// 설치된 의존성을 cbuild.lock에 기록 (내용이 같으면 쓰지 않음)
+///
+/// 이번에 설치하지 않은 의존성(`--no-dev` 등)은 이전 기록을 유지하고,
+/// 설정에서 빠진 의존성은 지운다. 이미 설치되어 건너뛴 의존성은 version과 출처가
+/// 그대로면 이전에 기록한 태그와 레지스트리 버전을 유지한다.
+pub fn write_lockfile(
+    project_dir: &Path,
+    config: &BuildConfig,
+    features: &BTreeMap<String, ResolvedFeatures>,
+    resolutions: &Resolutions,
+) -> BuildResult<()> {
+    let path = project_dir.join(LOCKFILE);
+    // 의존성이 없는 프로젝트에는 만들지 않음
+    if config.all_dependencies().next().is_none() && !path.exists() {
+        return Ok(());
+    }
+
+    let previous = Lockfile::load(project_dir)?.unwrap_or_default();
+    let mut lockfile = Lockfile {
+        version: LOCKFILE_VERSION,
+        dependencies: Vec::new(),
+    };
+
+    for (name, dependency) in config.all_dependencies() {
+        let dep_dir = project_dir.join("deps").join(name);
+        if !dep_dir.exists() {
+            lockfile.dependencies.extend(previous.find(name).cloned());
+            continue;
+        }
+
+        let source = match (&dependency.git, &dependency.registry, &dependency.url) {
+            (Some(git), _, _) => format!("git+{}", git),
+            (None, Some(registry), _) => format!("registry+{}", registry),
+            (None, None, Some(url)) => url.clone(),
+            (None, None, None) => continue,
+        };
+        let kept = previous
+            .find(name)
+            .filter(|locked| locked.version == dependency.version && locked.source == source);
+        // 의존성 디렉토리가 git 저장소가 아니면 상위 프로젝트의 커밋이 나오므로 확인
+        let rev = if dependency.git.is_some() && dep_dir.join(".git").exists() {
+            head_commit(&dep_dir)
+        } else {
+            None
+        };
+        let tag = resolutions
+            .tags
+            .get(name)
+            .or(dependency.tag.as_ref())
+            .cloned()
+            .or_else(|| kept.and_then(|locked| locked.tag.clone()));
+        let release = resolutions.releases.get(name);
+        let resolved = release
+            .map(|release| release.version.clone())
+            .or_else(|| kept.and_then(|locked| locked.resolved.clone()));
+        let sha256 = match (release, &dependency.registry) {
+            (Some(release), _) => Some(release.sha256.clone()),
+            (None, Some(_)) => kept.and_then(|locked| locked.sha256.clone()),
+            (None, None) => dependency.sha256.clone(),
+        };
+        lockfile.dependencies.push(LockedDependency {
+            name: name.clone(),
+            version: dependency.version.clone(),
+            source,
+            tag,
+            resolved,
+            rev,
+            sha256: sha256.map(|s| s.to_lowercase()),
+            features: features
+                .get(name)
+                .map(|f| f.features.iter().cloned().collect())
+                .unwrap_or_default(),
+            dev: config.is_dev_dependency(name),
+        });
+    }
+
+    let body = toml::to_string(&lockfile).map_err(|e| {
+        BuildError::DependencyError(tr!(
+            "{}을(를) 만들 수 없습니다: {}",
+            "cannot serialize {}: {}",
+            LOCKFILE,
+            e
+        ))
+    })?;
+    let content = format!(
+        "# cbuild가 'cbuild dependencies' 실행 때 갱신하는 파일입니다. 직접 수정하지 마세요.\n\n{}",
+        body
+    );
+    write_if_changed(&path, &content).map_err(BuildError::IoError)?;
+    Ok(())
+}
@@ -1,29 +1,121 @@
+mod amalgamate;
+mod audit;
 mod builder;
+mod buildlog;
+mod cli_help;
+mod cmdlog;
 mod config;
+mod config_edit;
+mod container;
+mod context;
+mod conventions;
+mod crash;
+mod custom;
 mod dependency;
+mod diagnostics;
+mod distributed;
+mod doctor;
+mod download;
+mod embed;
 mod error;
+mod executor;
+mod features;
+mod gc;
+mod generated;
+mod graph;
+mod headers;
+mod hints;
+mod history;
+mod i18n;
+mod ide;
+mod inspect;
+mod install;
+mod jobserver;
+mod layout;
+mod leakcheck;
+mod license;
+mod lockfile;
+mod memory;
+mod migrate;
+mod package;
 mod parser;
+mod pch;
+mod pgo;
+mod progress;
+mod projgen;
+mod provision;
+mod registry;
+mod remote_exec;
+mod report;
+mod retry;
 mod runner;
+mod sarif;
+mod semver;
+mod signing;
+mod state;
+mod stats;
+mod test_report;
+mod testing;
+mod toolchain;
+mod tools;
 mod utils;
+mod vcs;
 
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
 use colored::Colorize;
-use log::{error, info};
-use std::path::PathBuf;
+use log::{error, info, warn};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
 
 use crate::builder::Builder;
-use crate::config::BuildConfig;
+use crate::config::{BuildConfig, Dependency, DEFAULT_CONFIGURATION};
+use crate::context::ProjectContext;
 use crate::dependency::DependencyManager;
+use crate::diagnostics::AnnotationFormat;
+use crate::download::Downloader;
+use crate::error::BuildError;
+use crate::i18n::{tr, Lang};
+use crate::inspect::DisasmOptions;
+use crate::migrate::MigrateSource;
+use crate::package::PackageFormat;
+use crate::progress::ProgressMode;
+use crate::projgen::IdeKind;
 use crate::runner::Runner;
+use crate::test_report::TestOutputFormat;
+use crate::testing::TestFramework;
+use crate::toolchain::Toolchain;
 
 /// 대규모 C 프로젝트 빌드 시스템
 #[derive(Parser)]
-#[command(author, version, about, long_about = None)]
+#[command(author, version, about, long_about = None, after_help = EXIT_CODES_HELP)]
 struct Cli {
     #[command(subcommand)]
     command: Command,
+
+    /// 색상 출력 끄기 (컴파일러 진단 포함, NO_COLOR 환경 변수와 같음)
+    #[arg(long, global = true)]
+    no_color: bool,
+
+    /// 메시지 언어 (en/ko, 기본: LANG 환경 변수)
+    #[arg(long, global = true, value_name = "LANG")]
+    lang: Option<Lang>,
+
+    /// git clone/fetch와 다운로드가 실패해도 다시 시도하지 않음 (cbuild.toml의 [retry] 무시)
+    #[arg(long, global = true)]
+    no_retry: bool,
 }
 
+/// `--help`에 표시하는 종료 코드 목록 (`error::exit_code`와 같아야 함)
+const EXIT_CODES_HELP: &str = "종료 코드:
+  0  성공
+  1  그 외 오류 (입출력, 경로, git 등)
+  2  잘못된 명령줄 인자
+  3  설정 오류 (설정 파일, 존재하지 않는 타겟)
+  4  컴파일 실패, 컴파일러·빌드 도구 없음
+  5  링크 실패
+  6  의존성 오류
+  7  테스트 실패";
+
 #[derive(Subcommand)]
 enum Command {
     /// 프로젝트 초기화
@@ -31,139 +123,2015 @@ enum Command {
         /// 프로젝트 디렉토리 (기본: 현재 디렉토리)
         #[arg(short, long)]
         directory: Option<PathBuf>,
+
+        /// .gitignore를 생성하지 않음
+        #[arg(long)]
+        no_gitignore: bool,
+
+        /// .clang-format과 .editorconfig를 생성하지 않음
+        #[arg(long)]
+        no_editor_config: bool,
     },
     /// 전체 프로젝트 빌드
     Build {
-        /// 프로젝트 디렉토리 (기본: 현재 디렉토리)
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 구성 (debug/release 또는 [profiles]의 이름, 기본: build.default_configuration 또는 debug)
+        #[arg(short, long)]
+        configuration: Option<String>,
+
+        /// 빌드 작업 병렬 처리를 위한 스레드 수 또는 auto-mem (기본: build.jobs 또는 CPU 코어 수)
+        #[arg(short, long)]
+        jobs: Option<Jobs>,
+
+        /// 변경 여부와 관계없이 모든 파일 다시 빌드 (기본은 바뀐 파일만 빌드)
+        #[arg(short, long)]
+        force: bool,
+
+        /// 이전 버전 호환용 (증분 빌드가 기본이라 효과 없음)
+        #[arg(short, long, hide = true)]
+        incremental: bool,
+
+        /// 지정한 git 참조 이후 변경의 영향을 받는 타겟만 빌드
+        #[arg(long, value_name = "REF")]
+        since: Option<String>,
+
+        /// 빌드할 타겟 이름, `종류:이름` 키 또는 별칭 (여러 번 지정 가능, 기본 별칭: all-tests)
+        #[arg(short, long = "target", value_name = "NAME")]
+        targets: Vec<String>,
+
+        /// 기본 빌드에서 제외된 타겟(default = false, 예제)까지 모두 빌드
+        #[arg(long, conflicts_with = "targets")]
+        all_targets: bool,
+
+        /// 빌드 후 자동으로 실행
+        #[arg(short, long)]
+        run: bool,
+
+        /// 명령을 실행하지 않고 컴파일·아카이브·링크 명령을 순서대로 출력
+        #[arg(long, conflicts_with = "run")]
+        dry_run: bool,
+
+        /// --dry-run의 명령 목록을 JSON 파일로 저장
+        #[arg(long, value_name = "PATH", requires = "dry_run")]
+        emit_plan: Option<PathBuf>,
+
+        /// 컴파일러/링커를 낮은 CPU·IO 우선순위로 실행
+        #[arg(long)]
+        nice: bool,
+
+        /// 진행 상황 표시 방식 (fancy/plain/none)
+        #[arg(long, value_name = "MODE", default_value = "fancy")]
+        progress: ProgressMode,
+
+        /// 진단을 CI 주석으로 다시 출력 (github/none, 기본: GITHUB_ACTIONS 설정 시 github)
+        #[arg(long, value_name = "FORMAT")]
+        annotations: Option<AnnotationFormat>,
+
+        /// 컴파일러 진단을 SARIF 2.1 파일로 저장
+        #[arg(long, value_name = "PATH")]
+        emit_sarif: Option<PathBuf>,
+
+        /// 오류로 처리할 경고 옵션 (쉼표로 구분, 예: unused-variable,shadow / all)
+        #[arg(
+            long,
+            value_name = "LIST",
+            value_delimiter = ',',
+            allow_hyphen_values = true
+        )]
+        deny_warnings: Vec<String>,
+
+        /// 자세한 빌드 정보 출력
+        #[arg(short, long)]
+        verbose: bool,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+
+        /// 컴파일·링크를 지정한 이미지의 docker/podman 컨테이너에서 실행
+        #[arg(long, value_name = "IMAGE")]
+        container: Option<String>,
+
+        /// [distributed]의 distcc/icecc로 컴파일을 원격 호스트에 분산
+        #[arg(long, conflicts_with = "container")]
+        distributed: bool,
+
+        /// (실험적) 컴파일을 [remote_execution] 서버에서 실행
+        #[arg(long, conflicts_with_all = ["container", "distributed"])]
+        remote_exec: bool,
+
+        /// 설치된 툴체인의 컴파일러·아카이버 사용 (`cbuild toolchain install`로 설치)
+        #[arg(long, value_name = "NAME")]
+        toolchain: Option<String>,
+
+        /// 빌드하면서 프로젝트 루트에 compile_commands.json 작성 (clangd, clang-tidy용)
+        #[arg(long)]
+        compile_commands: bool,
+    },
+    /// 오브젝트 생성 없이 빠르게 문법만 검사 (-fsyntax-only)
+    Check {
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 구성 (debug/release 또는 [profiles]의 이름, 기본: build.default_configuration 또는 debug)
+        #[arg(short, long)]
+        configuration: Option<String>,
+
+        /// 병렬 처리를 위한 스레드 수 또는 auto-mem (기본: build.jobs 또는 CPU 코어 수)
+        #[arg(short, long)]
+        jobs: Option<Jobs>,
+
+        /// 마지막 증분 빌드 이후 변경된 파일만 검사
+        #[arg(long)]
+        changed: bool,
+
+        /// 컴파일러/링커를 낮은 CPU·IO 우선순위로 실행
+        #[arg(long)]
+        nice: bool,
+
+        /// 진단을 CI 주석으로 다시 출력 (github/none, 기본: GITHUB_ACTIONS 설정 시 github)
+        #[arg(long, value_name = "FORMAT")]
+        annotations: Option<AnnotationFormat>,
+
+        /// 컴파일러 진단을 SARIF 2.1 파일로 저장
+        #[arg(long, value_name = "PATH")]
+        emit_sarif: Option<PathBuf>,
+
+        /// 오류로 처리할 경고 옵션 (쉼표로 구분, 예: unused-variable,shadow / all)
+        #[arg(
+            long,
+            value_name = "LIST",
+            value_delimiter = ',',
+            allow_hyphen_values = true
+        )]
+        deny_warnings: Vec<String>,
+
+        /// 실행되는 컴파일러 명령 출력
+        #[arg(short, long)]
+        verbose: bool,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+
+        /// 설치된 툴체인의 컴파일러·아카이버 사용 (`cbuild toolchain install`로 설치)
+        #[arg(long, value_name = "NAME")]
+        toolchain: Option<String>,
+    },
+    /// 소스 파일의 전처리(-E) 결과 출력
+    Expand {
+        /// 전처리할 소스 파일
+        file: PathBuf,
+
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 파일이 여러 타겟에 속할 때 사용할 타겟
+        #[arg(short, long)]
+        target: Option<String>,
+
+        /// 빌드 구성 (debug/release 또는 [profiles]의 이름, 기본: build.default_configuration 또는 debug)
+        #[arg(short, long)]
+        configuration: Option<String>,
+
+        /// 페이저를 사용하지 않고 바로 출력
+        #[arg(long)]
+        no_pager: bool,
+    },
+    /// 소스 파일 하나만 소속 타겟의 플래그로 컴파일하고 진단을 바로 출력
+    Compile {
+        /// 컴파일할 소스 파일
+        file: PathBuf,
+
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 파일이 여러 타겟에 속할 때 사용할 타겟 (없으면 터미널에서 선택)
+        #[arg(short, long)]
+        target: Option<String>,
+
+        /// 빌드 구성 (debug/release 또는 [profiles]의 이름, 기본: build.default_configuration 또는 debug)
+        #[arg(short, long)]
+        configuration: Option<String>,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+
+        /// 컴파일 명령 출력
+        #[arg(short, long)]
+        verbose: bool,
+    },
+    /// 빌드하지 않고 빌드와 같은 컴파일 명령으로 compile_commands.json 작성 (clangd, clang-tidy용)
+    CompileCommands {
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 구성 (debug/release 또는 [profiles]의 이름, 기본: build.default_configuration 또는 debug)
+        #[arg(short, long)]
+        configuration: Option<String>,
+
+        /// 출력 파일 (기본: <프로젝트>/compile_commands.json)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+    /// 변경된 파일의 영향을 받는 타겟과 테스트 출력
+    Affected {
+        /// 변경된 파일 목록
+        #[arg(long, num_args = 1.., required_unless_present = "since")]
+        files: Vec<PathBuf>,
+
+        /// 지정한 git 참조 이후 변경된 파일 사용
+        #[arg(long, value_name = "REF")]
+        since: Option<String>,
+
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+    /// 이전 빌드 기록 목록 출력
+    History {
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 최근 N개만 출력
+        #[arg(short = 'n', long)]
+        limit: Option<usize>,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+    /// 마지막 빌드의 소요 시간, 경고, 결과물, 테스트 결과를 HTML 보고서로 저장 (build/report)
+    Report {
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 구성 (debug/release 또는 [profiles]의 이름, 기본: build.default_configuration 또는 debug)
+        #[arg(short, long)]
+        configuration: Option<String>,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+    /// 현재 디렉토리에서 위로 찾은 프로젝트 루트(cbuild.toml의 디렉토리)를 JSON으로 출력
+    LocateProject {
+        /// JSON 대신 디렉토리 경로만 출력
+        #[arg(long)]
+        plain: bool,
+    },
+    /// 마지막 빌드에서 실행한 외부 명령 출력 (build/commands.log)
+    LastCommands {
+        /// 타겟 키(exe:app), 타겟 이름 또는 소스 파일 경로 (기본: 모든 명령)
+        filter: Option<String>,
+
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+
+        /// JSON으로 출력
+        #[arg(long)]
+        json: bool,
+    },
+    /// 두 빌드의 결과물 크기와 해시 비교
+    DiffArtifacts {
+        /// 비교 기준 빌드 ID
+        id: u64,
+
+        /// 비교 대상 빌드 ID (기본: 가장 최근 빌드)
+        #[arg(long)]
+        against: Option<u64>,
+
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+    /// 빌드된 타겟의 디스어셈블 출력
+    Disasm {
+        /// 디스어셈블할 타겟 이름
+        target: String,
+
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
         #[arg(short, long)]
         directory: Option<PathBuf>,
 
-        /// 빌드 구성 (debug/release)
-        #[arg(short, long, default_value = "debug")]
+        /// 특정 심볼만 디스어셈블
+        #[arg(short, long)]
+        symbol: Option<String>,
+
+        /// 빌드 구성 (debug/release 또는 [profiles]의 이름, 기본: build.default_configuration 또는 debug)
+        #[arg(short, long)]
+        configuration: Option<String>,
+
+        /// Intel 문법 사용 (x86)
+        #[arg(long)]
+        intel: bool,
+
+        /// 소스 코드와 함께 출력 (디버그 정보 필요)
+        #[arg(long)]
+        source: bool,
+
+        /// 페이저를 사용하지 않고 바로 출력
+        #[arg(long)]
+        no_pager: bool,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+    /// 프로젝트 정리 (빌드 결과물 삭제)
+    Clean {
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 구성 (debug/release 또는 [profiles]의 이름, 기본: build.default_configuration 또는 debug)
+        #[arg(short, long)]
+        configuration: Option<String>,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+    /// 쓰지 않는 오브젝트, 오래된 빌드 구성, 다운로드 캐시, 빠진 의존성 정리
+    Gc {
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+
+        /// 지우지 않고 정리할 항목과 확보할 용량만 출력
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// 프로젝트 실행
+    Run {
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 실행 인자
+        #[arg(short, long)]
+        args: Option<String>,
+
+        /// [run.aliases]에 정의된 실행 별칭
+        #[arg(long)]
+        alias: Option<String>,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+    /// 예제 목록 출력 또는 예제를 빌드해 실행 (예: cbuild examples demo -- --verbose)
+    Examples {
+        /// 실행할 예제 이름 (없으면 예제 목록 출력)
+        name: Option<String>,
+
+        /// 예제에 전달할 인자
+        #[arg(last = true)]
+        args: Vec<String>,
+
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 구성 (debug/release 또는 [profiles]의 이름, 기본: build.default_configuration 또는 debug)
+        #[arg(short, long)]
+        configuration: Option<String>,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+    /// 프로젝트 빌드 환경에서 명령 실행 (예: cbuild exec -- gdb main)
+    Exec {
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 실행할 명령과 인자
+        #[arg(last = true, required = true)]
+        command: Vec<String>,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+    /// 테스트 타겟 빌드 후 실행
+    Test {
+        #[command(subcommand)]
+        action: Option<TestCommand>,
+
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 구성 (debug/release 또는 [profiles]의 이름, 기본: build.default_configuration 또는 debug)
+        #[arg(short, long)]
+        configuration: Option<String>,
+
+        /// 빌드 작업 병렬 처리를 위한 스레드 수 또는 auto-mem (기본: build.jobs 또는 CPU 코어 수)
+        #[arg(short, long)]
+        jobs: Option<Jobs>,
+
+        /// 테스트 실패를 CI 주석으로 출력 (github/none, 기본: GITHUB_ACTIONS 설정 시 github)
+        #[arg(long, value_name = "FORMAT")]
+        annotations: Option<AnnotationFormat>,
+
+        /// 모든 테스트 타겟의 출력 형식 (exit-code/tap/unity/cmocka/greatest, 기본: 타겟의 format)
+        #[arg(long)]
+        format: Option<TestOutputFormat>,
+
+        /// 테스트 케이스별 결과를 JUnit XML로 저장할 경로
+        #[arg(long, value_name = "PATH")]
+        junit: Option<PathBuf>,
+
+        /// 첫 번째 테스트 실패에서 중단
+        #[arg(long)]
+        fail_fast: bool,
+
+        /// 골든 테스트의 기대 결과 파일을 현재 출력으로 갱신
+        #[arg(long)]
+        update_golden: bool,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+
+        /// 설치된 툴체인의 컴파일러·아카이버 사용 (`cbuild toolchain install`로 설치)
+        #[arg(long, value_name = "NAME")]
+        toolchain: Option<String>,
+    },
+    /// 다시 빌드하지 않고 결과물이 최신인지, 마지막 빌드 이후 바뀌지 않았는지 확인
+    Verify {
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 구성 (debug/release 또는 [profiles]의 이름, 기본: build.default_configuration 또는 debug)
+        #[arg(short, long)]
+        configuration: Option<String>,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+
+        /// 설치된 툴체인의 컴파일러·아카이버 사용 (`cbuild toolchain install`로 설치)
+        #[arg(long, value_name = "NAME")]
+        toolchain: Option<String>,
+    },
+    /// 의존성의 알려진 취약점 검사 (취약점이 있으면 실패 종료, `audit layout`은 [layout] 규칙 검사)
+    #[command(args_conflicts_with_subcommands = true)]
+    Audit {
+        #[command(subcommand)]
+        action: Option<AuditCommand>,
+
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// OSV API를 조회하지 않고 [audit] database만 사용
+        #[arg(long)]
+        offline: bool,
+
+        /// 실패로 세지 않을 권고 ID (여러 번 지정 가능, [audit] ignore에 추가)
+        #[arg(long, value_name = "ID")]
+        ignore: Vec<String>,
+    },
+    /// 빌드 환경(컴파일러, 도구), cbuild.toml, 의존성 설치 상태 점검
+    Doctor {
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+    },
+    /// 의존성 다운로드 및 설치
+    Dependencies {
+        #[command(subcommand)]
+        action: Option<DependenciesCommand>,
+
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 의존성 업데이트
+        #[arg(short, long)]
+        update: bool,
+
+        /// 테스트 타겟 전용 의존성([dev-dependencies], scope = "test")은 설치하지 않음
+        #[arg(long)]
+        no_dev: bool,
+    },
+    /// cbuild 타겟을 감싸는 IDE 프로젝트 생성 (xcode/vs)
+    Gen {
+        /// 생성할 IDE 프로젝트 종류 (xcode/vs)
+        #[arg(long)]
+        ide: IdeKind,
+
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+    /// IDE/편집기 설정 파일 생성
+    Ide {
+        #[command(subcommand)]
+        ide: IdeCommand,
+    },
+    /// 라이브러리를 하나의 .c와 .h로 합침 (배포용)
+    Amalgamate {
+        /// 합칠 라이브러리 타겟 이름
+        #[arg(long)]
+        target: String,
+
+        /// 생성 파일을 저장할 디렉토리 (기본: <출력 디렉토리>/amalgamation)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+    /// 소스와 헤더의 포함 관계 출력
+    Graph {
+        /// 순환 포함 경로만 출력 (a.h -> b.h -> a.h)
+        #[arg(long)]
+        cycles: bool,
+
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 구성 (debug/release 또는 [profiles]의 이름, 기본: build.default_configuration 또는 debug)
+        #[arg(short, long)]
+        configuration: Option<String>,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+    /// 헤더 파일 검사
+    Headers {
+        #[command(subcommand)]
+        action: HeadersCommand,
+    },
+    /// 타겟별 소스 파일 수, 줄 수, 객체 파일과 결과물 크기 출력
+    Stats {
+        #[command(subcommand)]
+        action: Option<StatsCommand>,
+
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 구성 (debug/release 또는 [profiles]의 이름, 기본: build.default_configuration 또는 debug)
+        #[arg(short, long)]
+        configuration: Option<String>,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+
+        /// JSON으로 출력
+        #[arg(long)]
+        json: bool,
+    },
+    /// 소스·헤더 파일의 라이선스 헤더 검사 및 추가 ([license])
+    License {
+        #[command(subcommand)]
+        action: LicenseCommand,
+    },
+    /// 기존 빌드 설정(Makefile, CMake 빌드 디렉토리)에서 cbuild.toml 생성
+    Migrate {
+        /// 변환할 빌드 시스템 (make/cmake)
+        #[arg(long)]
+        from: MigrateSource,
+
+        /// 입력 파일 (make: Makefile, cmake: 빌드 디렉토리 또는 compile_commands.json, 기본: build)
+        #[arg(long, value_name = "PATH")]
+        input: Option<PathBuf>,
+
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 이미 있는 cbuild.toml 덮어쓰기
+        #[arg(long)]
+        force: bool,
+    },
+    /// 빌드 후 실행 파일, 라이브러리, 공개 헤더를 설치 접두사 아래에 복사
+    Install {
+        /// 설치 접두사 (bin, lib, include 디렉토리 생성)
+        #[arg(long, default_value = "/usr/local")]
+        prefix: PathBuf,
+
+        /// 다른 CMake 프로젝트가 find_package()로 찾을 수 있는 <이름>Config.cmake 생성
+        #[arg(long)]
+        cmake_config: bool,
+
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 구성 (debug/release 또는 [profiles]의 이름)
+        #[arg(short, long, default_value = "release")]
+        configuration: String,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+    /// 실행 파일과 공유 라이브러리, 에셋을 재배치 가능한 Linux 번들로 묶기
+    Package {
+        /// 번들 형식 (appimage/flatpak)
+        #[arg(long, default_value = "appimage")]
+        format: PackageFormat,
+
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 구성 (debug/release 또는 [profiles]의 이름)
+        #[arg(short, long, default_value = "release")]
         configuration: String,
 
-        /// 빌드 작업 병렬 처리를 위한 스레드 수 (기본: 시스템 CPU 코어 수)
-        #[arg(short, long)]
-        jobs: Option<usize>,
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+    /// 프로파일 기반 최적화 빌드 (계측 빌드 -> 학습 실행 -> -fprofile-use 재빌드)
+    Pgo {
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 구성 (debug/release 또는 [profiles]의 이름)
+        #[arg(short, long, default_value = "release")]
+        configuration: String,
+
+        /// 빌드 작업 병렬 처리를 위한 스레드 수 (기본: build.jobs 또는 CPU 코어 수)
+        #[arg(short, long)]
+        jobs: Option<usize>,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+    /// 휴대용 툴체인 설치 및 관리
+    Toolchain {
+        #[command(subcommand)]
+        action: ToolchainCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum ToolchainCommand {
+    /// cbuild.toml의 [toolchains.<name>]에 고정한 툴체인을 내려받아 ~/.cbuild/toolchains에 설치
+    Install {
+        /// 설치할 툴체인 이름
+        name: String,
+
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 이미 설치되어 있어도 다시 내려받기
+        #[arg(long)]
+        force: bool,
+    },
+    /// 설치된 툴체인 목록
+    List,
+    /// 프로젝트가 사용하는 컴파일러·아카이버·링커와 타겟 트리플 정보 출력
+    Show {
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 설치된 툴체인의 컴파일러·아카이버 사용 (`cbuild toolchain install`로 설치)
+        #[arg(long, value_name = "NAME")]
+        toolchain: Option<String>,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum TestCommand {
+    /// 테스트 프레임워크(unity/cmocka)를 추가하고 예제 테스트 타겟 생성
+    Init {
+        /// 사용할 테스트 프레임워크 (unity/cmocka)
+        #[arg(short, long, default_value = "unity")]
+        framework: TestFramework,
+    },
+}
+
+#[derive(Subcommand)]
+enum IdeCommand {
+    /// VS Code 설정 생성 (.vscode/c_cpp_properties.json, tasks.json, launch.json)
+    Vscode {
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 구성 (debug/release 또는 [profiles]의 이름, 기본: build.default_configuration 또는 debug)
+        #[arg(short, long)]
+        configuration: Option<String>,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+    /// clangd 설정 생성 (compile_commands.json, .clangd)
+    Clangd {
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 구성 (debug/release 또는 [profiles]의 이름, 기본: build.default_configuration 또는 debug)
+        #[arg(short, long)]
+        configuration: Option<String>,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum AuditCommand {
+    /// [layout] 규칙(테스트 위치, 공개 헤더 위치, 파일 이름)을 어긴 파일 보고 (있으면 실패 종료)
+    Layout {
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum LicenseCommand {
+    /// 라이선스 헤더가 없는 소스·헤더 파일 보고 (있으면 실패 종료)
+    Check {
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+    },
+    /// 라이선스 헤더가 없는 파일 맨 앞에 헤더 추가
+    Apply {
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum HeadersCommand {
+    /// 공개 헤더의 단독 컴파일 가능 여부, include guard, 전이적 포함 수 검사
+    Check {
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 구성 (debug/release 또는 [profiles]의 이름, 기본: build.default_configuration 또는 debug)
+        #[arg(short, long)]
+        configuration: Option<String>,
+
+        /// 전이적 포함 수 상위 몇 개를 표시할지
+        #[arg(long, default_value_t = 10)]
+        top: usize,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum DependenciesCommand {
+    /// [dependencies]에 의존성 추가 또는 변경 (cbuild.toml의 주석과 순서 유지, 이전 내용은 .bak)
+    Add {
+        /// 의존성 이름 (deps/<이름>에 설치)
+        name: String,
+
+        /// 버전
+        #[arg(long)]
+        version: String,
+
+        /// git 저장소 URL
+        #[arg(long, conflicts_with = "url")]
+        git: Option<String>,
+
+        /// git 태그
+        #[arg(long, requires = "git")]
+        tag: Option<String>,
+
+        /// git 브랜치
+        #[arg(long, requires = "git")]
+        branch: Option<String>,
+
+        /// 압축 파일 URL (--sha256 필요)
+        #[arg(long, requires = "sha256", conflicts_with = "registry")]
+        url: Option<String>,
+
+        /// 압축 파일의 SHA-256
+        #[arg(long, requires = "url")]
+        sha256: Option<String>,
+
+        /// 패키지 레지스트리 주소 (--version은 "^1.2" 같은 조건도 가능)
+        #[arg(long, conflicts_with = "git")]
+        registry: Option<String>,
+
+        /// 사용할 기능 (쉼표로 구분)
+        #[arg(long, value_delimiter = ',')]
+        features: Vec<String>,
+
+        /// 테스트 타겟 전용 의존성으로 [dev-dependencies]에 추가
+        #[arg(long)]
+        dev: bool,
+
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+    },
+    /// [dependencies]나 [dev-dependencies]에서 의존성 삭제 (cbuild.toml의 주석과 순서 유지, 이전 내용은 .bak)
+    Remove {
+        /// 의존성 이름
+        name: String,
+
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum StatsCommand {
+    /// 가장 많이 포함되는 헤더, 가장 깊은 포함 경로, 번역 단위당 평균 포함 수와 추이
+    Headers {
+        /// 프로젝트 디렉토리 (기본: cbuild.toml이 있는 현재 또는 상위 디렉토리)
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+
+        /// 빌드 구성 (debug/release 또는 [profiles]의 이름, 기본: build.default_configuration 또는 debug)
+        #[arg(short, long)]
+        configuration: Option<String>,
+
+        /// 헤더와 포함 경로를 상위 몇 개까지 표시할지
+        #[arg(long, default_value_t = 10)]
+        top: usize,
+
+        /// 빌드 결과물 출력 디렉토리 (기본: build.output_dir 또는 <프로젝트>/build)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+}
+
+/// `--jobs` 값: 스레드 수 또는 메모리 기반 자동 조절
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Jobs {
+    Count(usize),
+    AutoMem,
+}
+
+impl Jobs {
+    fn count(self) -> usize {
+        match self {
+            Jobs::Count(n) => n.max(1),
+            Jobs::AutoMem => num_cpus::get(),
+        }
+    }
+}
+
+impl std::str::FromStr for Jobs {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto-mem" => Ok(Jobs::AutoMem),
+            _ => s.parse().map(Jobs::Count).map_err(|_| {
+                tr!(
+                    "숫자 또는 auto-mem이어야 합니다: {}",
+                    "expected a number or auto-mem: {}",
+                    s
+                )
+            }),
+        }
+    }
+}
+
+/// 도움말 언어를 정하기 위해 명령줄 파싱 전에 `--lang` 값을 찾음
+fn lang_from_args() -> Option<Lang> {
+    let mut args = std::env::args_os().skip(1);
+    while let Some(arg) = args.next() {
+        let arg = arg.to_str()?;
+        if arg == "--" {
+            break;
+        }
+        if arg == "--lang" {
+            return args.next()?.to_str()?.parse().ok();
+        }
+        if let Some(value) = arg.strip_prefix("--lang=") {
+            return value.parse().ok();
+        }
+    }
+    None
+}
+
+fn main() {
+    env_logger::init();
+    if let Some(lang) = lang_from_args() {
+        i18n::set_lang(lang);
+    }
+    let matches = cli_help::localize(Cli::command()).get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    if cli.no_color {
+        colored::control::set_override(false);
+    }
+    if cli.no_retry {
+        retry::disable();
+    }
+
+    let current_dir = std::env::current_dir().expect(&tr!(
+        "현재 디렉토리를 확인할 수 없습니다",
+        "cannot determine the current directory"
+    ));
+
+    match cli.command {
+        Command::Init {
+            directory,
+            no_gitignore,
+            no_editor_config,
+        } => {
+            let project_dir = directory.unwrap_or(current_dir);
+            init_project(&project_dir, !no_gitignore, !no_editor_config);
+        }
+        Command::Build {
+            directory,
+            configuration,
+            jobs,
+            force,
+            incremental: _,
+            since,
+            targets,
+            all_targets,
+            run,
+            dry_run,
+            emit_plan,
+            nice,
+            progress,
+            annotations,
+            emit_sarif,
+            deny_warnings,
+            verbose,
+            out_dir,
+            container,
+            distributed,
+            remote_exec,
+            toolchain,
+            compile_commands,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            if nice {
+                enable_background_priority();
+            }
+            let context = project_context(&project_dir, toolchain);
+            let defaults = BuildDefaults::load(&context);
+            let configuration = defaults.configuration(configuration);
+            let jobs = defaults.jobs(jobs);
+
+            let mut builder = project_builder(context.clone(), &configuration, jobs.count());
+            builder.set_adaptive_memory(jobs == Jobs::AutoMem);
+            builder.set_incremental(defaults.incremental && !force);
+            builder.set_since(since);
+            builder.set_targets(targets);
+            builder.set_all_targets(all_targets);
+            builder.set_progress(progress);
+            builder.set_annotations(annotations.unwrap_or_else(AnnotationFormat::from_env));
+            builder.set_sarif_output(emit_sarif);
+            builder.set_deny_warnings(deny_warnings);
+            builder.set_verbose(verbose);
+            builder.set_output_dir(out_dir.clone());
+            builder.set_container(container);
+            builder.set_distributed(distributed);
+            builder.set_remote_execution(remote_exec);
+            builder.set_compile_commands(compile_commands);
+            if dry_run {
+                builder.set_dry_run(emit_plan);
+            }
+
+            if let Err(e) = builder.build() {
+                exit_with_error(&tr!("빌드 실패", "build failed"), e);
+            }
+
+            if run {
+                let mut runner = Runner::with_context(context);
+                runner.set_configuration(&configuration);
+                runner.set_output_dir(out_dir);
+                if let Err(e) = runner.run(None, None) {
+                    exit_with_error(&tr!("실행 실패", "run failed"), e);
+                }
+            }
+        }
+        Command::Check {
+            directory,
+            configuration,
+            jobs,
+            changed,
+            nice,
+            annotations,
+            emit_sarif,
+            deny_warnings,
+            verbose,
+            out_dir,
+            toolchain,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            if nice {
+                enable_background_priority();
+            }
+            let context = project_context(&project_dir, toolchain);
+            let defaults = BuildDefaults::load(&context);
+            let configuration = defaults.configuration(configuration);
+            let jobs = defaults.jobs(jobs);
+
+            let mut builder = project_builder(context, &configuration, jobs.count());
+            builder.set_adaptive_memory(jobs == Jobs::AutoMem);
+            builder.set_annotations(annotations.unwrap_or_else(AnnotationFormat::from_env));
+            builder.set_sarif_output(emit_sarif);
+            builder.set_deny_warnings(deny_warnings);
+            builder.set_verbose(verbose);
+            builder.set_output_dir(out_dir);
+
+            if let Err(e) = builder.check(changed) {
+                exit_with_error(&tr!("문법 검사 실패", "syntax check failed"), e);
+            }
+        }
+        Command::Expand {
+            file,
+            directory,
+            target,
+            configuration,
+            no_pager,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            let context = project_context(&project_dir, None);
+            let configuration = BuildDefaults::load(&context).configuration(configuration);
+            let mut builder = project_builder(context, &configuration, 1);
+
+            if let Err(e) = builder.expand(&file, target.as_deref(), !no_pager) {
+                exit_with_error(&tr!("전처리 실패", "preprocessing failed"), e);
+            }
+        }
+        Command::Compile {
+            file,
+            directory,
+            target,
+            configuration,
+            out_dir,
+            verbose,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            let context = project_context(&project_dir, None);
+            let configuration = BuildDefaults::load(&context).configuration(configuration);
+            let mut builder = project_builder(context, &configuration, 1);
+            builder.set_output_dir(out_dir);
+            builder.set_verbose(verbose);
+
+            if let Err(e) = builder.compile_file(&file, target.as_deref()) {
+                exit_with_error(&tr!("컴파일 실패", "compile failed"), e);
+            }
+        }
+        Command::CompileCommands {
+            directory,
+            configuration,
+            output,
+            out_dir,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            let context = project_context(&project_dir, None);
+            let configuration = BuildDefaults::load(&context).configuration(configuration);
+            let mut builder = project_builder(context, &configuration, 1);
+            builder.set_output_dir(out_dir);
+
+            let output = output
+                .map(|path| current_dir.join(path))
+                .unwrap_or_else(|| project_dir.join("compile_commands.json"));
+            if let Err(e) = builder.compile_commands(&output) {
+                exit_with_error(
+                    &tr!(
+                        "compile_commands.json 생성 실패",
+                        "compile_commands.json generation failed"
+                    ),
+                    e,
+                );
+            }
+        }
+        Command::Affected {
+            files,
+            since,
+            directory,
+            out_dir,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            let context = project_context(&project_dir, None);
+            let configuration = BuildDefaults::load(&context).configuration(None);
+            let mut builder = project_builder(context, &configuration, 1);
+            builder.set_output_dir(out_dir);
+
+            if let Err(e) = builder.affected(&files, since.as_deref()) {
+                exit_with_error(&tr!("영향 분석 실패", "affected analysis failed"), e);
+            }
+        }
+        Command::History {
+            directory,
+            limit,
+            out_dir,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            let context = project_context(&project_dir, None);
+            let configuration = BuildDefaults::load(&context).configuration(None);
+
+            let layout = context.layout(&configuration, out_dir.as_deref());
+
+            if let Err(e) = history::print_history(&layout, limit) {
+                exit_with_error(
+                    &tr!("빌드 기록 조회 실패", "failed to read build history"),
+                    e,
+                );
+            }
+        }
+        Command::Report {
+            directory,
+            configuration,
+            out_dir,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            let context = project_context(&project_dir, None);
+            let configuration = BuildDefaults::load(&context).configuration(configuration);
+
+            match report::report(&context, &configuration, out_dir) {
+                Ok(path) => println!(
+                    "{} {}",
+                    tr!("보고서 저장:", "Report written:").green().bold(),
+                    path.display()
+                ),
+                Err(e) => exit_with_error(&tr!("보고서 생성 실패", "report failed"), e),
+            }
+        }
+        Command::LocateProject { plain } => {
+            if let Err(e) = locate_project(&current_dir, plain) {
+                exit_with_error(
+                    &tr!("프로젝트를 찾을 수 없음", "could not locate project"),
+                    e,
+                );
+            }
+        }
+        Command::LastCommands {
+            filter,
+            directory,
+            out_dir,
+            json,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            let context = project_context(&project_dir, None);
+            let configuration = BuildDefaults::load(&context).configuration(None);
+            let layout = context.layout(&configuration, out_dir.as_deref());
+
+            if let Err(e) = cmdlog::last_commands(&project_dir, &layout, filter.as_deref(), json) {
+                exit_with_error(&tr!("명령 기록 조회 실패", "failed to read command log"), e);
+            }
+        }
+        Command::DiffArtifacts {
+            id,
+            against,
+            directory,
+            out_dir,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            let context = project_context(&project_dir, None);
+            let configuration = BuildDefaults::load(&context).configuration(None);
+            let layout = context.layout(&configuration, out_dir.as_deref());
 
-        /// 변경된 파일만 다시 빌드
-        #[arg(short, long)]
-        incremental: bool,
+            if let Err(e) = history::diff_artifacts(&layout, id, against) {
+                exit_with_error(&tr!("결과물 비교 실패", "artifact diff failed"), e);
+            }
+        }
+        Command::Disasm {
+            target,
+            directory,
+            symbol,
+            configuration,
+            intel,
+            source,
+            no_pager,
+            out_dir,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            let context = project_context(&project_dir, None);
+            let configuration = BuildDefaults::load(&context).configuration(configuration);
+            let options = DisasmOptions {
+                symbol,
+                intel_syntax: intel,
+                with_source: source,
+                use_pager: !no_pager,
+            };
 
-        /// 빌드 후 자동으로 실행
-        #[arg(short, long)]
-        run: bool,
+            if let Err(e) =
+                inspect::disassemble(&context, &configuration, out_dir, &target, &options)
+            {
+                exit_with_error(&tr!("디스어셈블 실패", "disassembly failed"), e);
+            }
+        }
+        Command::Clean {
+            directory,
+            configuration,
+            out_dir,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            let context = project_context(&project_dir, None);
+            let configuration = BuildDefaults::load(&context).configuration(configuration);
+            let mut builder = project_builder(context, &configuration, 1);
+            builder.set_output_dir(out_dir);
 
-        /// 자세한 빌드 정보 출력
-        #[arg(short, long)]
-        verbose: bool,
-    },
-    /// 프로젝트 정리 (빌드 결과물 삭제)
-    Clean {
-        /// 프로젝트 디렉토리 (기본: 현재 디렉토리)
-        #[arg(short, long)]
-        directory: Option<PathBuf>,
-    },
-    /// 프로젝트 실행
-    Run {
-        /// 프로젝트 디렉토리 (기본: 현재 디렉토리)
-        #[arg(short, long)]
-        directory: Option<PathBuf>,
+            if let Err(e) = builder.clean() {
+                exit_with_error(&tr!("정리 실패", "clean failed"), e);
+            }
+        }
+        Command::Gc {
+            directory,
+            out_dir,
+            dry_run,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            let context = project_context(&project_dir, None);
+            if let Err(e) = gc::gc(&context, out_dir.as_deref(), dry_run) {
+                exit_with_error(&tr!("정리 실패", "gc failed"), e);
+            }
+        }
+        Command::Run {
+            directory,
+            args,
+            alias,
+            out_dir,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            let context = project_context(&project_dir, None);
+            let configuration = BuildDefaults::load(&context).configuration(None);
+            let mut runner = Runner::with_context(context);
+            runner.set_configuration(&configuration);
+            runner.set_output_dir(out_dir);
 
-        /// 실행 인자
-        #[arg(short, long)]
-        args: Option<String>,
-    },
-    /// 의존성 다운로드 및 설치
-    Dependencies {
-        /// 프로젝트 디렉토리 (기본: 현재 디렉토리)
-        #[arg(short, long)]
-        directory: Option<PathBuf>,
+            if let Err(e) = runner.run(alias.as_deref(), args.as_deref()) {
+                exit_with_error(&tr!("실행 실패", "run failed"), e);
+            }
+        }
+        Command::Examples {
+            name,
+            args,
+            directory,
+            configuration,
+            out_dir,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            let context = project_context(&project_dir, None);
+            let defaults = BuildDefaults::load(&context);
+            let configuration = defaults.configuration(configuration);
 
-        /// 의존성 업데이트
-        #[arg(short, long)]
-        update: bool,
-    },
-}
+            let name = match name {
+                Some(name) => name,
+                None => {
+                    if let Err(e) = print_examples(&context) {
+                        exit_with_error(&tr!("예제 목록 실패", "listing examples failed"), e);
+                    }
+                    return;
+                }
+            };
 
-fn main() {
-    env_logger::init();
-    let cli = Cli::parse();
+            let mut builder = project_builder(context.clone(), &configuration, defaults.jobs);
+            builder.set_targets(vec![name.clone()]);
+            builder.set_output_dir(out_dir.clone());
+            if let Err(e) = builder.build() {
+                exit_with_error(&tr!("빌드 실패", "build failed"), e);
+            }
 
-    let current_dir = std::env::current_dir().expect("현재 디렉토리를 확인할 수 없습니다");
+            let mut runner = Runner::with_context(context);
+            runner.set_configuration(&configuration);
+            runner.set_output_dir(out_dir);
+            if let Err(e) = runner.run_example(&name, &args) {
+                exit_with_error(&tr!("실행 실패", "run failed"), e);
+            }
+        }
+        Command::Exec {
+            directory,
+            command,
+            out_dir,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            let mut runner = Runner::new(&project_dir);
+            runner.set_output_dir(out_dir);
 
-    match cli.command {
-        Command::Init { directory } => {
-            let project_dir = directory.unwrap_or(current_dir);
-            init_project(&project_dir);
+            match runner.exec(&command) {
+                Ok(code) => std::process::exit(code),
+                Err(e) => {
+                    exit_with_error(&tr!("실행 실패", "run failed"), e);
+                }
+            }
         }
-        Command::Build {
+        Command::Test {
+            action,
             directory,
             configuration,
             jobs,
-            incremental,
-            run,
-            verbose,
+            annotations,
+            format,
+            junit,
+            fail_fast,
+            update_golden,
+            out_dir,
+            toolchain,
         } => {
-            let project_dir = directory.unwrap_or(current_dir);
-            let jobs = jobs.unwrap_or_else(|| num_cpus::get());
+            let project_dir = project_root(directory, &current_dir);
 
-            let mut builder = Builder::new(&project_dir, &configuration, jobs);
-            builder.set_incremental(incremental);
-            builder.set_verbose(verbose);
+            match action {
+                Some(TestCommand::Init { framework }) => {
+                    if let Err(e) = testing::init_test_harness(&project_dir, framework) {
+                        exit_with_error(&tr!("테스트 초기화 실패", "test init failed"), e);
+                    }
+                }
+                None => {
+                    let annotations = annotations.unwrap_or_else(AnnotationFormat::from_env);
+                    let context = project_context(&project_dir, toolchain);
+                    let defaults = BuildDefaults::load(&context);
+                    let configuration = defaults.configuration(configuration);
+                    let jobs = defaults.jobs(jobs);
 
-            if let Err(e) = builder.build() {
-                error!("빌드 실패: {}", e);
-                std::process::exit(1);
-            }
+                    let mut builder =
+                        project_builder(context.clone(), &configuration, jobs.count());
+                    builder.set_adaptive_memory(jobs == Jobs::AutoMem);
+                    builder.set_annotations(annotations);
+                    builder.set_output_dir(out_dir.clone());
 
-            if run {
-                let runner = Runner::new(&project_dir);
-                if let Err(e) = runner.run(None) {
-                    error!("실행 실패: {}", e);
-                    std::process::exit(1);
+                    if let Err(e) = builder.build() {
+                        exit_with_error(&tr!("빌드 실패", "build failed"), e);
+                    }
+
+                    let mut runner = Runner::with_context(context);
+                    runner.set_configuration(&configuration);
+                    runner.set_output_dir(out_dir);
+                    runner.set_annotations(annotations);
+                    runner.set_test_format(format);
+                    runner.set_junit_output(junit);
+                    runner.set_fail_fast(fail_fast);
+                    runner.set_update_golden(update_golden);
+                    if let Err(e) = runner.run_tests() {
+                        exit_with_error(&tr!("테스트 실패", "tests failed"), e);
+                    }
                 }
             }
         }
-        Command::Clean { directory } => {
-            let project_dir = directory.unwrap_or(current_dir);
-            let builder = Builder::new(&project_dir, "debug", 1);
+        Command::Verify {
+            directory,
+            configuration,
+            out_dir,
+            toolchain,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            let context = project_context(&project_dir, toolchain);
+            let configuration = BuildDefaults::load(&context).configuration(configuration);
+            let mut builder = project_builder(context, &configuration, 1);
+            builder.set_output_dir(out_dir);
 
-            if let Err(e) = builder.clean() {
-                error!("정리 실패: {}", e);
-                std::process::exit(1);
+            match builder.verify() {
+                Ok(0) => {}
+                Ok(_) => std::process::exit(error::exit_code::FAILURE),
+                Err(e) => exit_with_error(&tr!("검증 실패", "verify failed"), e),
             }
         }
-        Command::Run { directory, args } => {
-            let project_dir = directory.unwrap_or(current_dir);
-            let runner = Runner::new(&project_dir);
-
-            if let Err(e) = runner.run(args.as_deref()) {
-                error!("실행 실패: {}", e);
-                std::process::exit(1);
+        Command::Audit {
+            action: Some(AuditCommand::Layout { directory }),
+            ..
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            match conventions::check(&project_dir) {
+                Ok(0) => {}
+                Ok(_) => std::process::exit(error::exit_code::FAILURE),
+                Err(e) => exit_with_error(&tr!("배치 규칙 검사 실패", "layout check failed"), e),
             }
         }
-        Command::Dependencies { directory, update } => {
-            let project_dir = directory.unwrap_or(current_dir);
+        Command::Audit {
+            action: None,
+            directory,
+            offline,
+            ignore,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            match audit::audit(&project_dir, offline, &ignore) {
+                Ok(0) => {}
+                Ok(_) => std::process::exit(error::exit_code::FAILURE),
+                Err(e) => exit_with_error(&tr!("취약점 검사 실패", "audit failed"), e),
+            }
+        }
+        Command::Doctor { directory } => {
+            let project_dir = project_root(directory, &current_dir);
+            if doctor::doctor(&project_dir) > 0 {
+                std::process::exit(error::exit_code::FAILURE);
+            }
+        }
+        Command::Dependencies {
+            action: Some(action),
+            ..
+        } => {
+            let result = match action {
+                DependenciesCommand::Add {
+                    name,
+                    version,
+                    git,
+                    tag,
+                    branch,
+                    url,
+                    sha256,
+                    registry,
+                    features,
+                    dev,
+                    directory,
+                } => {
+                    let dependency = Dependency {
+                        version,
+                        features: (!features.is_empty()).then_some(features),
+                        git,
+                        tag,
+                        branch,
+                        url,
+                        sha256,
+                        registry,
+                        scope: None,
+                    };
+                    dependency::add_dependency(
+                        &project_root(directory, &current_dir),
+                        &name,
+                        &dependency,
+                        dev,
+                    )
+                }
+                DependenciesCommand::Remove { name, directory } => {
+                    dependency::remove_dependency(&project_root(directory, &current_dir), &name)
+                }
+            };
+            if let Err(e) = result {
+                exit_with_error(&tr!("설정 수정 실패", "failed to edit configuration"), e);
+            }
+        }
+        Command::Dependencies {
+            action: None,
+            directory,
+            update,
+            no_dev,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
             let mut dep_manager = DependencyManager::new(&project_dir);
+            dep_manager.set_include_dev(!no_dev);
 
             if update {
                 if let Err(e) = dep_manager.update() {
-                    error!("의존성 업데이트 실패: {}", e);
-                    std::process::exit(1);
+                    exit_with_error(&tr!("의존성 업데이트 실패", "dependency update failed"), e);
                 }
             } else {
                 if let Err(e) = dep_manager.install() {
-                    error!("의존성 설치 실패: {}", e);
-                    std::process::exit(1);
+                    exit_with_error(&tr!("의존성 설치 실패", "dependency install failed"), e);
+                }
+            }
+        }
+        Command::Gen {
+            ide,
+            directory,
+            out_dir,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+
+            if let Err(e) = projgen::generate_project(&project_dir, ide, out_dir) {
+                exit_with_error(
+                    &tr!("IDE 프로젝트 생성 실패", "IDE project generation failed"),
+                    e,
+                );
+            }
+        }
+        Command::Ide { ide } => match ide {
+            IdeCommand::Vscode {
+                directory,
+                configuration,
+                out_dir,
+            } => {
+                let project_dir = project_root(directory, &current_dir);
+                let context = project_context(&project_dir, None);
+                let configuration = BuildDefaults::load(&context).configuration(configuration);
+
+                if let Err(e) = ide::generate_vscode(context, &configuration, out_dir) {
+                    exit_with_error(
+                        &tr!("VS Code 설정 생성 실패", "VS Code config generation failed"),
+                        e,
+                    );
+                }
+            }
+            IdeCommand::Clangd {
+                directory,
+                configuration,
+                out_dir,
+            } => {
+                let project_dir = project_root(directory, &current_dir);
+                let context = project_context(&project_dir, None);
+                let configuration = BuildDefaults::load(&context).configuration(configuration);
+
+                if let Err(e) = ide::generate_clangd(context, &configuration, out_dir) {
+                    exit_with_error(
+                        &tr!("clangd 설정 생성 실패", "clangd config generation failed"),
+                        e,
+                    );
+                }
+            }
+        },
+        Command::Amalgamate {
+            target,
+            output,
+            directory,
+            out_dir,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+
+            if let Err(e) = amalgamate::amalgamate(&project_dir, &target, output, out_dir) {
+                exit_with_error(&tr!("amalgamation 생성 실패", "amalgamation failed"), e);
+            }
+        }
+        Command::Graph {
+            cycles,
+            directory,
+            configuration,
+            out_dir,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            let context = project_context(&project_dir, None);
+            let configuration = BuildDefaults::load(&context).configuration(configuration);
+
+            if let Err(e) = graph::print_include_graph(context, &configuration, out_dir, cycles) {
+                exit_with_error(
+                    &tr!("포함 관계 분석 실패", "include graph analysis failed"),
+                    e,
+                );
+            }
+        }
+        Command::Headers { action } => match action {
+            HeadersCommand::Check {
+                directory,
+                configuration,
+                top,
+                out_dir,
+            } => {
+                let project_dir = project_root(directory, &current_dir);
+                let context = project_context(&project_dir, None);
+                let configuration = BuildDefaults::load(&context).configuration(configuration);
+
+                if let Err(e) = headers::check_headers(context, &configuration, out_dir, top) {
+                    exit_with_error(&tr!("헤더 검사 실패", "header check failed"), e);
+                }
+            }
+        },
+        Command::Stats {
+            action,
+            directory,
+            configuration,
+            out_dir,
+            json,
+        } => match action {
+            None => {
+                let project_dir = project_root(directory, &current_dir);
+                let context = project_context(&project_dir, None);
+                let configuration = BuildDefaults::load(&context).configuration(configuration);
+
+                if let Err(e) = stats::print_target_stats(context, &configuration, out_dir, json) {
+                    exit_with_error(&tr!("타겟 지표 분석 실패", "target stats failed"), e);
+                }
+            }
+            Some(StatsCommand::Headers {
+                directory,
+                configuration,
+                top,
+                out_dir,
+            }) => {
+                let project_dir = project_root(directory, &current_dir);
+                let context = project_context(&project_dir, None);
+                let configuration = BuildDefaults::load(&context).configuration(configuration);
+
+                if let Err(e) = stats::print_header_stats(context, &configuration, out_dir, top) {
+                    exit_with_error(&tr!("헤더 지표 분석 실패", "header stats failed"), e);
+                }
+            }
+        },
+        Command::License { action } => match action {
+            LicenseCommand::Check { directory } => {
+                let project_dir = project_root(directory, &current_dir);
+                match license::check(&project_dir) {
+                    Ok(0) => {}
+                    Ok(_) => std::process::exit(error::exit_code::FAILURE),
+                    Err(e) => {
+                        exit_with_error(&tr!("라이선스 헤더 검사 실패", "license check failed"), e)
+                    }
+                }
+            }
+            LicenseCommand::Apply { directory } => {
+                let project_dir = project_root(directory, &current_dir);
+                if let Err(e) = license::apply(&project_dir) {
+                    exit_with_error(&tr!("라이선스 헤더 추가 실패", "license apply failed"), e);
+                }
+            }
+        },
+        Command::Migrate {
+            from,
+            input,
+            directory,
+            force,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            if let Err(e) = migrate::migrate(&project_dir, from, input.as_deref(), force) {
+                exit_with_error(&tr!("변환 실패", "migration failed"), e);
+            }
+        }
+        Command::Install {
+            prefix,
+            cmake_config,
+            directory,
+            configuration,
+            out_dir,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+
+            if let Err(e) =
+                install::install(&project_dir, &configuration, out_dir, &prefix, cmake_config)
+            {
+                exit_with_error(&tr!("설치 실패", "install failed"), e);
+            }
+        }
+        Command::Package {
+            format,
+            directory,
+            configuration,
+            out_dir,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+
+            if let Err(e) = package::package(&project_dir, &configuration, out_dir, format) {
+                exit_with_error(&tr!("패키지 생성 실패", "packaging failed"), e);
+            }
+        }
+        Command::Pgo {
+            directory,
+            configuration,
+            jobs,
+            out_dir,
+        } => {
+            let project_dir = project_root(directory, &current_dir);
+            let context = project_context(&project_dir, None);
+            let jobs = jobs.unwrap_or_else(|| BuildDefaults::load(&context).jobs);
+
+            if let Err(e) = pgo::pgo(context, &configuration, out_dir, jobs) {
+                exit_with_error(&tr!("PGO 빌드 실패", "PGO build failed"), e);
+            }
+        }
+        Command::Toolchain { action } => match action {
+            ToolchainCommand::Install {
+                name,
+                directory,
+                force,
+            } => {
+                let project_dir = project_root(directory, &current_dir);
+                let result = BuildConfig::from_file(&project_dir).and_then(|config| {
+                    let spec = config.toolchains.get(&name).ok_or_else(|| {
+                        BuildError::ToolchainError(tr!(
+                            "cbuild.toml에 [toolchains.{}]이(가) 정의되지 않았습니다",
+                            "[toolchains.{}] is not defined in cbuild.toml",
+                            name
+                        ))
+                    })?;
+                    provision::install(&name, spec, force, &Downloader::from_config(&config))
+                });
+                if let Err(e) = result {
+                    exit_with_error(&tr!("툴체인 설치 실패", "toolchain install failed"), e);
+                }
+            }
+            ToolchainCommand::Show {
+                directory,
+                toolchain,
+                out_dir,
+            } => {
+                let project_dir = project_root(directory, &current_dir);
+                let context = project_context(&project_dir, toolchain);
+                let configuration = BuildDefaults::load(&context).configuration(None);
+                let result = context.config().and_then(|config| {
+                    let layout = context.layout(&configuration, out_dir.as_deref());
+                    let probed =
+                        Toolchain::probe(&config.build.compiler, &layout.toolchain_cache_path())?;
+                    toolchain::print_report(config, &probed);
+                    Ok(())
+                });
+                if let Err(e) = result {
+                    exit_with_error(&tr!("툴체인 확인 실패", "toolchain check failed"), e);
                 }
             }
+            ToolchainCommand::List => match provision::installed() {
+                Ok(toolchains) if toolchains.is_empty() => {
+                    println!(
+                        "{}",
+                        tr!("설치된 툴체인이 없습니다.", "No toolchains installed.")
+                    );
+                }
+                Ok(toolchains) => {
+                    for (name, toolchain) in toolchains {
+                        println!("{} {}", name.bold(), toolchain.cc.display());
+                    }
+                }
+                Err(e) => {
+                    exit_with_error(&tr!("툴체인 목록 조회 실패", "toolchain list failed"), e)
+                }
+            },
+        },
+    }
+}
+
+/// 명령이 사용하는 프로젝트 디렉토리 (오류 메시지에 표시)
+static PROJECT_ROOT: OnceLock<PathBuf> = OnceLock::new();
+
+/// `-d`로 지정한 디렉토리, 없으면 현재 디렉토리부터 위로 찾은 cbuild.toml의 디렉토리
+///
+/// 찾지 못하면 현재 디렉토리를 사용해 설정 파일이 없다는 오류가 나게 한다. 상대 경로는
+/// 절대 경로로 바꾼다 (`[run] cwd`로 작업 디렉토리를 바꿔도 실행 파일 경로가 유지되도록).
+fn project_root(directory: Option<PathBuf>, current_dir: &Path) -> PathBuf {
+    let directory =
+        directory.map(|dir| utils::normalize_path(&utils::to_absolute_path(&dir, current_dir)));
+    let root = directory.unwrap_or_else(|| {
+        BuildConfig::find_project_root(current_dir).unwrap_or_else(|| current_dir.to_path_buf())
+    });
+    let _ = PROJECT_ROOT.set(root.clone());
+    root
+}
+
+/// 명령줄에서 지정하지 않았을 때 쓰는 [build]의 기본값 (설정을 읽을 수 없으면 내장 기본값)
+struct BuildDefaults {
+    configuration: String,
+    jobs: usize,
+    incremental: bool,
+}
+
+impl BuildDefaults {
+    /// 컨텍스트의 설정에서 기본값 읽기
+    ///
+    /// cbuild.toml이 없으면 내장 기본값을 쓰고, 있는데 해석할 수 없으면 오류로 종료한다.
+    fn load(context: &ProjectContext) -> Self {
+        let build = match context.config() {
+            Ok(config) => Some(&config.build),
+            Err(BuildError::ConfigNotFound(_)) => None,
+            Err(e) => exit_with_error(
+                &tr!("설정 파일 읽기 실패", "failed to read configuration"),
+                e,
+            ),
+        };
+
+        BuildDefaults {
+            configuration: build
+                .and_then(|build| build.default_configuration.clone())
+                .unwrap_or_else(|| DEFAULT_CONFIGURATION.to_string()),
+            jobs: build
+                .and_then(|build| build.jobs)
+                .unwrap_or_else(num_cpus::get)
+                .max(1),
+            incremental: build.and_then(|build| build.incremental).unwrap_or(true),
+        }
+    }
+
+    /// `-c` 값 또는 build.default_configuration
+    fn configuration(&self, configuration: Option<String>) -> String {
+        configuration.unwrap_or_else(|| self.configuration.clone())
+    }
+
+    /// `-j` 값 또는 build.jobs
+    fn jobs(&self, jobs: Option<Jobs>) -> Jobs {
+        jobs.unwrap_or(Jobs::Count(self.jobs))
+    }
+}
+
+/// `cbuild locate-project`: 현재 디렉토리에서 찾은 프로젝트 루트 출력
+fn locate_project(current_dir: &Path, plain: bool) -> Result<(), BuildError> {
+    let root = BuildConfig::find_project_root(current_dir)
+        .ok_or_else(|| BuildError::ConfigNotFound(current_dir.join("cbuild.toml")))?;
+    let manifest = root.join("cbuild.toml");
+
+    if plain {
+        println!("{}", root.display());
+    } else {
+        println!(
+            "{}",
+            serde_json::json!({
+                "root": root.to_string_lossy(),
+                "manifest_path": manifest.to_string_lossy(),
+            })
+        );
+    }
+    Ok(())
+}
+
+/// 컨텍스트를 공유하는 빌더 (설정을 읽을 수 없으면 오류로 종료)
+fn project_builder(context: Arc<ProjectContext>, configuration: &str, jobs: usize) -> Builder {
+    Builder::with_context(context, configuration, jobs).unwrap_or_else(|e| {
+        exit_with_error(
+            &tr!("설정 파일 읽기 실패", "failed to read configuration"),
+            e,
+        )
+    })
+}
+
+/// 명령 단계가 공유하는 프로젝트 컨텍스트 (`--toolchain`이 있으면 설치된 툴체인 적용)
+fn project_context(
+    project_dir: &std::path::Path,
+    toolchain: Option<String>,
+) -> Arc<ProjectContext> {
+    let mut context = ProjectContext::new(project_dir);
+    if let Some(name) = toolchain {
+        match provision::find(&name) {
+            Ok(installed) => context.set_toolchain(installed),
+            Err(e) => exit_with_error(&tr!("툴체인 설정 실패", "toolchain setup failed"), e),
         }
     }
+    Arc::new(context)
+}
+
+/// `[[targets.example]]` 목록과 소스 출력
+fn print_examples(context: &ProjectContext) -> Result<(), BuildError> {
+    let config = context.config()?;
+    let examples: Vec<_> = config.examples().collect();
+    if examples.is_empty() {
+        println!(
+            "{}",
+            tr!(
+                "정의된 예제가 없습니다 ([[targets.example]])",
+                "No examples defined ([[targets.example]])"
+            )
+        );
+        return Ok(());
+    }
+
+    println!("{}", tr!("예제:", "Examples:").bold());
+    for example in examples {
+        println!("  {:<20} {}", example.name, example.src.join(", ").dimmed());
+    }
+    Ok(())
+}
+
+/// 오류와 실패한 명령, 해결 방법 제안을 출력하고 오류 종류에 맞는 종료 코드로 종료
+fn exit_with_error(action: &str, e: BuildError) -> ! {
+    error!("{}: {}", action, e);
+    if let Some(root) = PROJECT_ROOT.get() {
+        eprintln!(
+            "  {} {}",
+            tr!("프로젝트:", "project:").dimmed(),
+            root.display()
+        );
+    }
+    if let Some(failure) = e.command_failure() {
+        eprintln!(
+            "  {} {}",
+            tr!("명령:", "command:").dimmed(),
+            failure.command
+        );
+    }
+    if let Some(hint) = hints::hint(&e) {
+        eprintln!("  {} {}", tr!("도움말:", "hint:").cyan().bold(), hint);
+    }
+    std::process::exit(e.exit_code());
+}
+
+fn enable_background_priority() {
+    if let Err(e) = utils::lower_process_priority() {
+        warn!(
+            "{}",
+            tr!(
+                "프로세스 우선순위를 낮출 수 없습니다: {}",
+                "could not lower process priority: {}",
+                e
+            )
+        );
+    }
+}
+
+const GITIGNORE_TEMPLATE: &str = "# cbuild 빌드 결과물과 내려받은 의존성
+/build/
+/deps/
+# 이 컴퓨터 전용 설정 재정의
+/cbuild.local.toml
+# 설정을 수정하는 명령이 남기는 이전 설정
+/cbuild.toml.bak
+*.o
+*.obj
+*.a
+*.so
+*.dylib
+*.dll
+*.exe
+compile_commands.json
+";
+
+const CLANG_FORMAT_TEMPLATE: &str = "BasedOnStyle: LLVM
+IndentWidth: 4
+ColumnLimit: 100
+BreakBeforeBraces: Attach
+AllowShortFunctionsOnASingleLine: Empty
+PointerAlignment: Right
+SortIncludes: true
+";
+
+const EDITORCONFIG_TEMPLATE: &str = "root = true
+
+[*]
+charset = utf-8
+end_of_line = lf
+insert_final_newline = true
+trim_trailing_whitespace = true
+
+[*.{c,h,cc,cpp,hpp,m,mm}]
+indent_style = space
+indent_size = 4
+
+[*.toml]
+indent_style = space
+indent_size = 2
+
+[Makefile]
+indent_style = tab
+";
+
+/// 초기화 시 보조 파일 생성 (이미 있으면 그대로 둠)
+fn write_starter_file(path: &Path, content: &str) {
+    if path.exists() {
+        return;
+    }
+    info!(
+        "{}",
+        tr!("파일 생성: {}", "creating file: {}", path.display())
+    );
+    if let Err(e) = std::fs::write(path, content) {
+        error!(
+            "{}",
+            tr!(
+                "파일 생성 실패 {}: {}",
+                "failed to write {}: {}",
+                path.display(),
+                e
+            )
+        );
+    }
 }
 
-fn init_project(directory: &PathBuf) {
-    info!("프로젝트 초기화 중: {}", directory.display());
+fn init_project(directory: &Path, gitignore: bool, editor_config: bool) {
+    info!(
+        "{}",
+        tr!(
+            "프로젝트 초기화 중: {}",
+            "initializing project: {}",
+            directory.display()
+        )
+    );
 
     // 기본 디렉토리 구조 생성
     let dirs = ["src", "include", "lib", "build", "test", "docs", "deps"];
@@ -171,9 +2139,24 @@ fn init_project(directory: &PathBuf) {
     for dir in dirs {
         let path = directory.join(dir);
         if !path.exists() {
-            info!("디렉토리 생성: {}", path.display());
+            info!(
+                "{}",
+                tr!(
+                    "디렉토리 생성: {}",
+                    "creating directory: {}",
+                    path.display()
+                )
+            );
             if let Err(e) = std::fs::create_dir_all(&path) {
-                error!("디렉토리 생성 실패 {}: {}", path.display(), e);
+                error!(
+                    "{}",
+                    tr!(
+                        "디렉토리 생성 실패 {}: {}",
+                        "failed to create directory {}: {}",
+                        path.display(),
+                        e
+                    )
+                );
             }
         }
     }
@@ -181,8 +2164,16 @@ fn init_project(directory: &PathBuf) {
     // 빌드 설정 파일 생성
     let config_path = directory.join("cbuild.toml");
     if !config_path.exists() {
-        info!("빌드 설정 파일 생성: {}", config_path.display());
+        info!(
+            "{}",
+            tr!(
+                "빌드 설정 파일 생성: {}",
+                "creating build config: {}",
+                config_path.display()
+            )
+        );
         let config_content = r#"# C 프로젝트 빌드 설정
+# 우선순위: ~/.config/cbuild/config.toml < cbuild.toml < cbuild.local.toml (커밋하지 않는 재정의)
 [project]
 name = "my_c_project"
 version = "0.1.0"
@@ -196,10 +2187,53 @@ cpp_standard = "c++17"
 optimization_level = 2 # O2
 debug_info = true
 warnings_as_errors = false
+# rpath = "absolute" # origin(기본, 실행 파일 기준 상대 경로) | absolute | none
+# output_dir = "build"
+# max_memory = "8G" # 동시 컴파일 메모리 한도
+# test_runner = "qemu-arm -L /usr/arm-linux-gnueabihf" # 크로스 빌드 테스트 실행 래퍼
+# target = "x86_64-linux-musl" # 크로스 컴파일 타겟 (compiler = "zig cc" 또는 clang)
+# static_link = true # 실행 파일 정적 링크 (musl 타겟과 함께 사용)
+# openmp = true # -fopenmp로 컴파일·링크 (타겟별 openmp로 덮어쓰기 가능)
+# log_retention = 20 # build/<구성>/log에 보관할 빌드 로그 수 (0이면 남기지 않음)
+# history_retention = 50 # cbuild history에 보관할 빌드 기록 수 (0이면 남기지 않음)
+# jobs = 8 # -j 기본값 (기본: CPU 코어 수)
+# default_configuration = "release" # -c 기본값
+# incremental = false # 항상 전체 빌드 (기본: 바뀐 파일만 빌드, --force로 한 번만 전체 빌드)
+# follow_symlinks = false # 소스 패턴이 심볼릭 링크 디렉토리 안을 찾지 않음 (기본: 찾음)
+# strict_includes = true # 타겟 include_dirs 밖의 프로젝트 헤더를 포함하면 빌드 실패 (-iquote/-isystem 사용)
+
+# 빌드 구성별 설정
+# [profiles.debug]
+# output_suffix = "_d"
+#
+# debug/release 외의 구성은 -c <이름>으로 빌드 (build/<이름>에 생성)
+# [profiles.asan]
+# debug_info = true
+# optimization_level = 1
+# extra_flags = ["-fsanitize=address", "-fno-omit-frame-pointer"]
+# link_flags = ["-fsanitize=address"]
+#
+# [profiles.minsize]
+# inherits = "release" # NDEBUG 정의와 스트립
+# optimization_level = "s"
+# defines = { SMALL_BUILD = "1" }
 
 [dependencies]
 # 예시: 의존성 설정
 # libcurl = { version = "7.75.0", features = ["ssl"] }
+# zlib = { version = "1.3.1", url = "https://zlib.net/zlib-1.3.1.tar.gz", sha256 = "9a93b2b7dfdac77ceba5a558a580e74667dd6fede4585b91eefb60f03b72df23" }
+# mylib = { version = "^2.1", registry = "https://packages.example.com/c" } # 레지스트리 색인(index/mylib.json)에서 가장 높은 버전
+# cjson = { version = "^1.7", git = "https://github.com/DaveGamble/cJSON.git" } # 조건에 맞는 가장 높은 태그 (cbuild.lock에 기록)
+
+# 테스트 타겟에서만 쓰는 의존성 (cbuild dependencies --no-dev로 설치하지 않음)
+# [dev-dependencies]
+# unity = { version = "2.6.0", git = "https://github.com/ThrowTheSwitch/Unity.git", tag = "v2.6.0" }
+
+# 의존성의 features는 그 의존성을 쓰는 타겟에 매크로로 전달 (기본: <의존성>_FEATURE_<기능>=1,
+# 같은 의존성을 여러 곳에서 쓰면 기능을 합침, 결과는 cbuild.lock에 기록)
+# 이 프로젝트를 의존성으로 쓰는 쪽이 켤 수 있는 기능과 매크로 정의
+# [features]
+# color = { MYLIB_COLOR = "1" }
 
 [targets]
 # 메인 실행 파일
@@ -209,6 +2243,20 @@ src = ["src/main.c"]
 include_dirs = ["include"]
 link_dirs = ["lib"]
 libs = []
+# embed = ["assets/*.txt"] # C 바이트 배열로 변환해 링크 (<name>_embed.h)
+# frameworks = ["Foundation"] # macOS 프레임워크 (.m/.mm 소스는 clang으로 컴파일)
+# 링크 후 최적화/후처리 명령 (마지막 결과가 run/install에 쓰이는 최종 실행 파일)
+# link_flags = ["-Wl,--emit-relocs"]
+# post_link = [
+#   ["llvm-bolt", "{input}", "-o", "{output}", "-data=perf.fdata"],
+#   ["strip", "{input}"],
+# ]
+
+# 링크 후 실행 파일 옆으로 복사할 런타임 파일
+# [targets.executable.copy]
+# shared_libs = true
+# files = ["assets/*"]
+# dest = "assets"
 
 # 정적 라이브러리 예시
 # [[targets.static_lib]]
@@ -223,16 +2271,164 @@ libs = []
 # include_dirs = ["include", "test/include"]
 # link_dirs = ["lib"]
 # libs = ["mylib"]
+
+# 헤더 전용 라이브러리: 결과물 없이 포함 디렉토리/매크로만 전파 (타겟의 interfaces에 나열)
+# [[targets.interface]]
+# name = "json"
+# include_dirs = ["third_party/json/include"]
+# defines = { JSON_NOEXCEPTION = "1" }
+
+# 사용자 정의 명령: 입력이 출력보다 새로울 때 빌드 중에 실행
+# (depends에 "exe:main" 같은 빌드 타겟이 있으면 링크 후 실행,
+#  {generated}는 포함 경로에 들어가는 build/<구성>/generated)
+# [[targets.custom]]
+# name = "build_info"
+# command = ["python3", "scripts/gen_build_info.py", "{generated}/build_info.h"]
+# inputs = ["scripts/gen_build_info.py"]
+# outputs = ["{generated}/build_info.h"]
+
+# 예제: 기본 빌드에서 제외, bin/examples에 생성 (cbuild examples demo)
+# 다른 타겟도 default = false로 기본 빌드에서 뺄 수 있음 (--target/--all-targets로 빌드)
+# [[targets.example]]
+# name = "demo"
+# src = ["examples/demo.c"]
+
+# 타겟 묶음: cbuild build --target all-tools (all-tests는 기본 제공)
+# [[targets.alias]]
+# name = "all-tools"
+# members = ["cli", "daemon"]
+
+# 골든 테스트: 실행 결과를 기대 파일과 비교 (cbuild test --update-golden으로 갱신)
+# [[targets.golden]]
+# name = "main_output"
+# target = "main"
+# args = ["--help"]
+# stdin = "test/golden/input.txt"
+# expected = "test/golden/main_output.txt"
+
+# 프로파일 기반 최적화 (cbuild pgo, train이 없으면 테스트 스위트로 학습)
+# [pgo]
+# train = ["main", "--benchmark"]
+
+# 함께 설치·패키징할 man 페이지와 셸 자동 완성 (share/man, share/*completions)
+# [install]
+# man = ["docs/*.1"]
+# [install.completions]
+# bash = "completions/main.bash"
+# command = ["main", "--completions", "{shell}"] # 파일 대신 빌드한 실행 파일로 생성
+
+# Linux 배포 번들 설정 (cbuild package --format appimage/flatpak)
+# [package]
+# executable = "main"
+# app_id = "org.example.Main"
+# icon = "assets/icon.png"
+# categories = ["Development"]
+# terminal = true
+
+# git clone/fetch와 다운로드 재시도 (--no-retry로 끔)
+# [retry]
+# attempts = 3
+# delay_ms = 1000 # 재시도마다 두 배씩 증가 (최대 30초)
+
+# 다운로드 프록시 (없으면 HTTPS_PROXY/NO_PROXY 환경 변수 사용)
+# [download]
+# proxy = "http://proxy.example.com:3128"
+# mirrors = { "https://zlib.net/" = "https://mirror.example.com/zlib/" }
+# cache_dir = "~/.cache/cbuild/downloads"
+
+# 결과물 서명 (기본: release 구성만, cbuild verify가 서명도 확인)
+# [signing]
+# gpg_key = "release@example.com" # cbuild package 결과물 옆에 .asc 분리 서명
+# codesign_identity = "Developer ID Application: Example (TEAMID)" # macOS
+# codesign_args = ["--options", "runtime", "--timestamp"]
+# signtool_args = ["/a", "/fd", "SHA256", "/tr", "http://timestamp.digicert.com", "/td", "SHA256"] # Windows
+
+# 소스와 헤더의 라이선스 헤더 (cbuild license check|apply)
+# [license]
+# header = """
+# Copyright (c) {year} {author}
+# SPDX-License-Identifier: MIT
+# """
+# author = "Example Corp" # 기본: project.authors
+# style = "block" # block(/* */) 또는 line(//)
+# exclude = ["third_party/**"]
+
+# distcc/icecc 분산 컴파일 (cbuild build --distributed, 원격 슬롯만큼 동시 작업 수 증가)
+# [distributed]
+# tool = "distcc" # distcc 또는 icecc
+# hosts = ["build1/8", "build2/8"] # distcc 호스트/슬롯 수
+# scheduler = "icecc-scheduler.local" # icecc 스케줄러
+# enabled = false # true면 --distributed 없이도 사용
+
+# 실험적 원격 실행 서버 (cbuild build --remote-exec, 컴파일만 원격에서 실행)
+# [remote_execution]
+# endpoint = "http://build-farm:8980"
+# token_env = "CBUILD_REMOTE_TOKEN" # Bearer 토큰 환경 변수
+
+# 자주 쓰는 시스템·외부 헤더를 플래그 조합마다 한 번 미리 컴파일해 타겟 사이에 공유
+# (컴파일러나 플래그가 바뀌면 다시 만듦, build/.pch)
+# [pch]
+# headers = ["stdio.h", "stdlib.h"]
+# cpp_headers = ["vector", "string", "map"] # C++ 소스에만 사용
+
+# build/<구성>/generated에 만드는 헤더 (이 디렉토리는 모든 타겟의 포함 경로에 추가됨)
+# [generated]
+# version_header = true # version.h: <PROJECT>_VERSION, _VERSION_MAJOR/MINOR/PATCH
+# export_headers = true # 공유 라이브러리마다 <이름>_export.h: <NAME>_EXPORT
+# config = { HAVE_UNISTD_H = "1", USE_FAST_PATH = "" } # config.h 매크로
+
+# 파일 배치와 이름 규칙 (cbuild audit layout)
+# [layout]
+# test_dir = "test" # 테스트 타겟 소스 위치
+# public_header_dir = "include/{project}" # 라이브러리 공개 헤더 위치
+# file_name = "^[a-z][a-z0-9_]*$" # 파일 이름(확장자 제외) 정규식
+# exclude = ["third_party/**"]
+
+# 의존성 취약점 검사 (cbuild audit, git 의존성은 OSV API로 조회)
+# [audit]
+# database = "advisories.json" # 오프라인 권고 데이터베이스
+# ignore = ["CVE-2018-25032"] # 보고만 하고 실패로 세지 않음
+
+# cbuild gc 정리 기준
+# [gc]
+# max_age_days = 30 # 이보다 오래 빌드하지 않은 구성 디렉토리와 다운로드 캐시 삭제
+# max_cache_size = "2G" # 다운로드 캐시 최대 크기 (오래된 파일부터 삭제)
+
+# 실행 환경 설정 (cbuild run)
+# [run]
+# default = "main"
+# args = ["--config", "config/dev.toml"]
+# env = { APP_ENV = "dev" }
+# env_files = [".env"]
+# cwd = "."
+#
+# [run.aliases.serve]
+# target = "main"
+# args = ["--port", "8080"]
 "#;
         if let Err(e) = std::fs::write(&config_path, config_content) {
-            error!("설정 파일 생성 실패: {}", e);
+            error!(
+                "{}",
+                tr!(
+                    "설정 파일 생성 실패: {}",
+                    "failed to write config file: {}",
+                    e
+                )
+            );
         }
     }
 
     // 기본 소스 파일 생성
     let main_c_path = directory.join("src/main.c");
     if !main_c_path.exists() {
-        info!("기본 소스 파일 생성: {}", main_c_path.display());
+        info!(
+            "{}",
+            tr!(
+                "기본 소스 파일 생성: {}",
+                "creating default source file: {}",
+                main_c_path.display()
+            )
+        );
         let main_c_content = r#"/**
  * @file main.c
  * @brief 프로그램 메인 진입점
@@ -250,13 +2446,50 @@ int main(int argc, char *argv[]) {
 }
 "#;
         if let Err(e) = std::fs::write(&main_c_path, main_c_content) {
-            error!("소스 파일 생성 실패: {}", e);
+            error!(
+                "{}",
+                tr!(
+                    "소스 파일 생성 실패: {}",
+                    "failed to write source file: {}",
+                    e
+                )
+            );
         }
     }
 
-    println!("{}", "프로젝트 초기화 완료!".green());
-    println!("다음 단계:");
-    println!("  1. cbuild.toml 설정 파일을 프로젝트에 맞게 수정하세요.");
-    println!("  2. 소스 코드를 src/ 디렉토리에 추가하세요.");
-    println!("  3. 다음 명령으로 프로젝트를 빌드하세요: c_build_system build");
+    // 빌드 결과물이 커밋되지 않도록 .gitignore, 팀 공통 서식용 편집기 설정 생성
+    if gitignore {
+        write_starter_file(&directory.join(".gitignore"), GITIGNORE_TEMPLATE);
+    }
+    if editor_config {
+        write_starter_file(&directory.join(".clang-format"), CLANG_FORMAT_TEMPLATE);
+        write_starter_file(&directory.join(".editorconfig"), EDITORCONFIG_TEMPLATE);
+    }
+
+    println!(
+        "{}",
+        tr!("프로젝트 초기화 완료!", "Project initialized!").green()
+    );
+    println!("{}", tr!("다음 단계:", "Next steps:"));
+    println!(
+        "{}",
+        tr!(
+            "  1. cbuild.toml 설정 파일을 프로젝트에 맞게 수정하세요.",
+            "  1. Edit cbuild.toml to match your project."
+        )
+    );
+    println!(
+        "{}",
+        tr!(
+            "  2. 소스 코드를 src/ 디렉토리에 추가하세요.",
+            "  2. Add your sources under src/."
+        )
+    );
+    println!(
+        "{}",
+        tr!(
+            "  3. 다음 명령으로 프로젝트를 빌드하세요: c_build_system build",
+            "  3. Build the project with: c_build_system build"
+        )
+    );
 }
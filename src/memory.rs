@@ -0,0 +1,170 @@
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use crate::utils::get_extension;
+use log::{debug, warn};
+use std::path::Path;
+use std::sync::{Condvar, Mutex};
+use std::time::Duration;
+
+/// 시스템에 항상 남겨둘 여유 메모리
+const SAFETY_MARGIN: u64 = 256 * 1024 * 1024;
+
+/// 메모리 사용량에 따라 동시 컴파일 수를 조절
+///
+/// 컴파일 작업마다 예상 메모리를 예약하고, 예약 합계가 한도를 넘거나 시스템의
+/// 가용 메모리가 부족하면 실행 중인 작업이 끝날 때까지 대기한다.
+/// 실행 중인 작업이 없으면 항상 진행해 교착 상태를 피한다.
+pub struct MemoryGovernor {
+    limit: u64,
+    reserved: Mutex<u64>,
+    released: Condvar,
+}
+
+/// 컴파일 작업의 메모리 예약. 해제되면 대기 중인 작업을 깨운다.
+pub struct MemoryReservation<'a> {
+    governor: &'a MemoryGovernor,
+    amount: u64,
+}
+
+impl MemoryGovernor {
+    /// `max_memory`가 없으면 현재 가용 메모리의 80%를 한도로 사용
+    pub fn new(max_memory: Option<u64>) -> Option<Self> {
+        let limit = match max_memory {
+            Some(limit) => limit,
+            None => match available_memory() {
+                Some(available) => available / 10 * 8,
+                None => {
+                    warn!("이 플랫폼에서는 가용 메모리를 확인할 수 없어 메모리 기반 조절을 사용하지 않습니다");
+                    return None;
+                }
+            },
+        };
+
+        debug!("메모리 한도: {} MiB", limit / 1024 / 1024);
+
+        Some(MemoryGovernor {
+            limit,
+            reserved: Mutex::new(0),
+            released: Condvar::new(),
+        })
+    }
+
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// 소스 파일 컴파일에 필요한 메모리를 예약 (부족하면 대기)
+    pub fn reserve(&self, source_file: &Path) -> MemoryReservation<'_> {
+        let amount = estimate_compile_memory(source_file);
+        let mut reserved = self.reserved.lock().unwrap();
+
+        loop {
+            let fits = *reserved + amount <= self.limit
+                && available_memory()
+                    .is_none_or(|available| available >= amount.saturating_add(SAFETY_MARGIN));
+
+            if *reserved == 0 || fits {
+                *reserved += amount;
+                break;
+            }
+
+            // 가용 메모리는 외부 요인으로도 변하므로 주기적으로 다시 확인
+            let (guard, _) = self
+                .released
+                .wait_timeout(reserved, Duration::from_millis(200))
+                .unwrap();
+            reserved = guard;
+        }
+
+        MemoryReservation {
+            governor: self,
+            amount,
+        }
+    }
+}
+
+impl Drop for MemoryReservation<'_> {
+    fn drop(&mut self) {
+        let mut reserved = self.governor.reserved.lock().unwrap();
+        *reserved = reserved.saturating_sub(self.amount);
+        self.governor.released.notify_all();
+    }
+}
+
+/// 컴파일 한 번에 필요한 메모리 추정 (C++는 템플릿 인스턴스화 때문에 더 크게 잡음)
+fn estimate_compile_memory(source_file: &Path) -> u64 {
+    let base: u64 = match get_extension(source_file).as_deref() {
+        Some("cpp" | "cxx" | "cc" | "mm") => 512 * 1024 * 1024,
+        _ => 128 * 1024 * 1024,
+    };
+
+    let size = std::fs::metadata(source_file).map(|m| m.len()).unwrap_or(0);
+    base + size * 64
+}
+
+/// "8G", "512M", "1024K" 또는 바이트 수 형식의 메모리 크기 파싱
+pub fn parse_memory_size(value: &str) -> BuildResult<u64> {
+    let value = value.trim();
+    let upper = value.to_uppercase();
+    let digits_end = upper
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(upper.len());
+    let (number, unit) = upper.split_at(digits_end);
+
+    let number: u64 = number
+        .parse()
+        .map_err(|_| BuildError::ConfigParsingError(format!("잘못된 메모리 크기: {}", value)))?;
+
+    let multiplier: u64 = match unit.trim_end_matches("IB").trim_end_matches('B') {
+        "" => 1,
+        "K" => 1024,
+        "M" => 1024 * 1024,
+        "G" => 1024 * 1024 * 1024,
+        _ => {
+            return Err(BuildError::ConfigParsingError(format!(
+                "잘못된 메모리 크기 단위: {}",
+                value
+            )))
+        }
+    };
+
+    number.checked_mul(multiplier).ok_or_else(|| {
+        BuildError::ConfigParsingError(tr!(
+            "메모리 크기가 너무 큽니다: {}",
+            "memory size is too large: {}",
+            value
+        ))
+    })
+}
+
+/// 현재 시스템의 가용 메모리 (Linux의 /proc/meminfo MemAvailable)
+fn available_memory() -> Option<u64> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+
+    meminfo.lines().find_map(|line| {
+        let rest = line.strip_prefix("MemAvailable:")?;
+        let kb: u64 = rest.trim().trim_end_matches("kB").trim().parse().ok()?;
+        Some(kb * 1024)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_units() {
+        assert_eq!(parse_memory_size("4096").unwrap(), 4096);
+        assert_eq!(parse_memory_size("512M").unwrap(), 512 * 1024 * 1024);
+        assert_eq!(parse_memory_size("8gib").unwrap(), 8 * 1024 * 1024 * 1024);
+        assert!(parse_memory_size("8T").is_err());
+    }
+
+    #[test]
+    fn rejects_overflowing_size() {
+        assert!(matches!(
+            parse_memory_size("99999999999G"),
+            Err(BuildError::ConfigParsingError(_))
+        ));
+    }
+}
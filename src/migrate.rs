@@ -0,0 +1,1619 @@
+use crate::config::BuildConfig;
+use crate::config_edit::write_config;
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use colored::Colorize;
+use lazy_static::lazy_static;
+use log::info;
+use regex::Regex;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+/// 기존 빌드 시스템 종류 (`cbuild migrate --from <source>`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrateSource {
+    Make,
+    Cmake,
+}
+
+impl std::str::FromStr for MigrateSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "make" => Ok(MigrateSource::Make),
+            "cmake" => Ok(MigrateSource::Cmake),
+            _ => Err(format!("make 또는 cmake여야 합니다: {}", s)),
+        }
+    }
+}
+
+/// 변환한 타겟 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TargetKind {
+    Executable,
+    StaticLib,
+    SharedLib,
+}
+
+impl TargetKind {
+    /// cbuild.toml의 `[[targets.*]]` 이름
+    fn section(self) -> &'static str {
+        match self {
+            TargetKind::Executable => "executable",
+            TargetKind::StaticLib => "static_lib",
+            TargetKind::SharedLib => "shared_lib",
+        }
+    }
+}
+
+/// 컴파일러·링커 플래그를 cbuild.toml 항목별로 나눈 결과
+#[derive(Debug, Default, Clone)]
+struct Flags {
+    include_dirs: Vec<String>,
+    defines: BTreeMap<String, String>,
+    link_dirs: Vec<String>,
+    libs: Vec<String>,
+    c_standard: Option<String>,
+    cpp_standard: Option<String>,
+    optimization_level: Option<u8>,
+    debug_info: bool,
+    warnings_as_errors: bool,
+    extra: Vec<String>,
+}
+
+impl Flags {
+    /// 플래그 목록 해석 (`-I dir`처럼 값이 분리된 형식 포함)
+    fn parse<S: AsRef<str>>(tokens: &[S]) -> Flags {
+        let mut flags = Flags::default();
+        let mut iter = tokens.iter().map(|t| t.as_ref());
+
+        while let Some(token) = iter.next() {
+            let mut value_of = |prefix: &str| -> Option<String> {
+                let rest = token.strip_prefix(prefix)?;
+                if rest.is_empty() {
+                    iter.next().map(|v| v.to_string())
+                } else {
+                    Some(rest.to_string())
+                }
+            };
+
+            if let Some(dir) = value_of("-I") {
+                push_unique(&mut flags.include_dirs, dir);
+            } else if let Some(define) = value_of("-D") {
+                let (name, value) = define.split_once('=').unwrap_or((&define, ""));
+                flags.defines.insert(name.to_string(), value.to_string());
+            } else if let Some(dir) = value_of("-L") {
+                push_unique(&mut flags.link_dirs, dir);
+            } else if let Some(lib) = value_of("-l") {
+                push_unique(&mut flags.libs, lib);
+            } else if let Some(standard) = token.strip_prefix("-std=") {
+                if standard.contains("++") {
+                    flags.cpp_standard = Some(standard.to_string());
+                } else {
+                    flags.c_standard = Some(standard.to_string());
+                }
+            } else if let Some(level) = token
+                .strip_prefix("-O")
+                .and_then(|level| level.parse::<u8>().ok())
+                .filter(|level| *level <= 3)
+            {
+                flags.optimization_level = Some(level);
+            } else if token == "-g" {
+                flags.debug_info = true;
+            } else if token == "-Werror" {
+                flags.warnings_as_errors = true;
+            } else {
+                push_unique(&mut flags.extra, token.to_string());
+            }
+        }
+
+        flags
+    }
+}
+
+impl Flags {
+    /// 다른 타겟이나 컴파일 그룹의 플래그를 합침
+    fn merge(&mut self, other: Flags) {
+        for dir in other.include_dirs {
+            push_unique(&mut self.include_dirs, dir);
+        }
+        self.defines.extend(other.defines);
+        for dir in other.link_dirs {
+            push_unique(&mut self.link_dirs, dir);
+        }
+        for lib in other.libs {
+            push_unique(&mut self.libs, lib);
+        }
+        self.c_standard = self.c_standard.take().or(other.c_standard);
+        self.cpp_standard = self.cpp_standard.take().or(other.cpp_standard);
+        self.optimization_level = self.optimization_level.or(other.optimization_level);
+        self.debug_info |= other.debug_info;
+        self.warnings_as_errors |= other.warnings_as_errors;
+        for flag in other.extra {
+            push_unique(&mut self.extra, flag);
+        }
+    }
+}
+
+fn push_unique(list: &mut Vec<String>, value: String) {
+    if !list.contains(&value) {
+        list.push(value);
+    }
+}
+
+/// 기존 빌드에서 찾은 타겟
+#[derive(Debug)]
+struct MigratedTarget {
+    kind: TargetKind,
+    name: String,
+    src: Vec<String>,
+    include_dirs: Vec<String>,
+    defines: BTreeMap<String, String>,
+    link_dirs: Vec<String>,
+    libs: Vec<String>,
+}
+
+/// 기존 빌드 시스템에서 읽은 프로젝트
+#[derive(Debug, Default)]
+struct MigratedProject {
+    name: String,
+    compiler: Option<String>,
+    compile_flags: Flags,
+    targets: Vec<MigratedTarget>,
+    /// 변환하지 못한 구문 (생성한 설정 파일 머리에 주석으로 남김)
+    notes: Vec<String>,
+}
+
+/// 기존 빌드 설정을 읽어 cbuild.toml 생성
+///
+/// `input`은 make이면 Makefile 경로, cmake이면 CMake 빌드 디렉토리(기본: build) 또는
+/// compile_commands.json 경로다. 완전한 변환이 아니므로 변환하지 못한 구문은
+/// 생성한 파일의 주석과 출력으로 알린다.
+pub fn migrate(
+    project_dir: &Path,
+    source: MigrateSource,
+    input: Option<&Path>,
+    force: bool,
+) -> BuildResult<()> {
+    let config_path = project_dir.join("cbuild.toml");
+    if config_path.exists() && !force {
+        return Err(BuildError::PathError(tr!(
+            "{}이(가) 이미 있습니다 (덮어쓰려면 --force)",
+            "{} already exists (use --force to overwrite)",
+            config_path.display()
+        )));
+    }
+
+    let (project, origin) = match source {
+        MigrateSource::Make => {
+            let makefile = match input {
+                Some(path) => project_dir.join(path),
+                None => find_makefile(project_dir)?,
+            };
+            info!("Makefile 변환: {}", makefile.display());
+            (parse_makefile(project_dir, &makefile)?, makefile)
+        }
+        MigrateSource::Cmake => {
+            let input = project_dir.join(input.unwrap_or(Path::new("build")));
+            read_cmake_build(project_dir, &input)?
+        }
+    };
+
+    if project.targets.is_empty() {
+        return Err(BuildError::ConfigParsingError(tr!(
+            "{}에서 빌드 타겟을 찾을 수 없습니다",
+            "no build targets found in {}",
+            origin.display()
+        )));
+    }
+
+    let origin_name = origin
+        .strip_prefix(project_dir)
+        .unwrap_or(&origin)
+        .display()
+        .to_string();
+    let content = render_config(&project, &origin_name);
+
+    // 생성한 설정이 cbuild에서 읽히는지 확인
+    toml::from_str::<BuildConfig>(&content)
+        .map_err(|e| BuildError::ConfigParsingError(e.to_string()))?;
+    let replaced = config_path.exists();
+    write_config(&config_path, &content)?;
+    if replaced {
+        println!(
+            "{}",
+            tr!(
+                "이전 설정은 cbuild.toml.bak에 보관했습니다",
+                "Previous configuration saved to cbuild.toml.bak"
+            )
+        );
+    }
+
+    for target in &project.targets {
+        println!(
+            "  {} {} {}",
+            target.kind.section(),
+            target.name.bold(),
+            tr!("(소스 {}개)", "({} sources)", target.src.len())
+        );
+    }
+
+    if !project.notes.is_empty() {
+        println!(
+            "{}",
+            tr!(
+                "변환하지 못한 항목 (직접 확인하세요):",
+                "Not translated (review manually):"
+            )
+            .yellow()
+            .bold()
+        );
+        for note in &project.notes {
+            println!("  - {}", note);
+        }
+    }
+
+    println!(
+        "{} {}",
+        tr!("생성됨:", "Created:").green().bold(),
+        config_path.display()
+    );
+    Ok(())
+}
+
+fn find_makefile(project_dir: &Path) -> BuildResult<PathBuf> {
+    ["GNUmakefile", "makefile", "Makefile"]
+        .iter()
+        .map(|name| project_dir.join(name))
+        .find(|path| path.exists())
+        .ok_or_else(|| {
+            BuildError::PathError(tr!(
+                "{}에 Makefile이 없습니다",
+                "no Makefile in {}",
+                project_dir.display()
+            ))
+        })
+}
+
+/// Makefile 규칙
+#[derive(Debug)]
+struct Rule {
+    line: usize,
+    targets: Vec<String>,
+    prerequisites: Vec<String>,
+    recipe: Vec<String>,
+}
+
+/// 변수와 규칙만 해석하는 간단한 Makefile 파서
+///
+/// 조건문, include, define, 대부분의 함수는 해석하지 않고 기록만 한다.
+/// 함수 중 wildcard, patsubst, addprefix, addsuffix, notdir와 치환 참조(`$(SRCS:.c=.o)`)는 지원한다.
+#[derive(Default)]
+struct Makefile {
+    vars: HashMap<String, String>,
+    rules: Vec<Rule>,
+    notes: Vec<String>,
+    /// 확장 중 만난 해석하지 않는 함수 (`$(shell ...)` 등)
+    unknown_functions: RefCell<BTreeSet<String>>,
+}
+
+impl Makefile {
+    fn parse(content: &str) -> Makefile {
+        let mut makefile = Makefile::default();
+        // 변수 기본값 (make 내장 규칙과 같음)
+        makefile.vars.insert("CC".to_string(), "cc".to_string());
+        makefile.vars.insert("CXX".to_string(), "g++".to_string());
+        makefile.vars.insert("AR".to_string(), "ar".to_string());
+
+        // 해석하지 않고 건너뛰는 조건문·define 블록 (시작 행, 지시문, 중첩 수준)
+        let mut skipped: Option<(usize, String, usize)> = None;
+
+        for (line_no, line) in logical_lines(content) {
+            if let Some((start, ref directive, ref mut depth)) = skipped {
+                let word = line.split_whitespace().next().unwrap_or("");
+                if BLOCK_START.contains(&word) {
+                    *depth += 1;
+                } else if word == "endif" || word == "endef" {
+                    if *depth == 0 {
+                        makefile.notes.push(tr!(
+                            "{}~{}행: {} 블록은 해석하지 않았습니다",
+                            "lines {}-{}: {} block was not interpreted",
+                            start,
+                            line_no,
+                            directive
+                        ));
+                        skipped = None;
+                    } else {
+                        *depth -= 1;
+                    }
+                }
+                continue;
+            }
+
+            if let Some(command) = line.strip_prefix('\t') {
+                if let Some(rule) = makefile.rules.last_mut() {
+                    rule.recipe.push(command.trim().to_string());
+                }
+                continue;
+            }
+
+            let line = strip_comment(&line);
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+
+            let directive = trimmed.split_whitespace().next().unwrap_or("");
+            if BLOCK_START.contains(&directive) {
+                skipped = Some((line_no, directive.to_string(), 0));
+                continue;
+            }
+            if matches!(
+                directive,
+                "include" | "-include" | "sinclude" | "export" | "unexport" | "vpath" | "override"
+            ) {
+                makefile.notes.push(tr!(
+                    "{}행: {} 지시문은 해석하지 않았습니다",
+                    "line {}: {} directive was not interpreted",
+                    line_no,
+                    directive
+                ));
+                continue;
+            }
+
+            if let Some((name, op, value)) = parse_assignment(trimmed) {
+                if name.contains(':') {
+                    // 타겟별 변수 (`app: CFLAGS += -O0`)
+                    makefile.notes.push(tr!(
+                        "{}행: 타겟별 변수 {}",
+                        "line {}: target-specific variable {}",
+                        line_no,
+                        name
+                    ));
+                    continue;
+                }
+                makefile.assign(&name, op, value.trim());
+                continue;
+            }
+
+            if let Some((targets, prerequisites)) = trimmed.split_once(':') {
+                let prerequisites = prerequisites.trim_start_matches(':');
+                let (prerequisites, inline_recipe) = match prerequisites.split_once(';') {
+                    Some((prereqs, recipe)) => (prereqs, Some(recipe.trim().to_string())),
+                    None => (prerequisites, None),
+                };
+                // 순서 전용 선행 조건(`| dir`)은 무시
+                let prerequisites = prerequisites.split('|').next().unwrap_or("");
+
+                makefile.rules.push(Rule {
+                    line: line_no,
+                    targets: makefile.expand_words(targets),
+                    prerequisites: makefile.expand_words(prerequisites),
+                    recipe: inline_recipe.into_iter().collect(),
+                });
+                continue;
+            }
+
+            makefile.notes.push(tr!(
+                "{}행: 해석할 수 없는 구문: {}",
+                "line {}: cannot interpret: {}",
+                line_no,
+                trimmed
+            ));
+        }
+
+        makefile
+    }
+
+    fn assign(&mut self, name: &str, op: &str, value: &str) {
+        let value = match op {
+            // 즉시 확장 변수
+            ":=" | "::=" => self.expand(value),
+            _ => value.to_string(),
+        };
+
+        match op {
+            "?=" => {
+                // 내장 기본값(CC 등)은 ?=로 바꿀 수 있는 것으로 취급
+                let is_builtin = matches!(name, "CC" | "CXX" | "AR");
+                if is_builtin || !self.vars.contains_key(name) {
+                    self.vars.insert(name.to_string(), value);
+                }
+            }
+            "+=" => {
+                let entry = self.vars.entry(name.to_string()).or_default();
+                if !entry.is_empty() {
+                    entry.push(' ');
+                }
+                entry.push_str(&value);
+            }
+            _ => {
+                self.vars.insert(name.to_string(), value);
+            }
+        }
+    }
+
+    fn var(&self, name: &str) -> String {
+        self.vars
+            .get(name)
+            .map(|value| self.expand(value))
+            .unwrap_or_default()
+    }
+
+    fn expand_words(&self, text: &str) -> Vec<String> {
+        self.expand(text)
+            .split_whitespace()
+            .map(|word| word.to_string())
+            .collect()
+    }
+
+    /// 확장한 값을 셸처럼 인자로 분리 (make는 레시피를 /bin/sh로 실행하므로 따옴표와 `\` 제거)
+    fn expand_args(&self, text: &str) -> Vec<String> {
+        split_command_line(&self.expand(text))
+    }
+
+    /// 변수 참조와 지원하는 함수를 확장 (자동 변수 `$@`, `$<`, `$^`는 그대로 둠)
+    fn expand(&self, text: &str) -> String {
+        self.expand_depth(text, 0)
+    }
+
+    fn expand_depth(&self, text: &str, depth: usize) -> String {
+        if depth > 32 {
+            return String::new();
+        }
+
+        let mut result = String::new();
+        let mut chars = text.char_indices().peekable();
+
+        while let Some((i, c)) = chars.next() {
+            if c != '$' {
+                result.push(c);
+                continue;
+            }
+
+            match chars.peek().copied() {
+                Some((_, '$')) => {
+                    chars.next();
+                    result.push('$');
+                }
+                Some((start, open @ ('(' | '{'))) => {
+                    let close = if open == '(' { ')' } else { '}' };
+                    let Some(end) = matching_close(text, start, open, close) else {
+                        result.push_str(&text[i..]);
+                        break;
+                    };
+                    let inner = &text[start + 1..end];
+                    while chars.peek().is_some_and(|(j, _)| *j <= end) {
+                        chars.next();
+                    }
+
+                    if is_automatic(inner) {
+                        result.push_str(&text[i..=end]);
+                    } else {
+                        result.push_str(&self.expand_reference(inner, depth));
+                    }
+                }
+                Some((_, name)) => {
+                    chars.next();
+                    if is_automatic(&name.to_string()) {
+                        result.push('$');
+                        result.push(name);
+                    } else {
+                        result.push_str(&self.expand_depth(
+                            self.vars.get(&name.to_string()).map_or("", |v| v),
+                            depth + 1,
+                        ));
+                    }
+                }
+                None => result.push('$'),
+            }
+        }
+
+        result
+    }
+
+    fn expand_reference(&self, inner: &str, depth: usize) -> String {
+        // 함수 호출
+        if let Some((function, args)) = inner.split_once(|c: char| c.is_whitespace()) {
+            let args = self.expand_depth(args, depth + 1);
+            let split_args =
+                |n: usize| -> Vec<String> { args.splitn(n, ',').map(|a| a.to_string()).collect() };
+
+            return match function {
+                // glob 패턴은 cbuild.toml의 src에 그대로 사용할 수 있다
+                "wildcard" => args,
+                "patsubst" => {
+                    let parts = split_args(3);
+                    if parts.len() < 3 {
+                        return String::new();
+                    }
+                    words(&parts[2])
+                        .map(|word| pattern_substitute(word, parts[0].trim(), parts[1].trim()))
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                }
+                "addprefix" | "addsuffix" => {
+                    let parts = split_args(2);
+                    if parts.len() < 2 {
+                        return String::new();
+                    }
+                    let affix = parts[0].trim();
+                    words(&parts[1])
+                        .map(|word| {
+                            if function == "addprefix" {
+                                format!("{}{}", affix, word)
+                            } else {
+                                format!("{}{}", word, affix)
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                }
+                "notdir" => words(&args)
+                    .map(|word| word.rsplit('/').next().unwrap_or(word).to_string())
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                "strip" => words(&args).collect::<Vec<_>>().join(" "),
+                _ => {
+                    self.unknown_functions
+                        .borrow_mut()
+                        .insert(function.to_string());
+                    String::new()
+                }
+            };
+        }
+
+        // 치환 참조 `$(VAR:.c=.o)`
+        if let Some((name, substitution)) = inner.split_once(':') {
+            if let Some((from, to)) = substitution.split_once('=') {
+                let (from, to) = if from.contains('%') {
+                    (from.to_string(), to.to_string())
+                } else {
+                    (format!("%{}", from), format!("%{}", to))
+                };
+                let value = self.expand_depth(self.vars.get(name).map_or("", |v| v), depth + 1);
+                return words(&value)
+                    .map(|word| pattern_substitute(word, &from, &to))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+            }
+        }
+
+        self.expand_depth(self.vars.get(inner).map_or("", |v| v), depth + 1)
+    }
+}
+
+/// 내용을 해석하지 않고 건너뛰는 블록의 시작 지시문
+const BLOCK_START: [&str; 5] = ["ifeq", "ifneq", "ifdef", "ifndef", "define"];
+
+/// 줄 끝 `\`로 이어진 줄을 합친 논리적 줄과 시작 행 번호
+fn logical_lines(content: &str) -> Vec<(usize, String)> {
+    let mut lines = Vec::new();
+    let mut current: Option<(usize, String)> = None;
+
+    for (index, line) in content.lines().enumerate() {
+        let (continues, text) = match line.strip_suffix('\\') {
+            Some(text) => (true, text),
+            None => (false, line),
+        };
+
+        match current {
+            Some((_, ref mut joined)) => {
+                joined.push(' ');
+                joined.push_str(text.trim_start());
+            }
+            None => current = Some((index + 1, text.to_string())),
+        }
+
+        if !continues {
+            lines.extend(current.take());
+        }
+    }
+
+    lines.extend(current);
+    lines
+}
+
+fn strip_comment(line: &str) -> String {
+    let mut result = String::new();
+    let mut escaped = false;
+    for c in line.chars() {
+        if c == '#' && !escaped {
+            break;
+        }
+        escaped = c == '\\';
+        result.push(c);
+    }
+    result
+}
+
+/// 변수 대입 해석 (이름, 연산자, 값)
+fn parse_assignment(line: &str) -> Option<(String, &'static str, &str)> {
+    const OPERATORS: [&str; 5] = ["::=", ":=", "?=", "+=", "="];
+
+    let (index, op) = OPERATORS
+        .iter()
+        .filter_map(|op| line.find(op).map(|index| (index, *op)))
+        .min_by_key(|(index, op)| (*index, std::cmp::Reverse(op.len())))?;
+
+    // 규칙의 콜론이 대입 연산자보다 앞에 있으면 규칙 (`a: b=c`는 타겟별 변수로 처리)
+    let name = line[..index].trim();
+    if name.is_empty() || name.contains(char::is_whitespace) && !name.contains(':') {
+        return None;
+    }
+
+    Some((name.to_string(), op, &line[index + op.len()..]))
+}
+
+fn matching_close(text: &str, open_index: usize, open: char, close: char) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in text[open_index..].char_indices() {
+        if c == open {
+            depth += 1;
+        } else if c == close {
+            depth -= 1;
+            if depth == 0 {
+                return Some(open_index + i);
+            }
+        }
+    }
+    None
+}
+
+fn is_automatic(name: &str) -> bool {
+    let base = name.trim_end_matches(['D', 'F']);
+    matches!(base, "@" | "<" | "^" | "+" | "?" | "*" | "|" | "%")
+}
+
+fn words(text: &str) -> impl Iterator<Item = &str> {
+    text.split_whitespace()
+}
+
+/// `%` 패턴 치환 (`patsubst %.c,%.o,foo.c` → `foo.o`)
+fn pattern_substitute(word: &str, from: &str, to: &str) -> String {
+    match from.split_once('%') {
+        Some((prefix, suffix)) => {
+            match word
+                .strip_prefix(prefix)
+                .and_then(|rest| rest.strip_suffix(suffix))
+            {
+                Some(stem) => to.replacen('%', stem, 1),
+                None => word.to_string(),
+            }
+        }
+        None if word == from => to.to_string(),
+        None => word.to_string(),
+    }
+}
+
+const SOURCE_EXTENSIONS: [&str; 8] = ["c", "cc", "cpp", "cxx", "C", "c++", "m", "mm"];
+
+fn is_source(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .is_some_and(|ext| SOURCE_EXTENSIONS.iter().any(|s| ext == *s))
+}
+
+/// 명령줄의 첫 단어가 가리키는 도구
+#[derive(Debug, PartialEq, Eq)]
+enum RecipeTool {
+    Compiler,
+    Archiver,
+    Ignored,
+    Other,
+}
+
+fn parse_makefile(project_dir: &Path, path: &Path) -> BuildResult<MigratedProject> {
+    let content = std::fs::read_to_string(path).map_err(BuildError::IoError)?;
+    let makefile = Makefile::parse(&content);
+
+    let mut project = MigratedProject {
+        name: project_name(project_dir),
+        notes: makefile.notes.clone(),
+        ..Default::default()
+    };
+
+    let cc = makefile.var("CC");
+    let cxx = makefile.var("CXX");
+    let ar = makefile.var("AR");
+    let tool_of = |command: &str| -> RecipeTool {
+        let expanded = makefile.expand(command);
+        let program = program_of(&expanded);
+        let base = program.rsplit('/').next().unwrap_or(program);
+        if program == cc
+            || program == cxx
+            || ["cc", "c++", "gcc", "g++", "clang", "clang++"].contains(&base)
+            || base.ends_with("-gcc")
+            || base.ends_with("-g++")
+        {
+            RecipeTool::Compiler
+        } else if program == ar || base == "ar" || base.ends_with("-ar") {
+            RecipeTool::Archiver
+        } else if ["mkdir", "echo", "rm", "@echo", "true", ":", "printf"].contains(&base) {
+            RecipeTool::Ignored
+        } else {
+            RecipeTool::Other
+        }
+    };
+
+    // 오브젝트 파일 → 소스 파일 규칙 (명시적 규칙과 `%.o: %.c` 패턴 규칙)
+    let mut object_sources: HashMap<String, String> = HashMap::new();
+    let mut object_patterns: Vec<(String, String)> = Vec::new();
+    let mut compile_tokens: Vec<String> = Vec::new();
+    let mut uses_cxx = false;
+
+    for rule in &makefile.rules {
+        let compiles = rule.recipe.iter().any(|command| {
+            tool_of(command) == RecipeTool::Compiler && has_compile_flag(&makefile.expand(command))
+        });
+        if !compiles {
+            continue;
+        }
+
+        let source = rule.prerequisites.iter().find(|p| is_source(p));
+        for target in &rule.targets {
+            match (target.contains('%'), source) {
+                (true, Some(source)) => {
+                    object_patterns.push((target.clone(), source.clone()));
+                }
+                (false, Some(source)) => {
+                    object_sources.insert(target.clone(), source.clone());
+                }
+                _ => {}
+            }
+        }
+
+        for command in &rule.recipe {
+            if tool_of(command) == RecipeTool::Compiler {
+                let expanded = makefile.expand(command);
+                uses_cxx |= program_of(&expanded) == cxx;
+                compile_tokens.extend(
+                    split_command_line(&expanded)
+                        .into_iter()
+                        .skip(1)
+                        .filter(|t| t != "-c"),
+                );
+            }
+        }
+    }
+
+    let compile_tokens = remove_output_args(compile_tokens);
+    project.compile_flags = if compile_tokens.is_empty() {
+        // 명시적 컴파일 규칙이 없으면 make 내장 규칙이 사용하는 변수
+        let mut tokens = makefile.expand_args("$(CPPFLAGS) $(CFLAGS)");
+        tokens.extend(makefile.expand_args("$(CXXFLAGS)"));
+        Flags::parse(&tokens)
+    } else {
+        Flags::parse(&compile_tokens)
+    };
+
+    let source_of = |object: &str| -> Option<String> {
+        if let Some(source) = object_sources.get(object) {
+            return Some(source.clone());
+        }
+        for (target_pattern, source_pattern) in &object_patterns {
+            let (prefix, suffix) = target_pattern.split_once('%')?;
+            if let Some(stem) = object
+                .strip_prefix(prefix)
+                .and_then(|rest| rest.strip_suffix(suffix))
+            {
+                return Some(source_pattern.replacen('%', stem, 1));
+            }
+        }
+        // 내장 규칙: 같은 이름의 소스 파일
+        let stem = object.strip_suffix(".o")?;
+        SOURCE_EXTENSIONS
+            .iter()
+            .map(|ext| format!("{}.{}", stem, ext))
+            .find(|candidate| project_dir.join(candidate).exists())
+    };
+
+    // 링크·아카이브 규칙에서 타겟 추출
+    let internal_libs: HashMap<String, TargetKind> = makefile
+        .rules
+        .iter()
+        .flat_map(|rule| rule.targets.iter())
+        .filter_map(|target| library_name(target))
+        .collect();
+
+    for rule in &makefile.rules {
+        if rule
+            .targets
+            .iter()
+            .any(|t| t.starts_with('.') || t.contains('%'))
+        {
+            continue;
+        }
+
+        let mut kind = None;
+        let mut link_tokens = Vec::new();
+        for command in &rule.recipe {
+            match tool_of(command) {
+                RecipeTool::Archiver => kind = Some(TargetKind::StaticLib),
+                RecipeTool::Compiler if !has_compile_flag(&makefile.expand(command)) => {
+                    let expanded = makefile.expand(command);
+                    uses_cxx |= program_of(&expanded) == cxx;
+                    let tokens: Vec<String> =
+                        split_command_line(&expanded).into_iter().skip(1).collect();
+                    kind = Some(if tokens.iter().any(|t| t == "-shared") {
+                        TargetKind::SharedLib
+                    } else {
+                        TargetKind::Executable
+                    });
+                    link_tokens = remove_output_args(tokens);
+                }
+                RecipeTool::Compiler | RecipeTool::Ignored => {}
+                RecipeTool::Other => project.notes.push(tr!(
+                    "{}행: {} 규칙의 명령을 변환하지 않았습니다: {}",
+                    "line {}: command in rule {} was not translated: {}",
+                    rule.line,
+                    rule.targets.join(" "),
+                    command
+                )),
+            }
+        }
+
+        let Some(kind) = kind else {
+            continue;
+        };
+
+        for target in &rule.targets {
+            let name = match kind {
+                TargetKind::Executable => Path::new(target)
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_else(|| target.clone()),
+                _ => library_name(target)
+                    .map(|(name, _)| name)
+                    .unwrap_or_else(|| target.clone()),
+            };
+
+            let mut src = Vec::new();
+            let mut libs = Vec::new();
+            for prerequisite in &rule.prerequisites {
+                if is_source(prerequisite) {
+                    push_unique(&mut src, prerequisite.clone());
+                } else if prerequisite.ends_with(".o") {
+                    match source_of(prerequisite) {
+                        Some(source) => push_unique(&mut src, source),
+                        None => project.notes.push(tr!(
+                            "{}행: {}의 소스 파일을 찾을 수 없습니다",
+                            "line {}: cannot find the source file of {}",
+                            rule.line,
+                            prerequisite
+                        )),
+                    }
+                } else if let Some((lib, lib_kind)) = library_name(prerequisite) {
+                    // 내부 정적 라이브러리는 cbuild가 모든 실행 파일에 자동으로 링크
+                    if lib_kind == TargetKind::SharedLib || !internal_libs.contains_key(&lib) {
+                        push_unique(&mut libs, lib);
+                    }
+                }
+            }
+
+            let link_flags = Flags::parse(&link_tokens);
+            let untranslated: Vec<&String> = link_flags
+                .extra
+                .iter()
+                .filter(|flag| !project.compile_flags.extra.contains(flag))
+                .collect();
+            if !untranslated.is_empty() {
+                project.notes.push(tr!(
+                    "{}행: {}의 링크 플래그 {}",
+                    "line {}: link flags of {}: {}",
+                    rule.line,
+                    target,
+                    untranslated
+                        .iter()
+                        .map(|flag| flag.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                ));
+            }
+            for lib in link_flags.libs {
+                if internal_libs.get(&lib) != Some(&TargetKind::StaticLib) {
+                    push_unique(&mut libs, lib);
+                }
+            }
+            if link_tokens.is_empty() && kind == TargetKind::Executable {
+                // 링크 명령이 없는 타겟은 make 내장 규칙의 LDLIBS를 사용
+                for lib in Flags::parse(&makefile.expand_args("$(LDFLAGS) $(LDLIBS) $(LIBS)")).libs
+                {
+                    push_unique(&mut libs, lib);
+                }
+            }
+
+            if src.is_empty() {
+                project.notes.push(tr!(
+                    "{}행: {}의 소스 파일을 찾을 수 없어 건너뜁니다",
+                    "line {}: no source files found for {}, skipped",
+                    rule.line,
+                    target
+                ));
+                continue;
+            }
+
+            project.targets.push(MigratedTarget {
+                kind,
+                name,
+                src,
+                include_dirs: project.compile_flags.include_dirs.clone(),
+                defines: project.compile_flags.defines.clone(),
+                link_dirs: link_flags.link_dirs,
+                libs,
+            });
+        }
+    }
+
+    for function in makefile.unknown_functions.borrow().iter() {
+        project.notes.push(tr!(
+            "$({} ...) 함수는 해석하지 않았습니다",
+            "$({} ...) function was not interpreted",
+            function
+        ));
+    }
+
+    let compiler = if uses_cxx { cxx } else { cc };
+    project.compiler = Some(if compiler == "cc" {
+        "gcc".to_string()
+    } else {
+        compiler
+    });
+
+    Ok(project)
+}
+
+/// 명령줄의 실행 파일 (make의 `@`, `-`, `+` 접두사 제거)
+fn program_of(command: &str) -> &str {
+    command
+        .trim_start_matches(['@', '-', '+'])
+        .split_whitespace()
+        .next()
+        .unwrap_or("")
+}
+
+fn has_compile_flag(command: &str) -> bool {
+    words(command).any(|t| t == "-c")
+}
+
+/// `libfoo.a` → (foo, 정적), `libfoo.so` → (foo, 공유)
+fn library_name(path: &str) -> Option<(String, TargetKind)> {
+    let file = Path::new(path).file_name()?.to_string_lossy().to_string();
+    let (stem, kind) = if let Some(stem) = file.strip_suffix(".a") {
+        (stem, TargetKind::StaticLib)
+    } else if let Some(stem) = file
+        .strip_suffix(".so")
+        .or_else(|| file.strip_suffix(".dylib"))
+    {
+        (stem, TargetKind::SharedLib)
+    } else {
+        return None;
+    };
+    Some((stem.strip_prefix("lib").unwrap_or(stem).to_string(), kind))
+}
+
+/// 명령줄에서 출력 파일(-o), 입력 파일, 자동 변수 제거
+fn remove_output_args(tokens: Vec<String>) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut iter = tokens.into_iter();
+    while let Some(token) = iter.next() {
+        if token == "-o" {
+            iter.next();
+        } else if !token.starts_with('$')
+            && !token.ends_with(".o")
+            && !is_source(&token)
+            && library_name(&token).is_none()
+            && token != "-shared"
+        {
+            result.push(token);
+        }
+    }
+    result
+}
+
+/// CMake 빌드 디렉토리 읽기 (file API 응답이 있으면 사용, 없으면 compile_commands.json)
+fn read_cmake_build(project_dir: &Path, input: &Path) -> BuildResult<(MigratedProject, PathBuf)> {
+    if input.is_file() {
+        return Ok((
+            read_compile_commands(project_dir, input)?,
+            input.to_path_buf(),
+        ));
+    }
+
+    let reply_dir = input.join(".cmake/api/v1/reply");
+    if let Some(index) = latest_reply_index(&reply_dir) {
+        info!("CMake file API 응답 변환: {}", index.display());
+        let mut project = read_file_api(project_dir, &reply_dir, &index)?;
+        project.compiler = cmake_cache_compiler(input, project.compiler.take());
+        return Ok((project, input.to_path_buf()));
+    }
+
+    let compile_commands = input.join("compile_commands.json");
+    if compile_commands.exists() {
+        info!("compile_commands.json 변환: {}", compile_commands.display());
+        return Ok((
+            read_compile_commands(project_dir, &compile_commands)?,
+            compile_commands,
+        ));
+    }
+
+    let dir = input.display();
+    Err(BuildError::PathError(tr!(
+        "{dir}에 CMake file API 응답이나 compile_commands.json이 없습니다. \
+         `mkdir -p {dir}/.cmake/api/v1/query && touch {dir}/.cmake/api/v1/query/codemodel-v2` 후 \
+         cmake를 다시 실행하거나 -DCMAKE_EXPORT_COMPILE_COMMANDS=ON으로 구성하세요",
+        "{dir} has no CMake file API reply or compile_commands.json. \
+         Run `mkdir -p {dir}/.cmake/api/v1/query && touch {dir}/.cmake/api/v1/query/codemodel-v2` \
+         and re-run cmake, or configure with -DCMAKE_EXPORT_COMPILE_COMMANDS=ON"
+    )))
+}
+
+/// 가장 최근의 file API 응답 색인 (`index-<시각>.json`)
+fn latest_reply_index(reply_dir: &Path) -> Option<PathBuf> {
+    std::fs::read_dir(reply_dir)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with("index-"))
+        })
+        .max()
+}
+
+fn read_json(path: &Path) -> BuildResult<serde_json::Value> {
+    let content = std::fs::read_to_string(path).map_err(BuildError::IoError)?;
+    serde_json::from_str(&content)
+        .map_err(|e| BuildError::ConfigParsingError(format!("{}: {}", path.display(), e)))
+}
+
+fn json_str<'a>(value: &'a serde_json::Value, key: &str) -> &'a str {
+    value.get(key).and_then(|v| v.as_str()).unwrap_or("")
+}
+
+fn json_array<'a>(value: &'a serde_json::Value, key: &str) -> &'a [serde_json::Value] {
+    value
+        .get(key)
+        .and_then(|v| v.as_array())
+        .map(|a| a.as_slice())
+        .unwrap_or(&[])
+}
+
+/// CMake file API codemodel-v2 응답에서 타겟 추출
+fn read_file_api(
+    project_dir: &Path,
+    reply_dir: &Path,
+    index: &Path,
+) -> BuildResult<MigratedProject> {
+    let index = read_json(index)?;
+    let codemodel_file = index
+        .pointer("/reply/codemodel-v2/jsonFile")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| {
+            BuildError::ConfigParsingError(tr!(
+                "file API 응답에 codemodel-v2가 없습니다",
+                "the file API reply has no codemodel-v2"
+            ))
+        })?;
+    let codemodel = read_json(&reply_dir.join(codemodel_file))?;
+
+    let source_root = codemodel
+        .pointer("/paths/source")
+        .and_then(|v| v.as_str())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| project_dir.to_path_buf());
+
+    let mut project = MigratedProject {
+        name: project_name(project_dir),
+        ..Default::default()
+    };
+
+    // 여러 구성(Debug/Release 등)이 있으면 첫 번째 구성 사용
+    let configurations = json_array(&codemodel, "configurations");
+    let Some(configuration) = configurations.first() else {
+        return Ok(project);
+    };
+    if configurations.len() > 1 {
+        project.notes.push(tr!(
+            "여러 CMake 구성 중 {}의 설정을 사용했습니다",
+            "used the settings of {} among several CMake configurations",
+            json_str(configuration, "name")
+        ));
+    }
+    if let Some(name) = json_array(configuration, "projects")
+        .first()
+        .map(|p| json_str(p, "name"))
+        .filter(|name| !name.is_empty())
+    {
+        project.name = name.to_string();
+    }
+
+    let mut uses_cxx = false;
+    for entry in json_array(configuration, "targets") {
+        let target = read_json(&reply_dir.join(json_str(entry, "jsonFile")))?;
+        let name = json_str(&target, "name").to_string();
+
+        let kind = match json_str(&target, "type") {
+            "EXECUTABLE" => TargetKind::Executable,
+            "STATIC_LIBRARY" => TargetKind::StaticLib,
+            "SHARED_LIBRARY" | "MODULE_LIBRARY" => TargetKind::SharedLib,
+            other => {
+                if other != "UTILITY" {
+                    project.notes.push(tr!(
+                        "{} 타겟 {}은(는) 변환하지 않았습니다",
+                        "{} target {} was not translated",
+                        other,
+                        name
+                    ));
+                }
+                continue;
+            }
+        };
+
+        let mut src = Vec::new();
+        for source in json_array(&target, "sources") {
+            let path = json_str(source, "path");
+            if source.get("compileGroupIndex").is_none() {
+                // 헤더 등 컴파일하지 않는 파일
+                continue;
+            }
+            if source.get("isGenerated").and_then(|v| v.as_bool()) == Some(true) {
+                project.notes.push(tr!(
+                    "{}의 생성된 소스 {}는 변환하지 않았습니다",
+                    "generated source {} of {} was not translated",
+                    name,
+                    path
+                ));
+                continue;
+            }
+            push_unique(
+                &mut src,
+                project_relative(project_dir, &source_root.join(path)),
+            );
+        }
+
+        let mut flags = Flags::default();
+        for group in json_array(&target, "compileGroups") {
+            uses_cxx |= json_str(group, "language") == "CXX";
+
+            let fragments: Vec<String> = json_array(group, "compileCommandFragments")
+                .iter()
+                .flat_map(|f| split_command_line(json_str(f, "fragment")))
+                .collect();
+            let mut group_flags = Flags::parse(&fragments);
+            for include in json_array(group, "includes") {
+                let path = Path::new(json_str(include, "path"));
+                push_unique(
+                    &mut group_flags.include_dirs,
+                    project_relative(project_dir, path),
+                );
+            }
+            for define in json_array(group, "defines") {
+                let define = json_str(define, "define");
+                let (name, value) = define.split_once('=').unwrap_or((define, ""));
+                group_flags
+                    .defines
+                    .insert(name.to_string(), value.to_string());
+            }
+            flags.merge(group_flags);
+        }
+
+        if kind == TargetKind::SharedLib {
+            strip_shared_lib_flags(&name, &mut flags);
+        }
+
+        let mut link_dirs = Vec::new();
+        let mut libs = Vec::new();
+        for fragment in target
+            .pointer("/link/commandFragments")
+            .and_then(|v| v.as_array())
+            .map(|a| a.as_slice())
+            .unwrap_or(&[])
+        {
+            let text = json_str(fragment, "fragment");
+            match json_str(fragment, "role") {
+                "libraries" => {
+                    for token in split_command_line(text) {
+                        if let Some(lib) = token.strip_prefix("-l") {
+                            push_unique(&mut libs, lib.to_string());
+                        } else if let Some((lib, lib_kind)) = library_name(&token) {
+                            // 같은 프로젝트의 정적 라이브러리는 cbuild가 자동으로 링크
+                            let is_internal = !Path::new(&token).is_absolute();
+                            if lib_kind == TargetKind::SharedLib || !is_internal {
+                                push_unique(&mut libs, lib);
+                            }
+                        } else if !token.starts_with("-Wl,-rpath") {
+                            project.notes.push(tr!(
+                                "{}의 링크 항목 {}",
+                                "link item of {}: {}",
+                                name,
+                                token
+                            ));
+                        }
+                    }
+                }
+                "libraryPath" => {
+                    for token in split_command_line(text) {
+                        if let Some(dir) = token.strip_prefix("-L") {
+                            push_unique(&mut link_dirs, dir.to_string());
+                        }
+                    }
+                }
+                "flags" if !text.trim().is_empty() => project.notes.push(tr!(
+                    "{}의 링크 플래그 {}",
+                    "link flags of {}: {}",
+                    name,
+                    text.trim()
+                )),
+                _ => {}
+            }
+        }
+
+        if src.is_empty() {
+            continue;
+        }
+
+        project.targets.push(MigratedTarget {
+            kind,
+            name,
+            src,
+            include_dirs: flags.include_dirs.clone(),
+            defines: flags.defines.clone(),
+            link_dirs,
+            libs,
+        });
+        project.compile_flags.merge(Flags {
+            include_dirs: Vec::new(),
+            defines: BTreeMap::new(),
+            ..flags
+        });
+    }
+
+    project.compiler = Some(if uses_cxx { "g++" } else { "gcc" }.to_string());
+    Ok(project)
+}
+
+/// CMakeCache.txt에 기록된 컴파일러 (실행 파일 이름만 사용)
+fn cmake_cache_compiler(build_dir: &Path, fallback: Option<String>) -> Option<String> {
+    let uses_cxx = fallback.as_deref().is_some_and(|c| c.contains("++"));
+    let key = if uses_cxx {
+        "CMAKE_CXX_COMPILER:"
+    } else {
+        "CMAKE_C_COMPILER:"
+    };
+
+    std::fs::read_to_string(build_dir.join("CMakeCache.txt"))
+        .ok()
+        .and_then(|cache| {
+            cache
+                .lines()
+                .find(|line| line.starts_with(key))
+                .and_then(|line| line.split_once('='))
+                .and_then(|(_, path)| Path::new(path.trim()).file_name())
+                .map(|name| name.to_string_lossy().to_string())
+        })
+        .or(fallback)
+}
+
+/// compile_commands.json에서 타겟 추출
+///
+/// 오브젝트 경로(`CMakeFiles/<타겟>.dir/...`)로 타겟을 나누고, CMake가 공유 라이브러리에
+/// 정의하는 `<타겟>_EXPORTS` 매크로로 공유 라이브러리를 구분한다. 링크 정보는 없다.
+fn read_compile_commands(project_dir: &Path, path: &Path) -> BuildResult<MigratedProject> {
+    lazy_static! {
+        static ref TARGET_DIR_RE: Regex = Regex::new(r"CMakeFiles/([^/]+)\.dir/").unwrap();
+    }
+
+    let entries = read_json(path)?;
+    let entries = entries.as_array().map(|a| a.as_slice()).unwrap_or(&[]);
+
+    let mut project = MigratedProject {
+        name: project_name(project_dir),
+        ..Default::default()
+    };
+    project.notes.push(tr!(
+        "compile_commands.json에는 링크 정보가 없어 libs와 타겟 종류를 확인해야 합니다",
+        "compile_commands.json has no link information; check libs and target kinds"
+    ));
+
+    let mut targets: BTreeMap<String, (Vec<String>, Flags)> = BTreeMap::new();
+    let mut compiler = None;
+
+    for entry in entries {
+        let directory = PathBuf::from(json_str(entry, "directory"));
+        let tokens: Vec<String> = match entry.get("arguments").and_then(|v| v.as_array()) {
+            Some(arguments) => arguments
+                .iter()
+                .filter_map(|a| a.as_str().map(|s| s.to_string()))
+                .collect(),
+            None => split_command_line(json_str(entry, "command")),
+        };
+        let Some((program, args)) = tokens.split_first() else {
+            continue;
+        };
+        compiler.get_or_insert_with(|| {
+            Path::new(program)
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| program.clone())
+        });
+
+        let file = directory.join(json_str(entry, "file"));
+        let output = entry
+            .get("output")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .or_else(|| {
+                args.iter()
+                    .position(|a| a == "-o")
+                    .and_then(|i| args.get(i + 1).cloned())
+            })
+            .unwrap_or_default();
+        let target = TARGET_DIR_RE
+            .captures(&output)
+            .map(|cap| cap[1].to_string())
+            .unwrap_or_else(|| project.name.clone());
+
+        let mut flags = Flags::parse(&compile_only_args(args, &file, &directory));
+        flags.include_dirs = flags
+            .include_dirs
+            .iter()
+            .map(|dir| project_relative(project_dir, &directory.join(dir)))
+            .collect();
+
+        let (src, target_flags) = targets.entry(target).or_default();
+        push_unique(src, project_relative(project_dir, &file));
+        target_flags.merge(flags);
+    }
+
+    for (name, (src, mut flags)) in targets {
+        let kind = if flags.defines.contains_key(&format!("{}_EXPORTS", name)) {
+            TargetKind::SharedLib
+        } else {
+            TargetKind::Executable
+        };
+        if kind == TargetKind::SharedLib {
+            strip_shared_lib_flags(&name, &mut flags);
+        }
+
+        project.targets.push(MigratedTarget {
+            kind,
+            name,
+            src,
+            include_dirs: flags.include_dirs.clone(),
+            defines: flags.defines.clone(),
+            link_dirs: Vec::new(),
+            libs: Vec::new(),
+        });
+        project.compile_flags.merge(Flags {
+            include_dirs: Vec::new(),
+            defines: BTreeMap::new(),
+            ..flags
+        });
+    }
+
+    project.compiler = compiler.map(|c| if c == "cc" { "gcc".to_string() } else { c });
+    Ok(project)
+}
+
+/// CMake가 공유 라이브러리에 자동으로 붙이는 플래그 제거 (cbuild도 -fPIC를 붙인다)
+fn strip_shared_lib_flags(name: &str, flags: &mut Flags) {
+    flags.defines.remove(&format!("{}_EXPORTS", name));
+    flags.extra.retain(|flag| flag != "-fPIC");
+}
+
+/// 컴파일 명령에서 입력·출력 파일과 의존성 파일 생성 옵션 제거
+fn compile_only_args(args: &[String], file: &Path, directory: &Path) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-o" | "-MF" | "-MT" | "-MQ" => {
+                iter.next();
+            }
+            "-c" | "-MD" | "-MMD" | "-MP" | "--" => {}
+            _ if directory.join(arg) == file => {}
+            _ => result.push(arg.clone()),
+        }
+    }
+    result
+}
+
+/// 프로젝트 안의 경로는 프로젝트 기준 상대 경로로 변환
+fn project_relative(project_dir: &Path, path: &Path) -> String {
+    let project_dir = project_dir
+        .canonicalize()
+        .unwrap_or_else(|_| project_dir.to_path_buf());
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    path.strip_prefix(&project_dir)
+        .unwrap_or(&path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+fn project_name(project_dir: &Path) -> String {
+    project_dir
+        .canonicalize()
+        .ok()
+        .and_then(|dir| dir.file_name().map(|n| n.to_string_lossy().to_string()))
+        .unwrap_or_else(|| "project".to_string())
+}
+
+/// POSIX 셸 규칙으로 명령줄을 인자로 분리
+///
+/// 작은따옴표 안은 그대로, 큰따옴표 안에서는 `\`가 `$`, `` ` ``, `"`, `\` 앞에서만
+/// 이스케이프로 동작한다 (예: `-DVERSION=\"1.0\"` -> `-DVERSION="1.0"`).
+fn split_command_line(command: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_arg = false;
+    let mut quote: Option<char> = None;
+    let mut chars = command.chars();
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                in_arg = true;
+            }
+            (None, '\\') => {
+                if let Some(next) = chars.next() {
+                    current.push(next);
+                }
+                in_arg = true;
+            }
+            (Some('"'), '\\') => match chars.clone().next() {
+                Some(next @ ('$' | '`' | '"' | '\\')) => {
+                    current.push(next);
+                    chars.next();
+                }
+                _ => current.push('\\'),
+            },
+            (None, c) if c.is_whitespace() => {
+                if in_arg {
+                    args.push(std::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            (_, c) => {
+                current.push(c);
+                in_arg = true;
+            }
+        }
+    }
+
+    if in_arg {
+        args.push(current);
+    }
+    args
+}
+
+/// 변환 결과를 cbuild.toml 형식으로 출력
+fn render_config(project: &MigratedProject, origin: &str) -> String {
+    let flags = &project.compile_flags;
+    let mut out = String::new();
+
+    out.push_str(&tr!(
+        "# {}에서 생성한 설정 (cbuild migrate)\n",
+        "# Generated from {} by cbuild migrate\n",
+        origin
+    ));
+    if !project.notes.is_empty() {
+        out.push_str(&tr!("# 변환하지 못한 항목:\n", "# Not translated:\n"));
+        for note in &project.notes {
+            out.push_str(&format!("#   - {}\n", note));
+        }
+    }
+
+    out.push_str("\n[project]\n");
+    out.push_str(&format!("name = {}\n", quote(&project.name)));
+    out.push_str("version = \"0.1.0\"\n");
+
+    out.push_str("\n[build]\n");
+    out.push_str(&format!(
+        "compiler = {}\n",
+        quote(project.compiler.as_deref().unwrap_or("gcc"))
+    ));
+    if let Some(ref standard) = flags.c_standard {
+        out.push_str(&format!("c_standard = {}\n", quote(standard)));
+    }
+    if let Some(ref standard) = flags.cpp_standard {
+        out.push_str(&format!("cpp_standard = {}\n", quote(standard)));
+    }
+    if let Some(level) = flags.optimization_level {
+        out.push_str(&format!("optimization_level = {}\n", level));
+    }
+    out.push_str(&format!("debug_info = {}\n", flags.debug_info));
+    out.push_str(&format!(
+        "warnings_as_errors = {}\n",
+        flags.warnings_as_errors
+    ));
+    if !flags.extra.is_empty() {
+        out.push_str(&format!("extra_flags = {}\n", quote_list(&flags.extra)));
+    }
+
+    out.push_str("\n[targets]\n");
+    for target in &project.targets {
+        out.push_str(&format!("\n[[targets.{}]]\n", target.kind.section()));
+        out.push_str(&format!("name = {}\n", quote(&target.name)));
+        out.push_str(&format!("src = {}\n", quote_list(&target.src)));
+        if !target.include_dirs.is_empty() {
+            out.push_str(&format!(
+                "include_dirs = {}\n",
+                quote_list(&target.include_dirs)
+            ));
+        }
+        if !target.defines.is_empty() {
+            let defines: Vec<String> = target
+                .defines
+                .iter()
+                .map(|(name, value)| format!("{} = {}", quote(name), quote(value)))
+                .collect();
+            out.push_str(&format!("defines = {{ {} }}\n", defines.join(", ")));
+        }
+        // 라이브러리 타겟에는 링크 설정이 없다
+        if target.kind == TargetKind::Executable {
+            if !target.link_dirs.is_empty() {
+                out.push_str(&format!("link_dirs = {}\n", quote_list(&target.link_dirs)));
+            }
+            if !target.libs.is_empty() {
+                out.push_str(&format!("libs = {}\n", quote_list(&target.libs)));
+            }
+        }
+    }
+
+    out
+}
+
+fn quote(value: &str) -> String {
+    toml::Value::String(value.to_string()).to_string()
+}
+
+fn quote_list(values: &[String]) -> String {
+    let quoted: Vec<String> = values.iter().map(|v| quote(v)).collect();
+    format!("[{}]", quoted.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_command_line_follows_posix_quoting() {
+        assert_eq!(
+            split_command_line(r#"cc -DVERSION=\"1.0\" -DNAME='"a b"' -I"inc dir" a\ b.c"#),
+            [
+                "cc",
+                r#"-DVERSION="1.0""#,
+                r#"-DNAME="a b""#,
+                "-Iinc dir",
+                "a b.c"
+            ]
+        );
+        // 큰따옴표 안의 `\`는 `"`, `\`, `$`, `` ` `` 앞에서만 이스케이프
+        assert_eq!(
+            split_command_line(r#""a\"b" "c\d" "e\\f" 'g\h'"#),
+            [r#"a"b"#, r"c\d", r"e\f", r"g\h"]
+        );
+        assert_eq!(split_command_line("  '' x  "), ["", "x"]);
+    }
+
+    #[test]
+    fn makefile_flags_are_shell_unquoted() {
+        let makefile =
+            Makefile::parse("CFLAGS = -O2 -DVERSION=\\\"1.0\\\" -DNAME='\"a b\"' -I\"inc dir\"\n");
+        let flags = Flags::parse(&makefile.expand_args("$(CFLAGS)"));
+        assert_eq!(
+            flags.defines.get("VERSION").map(String::as_str),
+            Some("\"1.0\"")
+        );
+        assert_eq!(
+            flags.defines.get("NAME").map(String::as_str),
+            Some("\"a b\"")
+        );
+        assert_eq!(flags.include_dirs, ["inc dir"]);
+        assert_eq!(flags.optimization_level, Some(2));
+    }
+}
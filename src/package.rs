@@ -0,0 +1,432 @@
+use crate::builder::Builder;
+use crate::config::{BuildConfig, ExecutableTarget};
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use crate::install::distribution_files;
+use crate::signing;
+use crate::utils::{
+    copy_file_with_dirs, is_tool_installed, make_executable, to_absolute_path, write_if_changed,
+};
+use colored::Colorize;
+use log::warn;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// `cbuild package` 번들 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackageFormat {
+    /// AppDir을 만들고 appimagetool이 있으면 .AppImage로 묶음
+    AppImage,
+    /// 번들 디렉토리와 flatpak-builder 매니페스트 생성
+    Flatpak,
+}
+
+impl std::str::FromStr for PackageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "appimage" => Ok(PackageFormat::AppImage),
+            "flatpak" => Ok(PackageFormat::Flatpak),
+            _ => Err(format!("appimage, flatpak 중 하나여야 합니다: {}", s)),
+        }
+    }
+}
+
+/// `cbuild package`: 실행 파일, 내부 공유 라이브러리, 선언된 에셋을 재배치 가능한
+/// Linux 번들로 묶음
+///
+/// 번들은 `usr/bin`(실행 파일과 에셋), `usr/lib`(공유 라이브러리) 구조이며, 실행 파일의
+/// rpath는 patchelf로 `$ORIGIN/../lib`로 바꾼다. patchelf가 없으면 AppRun이 설정하는
+/// `LD_LIBRARY_PATH`에 의존한다.
+pub fn package(
+    project_dir: &Path,
+    configuration: &str,
+    out_dir: Option<PathBuf>,
+    format: PackageFormat,
+) -> BuildResult<()> {
+    if !cfg!(target_os = "linux") {
+        return Err(BuildError::ConfigParsingError(tr!(
+            "AppImage/flatpak 번들은 Linux에서만 만들 수 있습니다",
+            "AppImage/flatpak bundles can only be created on Linux"
+        )));
+    }
+
+    let mut builder = Builder::new(project_dir, configuration, num_cpus::get())?;
+    builder.set_output_dir(out_dir);
+    builder.build()?;
+
+    let config = builder.config();
+    let layout = builder.layout();
+    let exe = bundle_executable(config)?;
+    let exe_path = layout.executable_path(&exe.name);
+    if !exe_path.exists() {
+        return Err(BuildError::ExecutableNotFound(exe_path));
+    }
+
+    let package_dir = layout.build_dir().join("package");
+    let bundle_dir = match format {
+        PackageFormat::AppImage => package_dir.join(format!("{}.AppDir", config.project.name)),
+        PackageFormat::Flatpak => package_dir.join(&config.project.name),
+    };
+    if bundle_dir.exists() {
+        std::fs::remove_dir_all(&bundle_dir).map_err(BuildError::IoError)?;
+    }
+
+    println!(
+        "{} {} -> {}",
+        "Packaging".green().bold(),
+        exe.name,
+        bundle_dir.display()
+    );
+    let exe_file = stage_bundle(&builder, project_dir, config, exe, &exe_path, &bundle_dir)?;
+
+    match format {
+        PackageFormat::AppImage => {
+            write_appimage_files(config, project_dir, &exe_file, &bundle_dir)?;
+            build_appimage(config, configuration, &bundle_dir, &package_dir)?;
+        }
+        PackageFormat::Flatpak => {
+            let manifest =
+                write_flatpak_manifest(config, project_dir, &exe_file, &bundle_dir, &package_dir)?;
+            println!(
+                "{} {}",
+                tr!("flatpak 매니페스트 생성:", "Created flatpak manifest:").green(),
+                manifest.display()
+            );
+            println!(
+                "  {}",
+                tr!(
+                    "빌드: flatpak-builder --user --install build-dir {}",
+                    "build with: flatpak-builder --user --install build-dir {}",
+                    manifest.display()
+                )
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// 번들에 넣을 실행 파일 (`[package] executable` > `[run] default` > 프로젝트 이름 > 첫 번째)
+fn bundle_executable(config: &BuildConfig) -> BuildResult<&ExecutableTarget> {
+    let executables = &config.targets.executable;
+    match config
+        .package
+        .executable
+        .as_deref()
+        .or(config.run.default.as_deref())
+    {
+        Some(name) => executables
+            .iter()
+            .find(|exe| exe.name == name)
+            .ok_or_else(|| BuildError::TargetNotFound(format!("exe:{}", name))),
+        None => executables
+            .iter()
+            .find(|exe| exe.name == config.project.name)
+            .or_else(|| executables.iter().find(|exe| !exe.example))
+            .ok_or_else(|| BuildError::TargetNotFound("exe:*".to_string())),
+    }
+}
+
+/// 실행 파일, 링크한 내부 공유 라이브러리, 에셋, `[install]`의 문서와 자동 완성을
+/// `usr/` 아래에 복사하고
+/// 번들 안 실행 파일 이름을 반환
+fn stage_bundle(
+    builder: &Builder,
+    project_dir: &Path,
+    config: &BuildConfig,
+    exe: &ExecutableTarget,
+    exe_path: &Path,
+    bundle_dir: &Path,
+) -> BuildResult<String> {
+    let layout = builder.layout();
+    let bin_dir = bundle_dir.join("usr/bin");
+    let lib_dir = bundle_dir.join("usr/lib");
+
+    let exe_file = layout.executable_file_name(&exe.name);
+    let bundled_exe = bin_dir.join(&exe_file);
+    copy_file_with_dirs(exe_path, &bundled_exe).map_err(BuildError::IoError)?;
+
+    let linked = exe.libs.clone().unwrap_or_default();
+    let mut lib_count = 0;
+    for lib in &config.targets.shared_lib {
+        if !linked.contains(&lib.name) {
+            continue;
+        }
+        let lib_path = layout.shared_lib_path(&lib.name);
+        copy_file_with_dirs(
+            &lib_path,
+            &lib_dir.join(layout.shared_lib_file_name(&lib.name)),
+        )
+        .map_err(BuildError::IoError)?;
+        lib_count += 1;
+    }
+
+    let mut asset_count = 0;
+    for (src, dst) in builder.asset_files(exe, &bin_dir)? {
+        copy_file_with_dirs(&src, &dst).map_err(BuildError::IoError)?;
+        asset_count += 1;
+    }
+
+    // [install]의 man 페이지와 자동 완성은 usr/share 아래에 설치 경로 그대로
+    for (src, relative) in distribution_files(config, layout, project_dir)? {
+        copy_file_with_dirs(&src, &bundle_dir.join("usr").join(relative))
+            .map_err(BuildError::IoError)?;
+    }
+
+    println!(
+        "  {}",
+        tr!(
+            "실행 파일 1개, 공유 라이브러리 {}개, 에셋 {}개",
+            "1 executable, {} shared libraries, {} assets",
+            lib_count,
+            asset_count
+        )
+    );
+
+    if lib_count > 0 {
+        set_bundle_rpath(&bundled_exe)?;
+    }
+
+    Ok(exe_file)
+}
+
+/// 번들 안 실행 파일이 `usr/lib`의 라이브러리를 찾도록 rpath 재설정
+fn set_bundle_rpath(exe: &Path) -> BuildResult<()> {
+    if !is_tool_installed("patchelf") {
+        warn!(
+            "{}",
+            tr!(
+                "patchelf가 없어 rpath를 바꾸지 않았습니다 (AppRun의 LD_LIBRARY_PATH 사용)",
+                "patchelf not found; rpath left unchanged (AppRun sets LD_LIBRARY_PATH)"
+            )
+        );
+        return Ok(());
+    }
+
+    let output = Command::new("patchelf")
+        .arg("--set-rpath")
+        .arg("$ORIGIN/../lib")
+        .arg(exe)
+        .output()
+        .map_err(BuildError::IoError)?;
+    if !output.status.success() {
+        return Err(BuildError::ToolchainError(tr!(
+            "rpath 설정 실패: {}",
+            "failed to set rpath: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(())
+}
+
+fn app_id(config: &BuildConfig) -> String {
+    config
+        .package
+        .app_id
+        .clone()
+        .unwrap_or_else(|| format!("io.cbuild.{}", config.project.name))
+}
+
+/// 데스크톱 항목 (AppImage와 flatpak 공통)
+fn desktop_entry(config: &BuildConfig, exe_file: &str, icon: &str) -> String {
+    let categories = config
+        .package
+        .categories
+        .clone()
+        .unwrap_or_else(|| vec!["Utility".to_string()]);
+    let mut entry = format!(
+        "[Desktop Entry]\nType=Application\nName={}\nExec={}\nIcon={}\nCategories={};\nTerminal={}\n",
+        config.project.name,
+        exe_file,
+        icon,
+        categories.join(";"),
+        config.package.terminal.unwrap_or(true)
+    );
+    if let Some(ref description) = config.project.description {
+        entry.push_str(&format!("Comment={}\n", description));
+    }
+    entry
+}
+
+/// 설정한 아이콘을 복사하거나 기본 아이콘을 생성하고 확장자를 포함한 파일 이름 반환
+fn write_icon(
+    config: &BuildConfig,
+    project_dir: &Path,
+    dir: &Path,
+    name: &str,
+) -> BuildResult<String> {
+    if let Some(ref icon) = config.package.icon {
+        let src = to_absolute_path(Path::new(icon), project_dir);
+        let extension = src
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_string())
+            .unwrap_or_else(|| "png".to_string());
+        let file_name = format!("{}.{}", name, extension);
+        copy_file_with_dirs(&src, &dir.join(&file_name)).map_err(BuildError::IoError)?;
+        return Ok(file_name);
+    }
+
+    let file_name = format!("{}.svg", name);
+    let letter = config
+        .project
+        .name
+        .chars()
+        .next()
+        .map(|c| c.to_ascii_uppercase())
+        .unwrap_or('C');
+    let svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"256\" height=\"256\">\
+         <rect width=\"256\" height=\"256\" rx=\"32\" fill=\"#3b6ea5\"/>\
+         <text x=\"128\" y=\"170\" font-size=\"140\" text-anchor=\"middle\" \
+         font-family=\"sans-serif\" fill=\"#ffffff\">{}</text></svg>\n",
+        letter
+    );
+    write_if_changed(&dir.join(&file_name), &svg).map_err(BuildError::IoError)?;
+    Ok(file_name)
+}
+
+/// AppDir 최상위의 AppRun, .desktop, 아이콘
+fn write_appimage_files(
+    config: &BuildConfig,
+    project_dir: &Path,
+    exe_file: &str,
+    bundle_dir: &Path,
+) -> BuildResult<()> {
+    let name = &config.project.name;
+    write_icon(config, project_dir, bundle_dir, name)?;
+    write_if_changed(
+        &bundle_dir.join(format!("{}.desktop", name)),
+        &desktop_entry(config, exe_file, name),
+    )
+    .map_err(BuildError::IoError)?;
+
+    let app_run = bundle_dir.join("AppRun");
+    let script = format!(
+        "#!/bin/sh\n\
+         HERE=\"$(dirname \"$(readlink -f \"$0\")\")\"\n\
+         export LD_LIBRARY_PATH=\"$HERE/usr/lib${{LD_LIBRARY_PATH:+:$LD_LIBRARY_PATH}}\"\n\
+         exec \"$HERE/usr/bin/{}\" \"$@\"\n",
+        exe_file
+    );
+    write_if_changed(&app_run, &script).map_err(BuildError::IoError)?;
+    make_executable(&app_run).map_err(BuildError::IoError)
+}
+
+/// appimagetool로 AppDir을 .AppImage 파일로 묶음 (없으면 AppDir만 남김)
+fn build_appimage(
+    config: &BuildConfig,
+    configuration: &str,
+    bundle_dir: &Path,
+    package_dir: &Path,
+) -> BuildResult<()> {
+    if !is_tool_installed("appimagetool") {
+        println!(
+            "{} {}",
+            tr!("AppDir 생성:", "Created AppDir:").green(),
+            bundle_dir.display()
+        );
+        println!(
+            "  {}",
+            tr!(
+                "appimagetool이 없어 .AppImage 파일은 만들지 않았습니다 (https://appimage.github.io/appimagetool/)",
+                "appimagetool not found; skipped creating the .AppImage file (https://appimage.github.io/appimagetool/)"
+            )
+        );
+        return Ok(());
+    }
+
+    let arch = std::env::consts::ARCH;
+    let image = package_dir.join(format!(
+        "{}-{}-{}.AppImage",
+        config.project.name, config.project.version, arch
+    ));
+    let output = Command::new("appimagetool")
+        .env("ARCH", arch)
+        .arg(bundle_dir)
+        .arg(&image)
+        .output()
+        .map_err(BuildError::IoError)?;
+    if !output.status.success() {
+        return Err(BuildError::ToolchainError(tr!(
+            "appimagetool 실패: {}",
+            "appimagetool failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    println!(
+        "{} {}",
+        tr!("AppImage 생성:", "Created AppImage:").green(),
+        image.display()
+    );
+
+    if signing::is_enabled(config, configuration) {
+        if let Some(signature) = signing::sign_archive(&config.signing, &image)? {
+            println!(
+                "{} {}",
+                tr!("서명 생성:", "Created signature:").green(),
+                signature.display()
+            );
+        }
+    }
+    Ok(())
+}
+
+/// 번들 디렉토리를 `/app`에 설치하는 flatpak-builder 매니페스트
+fn write_flatpak_manifest(
+    config: &BuildConfig,
+    project_dir: &Path,
+    exe_file: &str,
+    bundle_dir: &Path,
+    package_dir: &Path,
+) -> BuildResult<PathBuf> {
+    let app_id = app_id(config);
+
+    let share_dir = bundle_dir.join("usr/share");
+    let icon_dir = share_dir.join("icons/hicolor/scalable/apps");
+    let icon = write_icon(config, project_dir, &icon_dir, &app_id)?;
+    if !icon.ends_with(".svg") {
+        // scalable 디렉토리에는 svg만 둘 수 있으므로 비트맵 아이콘은 256x256으로 이동
+        let sized = share_dir.join("icons/hicolor/256x256/apps");
+        std::fs::create_dir_all(&sized).map_err(BuildError::IoError)?;
+        std::fs::rename(icon_dir.join(&icon), sized.join(&icon)).map_err(BuildError::IoError)?;
+    }
+    write_if_changed(
+        &share_dir
+            .join("applications")
+            .join(format!("{}.desktop", app_id)),
+        &desktop_entry(config, exe_file, &app_id),
+    )
+    .map_err(BuildError::IoError)?;
+
+    let mut finish_args = vec!["--share=ipc"];
+    if !config.package.terminal.unwrap_or(true) {
+        finish_args.extend(["--socket=fallback-x11", "--socket=wayland"]);
+    }
+
+    let manifest = serde_json::json!({
+        "app-id": app_id,
+        "runtime": "org.freedesktop.Platform",
+        "runtime-version": config.package.runtime_version.as_deref().unwrap_or("23.08"),
+        "sdk": "org.freedesktop.Sdk",
+        "command": exe_file,
+        "finish-args": finish_args,
+        "modules": [{
+            "name": config.project.name,
+            "buildsystem": "simple",
+            "build-commands": ["cp -r usr/. /app/"],
+            "sources": [{
+                "type": "dir",
+                "path": bundle_dir.file_name().map(|n| n.to_string_lossy().to_string()),
+            }],
+        }],
+    });
+
+    let path = package_dir.join(format!("{}.json", app_id));
+    let content = serde_json::to_string_pretty(&manifest)
+        .map_err(|e| BuildError::ConfigParsingError(e.to_string()))?;
+    write_if_changed(&path, &format!("{}\n", content)).map_err(BuildError::IoError)?;
+    Ok(path)
+}
@@ -1,14 +1,17 @@
 use crate::error::{BuildError, BuildResult};
+use crate::utils::canonical_path;
 use lazy_static::lazy_static;
 use log::{debug, warn};
 use regex::Regex;
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
-use walkdir::WalkDir;
+use std::sync::Mutex;
 
 pub struct DependencyParser {
     source_extensions: HashSet<String>,
     header_extensions: HashSet<String>,
+    /// 의존성 탐색 중 발견한 순환 포함 (a.h -> b.h -> a.h)
+    cycles: Mutex<Vec<Vec<PathBuf>>>,
 }
 
 impl DependencyParser {
@@ -18,6 +21,8 @@ impl DependencyParser {
         source_extensions.insert("cpp".to_string());
         source_extensions.insert("cxx".to_string());
         source_extensions.insert("cc".to_string());
+        source_extensions.insert("m".to_string());
+        source_extensions.insert("mm".to_string());
 
         let mut header_extensions = HashSet::new();
         header_extensions.insert("h".to_string());
@@ -28,42 +33,128 @@ impl DependencyParser {
         DependencyParser {
             source_extensions,
             header_extensions,
+            cycles: Mutex::new(Vec::new()),
         }
     }
 
+    /// 파일이 직접·간접적으로 포함하는 모든 프로젝트 헤더
+    ///
+    /// 각 헤더는 한 번만 탐색하며, 탐색 중인 헤더를 다시 포함하면 순환으로
+    /// 기록하고 더 들어가지 않는다. 기록된 순환은 `cycles()`로 확인한다.
     pub fn parse_dependencies(
         &self,
         source_file: &Path,
         include_dirs: &[PathBuf],
     ) -> BuildResult<HashSet<PathBuf>> {
         let mut dependencies = HashSet::new();
-        let content = std::fs::read_to_string(source_file).map_err(|e| BuildError::IoError(e))?;
+        let mut stack = vec![canonical_path(source_file)];
+        let mut visited: HashSet<PathBuf> = stack.iter().cloned().collect();
 
-        // 직접 포함되는 헤더 파일 추출
-        let direct_includes = self.extract_includes(&content);
+        self.visit(
+            source_file,
+            include_dirs,
+            &mut stack,
+            &mut visited,
+            &mut dependencies,
+        )?;
 
-        // 헤더 파일 경로 해결 및 재귀적 의존성 검색
-        for include in direct_includes {
-            if let Some(header_path) = self.resolve_header_path(&include, source_file, include_dirs)
-            {
-                dependencies.insert(header_path.clone());
+        Ok(dependencies)
+    }
+
+    fn visit(
+        &self,
+        file: &Path,
+        include_dirs: &[PathBuf],
+        stack: &mut Vec<PathBuf>,
+        visited: &mut HashSet<PathBuf>,
+        dependencies: &mut HashSet<PathBuf>,
+    ) -> BuildResult<()> {
+        for header_path in self.direct_dependencies(file, include_dirs)? {
+            let key = canonical_path(&header_path);
 
-                // 간접 의존성 추가 (재귀적)
-                let indirect_deps = self.parse_dependencies(&header_path, include_dirs)?;
-                dependencies.extend(indirect_deps);
+            // 현재 탐색 경로에 있는 헤더를 다시 포함하면 순환
+            if let Some(position) = stack.iter().position(|p| *p == key) {
+                let mut cycle = stack[position..].to_vec();
+                cycle.push(key);
+                self.record_cycle(cycle);
+                continue;
+            }
+
+            dependencies.insert(header_path.clone());
+
+            if visited.insert(key.clone()) {
+                stack.push(key);
+                self.visit(&header_path, include_dirs, stack, visited, dependencies)?;
+                stack.pop();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 파일이 직접 포함하는 프로젝트 헤더 (경로 순 정렬)
+    pub fn direct_dependencies(
+        &self,
+        file: &Path,
+        include_dirs: &[PathBuf],
+    ) -> BuildResult<Vec<PathBuf>> {
+        let content = std::fs::read_to_string(file).map_err(BuildError::IoError)?;
+        let mut headers = Vec::new();
+
+        for include in self.extract_includes(&content) {
+            if let Some(header_path) = self.resolve_header_path(&include, file, include_dirs) {
+                headers.push(header_path);
             } else {
                 // 표준 라이브러리 헤더일 가능성이 있음
                 if !is_likely_standard_header(&include) {
                     warn!(
                         "Could not resolve header: {} included from {}",
                         include,
-                        source_file.display()
+                        file.display()
                     );
                 }
             }
         }
 
-        Ok(dependencies)
+        headers.sort();
+        Ok(headers)
+    }
+
+    /// 지금까지 발견한 순환 포함 목록 (각 순환은 시작 헤더로 끝남)
+    pub fn cycles(&self) -> Vec<Vec<PathBuf>> {
+        let mut cycles = self.cycles.lock().unwrap().clone();
+        cycles.sort();
+        cycles
+    }
+
+    /// 같은 순환을 다른 헤더에서 시작해 발견해도 한 번만 기록
+    fn record_cycle(&self, mut cycle: Vec<PathBuf>) {
+        // 가장 작은 경로에서 시작하도록 회전 (마지막 원소는 시작 헤더의 반복)
+        cycle.pop();
+        if let Some(start) = cycle
+            .iter()
+            .enumerate()
+            .min_by(|a, b| a.1.cmp(b.1))
+            .map(|(i, _)| i)
+        {
+            cycle.rotate_left(start);
+        }
+        if let Some(first) = cycle.first().cloned() {
+            cycle.push(first);
+        }
+
+        let mut cycles = self.cycles.lock().unwrap();
+        if !cycles.contains(&cycle) {
+            debug!(
+                "순환 포함 발견: {}",
+                cycle
+                    .iter()
+                    .map(|p| p.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> ")
+            );
+            cycles.push(cycle);
+        }
     }
 
     fn extract_includes(&self, content: &str) -> HashSet<String> {
@@ -85,7 +176,8 @@ impl DependencyParser {
         includes
     }
 
-    fn resolve_header_path(
+    /// 포함 이름을 포함한 파일 기준 상대 경로 또는 포함 디렉토리에서 찾음
+    pub fn resolve_header_path(
         &self,
         include_name: &str,
         source_file: &Path,
@@ -253,3 +345,54 @@ fn is_likely_standard_header(header_name: &str) -> bool {
 
     C_STD_HEADERS.contains(&header_name) || CPP_STD_HEADERS.contains(&header_name)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn reports_two_header_cycle_once() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = canonical_path(tmp.path());
+        fs::write(dir.join("main.c"), "#include \"a.h\"\n").unwrap();
+        fs::write(dir.join("other.c"), "#include \"b.h\"\n").unwrap();
+        fs::write(dir.join("a.h"), "#pragma once\n#include \"b.h\"\n").unwrap();
+        fs::write(dir.join("b.h"), "#pragma once\n#include \"a.h\"\n").unwrap();
+
+        let parser = DependencyParser::new();
+        let dependencies = parser.parse_dependencies(&dir.join("main.c"), &[]).unwrap();
+        assert_eq!(
+            dependencies,
+            HashSet::from([dir.join("a.h"), dir.join("b.h")])
+        );
+
+        // 같은 순환을 b.h에서 시작해 다시 발견해도 한 번만 기록
+        parser
+            .parse_dependencies(&dir.join("other.c"), &[])
+            .unwrap();
+        assert_eq!(
+            parser.cycles(),
+            vec![vec![dir.join("a.h"), dir.join("b.h"), dir.join("a.h")]]
+        );
+    }
+
+    #[test]
+    fn reports_self_include() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = canonical_path(tmp.path());
+        fs::create_dir(dir.join("include")).unwrap();
+        fs::write(dir.join("main.c"), "#include <self.h>\n").unwrap();
+        fs::write(dir.join("include/self.h"), "#include \"self.h\"\n").unwrap();
+
+        let parser = DependencyParser::new();
+        let dependencies = parser
+            .parse_dependencies(&dir.join("main.c"), &[dir.join("include")])
+            .unwrap();
+        assert_eq!(dependencies, HashSet::from([dir.join("include/self.h")]));
+        assert_eq!(
+            parser.cycles(),
+            vec![vec![dir.join("include/self.h"), dir.join("include/self.h")]]
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use crate::config::PchSettings;
+use crate::i18n::tr;
+use crate::toolchain::{CompilerFamily, Toolchain};
+use crate::utils::{get_extension, tool_command};
+use colored::Colorize;
+use log::warn;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::sync::Mutex;
+
+/// 미리 컴파일한 헤더를 만들 헤더 파일 이름 (`-include`로 소스 앞에 포함)
+const WRAPPER_NAME: &str = "cbuild_pch";
+
+/// [pch] 헤더를 플래그 조합마다 한 번만 미리 컴파일해 타겟 사이에 공유하는 캐시
+///
+/// PCH는 `<출력 디렉토리>/.pch/<지문>/`에 만들며, 지문에는 컴파일러 경로와 버전,
+/// 컴파일 플래그, 헤더 목록이 들어가므로 이 중 하나가 바뀌면 새로 만든다.
+/// 만들 수 없으면 경고만 하고 PCH 없이 컴파일한다.
+pub struct PchCache {
+    dir: PathBuf,
+    settings: PchSettings,
+    compiler: String,
+    family: CompilerFamily,
+    /// 컴파일러 경로와 버전 (지문에 포함)
+    compiler_id: String,
+    /// 지문별로 만든 PCH의 컴파일 인자 (만들지 못했으면 None)
+    entries: Mutex<HashMap<String, Option<Vec<OsString>>>>,
+}
+
+impl PchCache {
+    /// [pch] 헤더가 설정되어 있고 GCC나 Clang일 때만 캐시 생성
+    pub fn new(
+        settings: &PchSettings,
+        compiler: &str,
+        toolchain: &Toolchain,
+        dir: PathBuf,
+    ) -> Option<Self> {
+        let configured = settings
+            .headers
+            .iter()
+            .chain(&settings.cpp_headers)
+            .flatten()
+            .count()
+            > 0;
+        let family = toolchain.family();
+        if !configured || family == CompilerFamily::Unknown {
+            return None;
+        }
+
+        Some(PchCache {
+            dir,
+            settings: settings.clone(),
+            compiler: compiler.to_string(),
+            family,
+            compiler_id: format!(
+                "{}\u{1f}{}",
+                toolchain.path().display(),
+                toolchain.version_line()
+            ),
+            entries: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// `source`를 `flags`로 컴파일할 때 PCH를 쓰는 인자 (필요하면 이때 PCH 생성)
+    ///
+    /// `fingerprint_flags`는 색상 진단처럼 결과에 영향이 없는 플래그를 뺀 지문용 플래그이고,
+    /// `run`은 컴파일러를 실행하는 함수(컨테이너 빌드 등)이다.
+    pub fn include_args(
+        &self,
+        source: &Path,
+        flags: &[OsString],
+        fingerprint_flags: &str,
+        run: impl Fn(&mut Command) -> std::io::Result<Output>,
+    ) -> Vec<OsString> {
+        let cpp = !matches!(get_extension(source).as_deref(), Some("c"));
+        let headers: Vec<&String> = if cpp {
+            self.settings
+                .headers
+                .iter()
+                .chain(&self.settings.cpp_headers)
+                .flatten()
+                .collect()
+        } else {
+            self.settings.headers.iter().flatten().collect()
+        };
+        if headers.is_empty() {
+            return Vec::new();
+        }
+
+        let mut hasher = Sha256::new();
+        hasher.update(self.compiler_id.as_bytes());
+        hasher.update([u8::from(cpp)]);
+        hasher.update(fingerprint_flags.as_bytes());
+        for header in &headers {
+            hasher.update(b"\x1f");
+            hasher.update(header.as_bytes());
+        }
+        let key = format!("{:x}", hasher.finalize());
+
+        // 같은 PCH를 여러 작업이 동시에 만들지 않도록 만드는 동안 잠금 유지
+        let mut entries = self.entries.lock().unwrap();
+        if let Some(args) = entries.get(&key) {
+            return args.clone().unwrap_or_default();
+        }
+
+        let args = match self.precompile(&key, cpp, &headers, flags, run) {
+            Ok(args) => Some(args),
+            Err(e) => {
+                warn!(
+                    "{}",
+                    tr!(
+                        "PCH를 만들지 못해 PCH 없이 컴파일합니다: {}",
+                        "could not build the PCH, compiling without it: {}",
+                        e
+                    )
+                );
+                None
+            }
+        };
+        entries.insert(key, args.clone());
+        args.unwrap_or_default()
+    }
+
+    fn precompile(
+        &self,
+        key: &str,
+        cpp: bool,
+        headers: &[&String],
+        flags: &[OsString],
+        run: impl Fn(&mut Command) -> std::io::Result<Output>,
+    ) -> Result<Vec<OsString>, String> {
+        let dir = self.dir.join(&key[..16]);
+        // C++ 헤더는 .hpp로 만들어 드라이버가 소스와 같은 언어로 컴파일하게 함
+        let wrapper = dir.join(format!(
+            "{}.{}",
+            WRAPPER_NAME,
+            if cpp { "hpp" } else { "h" }
+        ));
+        let output = match self.family {
+            CompilerFamily::Gcc => {
+                let mut name = wrapper.clone().into_os_string();
+                name.push(".gch");
+                PathBuf::from(name)
+            }
+            _ => wrapper.with_extension("pch"),
+        };
+        let args: Vec<OsString> = match self.family {
+            // GCC는 -include한 헤더 옆의 .gch를 찾고, 맞지 않으면 헤더를 그대로 포함
+            CompilerFamily::Gcc => vec!["-include".into(), wrapper.clone().into()],
+            _ => vec!["-include-pch".into(), output.clone().into()],
+        };
+        if output.exists() {
+            return Ok(args);
+        }
+
+        let content: String = headers
+            .iter()
+            .map(|header| format!("#include <{}>\n", header))
+            .collect();
+        std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+        std::fs::write(&wrapper, content).map_err(|e| e.to_string())?;
+
+        println!(
+            "{} {} ({})",
+            "Precompiling".green().bold(),
+            headers
+                .iter()
+                .map(|header| header.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            &key[..16]
+        );
+
+        // 중간에 실패해도 깨진 PCH가 남지 않도록 임시 파일에 만든 뒤 이름 변경
+        let partial = output.with_extension("tmp");
+        let mut cmd = tool_command(&self.compiler);
+        cmd.args(flags).arg(&wrapper).arg("-o").arg(&partial);
+        let result = run(&mut cmd).map_err(|e| e.to_string())?;
+        if !result.status.success() {
+            let _ = std::fs::remove_file(&partial);
+            return Err(String::from_utf8_lossy(&result.stderr).trim().to_string());
+        }
+        std::fs::rename(&partial, &output).map_err(|e| e.to_string())?;
+
+        Ok(args)
+    }
+}
@@ -0,0 +1,241 @@
+use crate::builder::{Builder, ExtraFlags};
+use crate::config::BuildConfig;
+use crate::context::ProjectContext;
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use crate::runner::Runner;
+use crate::toolchain::{CompilerFamily, Toolchain};
+use crate::tools::{RequiredTool, ToolKind};
+use crate::utils::{
+    hash_file_content, is_tool_installed, path_flag, split_tool_command, tool_command,
+};
+use colored::Colorize;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use walkdir::WalkDir;
+
+/// `cbuild pgo`: 프로파일 기반 최적화 빌드
+///
+/// 1. `-fprofile-generate`로 계측 빌드
+/// 2. `[pgo] train` 명령(없으면 테스트 스위트)을 실행해 프로파일 수집
+/// 3. Clang 계열이면 llvm-profdata로 병합
+/// 4. `-fprofile-use`로 다시 빌드
+///
+/// 두 빌드는 같은 출력 디렉토리를 쓰며, 플래그와 프로파일 데이터가 증분 빌드 지문에
+/// 반영되므로 단계가 바뀌면 모든 소스를 다시 컴파일한다.
+pub fn pgo(
+    context: Arc<ProjectContext>,
+    configuration: &str,
+    out_dir: Option<PathBuf>,
+    jobs: usize,
+) -> BuildResult<()> {
+    let config = context.config()?;
+    let layout = context.layout(configuration, out_dir.as_deref());
+
+    let toolchain = Toolchain::probe(&config.build.compiler, &layout.toolchain_cache_path())?;
+    let family = toolchain.family();
+    if family == CompilerFamily::Unknown {
+        return Err(BuildError::ToolchainError(tr!(
+            "컴파일러 {}에서 PGO를 사용할 수 없습니다 (GCC 또는 Clang 필요)",
+            "PGO is not supported with compiler {} (GCC or Clang required)",
+            config.build.compiler
+        )));
+    }
+
+    // 이전 학습 결과는 새 계측 빌드와 맞지 않으므로 삭제
+    let profile_dir = layout.build_dir().join("pgo");
+    let raw_dir = profile_dir.join("raw");
+    if raw_dir.exists() {
+        std::fs::remove_dir_all(&raw_dir).map_err(BuildError::IoError)?;
+    }
+    std::fs::create_dir_all(&raw_dir).map_err(BuildError::IoError)?;
+
+    print_phase(1, tr!("계측 빌드", "instrumented build"));
+    let generate = path_flag("-fprofile-generate=", &raw_dir);
+    build(
+        &context,
+        configuration,
+        &out_dir,
+        jobs,
+        ExtraFlags {
+            compile: vec![generate.clone()],
+            link: vec![generate],
+            fingerprint: String::new(),
+        },
+    )?;
+
+    print_phase(2, tr!("학습 실행", "training run"));
+    train(&context, config, configuration, &out_dir)?;
+
+    let extension = match family {
+        CompilerFamily::Gcc => "gcda",
+        _ => "profraw",
+    };
+    let profiles = collect_profiles(&raw_dir, extension);
+    if profiles.is_empty() {
+        return Err(BuildError::ToolchainError(tr!(
+            "학습 실행에서 프로파일 데이터가 생성되지 않았습니다: {}",
+            "the training run produced no profile data: {}",
+            raw_dir.display()
+        )));
+    }
+
+    let (compile, fingerprint) = match family {
+        CompilerFamily::Gcc => (
+            vec![
+                path_flag("-fprofile-use=", &raw_dir),
+                "-fprofile-correction".into(),
+                "-Wno-missing-profile".into(),
+            ],
+            profiles_fingerprint(&profiles)?,
+        ),
+        _ => {
+            let merged = profile_dir.join("default.profdata");
+            merge_profiles(config, &profiles, &merged)?;
+            (
+                vec![
+                    path_flag("-fprofile-use=", &merged),
+                    "-Wno-profile-instr-unprofiled".into(),
+                    "-Wno-profile-instr-out-of-date".into(),
+                ],
+                hash_file_content(&merged).map_err(BuildError::IoError)?,
+            )
+        }
+    };
+
+    print_phase(3, tr!("최적화 빌드", "optimized build"));
+    build(
+        &context,
+        configuration,
+        &out_dir,
+        jobs,
+        ExtraFlags {
+            compile,
+            link: Vec::new(),
+            fingerprint,
+        },
+    )?;
+
+    println!(
+        "{} {}",
+        tr!("PGO 빌드 완료", "PGO build finished").green().bold(),
+        tr!("(프로파일 {}개)", "({} profiles)", profiles.len())
+    );
+    Ok(())
+}
+
+fn print_phase(step: usize, name: String) {
+    println!("{} {}/3 {}", "PGO".green().bold(), step, name.bold());
+}
+
+fn build(
+    context: &Arc<ProjectContext>,
+    configuration: &str,
+    out_dir: &Option<PathBuf>,
+    jobs: usize,
+    extra_flags: ExtraFlags,
+) -> BuildResult<()> {
+    let mut builder = Builder::with_context(context.clone(), configuration, jobs)?;
+    builder.set_output_dir(out_dir.clone());
+    builder.set_extra_flags(extra_flags);
+    builder.build()
+}
+
+/// 계측된 결과물로 학습 명령 또는 테스트 스위트 실행
+fn train(
+    context: &Arc<ProjectContext>,
+    config: &BuildConfig,
+    configuration: &str,
+    out_dir: &Option<PathBuf>,
+) -> BuildResult<()> {
+    let mut runner = Runner::with_context(context.clone());
+    runner.set_configuration(configuration);
+    runner.set_output_dir(out_dir.clone());
+
+    match config.pgo.train {
+        Some(ref command) => {
+            let code = runner.exec(command)?;
+            if code != 0 {
+                return Err(BuildError::TestFailure(tr!(
+                    "학습 명령이 실패했습니다 (코드: {})",
+                    "the training command failed (code: {})",
+                    code
+                )));
+            }
+            Ok(())
+        }
+        None => runner.run_tests(),
+    }
+}
+
+fn collect_profiles(raw_dir: &Path, extension: &str) -> Vec<PathBuf> {
+    let mut profiles: Vec<PathBuf> = WalkDir::new(raw_dir)
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.into_path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == extension))
+        .collect();
+    profiles.sort();
+    profiles
+}
+
+/// GCC 프로파일(.gcda) 전체의 지문
+fn profiles_fingerprint(profiles: &[PathBuf]) -> BuildResult<String> {
+    let mut fingerprint = String::new();
+    for profile in profiles {
+        fingerprint.push_str(&hash_file_content(profile).map_err(BuildError::IoError)?);
+    }
+    Ok(fingerprint)
+}
+
+/// Clang 원시 프로파일(.profraw)을 llvm-profdata로 병합
+fn merge_profiles(config: &BuildConfig, profiles: &[PathBuf], merged: &Path) -> BuildResult<()> {
+    let tool = profdata_tool(config);
+    if !is_tool_installed(&tool) {
+        return Err(BuildError::ToolsNotFound(vec![RequiredTool {
+            program: tool,
+            kind: ToolKind::ProfileMerger,
+        }]));
+    }
+
+    let output = tool_command(&tool)
+        .arg("merge")
+        .arg(path_flag("-output=", merged))
+        .args(profiles)
+        .output()
+        .map_err(BuildError::IoError)?;
+    if !output.status.success() {
+        return Err(BuildError::ToolchainError(tr!(
+            "프로파일 병합 실패: {}",
+            "profile merge failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(())
+}
+
+/// 컴파일러에 맞는 llvm-profdata (clang-17 -> llvm-profdata-17, Apple clang -> xcrun)
+fn profdata_tool(config: &BuildConfig) -> String {
+    if let Some(ref tool) = config.pgo.profdata {
+        return tool.clone();
+    }
+    if cfg!(target_os = "macos") {
+        return "xcrun llvm-profdata".to_string();
+    }
+
+    let program = Path::new(split_tool_command(&config.build.compiler).0)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    if let Some(version) = program
+        .strip_prefix("clang-")
+        .or_else(|| program.strip_prefix("clang++-"))
+    {
+        let versioned = format!("llvm-profdata-{}", version);
+        if is_tool_installed(&versioned) {
+            return versioned;
+        }
+    }
+    "llvm-profdata".to_string()
+}
@@ -0,0 +1,196 @@
+use crate::i18n::tr;
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+
+/// 컴파일 진행 상황 표시 방식 (`--progress`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProgressMode {
+    /// 타겟별·전체 진행 표시줄 (터미널이 아니면 표시하지 않음)
+    #[default]
+    Fancy,
+    /// 파일 하나가 끝날 때마다 한 줄씩 출력 (CI 로그, 단순한 터미널)
+    Plain,
+    None,
+}
+
+impl std::str::FromStr for ProgressMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fancy" => Ok(ProgressMode::Fancy),
+            "plain" => Ok(ProgressMode::Plain),
+            "none" => Ok(ProgressMode::None),
+            _ => Err(format!("fancy, plain 또는 none이어야 합니다: {}", s)),
+        }
+    }
+}
+
+/// 타겟별 진행 표시줄과 남은 작업 수
+struct TargetProgress {
+    name: String,
+    files: usize,
+    bar: OnceLock<ProgressBar>,
+    remaining: AtomicUsize,
+}
+
+/// 여러 작업자가 함께 갱신하는 컴파일 진행 상황
+///
+/// 전체 ETA, 대기/실행/완료 작업 수, 증분 빌드에서 다시 컴파일하지 않은 파일의 비율
+/// (캐시 적중률)을 표시한다. Fancy에서는 타겟별 ETA도 표시한다.
+pub struct CompileProgress<'a> {
+    mode: ProgressMode,
+    multi: MultiProgress,
+    style: ProgressStyle,
+    /// 전체 진행률 (Plain에서는 숨긴 채 ETA 계산에만 사용)
+    total: ProgressBar,
+    targets: HashMap<&'a str, TargetProgress>,
+    started: AtomicUsize,
+    completed: AtomicUsize,
+    cache_rate: Option<usize>,
+}
+
+impl<'a> CompileProgress<'a> {
+    /// `targets`는 (타겟 키, 컴파일할 파일 수), `cached`는 최신 상태라 건너뛴 파일 수
+    pub fn new(
+        mode: ProgressMode,
+        targets: impl Iterator<Item = (&'a str, usize)>,
+        cached: usize,
+    ) -> Self {
+        let targets: HashMap<&str, TargetProgress> = targets
+            .map(|(key, files)| {
+                let name = key.split_once(':').map_or(key, |(_, name)| name);
+                (
+                    key,
+                    TargetProgress {
+                        name: name.to_string(),
+                        files,
+                        bar: OnceLock::new(),
+                        remaining: AtomicUsize::new(files),
+                    },
+                )
+            })
+            .collect();
+        let jobs: usize = targets.values().map(|t| t.files).sum();
+
+        let multi = MultiProgress::new();
+        let style = ProgressStyle::default_bar()
+            .template("{prefix:.bold.dim} [{bar:40}] {pos}/{len} {eta:>4} {msg}")
+            .unwrap()
+            .progress_chars("=> ");
+
+        let total = match mode {
+            ProgressMode::Fancy => multi.add(ProgressBar::new(jobs as u64)),
+            _ => ProgressBar::hidden(),
+        };
+        total.set_length(jobs as u64);
+        total.set_style(style.clone());
+        total.set_prefix(tr!("[전체]", "[total]"));
+
+        let progress = CompileProgress {
+            mode,
+            multi,
+            style,
+            total,
+            targets,
+            started: AtomicUsize::new(0),
+            completed: AtomicUsize::new(0),
+            cache_rate: (cached > 0).then(|| cached * 100 / (cached + jobs)),
+        };
+        progress.update_summary();
+        progress
+    }
+
+    /// 작업 시작 (타겟의 첫 작업이면 진행 표시줄 생성)
+    pub fn start(&self, target_key: &str, source_file: &Path) {
+        self.started.fetch_add(1, Ordering::Relaxed);
+
+        if self.mode == ProgressMode::Fancy {
+            let target = &self.targets[target_key];
+            let bar = target.bar.get_or_init(|| {
+                let bar = self
+                    .multi
+                    .insert_before(&self.total, ProgressBar::new(target.files as u64));
+                bar.set_style(self.style.clone());
+                bar.set_prefix(format!("[{}]", target.name));
+                bar
+            });
+            bar.set_message(file_name(source_file));
+            self.update_summary();
+        }
+    }
+
+    /// 작업 완료 (타겟의 마지막 작업이면 진행 표시줄 제거)
+    pub fn finish(&self, target_key: &str, source_file: &Path) {
+        let completed = self.completed.fetch_add(1, Ordering::Relaxed) + 1;
+        self.total.inc(1);
+
+        let target = &self.targets[target_key];
+        let last = target.remaining.fetch_sub(1, Ordering::Relaxed) == 1;
+        if let Some(bar) = target.bar.get() {
+            bar.inc(1);
+            if last {
+                bar.finish_and_clear();
+                self.multi.remove(bar);
+            }
+        }
+
+        match self.mode {
+            ProgressMode::Fancy => self.update_summary(),
+            ProgressMode::Plain => {
+                let width = self.total.length().unwrap_or(0).to_string().len();
+                println!(
+                    "[{:>width$}/{}] {} {}  ({})",
+                    completed,
+                    self.total.length().unwrap_or(0),
+                    target.name,
+                    file_name(source_file),
+                    self.summary(),
+                    width = width
+                );
+            }
+            ProgressMode::None => {}
+        }
+    }
+
+    /// 모든 진행 표시줄 제거
+    pub fn clear(&self) {
+        self.total.finish_and_clear();
+    }
+
+    fn update_summary(&self) {
+        if self.mode == ProgressMode::Fancy {
+            self.total.set_message(self.summary());
+        }
+    }
+
+    /// "대기 3 · 실행 2 · 완료 5 · 캐시 40% · ETA 3s"
+    fn summary(&self) -> String {
+        let jobs = self.total.length().unwrap_or(0) as usize;
+        let started = self.started.load(Ordering::Relaxed);
+        let completed = self.completed.load(Ordering::Relaxed);
+
+        let mut parts = vec![
+            tr!("대기 {}", "queued {}", jobs.saturating_sub(started)),
+            tr!("실행 {}", "running {}", started.saturating_sub(completed)),
+            tr!("완료 {}", "done {}", completed),
+        ];
+        if let Some(rate) = self.cache_rate {
+            parts.push(tr!("캐시 {}%", "cache {}%", rate));
+        }
+        if self.mode == ProgressMode::Plain && completed < jobs {
+            parts.push(format!("ETA {}s", self.total.eta().as_secs_f32().ceil()));
+        }
+        parts.join(" · ")
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string()
+}
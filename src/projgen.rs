@@ -0,0 +1,367 @@
+use crate::builder::{Builder, TargetCompileInfo};
+use crate::config::BuildConfig;
+use crate::error::{BuildError, BuildResult};
+use crate::ide::{cli_name, defines, include_dirs};
+use crate::layout::BuildLayout;
+use crate::utils::{write_if_changed, xml_escape};
+use sha2::{Digest, Sha256};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+/// IDE 프로젝트 생성 대상
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdeKind {
+    Xcode,
+    VisualStudio,
+}
+
+impl std::str::FromStr for IdeKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "xcode" => Ok(IdeKind::Xcode),
+            "vs" => Ok(IdeKind::VisualStudio),
+            _ => Err(format!("xcode 또는 vs여야 합니다: {}", s)),
+        }
+    }
+}
+
+/// 생성된 프로젝트가 cbuild에 넘겨줄 빌드 구성
+const CONFIGURATIONS: [&str; 2] = ["debug", "release"];
+
+/// `cbuild gen --ide`: cbuild 타겟을 감싸는 IDE 프로젝트 생성
+///
+/// 생성된 프로젝트는 소스 탐색과 코드 분석을 위한 포함 디렉토리/매크로 정의만
+/// 담고, 실제 빌드는 외부 빌드 명령으로 cbuild를 호출한다.
+pub fn generate_project(
+    project_dir: &Path,
+    ide: IdeKind,
+    out_dir: Option<PathBuf>,
+) -> BuildResult<()> {
+    let mut builder = Builder::new(project_dir, "debug", 1)?;
+    builder.set_output_dir(out_dir.clone());
+    let targets = builder.compile_info()?;
+    let config = builder.config().clone();
+
+    let context = ProjectContext {
+        project_dir,
+        config: &config,
+        out_dir: out_dir.as_deref(),
+        targets: &targets,
+    };
+
+    match ide {
+        IdeKind::Xcode => generate_xcode(&context),
+        IdeKind::VisualStudio => generate_visual_studio(&context),
+    }
+}
+
+struct ProjectContext<'a> {
+    project_dir: &'a Path,
+    config: &'a BuildConfig,
+    out_dir: Option<&'a Path>,
+    targets: &'a [TargetCompileInfo],
+}
+
+impl ProjectContext<'_> {
+    fn layout(&self, configuration: &str) -> BuildLayout {
+        BuildLayout::new(
+            self.project_dir,
+            configuration,
+            self.out_dir,
+            Some(self.config),
+        )
+    }
+
+    fn relative(&self, path: &Path) -> String {
+        path.strip_prefix(self.project_dir)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .to_string()
+    }
+
+    /// 타겟 결과물 경로 (실행 파일, 라이브러리, 테스트)
+    fn artifact(&self, target: &TargetCompileInfo, configuration: &str) -> PathBuf {
+        let layout = self.layout(configuration);
+        match target.target_type.as_str() {
+            "static" => layout.static_lib_path(&target.name),
+            "shared" => layout.shared_lib_path(&target.name),
+            "test" => layout.test_path(&target.name),
+            _ => layout.executable_path(&target.name),
+        }
+    }
+}
+
+fn generate_xcode(context: &ProjectContext) -> BuildResult<()> {
+    let project_name = &context.config.project.name;
+    let bundle_dir = context
+        .project_dir
+        .join(format!("{}.xcodeproj", project_name));
+    std::fs::create_dir_all(&bundle_dir).map_err(BuildError::IoError)?;
+
+    let cli = cli_name();
+    let root_id = object_id(&["project", project_name]);
+    let main_group_id = object_id(&["group", project_name]);
+    let project_configs_id = object_id(&["configlist", "project"]);
+
+    let mut file_refs = String::new();
+    let mut targets = String::new();
+    let mut configs = String::new();
+    let mut config_lists = String::new();
+    let mut group_children = Vec::new();
+    let mut target_ids = Vec::new();
+
+    let mut sources: Vec<&PathBuf> = context.targets.iter().flat_map(|t| &t.sources).collect();
+    sources.sort();
+    sources.dedup();
+    for source in sources {
+        let path = context.relative(source);
+        let id = object_id(&["file", &path]);
+        let _ = writeln!(
+            file_refs,
+            "\t\t{} = {{isa = PBXFileReference; lastKnownFileType = {}; path = {}; sourceTree = SOURCE_ROOT; }};",
+            id,
+            xcode_file_type(source),
+            pbx_string(&path)
+        );
+        group_children.push(id);
+    }
+
+    for target in context.targets {
+        let key = format!("{}:{}", target.target_type, target.name);
+        let target_id = object_id(&["target", &key]);
+        let list_id = object_id(&["configlist", &key]);
+        target_ids.push(target_id.clone());
+
+        let header_paths: Vec<String> = include_dirs(target)
+            .iter()
+            .map(|dir| pbx_string(&dir.to_string_lossy()))
+            .collect();
+        let definitions: Vec<String> = defines(target).iter().map(|d| pbx_string(d)).collect();
+
+        let mut config_ids = Vec::new();
+        for configuration in CONFIGURATIONS {
+            let config_id = object_id(&["config", &key, configuration]);
+            let _ = writeln!(
+                configs,
+                "\t\t{} = {{isa = XCBuildConfiguration; buildSettings = {{ HEADER_SEARCH_PATHS = ({}); GCC_PREPROCESSOR_DEFINITIONS = ({}); PRODUCT_NAME = {}; }}; name = {}; }};",
+                config_id,
+                header_paths.join(", "),
+                definitions.join(", "),
+                pbx_string(&target.name),
+                configuration
+            );
+            config_ids.push(config_id);
+        }
+        let _ = writeln!(
+            config_lists,
+            "\t\t{} = {{isa = XCConfigurationList; buildConfigurations = ({}); defaultConfigurationName = debug; }};",
+            list_id,
+            config_ids.join(", ")
+        );
+
+        let _ = writeln!(
+            targets,
+            "\t\t{} = {{isa = PBXLegacyTarget; buildArgumentsString = \"build -c $(CONFIGURATION)\"; buildConfigurationList = {}; buildPhases = (); buildToolPath = {}; buildWorkingDirectory = {}; dependencies = (); name = {}; passBuildSettingsInEnvironment = 0; productName = {}; }};",
+            target_id,
+            list_id,
+            pbx_string(&cli),
+            pbx_string(&context.project_dir.to_string_lossy()),
+            pbx_string(&key.replace(':', "_")),
+            pbx_string(&target.name)
+        );
+    }
+
+    let mut project_config_ids = Vec::new();
+    for configuration in CONFIGURATIONS {
+        let config_id = object_id(&["config", "project", configuration]);
+        let _ = writeln!(
+            configs,
+            "\t\t{} = {{isa = XCBuildConfiguration; buildSettings = {{ }}; name = {}; }};",
+            config_id, configuration
+        );
+        project_config_ids.push(config_id);
+    }
+    let _ = writeln!(
+        config_lists,
+        "\t\t{} = {{isa = XCConfigurationList; buildConfigurations = ({}); defaultConfigurationName = debug; }};",
+        project_configs_id,
+        project_config_ids.join(", ")
+    );
+
+    let mut content = String::new();
+    content.push_str("// !$*UTF8*$!\n{\n\tarchiveVersion = 1;\n\tclasses = {\n\t};\n\tobjectVersion = 46;\n\tobjects = {\n");
+    content.push_str(&file_refs);
+    let _ = writeln!(
+        content,
+        "\t\t{} = {{isa = PBXGroup; children = ({}); sourceTree = \"<group>\"; }};",
+        main_group_id,
+        group_children.join(", ")
+    );
+    content.push_str(&targets);
+    let _ = writeln!(
+        content,
+        "\t\t{} = {{isa = PBXProject; buildConfigurationList = {}; compatibilityVersion = \"Xcode 3.2\"; mainGroup = {}; projectDirPath = \"\"; projectRoot = \"\"; targets = ({}); }};",
+        root_id,
+        project_configs_id,
+        main_group_id,
+        target_ids.join(", ")
+    );
+    content.push_str(&configs);
+    content.push_str(&config_lists);
+    let _ = write!(content, "\t}};\n\trootObject = {};\n}}\n", root_id);
+
+    let pbxproj = bundle_dir.join("project.pbxproj");
+    write_if_changed(&pbxproj, &content).map_err(BuildError::IoError)?;
+    println!("생성됨: {}", bundle_dir.display());
+
+    Ok(())
+}
+
+fn generate_visual_studio(context: &ProjectContext) -> BuildResult<()> {
+    let project_name = &context.config.project.name;
+    let cli = cli_name();
+    let mut projects = Vec::new();
+
+    for target in context.targets {
+        let key = format!("{}:{}", target.target_type, target.name);
+        let file_name = format!("{}.vcxproj", key.replace(':', "_"));
+        let guid = guid(&["vcxproj", &key]);
+
+        let include_path = include_dirs(target)
+            .iter()
+            .map(|dir| dir.to_string_lossy().to_string())
+            .collect::<Vec<_>>()
+            .join(";");
+        let definitions = defines(target).join(";");
+
+        let mut content = String::new();
+        content.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+        content.push_str("<Project DefaultTargets=\"Build\" xmlns=\"http://schemas.microsoft.com/developer/msbuild/2003\">\n");
+
+        content.push_str("  <ItemGroup Label=\"ProjectConfigurations\">\n");
+        for configuration in CONFIGURATIONS {
+            let _ = writeln!(
+                content,
+                "    <ProjectConfiguration Include=\"{0}|x64\">\n      <Configuration>{0}</Configuration>\n      <Platform>x64</Platform>\n    </ProjectConfiguration>",
+                configuration
+            );
+        }
+        content.push_str("  </ItemGroup>\n");
+
+        let _ = writeln!(
+            content,
+            "  <PropertyGroup Label=\"Globals\">\n    <ProjectGuid>{{{}}}</ProjectGuid>\n    <RootNamespace>{}</RootNamespace>\n  </PropertyGroup>",
+            guid,
+            xml_escape(&target.name)
+        );
+        content
+            .push_str("  <Import Project=\"$(VCTargetsPath)\\Microsoft.Cpp.Default.props\" />\n");
+
+        for configuration in CONFIGURATIONS {
+            let output = context.artifact(target, configuration);
+            let _ = writeln!(
+                content,
+                "  <PropertyGroup Condition=\"'$(Configuration)|$(Platform)'=='{config}|x64'\" Label=\"Configuration\">\n    <ConfigurationType>Makefile</ConfigurationType>\n    <PlatformToolset>v143</PlatformToolset>\n  </PropertyGroup>\n  <PropertyGroup Condition=\"'$(Configuration)|$(Platform)'=='{config}|x64'\">\n    <NMakeBuildCommandLine>{cli} build -c {config} -d \"{dir}\"</NMakeBuildCommandLine>\n    <NMakeReBuildCommandLine>{cli} clean -d \"{dir}\" &amp;&amp; {cli} build -c {config} -d \"{dir}\"</NMakeReBuildCommandLine>\n    <NMakeCleanCommandLine>{cli} clean -d \"{dir}\"</NMakeCleanCommandLine>\n    <NMakeOutput>{output}</NMakeOutput>\n    <NMakeIncludeSearchPath>{include}</NMakeIncludeSearchPath>\n    <NMakePreprocessorDefinitions>{defines}</NMakePreprocessorDefinitions>\n  </PropertyGroup>",
+                config = configuration,
+                cli = xml_escape(&cli),
+                dir = xml_escape(&context.project_dir.to_string_lossy()),
+                output = xml_escape(&output.to_string_lossy()),
+                include = xml_escape(&include_path),
+                defines = xml_escape(&definitions),
+            );
+        }
+
+        content.push_str("  <Import Project=\"$(VCTargetsPath)\\Microsoft.Cpp.props\" />\n");
+        content.push_str("  <ItemGroup>\n");
+        for source in &target.sources {
+            let _ = writeln!(
+                content,
+                "    <ClCompile Include=\"{}\" />",
+                xml_escape(&context.relative(source))
+            );
+        }
+        content.push_str("  </ItemGroup>\n");
+        content.push_str("  <Import Project=\"$(VCTargetsPath)\\Microsoft.Cpp.targets\" />\n");
+        content.push_str("</Project>\n");
+
+        let path = context.project_dir.join(&file_name);
+        write_if_changed(&path, &content).map_err(BuildError::IoError)?;
+        println!("생성됨: {}", path.display());
+
+        projects.push((key.replace(':', "_"), file_name, guid));
+    }
+
+    // 솔루션 파일
+    const VCXPROJ_TYPE: &str = "8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942";
+    let mut sln = String::new();
+    sln.push_str("\u{feff}\r\nMicrosoft Visual Studio Solution File, Format Version 12.00\r\n# Visual Studio Version 17\r\n");
+    for (name, file_name, guid) in &projects {
+        let _ = write!(
+            sln,
+            "Project(\"{{{}}}\") = \"{}\", \"{}\", \"{{{}}}\"\r\nEndProject\r\n",
+            VCXPROJ_TYPE, name, file_name, guid
+        );
+    }
+    sln.push_str("Global\r\n\tGlobalSection(SolutionConfigurationPlatforms) = preSolution\r\n");
+    for configuration in CONFIGURATIONS {
+        let _ = write!(sln, "\t\t{0}|x64 = {0}|x64\r\n", configuration);
+    }
+    sln.push_str(
+        "\tEndGlobalSection\r\n\tGlobalSection(ProjectConfigurationPlatforms) = postSolution\r\n",
+    );
+    for (_, _, guid) in &projects {
+        for configuration in CONFIGURATIONS {
+            let _ = write!(
+                sln,
+                "\t\t{{{0}}}.{1}|x64.ActiveCfg = {1}|x64\r\n\t\t{{{0}}}.{1}|x64.Build.0 = {1}|x64\r\n",
+                guid, configuration
+            );
+        }
+    }
+    sln.push_str("\tEndGlobalSection\r\nEndGlobal\r\n");
+
+    let sln_path = context.project_dir.join(format!("{}.sln", project_name));
+    write_if_changed(&sln_path, &sln).map_err(BuildError::IoError)?;
+    println!("생성됨: {}", sln_path.display());
+
+    Ok(())
+}
+
+/// 재생성해도 바뀌지 않도록 이름에서 파생한 Xcode 객체 ID (24자리 16진수)
+fn object_id(parts: &[&str]) -> String {
+    let digest = Sha256::digest(parts.join("\0").as_bytes());
+    digest[..12].iter().map(|b| format!("{:02X}", b)).collect()
+}
+
+/// 이름에서 파생한 GUID
+fn guid(parts: &[&str]) -> String {
+    let hex: String = Sha256::digest(parts.join("\0").as_bytes())[..16]
+        .iter()
+        .map(|b| format!("{:02X}", b))
+        .collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+fn xcode_file_type(path: &Path) -> &'static str {
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("c") => "sourcecode.c.c",
+        Some("m") => "sourcecode.c.objc",
+        Some("mm") => "sourcecode.cpp.objcpp",
+        Some("h") => "sourcecode.c.h",
+        _ => "sourcecode.cpp.cpp",
+    }
+}
+
+/// pbxproj 문자열 (항상 따옴표로 감쌈)
+fn pbx_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
@@ -0,0 +1,178 @@
+use crate::config::ToolchainSpec;
+use crate::download::{extract, file_name_from_url, single_top_level_dir, Downloader};
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// 설치된 툴체인 (~/.cbuild/toolchains/registry.json에 기록)
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct InstalledToolchain {
+    pub url: String,
+    pub sha256: String,
+    pub root: PathBuf,
+    pub cc: PathBuf,
+    pub cxx: Option<PathBuf>,
+    pub ar: Option<PathBuf>,
+}
+
+impl InstalledToolchain {
+    /// 설정의 컴파일러 대신 사용할 툴체인 컴파일러
+    ///
+    /// build.compiler가 C++ 컴파일러(g++, clang++ 등)이고 툴체인에 cxx가 있으면 cxx를 사용한다.
+    pub fn compiler_for(&self, configured: &str) -> String {
+        let compiler = match self.cxx {
+            Some(ref cxx) if configured.contains("++") => cxx,
+            _ => &self.cc,
+        };
+        compiler.to_string_lossy().to_string()
+    }
+}
+
+/// 툴체인을 설치하는 디렉토리 (CBUILD_HOME/toolchains, 기본: ~/.cbuild/toolchains)
+pub fn toolchains_dir() -> BuildResult<PathBuf> {
+    let home = std::env::var_os("CBUILD_HOME")
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME")
+                .or_else(|| std::env::var_os("USERPROFILE"))
+                .map(|home| PathBuf::from(home).join(".cbuild"))
+        })
+        .ok_or_else(|| {
+            BuildError::PathError(tr!(
+                "홈 디렉토리를 찾을 수 없습니다 (HOME 또는 CBUILD_HOME을 설정하세요)",
+                "cannot find the home directory (set HOME or CBUILD_HOME)"
+            ))
+        })?;
+
+    Ok(home.join("toolchains"))
+}
+
+/// 설치된 툴체인 목록
+pub fn installed() -> BuildResult<BTreeMap<String, InstalledToolchain>> {
+    let registry_path = toolchains_dir()?.join("registry.json");
+    if !registry_path.exists() {
+        return Ok(BTreeMap::new());
+    }
+
+    let content = std::fs::read_to_string(&registry_path).map_err(BuildError::IoError)?;
+    serde_json::from_str(&content).map_err(|e| {
+        BuildError::ToolchainError(tr!(
+            "툴체인 목록 {}을(를) 읽을 수 없습니다: {}",
+            "cannot read toolchain registry {}: {}",
+            registry_path.display(),
+            e
+        ))
+    })
+}
+
+/// 이름으로 설치된 툴체인 찾기 (`--toolchain <name>`)
+pub fn find(name: &str) -> BuildResult<InstalledToolchain> {
+    installed()?
+        .remove(name)
+        .ok_or_else(|| BuildError::ToolchainNotInstalled(name.to_string()))
+}
+
+/// 툴체인을 내려받아 체크섬을 확인하고 설치한 뒤 목록에 등록
+///
+/// 이미 같은 체크섬으로 설치되어 있으면 `force`가 아닌 한 다시 내려받지 않는다.
+pub fn install(
+    name: &str,
+    spec: &ToolchainSpec,
+    force: bool,
+    downloader: &Downloader,
+) -> BuildResult<InstalledToolchain> {
+    let dir = toolchains_dir()?;
+    let mut registry = installed()?;
+
+    if let Some(existing) = registry.get(name) {
+        if !force && existing.sha256.eq_ignore_ascii_case(&spec.sha256) && existing.cc.exists() {
+            println!(
+                "{}",
+                tr!(
+                    "툴체인 {}이(가) 이미 설치되어 있습니다: {}",
+                    "Toolchain {} is already installed: {}",
+                    name,
+                    existing.root.display()
+                )
+                .green()
+            );
+            return Ok(existing.clone());
+        }
+    }
+
+    std::fs::create_dir_all(&dir).map_err(BuildError::IoError)?;
+    let staging = tempfile::Builder::new()
+        .prefix(&format!(".{}-", name))
+        .tempdir_in(&dir)
+        .map_err(BuildError::IoError)?;
+
+    // 다운로드와 체크섬 확인 (중단된 다운로드는 다음 설치 때 이어 받음)
+    let archive = downloader.cache_path(
+        &dir.join(".downloads"),
+        &format!("{}-{}", name, file_name_from_url(&spec.url)),
+    );
+    downloader.fetch(&spec.url, &spec.sha256, &archive)?;
+
+    // 압축 해제
+    println!("{} {}", "Extracting".green().bold(), name);
+    let extract_dir = staging.path().join("contents");
+    std::fs::create_dir_all(&extract_dir).map_err(BuildError::IoError)?;
+    extract(&archive, &extract_dir)?;
+
+    // 최상위 디렉토리 하나로 된 압축 파일은 그 디렉토리를 툴체인 루트로 사용
+    let contents = single_top_level_dir(&extract_dir)?.unwrap_or(extract_dir);
+    let root = dir.join(name);
+    if root.exists() {
+        std::fs::remove_dir_all(&root).map_err(BuildError::IoError)?;
+    }
+    std::fs::rename(&contents, &root).map_err(BuildError::IoError)?;
+    // [download] cache_dir에 둔 압축 파일은 다음 설치를 위해 남김
+    if archive.starts_with(&dir) {
+        let _ = std::fs::remove_file(&archive);
+    }
+
+    let tool_path = |relative: &str| -> BuildResult<PathBuf> {
+        let path = root.join(relative);
+        if path.exists() {
+            Ok(path)
+        } else {
+            Err(BuildError::ToolchainError(tr!(
+                "툴체인 {}에 {}이(가) 없습니다",
+                "toolchain {} does not contain {}",
+                name,
+                relative
+            )))
+        }
+    };
+
+    let toolchain = InstalledToolchain {
+        url: spec.url.clone(),
+        sha256: spec.sha256.to_lowercase(),
+        cc: tool_path(&spec.cc)?,
+        cxx: spec.cxx.as_deref().map(tool_path).transpose()?,
+        ar: spec.ar.as_deref().map(tool_path).transpose()?,
+        root: root.clone(),
+    };
+
+    registry.insert(name.to_string(), toolchain.clone());
+    save_registry(&dir, &registry)?;
+
+    println!(
+        "{} {} ({})",
+        tr!("툴체인 설치 완료:", "Installed toolchain:")
+            .green()
+            .bold(),
+        name,
+        root.display()
+    );
+    Ok(toolchain)
+}
+
+fn save_registry(dir: &Path, registry: &BTreeMap<String, InstalledToolchain>) -> BuildResult<()> {
+    let content = serde_json::to_string_pretty(registry)
+        .map_err(|e| BuildError::ToolchainError(e.to_string()))?;
+    std::fs::write(dir.join("registry.json"), content).map_err(BuildError::IoError)
+}
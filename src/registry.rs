@@ -0,0 +1,217 @@
+use crate::download::Downloader;
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use crate::semver::{Version, VersionReq};
+use serde::Deserialize;
+
+/// 패키지 레지스트리 색인의 패키지 하나
+///
+/// 레지스트리는 정적 파일만으로 운영할 수 있는 HTTP 디렉토리이며, 패키지마다
+/// `<레지스트리>/index/<이름>.json`에 다음 형식의 색인을 둔다.
+///
+/// ```json
+/// {
+///   "name": "zlib",
+///   "versions": [
+///     {
+///       "version": "1.3.1",
+///       "url": "archives/zlib-1.3.1.tar.gz",
+///       "sha256": "9a93b2b7dfdac77ceba5a558a580e74667dd6fede4585b91eefb60f03b72df23",
+///       "description": "compression library",
+///       "license": "Zlib",
+///       "yanked": false
+///     }
+///   ]
+/// }
+/// ```
+///
+/// `url`이 상대 경로면 레지스트리 주소 기준이다. `yanked` 버전은 잠금 파일에
+/// 이미 기록된 경우에만 설치한다.
+#[derive(Debug, Deserialize)]
+pub struct RegistryPackage {
+    pub name: String,
+    #[serde(default)]
+    pub versions: Vec<RegistryRelease>,
+}
+
+/// 레지스트리에 올린 버전 하나
+#[derive(Debug, Deserialize, Clone)]
+pub struct RegistryRelease {
+    pub version: String,
+    /// 압축 파일 URL (절대 URL 또는 레지스트리 기준 상대 경로)
+    pub url: String,
+    pub sha256: String,
+    #[serde(default)]
+    pub yanked: bool,
+    pub description: Option<String>,
+    pub license: Option<String>,
+}
+
+/// 레지스트리에서 패키지 색인 가져오기
+pub fn fetch_package(
+    downloader: &Downloader,
+    registry: &str,
+    name: &str,
+) -> BuildResult<RegistryPackage> {
+    let url = format!("{}/index/{}.json", registry.trim_end_matches('/'), name);
+    let content = downloader.fetch_text(&url)?;
+    let mut package: RegistryPackage = serde_json::from_str(&content).map_err(|e| {
+        BuildError::DependencyError(tr!(
+            "레지스트리 색인 {}을(를) 읽을 수 없습니다: {}",
+            "cannot parse registry index {}: {}",
+            url,
+            e
+        ))
+    })?;
+    if package.name != name {
+        return Err(BuildError::DependencyError(tr!(
+            "레지스트리 색인 {}의 이름이 {}입니다 (예상: {})",
+            "registry index {} is for {} (expected {})",
+            url,
+            package.name,
+            name
+        )));
+    }
+    for release in &mut package.versions {
+        if !release.url.contains("://") {
+            release.url = format!(
+                "{}/{}",
+                registry.trim_end_matches('/'),
+                release.url.trim_start_matches('/')
+            );
+        }
+    }
+    Ok(package)
+}
+
+/// 버전 조건에 맞는 가장 높은 버전 선택
+///
+/// 색인의 버전은 `zlib-1.3.1`, `v1.3.1`처럼 git 태그 형식이어도 된다.
+/// `requirement`가 `1.3.1`처럼 연산자 없는 버전이면 그 버전만 고른다. `locked`는
+/// 잠금 파일에 기록된 버전으로, 조건에 맞으면 더 높은 버전이나 yanked 여부와 관계없이
+/// 그대로 쓴다.
+pub fn select_release(
+    package: &RegistryPackage,
+    requirement: &str,
+    locked: Option<&str>,
+) -> BuildResult<RegistryRelease> {
+    let parsed = if VersionReq::is_requirement(requirement) {
+        VersionReq::parse(requirement)
+    } else {
+        VersionReq::parse(&format!("={}", requirement))
+    };
+    let matches = |release: &RegistryRelease| match (&parsed, Version::from_tag(&release.version)) {
+        (Some(req), Some(version)) => req.matches(&version),
+        _ => release.version == requirement,
+    };
+
+    if let Some(release) = locked.and_then(|locked| {
+        package
+            .versions
+            .iter()
+            .find(|release| release.version == locked && matches(release))
+    }) {
+        return Ok(release.clone());
+    }
+
+    let best = package
+        .versions
+        .iter()
+        .filter(|release| !release.yanked && matches(release))
+        .max_by_key(|release| Version::from_tag(&release.version));
+    match best {
+        Some(release) => Ok(release.clone()),
+        None => {
+            let mut available: Vec<&RegistryRelease> =
+                package.versions.iter().filter(|r| !r.yanked).collect();
+            available.sort_by_key(|release| std::cmp::Reverse(Version::from_tag(&release.version)));
+            let available: Vec<&str> = available
+                .iter()
+                .take(5)
+                .map(|r| r.version.as_str())
+                .collect();
+            Err(BuildError::DependencyError(tr!(
+                "레지스트리의 {} 버전 중 '{}'을(를) 만족하는 것이 없습니다 (최근 버전: {})",
+                "no version of {} in the registry satisfies '{}' (latest versions: {})",
+                package.name,
+                requirement,
+                if available.is_empty() {
+                    "-".to_string()
+                } else {
+                    available.join(", ")
+                }
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn package(versions: &[(&str, bool)]) -> RegistryPackage {
+        RegistryPackage {
+            name: "zlib".to_string(),
+            versions: versions
+                .iter()
+                .map(|&(version, yanked)| RegistryRelease {
+                    version: version.to_string(),
+                    url: format!("archives/zlib-{}.tar.gz", version),
+                    sha256: String::new(),
+                    yanked,
+                    description: None,
+                    license: None,
+                })
+                .collect(),
+        }
+    }
+
+    fn select(package: &RegistryPackage, requirement: &str, locked: Option<&str>) -> String {
+        select_release(package, requirement, locked)
+            .unwrap()
+            .version
+    }
+
+    #[test]
+    fn picks_highest_matching_version() {
+        let zlib = package(&[
+            ("1.2.13", false),
+            ("1.3.1", false),
+            ("1.2.0", false),
+            ("2.0.0", false),
+            ("1.3.2", true),
+        ]);
+        assert_eq!(select(&zlib, "^1.2", None), "1.3.1");
+        assert_eq!(select(&zlib, "~1.2", None), "1.2.13");
+        assert_eq!(select(&zlib, ">=1.2.5, <2", None), "1.3.1");
+        assert_eq!(select(&zlib, "*", None), "2.0.0");
+        assert_eq!(select(&zlib, "1.2.0", None), "1.2.0");
+
+        // yanked 버전은 잠금 파일에 기록된 경우에만
+        assert_eq!(select(&zlib, "^1.2", Some("1.3.2")), "1.3.2");
+        assert_eq!(select(&zlib, "^1.2", Some("1.2.0")), "1.2.0");
+        assert_eq!(select(&zlib, "^1.2", Some("2.0.0")), "1.3.1");
+        assert!(select_release(&zlib, "^3", None).is_err());
+    }
+
+    #[test]
+    fn excludes_pre_releases_unless_requested() {
+        let zlib = package(&[("1.3.1", false), ("1.4.0-rc.1", false)]);
+        assert_eq!(select(&zlib, "^1.3", None), "1.3.1");
+        assert_eq!(select(&zlib, "*", None), "1.3.1");
+        assert_eq!(select(&zlib, "=1.4.0-rc.1", None), "1.4.0-rc.1");
+        assert_eq!(select(&zlib, ">=1.4.0-rc.1", None), "1.4.0-rc.1");
+    }
+
+    #[test]
+    fn matches_prefixed_tags() {
+        let zlib = package(&[
+            ("zlib-1.2.13", false),
+            ("zlib-1.3.1", false),
+            ("v1.3.2-beta", false),
+        ]);
+        assert_eq!(select(&zlib, "^1.2", None), "zlib-1.3.1");
+        assert_eq!(select(&zlib, "1.2.13", None), "zlib-1.2.13");
+        assert_eq!(select(&zlib, "zlib-1.3.1", None), "zlib-1.3.1");
+    }
+}
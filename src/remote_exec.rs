@@ -0,0 +1,454 @@
+use crate::config::BuildConfig;
+use crate::error::{BuildError, BuildResult};
+use crate::executor::{Action, ActionExecutor, LocalExecutor};
+use crate::i18n::tr;
+use crate::retry::RetryPolicy;
+use crate::utils::{
+    collect_files_with_extension, get_common_header_extensions, hash_file_content,
+    is_tool_installed,
+};
+use colored::Colorize;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Output, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+#[derive(Serialize)]
+struct ExecuteRequest<'a> {
+    arguments: Vec<String>,
+    environment: BTreeMap<String, String>,
+    inputs: &'a BTreeMap<String, String>,
+    outputs: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct ExecuteResponse {
+    exit_code: i32,
+    #[serde(default)]
+    stdout: String,
+    #[serde(default)]
+    stderr: String,
+    #[serde(default)]
+    outputs: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize)]
+struct MissingResponse {
+    #[serde(default)]
+    missing: Vec<String>,
+}
+
+/// 출력 파일을 지정한 동작(컴파일)을 원격 실행 서버에서 실행하는 실험적 백엔드
+///
+/// 간단한 HTTP 프로토콜을 curl로 사용한다.
+///
+/// - `POST <endpoint>/cas/missing`: `{"digests": [...]}` → `{"missing": [...]}` 서버에 없는 blob
+/// - `PUT <endpoint>/cas/<sha256>`: 입력 파일 업로드
+/// - `POST <endpoint>/execute`: `{"arguments", "environment", "inputs": {경로: 해시}, "outputs"}`
+///   → `{"exit_code", "stdout", "stderr", "outputs": {경로: 해시}}`
+/// - `GET <endpoint>/cas/<sha256>`: 출력 파일 다운로드
+///
+/// 경로는 모두 프로젝트 기준 상대 경로이며, 서버는 입력을 임시 디렉토리에 배치하고 그
+/// 디렉토리에서 명령을 실행한다. 컴파일러와 시스템 헤더는 서버에 같은 버전이 있어야 한다.
+/// 입력은 명령에 나온 프로젝트 안의 파일, `-I`/`-iquote`/`-isystem` 디렉토리와 소스
+/// 디렉토리의 헤더이다. 서버에 연결할 수 없거나 입력이 프로젝트 밖에 있으면 경고하고
+/// 로컬에서 실행한다.
+pub struct RemoteExecutor {
+    endpoint: String,
+    root: PathBuf,
+    token: Option<String>,
+    proxy: Option<String>,
+    retry: RetryPolicy,
+    /// 서버에 있는 것으로 확인한 blob
+    uploaded: Mutex<HashSet<String>>,
+    /// 이번 빌드에서 계산한 파일 해시
+    digests: Mutex<HashMap<PathBuf, String>>,
+    /// 디렉토리별 헤더 목록
+    headers: Mutex<HashMap<PathBuf, Vec<PathBuf>>>,
+    /// 로컬 대체 경고를 출력했는지
+    warned: AtomicBool,
+}
+
+impl RemoteExecutor {
+    pub fn from_config(config: &BuildConfig, project_dir: &Path) -> BuildResult<Self> {
+        let settings = &config.remote_execution;
+        let Some(ref endpoint) = settings.endpoint else {
+            return Err(BuildError::ConfigParsingError(tr!(
+                "--remote-exec에는 [remote_execution] endpoint가 필요합니다",
+                "--remote-exec requires [remote_execution] endpoint"
+            )));
+        };
+        if !is_tool_installed("curl") {
+            return Err(BuildError::DownloadError(tr!(
+                "원격 실행에 curl이 필요합니다",
+                "curl is required for remote execution"
+            )));
+        }
+
+        Ok(RemoteExecutor {
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            root: project_dir.to_path_buf(),
+            token: settings
+                .token_env
+                .as_ref()
+                .and_then(|name| std::env::var(name).ok()),
+            proxy: config.download.proxy.clone(),
+            retry: RetryPolicy::from_settings(&config.retry),
+            uploaded: Mutex::new(HashSet::new()),
+            digests: Mutex::new(HashMap::new()),
+            headers: Mutex::new(HashMap::new()),
+            warned: AtomicBool::new(false),
+        })
+    }
+
+    /// 서버 주소 (빌드 시작 시 표시)
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    fn execute_remote(&self, command: &Command, outputs: &[PathBuf]) -> Result<Output, String> {
+        if command.get_current_dir().is_some() {
+            return Err("working directory is not supported".to_string());
+        }
+        let output_paths = outputs
+            .iter()
+            .map(|output| self.relative(output))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut arguments = vec![command.get_program().to_string_lossy().to_string()];
+        arguments.extend(
+            command
+                .get_args()
+                .map(|arg| self.map_arg(&arg.to_string_lossy())),
+        );
+        let environment = command
+            .get_envs()
+            .filter_map(|(key, value)| {
+                Some((
+                    key.to_string_lossy().to_string(),
+                    self.map_arg(&value?.to_string_lossy()),
+                ))
+            })
+            .collect();
+
+        let inputs = self.inputs(command, outputs)?;
+        self.upload(&inputs)?;
+
+        let request = ExecuteRequest {
+            arguments,
+            environment,
+            inputs: &inputs.values().cloned().collect(),
+            outputs: output_paths.clone(),
+        };
+        let body = serde_json::to_vec(&request).map_err(|e| e.to_string())?;
+        let response = self.post("execute", &body)?;
+        let response: ExecuteResponse =
+            serde_json::from_slice(&response).map_err(|e| e.to_string())?;
+
+        if response.exit_code == 0 {
+            for (output, relative) in outputs.iter().zip(&output_paths) {
+                let digest = response
+                    .outputs
+                    .get(relative)
+                    .ok_or_else(|| format!("missing output {}", relative))?;
+                self.download(digest, output)?;
+                if output.extension().is_some_and(|ext| ext == "d") {
+                    self.absolutize_depfile(output)?;
+                }
+            }
+        }
+
+        Ok(Output {
+            status: exit_status(response.exit_code),
+            stdout: response.stdout.into_bytes(),
+            stderr: response.stderr.into_bytes(),
+        })
+    }
+
+    /// 동작의 입력 파일 (로컬 경로 → (상대 경로, 해시))
+    fn inputs(
+        &self,
+        command: &Command,
+        outputs: &[PathBuf],
+    ) -> Result<BTreeMap<PathBuf, (String, String)>, String> {
+        let mut files: Vec<PathBuf> = Vec::new();
+        let mut dirs: Vec<PathBuf> = Vec::new();
+
+        let args: Vec<String> = command
+            .get_args()
+            .map(|arg| arg.to_string_lossy().to_string())
+            .collect();
+        let mut index = 0;
+        while index < args.len() {
+            let arg = &args[index];
+            let include_dir = ["-I", "-iquote", "-isystem"]
+                .iter()
+                .find_map(|flag| arg.strip_prefix(flag));
+            match include_dir {
+                Some("") if index + 1 < args.len() => {
+                    dirs.push(PathBuf::from(&args[index + 1]));
+                    index += 1;
+                }
+                Some(dir) if !dir.is_empty() => dirs.push(PathBuf::from(dir)),
+                _ => {
+                    let path = Path::new(arg);
+                    if path.is_absolute() && path.is_file() && !outputs.iter().any(|o| o == path) {
+                        // 따옴표 include는 소스 디렉토리에서 찾으므로 함께 보냄
+                        if let Some(parent) = path.parent() {
+                            dirs.push(parent.to_path_buf());
+                        }
+                        files.push(path.to_path_buf());
+                    }
+                }
+            }
+            index += 1;
+        }
+
+        for dir in dirs.iter().filter(|dir| dir.starts_with(&self.root)) {
+            files.extend(self.headers_in(dir));
+        }
+
+        let mut inputs = BTreeMap::new();
+        for file in files {
+            if inputs.contains_key(&file) {
+                continue;
+            }
+            let relative = self.relative(&file)?;
+            let digest = self.digest(&file)?;
+            inputs.insert(file, (relative, digest));
+        }
+        Ok(inputs)
+    }
+
+    fn headers_in(&self, dir: &Path) -> Vec<PathBuf> {
+        let mut headers = self.headers.lock().unwrap();
+        headers
+            .entry(dir.to_path_buf())
+            .or_insert_with(|| collect_files_with_extension(dir, &get_common_header_extensions()))
+            .clone()
+    }
+
+    fn digest(&self, path: &Path) -> Result<String, String> {
+        if let Some(digest) = self.digests.lock().unwrap().get(path) {
+            return Ok(digest.clone());
+        }
+        let digest = hash_file_content(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        self.digests
+            .lock()
+            .unwrap()
+            .insert(path.to_path_buf(), digest.clone());
+        Ok(digest)
+    }
+
+    /// 서버에 없는 입력 파일 업로드
+    fn upload(&self, inputs: &BTreeMap<PathBuf, (String, String)>) -> Result<(), String> {
+        let unknown: Vec<&String> = {
+            let uploaded = self.uploaded.lock().unwrap();
+            let mut unknown: Vec<&String> = inputs
+                .values()
+                .map(|(_, digest)| digest)
+                .filter(|digest| !uploaded.contains(*digest))
+                .collect();
+            unknown.sort();
+            unknown.dedup();
+            unknown
+        };
+        if unknown.is_empty() {
+            return Ok(());
+        }
+
+        let body = serde_json::json!({ "digests": unknown }).to_string();
+        let response = self.post("cas/missing", body.as_bytes())?;
+        let missing: MissingResponse =
+            serde_json::from_slice(&response).map_err(|e| e.to_string())?;
+        let missing: HashSet<String> = missing.missing.into_iter().collect();
+
+        for (path, (_, digest)) in inputs {
+            if !missing.contains(digest) || self.uploaded.lock().unwrap().contains(digest) {
+                continue;
+            }
+            let mut cmd = self.curl(&format!("cas/{}", digest));
+            cmd.args(["--request", "PUT", "--data-binary"])
+                .arg(format!("@{}", path.display()));
+            self.run(cmd)?;
+            self.uploaded.lock().unwrap().insert(digest.clone());
+        }
+
+        self.uploaded
+            .lock()
+            .unwrap()
+            .extend(unknown.into_iter().cloned());
+        Ok(())
+    }
+
+    fn download(&self, digest: &str, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        let partial = path.with_extension("download");
+        let mut cmd = self.curl(&format!("cas/{}", digest));
+        cmd.arg("--output").arg(&partial);
+        self.run(cmd)?;
+        std::fs::rename(&partial, path).map_err(|e| e.to_string())
+    }
+
+    fn post(&self, path: &str, body: &[u8]) -> Result<Vec<u8>, String> {
+        self.retry.run(&format!("{}/{}", self.endpoint, path), || {
+            let mut cmd = self.curl(path);
+            cmd.args([
+                "--header",
+                "Content-Type: application/json",
+                "--data-binary",
+                "@-",
+            ])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+            let mut child = cmd.spawn().map_err(|e| e.to_string())?;
+            if let Some(mut stdin) = child.stdin.take() {
+                stdin.write_all(body).map_err(|e| e.to_string())?;
+            }
+            let output = child.wait_with_output().map_err(|e| e.to_string())?;
+            if !output.status.success() {
+                return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+            }
+            Ok(output.stdout)
+        })
+    }
+
+    fn run(&self, mut cmd: Command) -> Result<(), String> {
+        let description = format!("{:?}", cmd.get_args().last().unwrap_or_default());
+        self.retry.run(&description, || {
+            let output = cmd.output().map_err(|e| e.to_string())?;
+            if !output.status.success() {
+                return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+            }
+            Ok(())
+        })
+    }
+
+    fn curl(&self, path: &str) -> Command {
+        let mut cmd = Command::new("curl");
+        cmd.args(["--fail", "--silent", "--show-error"]);
+        if let Some(ref token) = self.token {
+            cmd.arg("--header")
+                .arg(format!("Authorization: Bearer {}", token));
+        }
+        if let Some(ref proxy) = self.proxy {
+            cmd.arg("--proxy").arg(proxy);
+        }
+        cmd.arg(format!("{}/{}", self.endpoint, path));
+        cmd
+    }
+
+    /// 프로젝트 기준 상대 경로 (프로젝트 밖이면 원격에서 실행할 수 없음)
+    fn relative(&self, path: &Path) -> Result<String, String> {
+        path.strip_prefix(&self.root)
+            .map(|relative| relative.to_string_lossy().replace('\\', "/"))
+            .map_err(|_| format!("{} is outside the project", path.display()))
+    }
+
+    /// 인자 안의 프로젝트 절대 경로를 상대 경로로 바꿈
+    fn map_arg(&self, arg: &str) -> String {
+        let root = self.root.to_string_lossy();
+        if arg == root {
+            return ".".to_string();
+        }
+        arg.replace(&format!("{}{}", root, std::path::MAIN_SEPARATOR), "")
+    }
+
+    /// 원격에서 만든 의존성 파일의 상대 경로를 로컬 절대 경로로 바꿈
+    fn absolutize_depfile(&self, path: &Path) -> Result<(), String> {
+        let content = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let mut rewritten = String::with_capacity(content.len());
+        for (index, line) in content.lines().enumerate() {
+            if index > 0 {
+                rewritten.push('\n');
+            }
+            let tokens: Vec<String> = split_depfile_line(line)
+                .into_iter()
+                .map(|token| {
+                    let (path, suffix) = match token.strip_suffix(':') {
+                        Some(path) => (path, ":"),
+                        None => (token.as_str(), ""),
+                    };
+                    if path == "\\" || Path::new(path).is_absolute() {
+                        token.clone()
+                    } else {
+                        format!("{}{}", self.root.join(path).display(), suffix)
+                    }
+                })
+                .collect();
+            rewritten.push_str(&tokens.join(" "));
+        }
+        rewritten.push('\n');
+        std::fs::write(path, rewritten).map_err(|e| e.to_string())
+    }
+}
+
+impl ActionExecutor for RemoteExecutor {
+    fn execute(&self, action: Action) -> std::io::Result<Output> {
+        let Some(outputs) = action.outputs.clone() else {
+            return LocalExecutor.execute(action);
+        };
+        match self.execute_remote(action.command, &outputs) {
+            Ok(output) => Ok(output),
+            Err(e) => {
+                let message = tr!(
+                    "원격 실행 실패, 로컬에서 실행합니다: {}",
+                    "remote execution failed, running locally: {}",
+                    e
+                );
+                // 서버가 내려가 있으면 모든 동작이 실패하므로 처음 한 번만 바로 보여줌
+                if self.warned.swap(true, Ordering::Relaxed) {
+                    warn!("{}", message);
+                } else {
+                    println!("{} {}", "warning:".yellow().bold(), message);
+                }
+                LocalExecutor.execute(action)
+            }
+        }
+    }
+}
+
+/// 의존성 파일 한 줄을 공백으로 나눔 (`\ `로 이스케이프한 공백은 유지)
+fn split_depfile_line(line: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&' ') => {
+                current.push_str("\\ ");
+                chars.next();
+            }
+            ' ' | '\t' => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            _ => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+#[cfg(unix)]
+fn exit_status(code: i32) -> ExitStatus {
+    use std::os::unix::process::ExitStatusExt;
+    ExitStatus::from_raw(code << 8)
+}
+
+#[cfg(windows)]
+fn exit_status(code: i32) -> ExitStatus {
+    use std::os::windows::process::ExitStatusExt;
+    ExitStatus::from_raw(code as u32)
+}
@@ -0,0 +1,468 @@
+use crate::cmdlog::{format_command, CommandLogFile};
+use crate::config::BuildConfig;
+use crate::context::ProjectContext;
+use crate::diagnostics::{warning_option, WarningEntry};
+use crate::error::{BuildError, BuildResult};
+use crate::history::format_timestamp;
+use crate::i18n::tr;
+use crate::layout::BuildLayout;
+use crate::test_report::{CaseStatus, TestSuiteResult};
+use crate::utils::{format_size, write_if_changed, xml_escape as html_escape};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use walkdir::WalkDir;
+
+/// 마지막 빌드 요약 (build/<구성>/summary.json)
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct BuildSummary {
+    pub configuration: String,
+    /// 빌드 종료 시각 (UNIX 초)
+    pub timestamp: u64,
+    pub duration_secs: f32,
+    pub success: bool,
+    pub error: Option<String>,
+    /// 다시 컴파일한 소스 수
+    pub compiled: usize,
+    /// 증분 빌드에서 최신 상태라 건너뛴 소스 수
+    pub cached: usize,
+    pub warnings: Vec<WarningRecord>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WarningRecord {
+    pub file: PathBuf,
+    pub line: Option<u32>,
+    pub message: String,
+    /// 경고를 켜는 옵션 (예: "unused-variable")
+    pub option: Option<String>,
+    /// 같은 경고가 나온 번역 단위 수
+    pub occurrences: usize,
+}
+
+impl From<&WarningEntry> for WarningRecord {
+    fn from(entry: &WarningEntry) -> Self {
+        WarningRecord {
+            file: entry.diagnostic.file.clone(),
+            line: entry.diagnostic.line,
+            message: entry.diagnostic.message.clone(),
+            option: warning_option(&entry.diagnostic.message),
+            occurrences: entry.occurrences,
+        }
+    }
+}
+
+/// 마지막 테스트 실행의 실행 파일별 결과 (build/<구성>/test-results.json)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TestRecord {
+    pub suite: String,
+    pub duration_secs: f32,
+    pub cases: Vec<CaseRecord>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CaseRecord {
+    pub name: String,
+    /// passed, failed, skipped, quarantined
+    pub status: String,
+    pub message: Option<String>,
+}
+
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+pub fn save_build_summary(layout: &BuildLayout, summary: &BuildSummary) -> BuildResult<()> {
+    save_json(&layout.build_summary_path(), summary)
+}
+
+pub fn save_test_results(layout: &BuildLayout, suites: &[TestSuiteResult]) -> BuildResult<()> {
+    let records: Vec<TestRecord> = suites
+        .iter()
+        .map(|suite| TestRecord {
+            suite: suite.name.clone(),
+            duration_secs: suite.duration.as_secs_f32(),
+            cases: suite
+                .cases
+                .iter()
+                .map(|case| CaseRecord {
+                    name: case.name.clone(),
+                    status: match case.status {
+                        CaseStatus::Passed => "passed",
+                        CaseStatus::Failed => "failed",
+                        CaseStatus::Skipped => "skipped",
+                        CaseStatus::Quarantined => "quarantined",
+                    }
+                    .to_string(),
+                    message: case.message.clone(),
+                })
+                .collect(),
+        })
+        .collect();
+    save_json(&layout.test_results_path(), &records)
+}
+
+fn save_json<T: Serialize + ?Sized>(path: &Path, value: &T) -> BuildResult<()> {
+    let json =
+        serde_json::to_string_pretty(value).map_err(|e| BuildError::PathError(e.to_string()))?;
+    write_if_changed(path, &format!("{}\n", json)).map_err(BuildError::IoError)?;
+    Ok(())
+}
+
+fn load_json<T: for<'de> Deserialize<'de>>(path: &Path) -> Option<T> {
+    let content = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// `cbuild report`: 마지막 빌드의 소요 시간, 캐시 통계, 파일별 경고, 결과물 크기,
+/// 테스트 결과(있으면 커버리지)를 하나의 HTML 파일(build/report/index.html)로 저장
+pub fn report(
+    context: &ProjectContext,
+    configuration: &str,
+    out_dir: Option<PathBuf>,
+) -> BuildResult<PathBuf> {
+    let project_dir = context.project_dir();
+    let config = context.config()?;
+    let layout = context.layout(configuration, out_dir.as_deref());
+
+    let summary: BuildSummary = load_json(&layout.build_summary_path()).ok_or_else(|| {
+        BuildError::PathError(tr!(
+            "{} 구성의 빌드 기록이 없습니다 (먼저 빌드하세요): {}",
+            "no build data for configuration {} (build first): {}",
+            configuration,
+            layout.build_summary_path().display()
+        ))
+    })?;
+    let commands: Option<CommandLogFile> = load_json(&layout.command_log_json_path())
+        .filter(|log: &CommandLogFile| log.configuration == configuration);
+    let tests: Option<Vec<TestRecord>> = load_json(&layout.test_results_path());
+
+    let mut html = String::new();
+    write_header(&mut html, config, &summary);
+    write_cache_section(&mut html, &summary);
+    if let Some(ref commands) = commands {
+        write_timing_section(&mut html, commands);
+    }
+    write_warning_section(&mut html, &summary, project_dir);
+    write_artifact_section(&mut html, &layout);
+    if let Some(ref tests) = tests {
+        write_test_section(&mut html, tests);
+    }
+    if let Some(coverage) = load_coverage(&layout) {
+        write_coverage_section(&mut html, &coverage, project_dir);
+    }
+    html.push_str("</body>\n</html>\n");
+
+    let path = layout.report_dir().join("index.html");
+    write_if_changed(&path, &html).map_err(BuildError::IoError)?;
+    Ok(path)
+}
+
+const STYLE: &str =
+    "body{font-family:system-ui,sans-serif;margin:2em auto;max-width:1100px;color:#222}
+h1{margin-bottom:0}.meta{color:#666}
+table{border-collapse:collapse;width:100%;margin:.5em 0 1.5em}
+th,td{border-bottom:1px solid #ddd;padding:4px 8px;text-align:left;vertical-align:top}
+td.num{text-align:right;font-variant-numeric:tabular-nums}
+.bar{background:#4a90d9;height:10px;border-radius:2px}
+.ok{color:#2e7d32}.fail{color:#c62828}.warn{color:#ef6c00}
+code,pre{font-family:ui-monospace,monospace;font-size:90%}
+pre{white-space:pre-wrap;background:#f6f8fa;padding:6px;margin:0}
+details summary{cursor:pointer}";
+
+fn write_header(html: &mut String, config: &BuildConfig, summary: &BuildSummary) {
+    let title = format!(
+        "{} v{} — {}",
+        config.project.name, config.project.version, summary.configuration
+    );
+    let _ = writeln!(
+        html,
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>",
+        html_escape(&title),
+        STYLE
+    );
+    let _ = writeln!(html, "<h1>{}</h1>", html_escape(&title));
+
+    let status = if summary.success {
+        format!("<span class=\"ok\">{}</span>", tr!("성공", "succeeded"))
+    } else {
+        format!("<span class=\"fail\">{}</span>", tr!("실패", "failed"))
+    };
+    let _ = writeln!(
+        html,
+        "<p class=\"meta\">{} (UTC) · {} · {:.2}s</p>",
+        format_timestamp(summary.timestamp),
+        status,
+        summary.duration_secs
+    );
+    if let Some(ref error) = summary.error {
+        let _ = writeln!(html, "<pre class=\"fail\">{}</pre>", html_escape(error));
+    }
+}
+
+fn write_cache_section(html: &mut String, summary: &BuildSummary) {
+    let total = summary.compiled + summary.cached;
+    let rate = if total > 0 {
+        summary.cached as f32 * 100.0 / total as f32
+    } else {
+        0.0
+    };
+    let _ = writeln!(
+        html,
+        "<h2>{}</h2>\n<table>\n<tr><th>{}</th><td class=\"num\">{}</td></tr>\n<tr><th>{}</th><td class=\"num\">{}</td></tr>\n<tr><th>{}</th><td class=\"num\">{:.1}%</td></tr>\n</table>",
+        tr!("캐시", "Cache"),
+        tr!("다시 컴파일", "Compiled"),
+        summary.compiled,
+        tr!("최신 상태", "Up to date"),
+        summary.cached,
+        tr!("적중률", "Hit rate"),
+        rate
+    );
+}
+
+fn write_timing_section(html: &mut String, log: &CommandLogFile) {
+    let mut by_target: BTreeMap<&str, (usize, u64)> = BTreeMap::new();
+    for command in &log.commands {
+        let entry = by_target.entry(&command.target).or_default();
+        entry.0 += 1;
+        entry.1 += command.duration_ms;
+    }
+    let mut targets: Vec<_> = by_target.into_iter().collect();
+    targets.sort_by_key(|(_, (_, ms))| std::cmp::Reverse(*ms));
+    let longest = targets.first().map_or(1, |t| t.1 .1.max(1));
+
+    let _ = writeln!(
+        html,
+        "<h2>{}</h2>\n<table>\n<tr><th>{}</th><th>{}</th><th>{}</th><th></th></tr>",
+        tr!("타겟별 소요 시간", "Time by target"),
+        tr!("타겟", "Target"),
+        tr!("명령", "Commands"),
+        tr!("합계", "Total")
+    );
+    for (target, (count, ms)) in &targets {
+        let _ = writeln!(
+            html,
+            "<tr><td><code>{}</code></td><td class=\"num\">{}</td><td class=\"num\">{:.2}s</td><td style=\"width:40%\"><div class=\"bar\" style=\"width:{}%\"></div></td></tr>",
+            html_escape(target),
+            count,
+            *ms as f32 / 1000.0,
+            ms * 100 / longest
+        );
+    }
+    html.push_str("</table>\n");
+
+    let mut slowest: Vec<_> = log.commands.iter().collect();
+    slowest.sort_by_key(|command| std::cmp::Reverse(command.duration_ms));
+    let _ = writeln!(
+        html,
+        "<h3>{}</h3>\n<table>",
+        tr!("가장 오래 걸린 명령", "Slowest commands")
+    );
+    for command in slowest.iter().take(10) {
+        let class = if command.exit_code == Some(0) {
+            ""
+        } else {
+            " class=\"fail\""
+        };
+        let _ = writeln!(
+            html,
+            "<tr{}><td class=\"num\">{:.2}s</td><td><code>{}</code></td><td><code>{}</code></td></tr>",
+            class,
+            command.duration_ms as f32 / 1000.0,
+            html_escape(&command.target),
+            html_escape(&format_command(command))
+        );
+    }
+    html.push_str("</table>\n");
+}
+
+fn write_warning_section(html: &mut String, summary: &BuildSummary, project_dir: &Path) {
+    let _ = writeln!(
+        html,
+        "<h2>{} ({})</h2>",
+        tr!("파일별 경고", "Warnings by file"),
+        summary.warnings.len()
+    );
+    if summary.warnings.is_empty() {
+        let _ = writeln!(
+            html,
+            "<p class=\"ok\">{}</p>",
+            tr!("경고 없음", "No warnings")
+        );
+        return;
+    }
+
+    let mut by_file: BTreeMap<&Path, Vec<&WarningRecord>> = BTreeMap::new();
+    for warning in &summary.warnings {
+        by_file.entry(&warning.file).or_default().push(warning);
+    }
+    let mut files: Vec<_> = by_file.into_iter().collect();
+    files.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then_with(|| a.0.cmp(b.0)));
+
+    for (file, warnings) in files {
+        let name = file.strip_prefix(project_dir).unwrap_or(file);
+        let _ = writeln!(
+            html,
+            "<details><summary><code>{}</code> <span class=\"warn\">{}</span></summary>\n<table>",
+            html_escape(&name.display().to_string()),
+            warnings.len()
+        );
+        for warning in warnings {
+            let _ = writeln!(
+                html,
+                "<tr><td class=\"num\">{}</td><td>{}</td><td><code>{}</code></td></tr>",
+                warning.line.map(|l| l.to_string()).unwrap_or_default(),
+                html_escape(&warning.message),
+                warning
+                    .option
+                    .as_deref()
+                    .map(|o| format!("-W{}", html_escape(o)))
+                    .unwrap_or_default()
+            );
+        }
+        html.push_str("</table></details>\n");
+    }
+}
+
+fn write_artifact_section(html: &mut String, layout: &BuildLayout) {
+    let mut artifacts = Vec::new();
+    for dir in [layout.lib_dir(), layout.bin_dir()] {
+        for entry in WalkDir::new(&dir).into_iter().flatten() {
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+            let name = entry
+                .path()
+                .strip_prefix(layout.build_dir())
+                .unwrap_or(entry.path())
+                .display()
+                .to_string();
+            artifacts.push((name, size));
+        }
+    }
+    artifacts.sort();
+
+    let _ = writeln!(
+        html,
+        "<h2>{}</h2>\n<table>\n<tr><th>{}</th><th>{}</th></tr>",
+        tr!("결과물", "Artifacts"),
+        tr!("파일", "File"),
+        tr!("크기", "Size")
+    );
+    for (name, size) in &artifacts {
+        let _ = writeln!(
+            html,
+            "<tr><td><code>{}</code></td><td class=\"num\">{}</td></tr>",
+            html_escape(name),
+            format_size(*size)
+        );
+    }
+    html.push_str("</table>\n");
+}
+
+fn write_test_section(html: &mut String, tests: &[TestRecord]) {
+    let count = |status: &str| {
+        tests
+            .iter()
+            .flat_map(|t| &t.cases)
+            .filter(|c| c.status == status)
+            .count()
+    };
+    let _ = writeln!(
+        html,
+        "<h2>{}</h2>\n<p><span class=\"ok\">{}</span> · <span class=\"fail\">{}</span> · {}</p>\n<table>\n<tr><th>{}</th><th>{}</th><th>{}</th><th>{}</th></tr>",
+        tr!("테스트", "Tests"),
+        tr!("성공 {}", "{} passed", count("passed")),
+        tr!("실패 {}", "{} failed", count("failed")),
+        tr!("건너뜀 {}", "{} skipped", count("skipped") + count("quarantined")),
+        tr!("실행 파일", "Suite"),
+        tr!("성공", "Passed"),
+        tr!("실패", "Failed"),
+        tr!("시간", "Time")
+    );
+    for test in tests {
+        let failed: Vec<&CaseRecord> = test.cases.iter().filter(|c| c.status == "failed").collect();
+        let _ = writeln!(
+            html,
+            "<tr><td><code>{}</code></td><td class=\"num\">{}</td><td class=\"num{}\">{}</td><td class=\"num\">{:.2}s</td></tr>",
+            html_escape(&test.suite),
+            test.cases.iter().filter(|c| c.status == "passed").count(),
+            if failed.is_empty() { "" } else { " fail" },
+            failed.len(),
+            test.duration_secs
+        );
+        for case in failed {
+            let _ = writeln!(
+                html,
+                "<tr><td colspan=\"4\"><details><summary class=\"fail\">{}</summary><pre>{}</pre></details></td></tr>",
+                html_escape(&case.name),
+                html_escape(case.message.as_deref().unwrap_or(""))
+            );
+        }
+    }
+    html.push_str("</table>\n");
+}
+
+/// 파일별 (실행된 줄, 전체 줄)
+type Coverage = BTreeMap<PathBuf, (u64, u64)>;
+
+/// build/<구성>/coverage/lcov.info가 있으면 파일별 줄 커버리지 로드
+fn load_coverage(layout: &BuildLayout) -> Option<Coverage> {
+    let content =
+        std::fs::read_to_string(layout.build_dir().join("coverage").join("lcov.info")).ok()?;
+
+    let mut coverage = Coverage::new();
+    let mut file = None;
+    for line in content.lines() {
+        if let Some(path) = line.strip_prefix("SF:") {
+            file = Some(PathBuf::from(path));
+        } else if let (Some(ref path), Some(hit)) = (&file, line.strip_prefix("LH:")) {
+            coverage.entry(path.clone()).or_default().0 += hit.parse::<u64>().unwrap_or(0);
+        } else if let (Some(ref path), Some(found)) = (&file, line.strip_prefix("LF:")) {
+            coverage.entry(path.clone()).or_default().1 += found.parse::<u64>().unwrap_or(0);
+        } else if line == "end_of_record" {
+            file = None;
+        }
+    }
+    Some(coverage)
+}
+
+fn write_coverage_section(html: &mut String, coverage: &Coverage, project_dir: &Path) {
+    let (hit, found) = coverage
+        .values()
+        .fold((0, 0), |(h, f), (hit, found)| (h + hit, f + found));
+    let percent = |hit: u64, found: u64| {
+        if found == 0 {
+            100.0
+        } else {
+            hit as f32 * 100.0 / found as f32
+        }
+    };
+
+    let _ = writeln!(
+        html,
+        "<h2>{} ({:.1}%)</h2>\n<table>\n<tr><th>{}</th><th>{}</th><th></th></tr>",
+        tr!("커버리지", "Coverage"),
+        percent(hit, found),
+        tr!("파일", "File"),
+        tr!("줄", "Lines")
+    );
+    for (file, (hit, found)) in coverage {
+        let name = file.strip_prefix(project_dir).unwrap_or(file);
+        let _ = writeln!(
+            html,
+            "<tr><td><code>{}</code></td><td class=\"num\">{}/{}</td><td class=\"num\">{:.1}%</td></tr>",
+            html_escape(&name.display().to_string()),
+            hit,
+            found,
+            percent(*hit, *found)
+        );
+    }
+    html.push_str("</table>\n");
+}
@@ -0,0 +1,177 @@
+use crate::config::RetrySettings;
+use crate::i18n::tr;
+use colored::Colorize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+/// 기본 최대 시도 횟수
+const DEFAULT_ATTEMPTS: u32 = 3;
+/// 첫 재시도 전 기본 대기 시간 (이후 두 배씩 증가)
+const DEFAULT_DELAY_MS: u64 = 1000;
+const MAX_DELAY: Duration = Duration::from_secs(30);
+
+static DISABLED: AtomicBool = AtomicBool::new(false);
+
+/// 명령줄에서 재시도를 끔 (`--no-retry`)
+pub fn disable() {
+    DISABLED.store(true, Ordering::Relaxed);
+}
+
+/// 일시적으로 실패할 수 있는 네트워크 작업(git clone/fetch, 다운로드)의 재시도 정책
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    attempts: u32,
+    delay: Duration,
+}
+
+impl RetryPolicy {
+    pub fn from_settings(settings: &RetrySettings) -> Self {
+        let attempts = if DISABLED.load(Ordering::Relaxed) {
+            1
+        } else {
+            settings.attempts.unwrap_or(DEFAULT_ATTEMPTS).max(1)
+        };
+        RetryPolicy {
+            attempts,
+            delay: Duration::from_millis(settings.delay_ms.unwrap_or(DEFAULT_DELAY_MS)),
+        }
+    }
+
+    /// `operation`을 성공할 때까지 지수 백오프로 재시도
+    ///
+    /// 다시 시도해도 소용없는 오류(저장소·파일 없음, 인증 실패 등)이면 바로 중단한다.
+    /// 모두 실패하면 시도별 오류를 모은 메시지를 반환한다.
+    pub fn run<T>(
+        &self,
+        description: &str,
+        mut operation: impl FnMut() -> Result<T, String>,
+    ) -> Result<T, String> {
+        let mut errors = Vec::new();
+        let mut delay = self.delay;
+
+        for attempt in 1..=self.attempts {
+            match operation() {
+                Ok(value) => return Ok(value),
+                Err(error) => {
+                    let permanent = is_permanent(&error);
+                    errors.push(error);
+                    if permanent || attempt == self.attempts {
+                        break;
+                    }
+
+                    println!(
+                        "{} {} ({}/{}), {}",
+                        "Retrying".yellow().bold(),
+                        description,
+                        attempt + 1,
+                        self.attempts,
+                        tr!("{:.1}초 후", "in {:.1}s", delay.as_secs_f32())
+                    );
+                    std::thread::sleep(delay);
+                    delay = (delay * 2).min(MAX_DELAY);
+                }
+            }
+        }
+
+        if errors.len() == 1 {
+            return Err(errors.pop().unwrap());
+        }
+        let mut message = tr!(
+            "{}번 시도 모두 실패했습니다",
+            "all {} attempts failed",
+            errors.len()
+        );
+        for (index, error) in errors.iter().enumerate() {
+            let error = error.trim().replace('\n', "\n      ");
+            message.push_str(&format!("\n  [{}] {}", index + 1, error));
+        }
+        Err(message)
+    }
+}
+
+/// 네트워크 상태와 관계없이 다시 시도해도 같은 결과가 나올 오류인지
+fn is_permanent(error: &str) -> bool {
+    const PATTERNS: &[&str] = &[
+        "not found",
+        "does not exist",
+        "Authentication failed",
+        "Permission denied",
+        "could not read Username",
+        "already exists and is not an empty directory",
+        "The requested URL returned error: 4",
+        "ERROR 404",
+        "ERROR 403",
+    ];
+    PATTERNS.iter().any(|pattern| error.contains(pattern))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_resources_are_permanent() {
+        // curl -f, wget, git clone의 HTTP 404 출력
+        assert!(is_permanent(
+            "curl: (22) The requested URL returned error: 404"
+        ));
+        assert!(is_permanent(
+            "https://example.com/zlib.tar.gz:\n2024-05-01 10:00:00 ERROR 404: Not Found."
+        ));
+        assert!(is_permanent(
+            "fatal: repository 'https://github.com/madler/no-such-repo.git/' not found"
+        ));
+    }
+
+    #[test]
+    fn transient_failures_are_retried() {
+        // HTTP 503
+        assert!(!is_permanent(
+            "curl: (22) The requested URL returned error: 503"
+        ));
+        assert!(!is_permanent(
+            "fatal: unable to access 'https://github.com/madler/zlib.git/': The requested URL returned error: 503"
+        ));
+        // DNS 실패
+        assert!(!is_permanent(
+            "fatal: unable to access 'https://github.com/madler/zlib.git/': Could not resolve host: github.com"
+        ));
+        assert!(!is_permanent(
+            "ssh: Could not resolve hostname github.com: Temporary failure in name resolution"
+        ));
+        // 연결 재설정
+        assert!(!is_permanent(
+            "fatal: unable to access 'https://github.com/madler/zlib.git/': Recv failure: Connection reset by peer"
+        ));
+        assert!(!is_permanent(
+            "curl: (56) Recv failure: Connection reset by peer"
+        ));
+    }
+
+    #[test]
+    fn permanent_error_stops_retrying() {
+        let policy = RetryPolicy::from_settings(&RetrySettings {
+            attempts: Some(3),
+            delay_ms: Some(0),
+        });
+
+        let mut calls = 0;
+        let result: Result<(), String> = policy.run("download", || {
+            calls += 1;
+            Err("curl: (22) The requested URL returned error: 404".to_string())
+        });
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+
+        let mut calls = 0;
+        let result = policy.run("download", || {
+            calls += 1;
+            if calls < 3 {
+                Err("curl: (56) Recv failure: Connection reset by peer".to_string())
+            } else {
+                Ok(calls)
+            }
+        });
+        assert_eq!(result, Ok(3));
+    }
+}
@@ -1,53 +1,142 @@
-use crate::config::BuildConfig;
+use crate::config::{BuildConfig, GoldenTarget};
+use crate::context::ProjectContext;
+use crate::diagnostics::{emit_error, AnnotationFormat};
 use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use crate::layout::BuildLayout;
+use crate::leakcheck::{leak_check_command, leak_tool, parse_leak_summary, LeakTool};
+use crate::report::save_test_results;
+use crate::test_report::{
+    parse_test_output, write_junit, CaseStatus, TestCaseResult, TestOutputFormat, TestSuiteResult,
+};
+use crate::utils::{deduplicate_paths, to_absolute_path, tool_command};
 use colored::Colorize;
-use log::{error, info};
+use log::{error, info, warn};
+use std::collections::BTreeMap;
+use std::ffi::OsString;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
+use std::sync::Arc;
+use std::time::Instant;
 
 pub struct Runner {
     project_dir: PathBuf,
-    build_dir: PathBuf,
-    config: Option<BuildConfig>,
+    configuration: String,
+    out_dir: Option<PathBuf>,
+    context: Arc<ProjectContext>,
+    annotations: AnnotationFormat,
+    test_format: Option<TestOutputFormat>,
+    junit_path: Option<PathBuf>,
+    fail_fast: bool,
+    update_golden: bool,
 }
 
 impl Runner {
     pub fn new(project_dir: &Path) -> Self {
-        let build_dir = project_dir.join("build").join("debug");
+        Self::with_context(Arc::new(ProjectContext::new(project_dir)))
+    }
 
+    /// 빌드 단계와 설정을 공유하는 실행기 생성
+    pub fn with_context(context: Arc<ProjectContext>) -> Self {
         Runner {
-            project_dir: project_dir.to_path_buf(),
-            build_dir,
-            config: None,
+            project_dir: context.project_dir().to_path_buf(),
+            configuration: "debug".to_string(),
+            out_dir: None,
+            context,
+            annotations: AnnotationFormat::None,
+            test_format: None,
+            junit_path: None,
+            fail_fast: false,
+            update_golden: false,
         }
     }
 
-    pub fn run(&self, args: Option<&str>) -> BuildResult<()> {
+    /// 실행할 결과물의 빌드 구성 (기본: debug)
+    pub fn set_configuration(&mut self, configuration: &str) {
+        self.configuration = configuration.to_string();
+    }
+
+    /// 명령줄에서 지정한 출력 디렉토리 (build.output_dir보다 우선)
+    pub fn set_output_dir(&mut self, out_dir: Option<PathBuf>) {
+        self.out_dir = out_dir;
+    }
+
+    /// 테스트 실패를 CI 주석 형식으로 출력 (`--annotations github`)
+    pub fn set_annotations(&mut self, annotations: AnnotationFormat) {
+        self.annotations = annotations;
+    }
+
+    /// 모든 테스트 타겟에 적용할 출력 형식 (`--format`, 타겟의 format보다 우선)
+    pub fn set_test_format(&mut self, format: Option<TestOutputFormat>) {
+        self.test_format = format;
+    }
+
+    /// 테스트 결과를 JUnit XML로 저장할 경로 (`--junit`)
+    pub fn set_junit_output(&mut self, path: Option<PathBuf>) {
+        self.junit_path = path;
+    }
+
+    /// 첫 번째 테스트 실패에서 나머지 테스트 실행 중단 (`--fail-fast`)
+    pub fn set_fail_fast(&mut self, fail_fast: bool) {
+        self.fail_fast = fail_fast;
+    }
+
+    /// 골든 테스트의 기대 결과 파일 갱신 (`--update-golden`)
+    pub fn set_update_golden(&mut self, update_golden: bool) {
+        self.update_golden = update_golden;
+    }
+
+    fn layout(&self) -> BuildLayout {
+        self.context
+            .layout(&self.configuration, self.out_dir.as_deref())
+    }
+
+    pub fn run(&self, alias: Option<&str>, args: Option<&str>) -> BuildResult<()> {
         // 설정 로드
         let config = self.load_config()?;
+        let layout = self.layout();
 
         // 실행할 타겟 찾기
         if config.targets.executable.is_empty() {
-            return Err(BuildError::ExecutableNotFound(self.build_dir.join("bin")));
+            return Err(BuildError::ExecutableNotFound(layout.bin_dir()));
         }
 
-        // 메인 실행 파일 결정 (첫 번째 또는 프로젝트 이름과 일치하는 것)
-        let main_exe = config
-            .targets
-            .executable
-            .iter()
-            .find(|exe| exe.name == config.project.name)
-            .or_else(|| config.targets.executable.first())
-            .unwrap();
+        // 실행 별칭 확인
+        let run_alias = match alias {
+            Some(name) => Some(
+                config
+                    .run
+                    .aliases
+                    .get(name)
+                    .ok_or_else(|| BuildError::RunAliasNotFound(name.to_string()))?,
+            ),
+            None => None,
+        };
 
-        // 실행 파일 경로
-        let exe_name = if cfg!(target_os = "windows") {
-            format!("{}.exe", main_exe.name)
-        } else {
-            main_exe.name.clone()
+        // 메인 실행 파일 결정 (별칭 타겟 > [run] default > 프로젝트 이름과 일치 > 첫 번째)
+        let target_name = run_alias
+            .and_then(|a| a.target.as_deref())
+            .or(config.run.default.as_deref());
+
+        let main_exe = match target_name {
+            Some(name) => config
+                .targets
+                .executable
+                .iter()
+                .find(|exe| exe.name == name)
+                .ok_or_else(|| BuildError::TargetNotFound(name.to_string()))?,
+            None => config
+                .targets
+                .executable
+                .iter()
+                .find(|exe| exe.name == config.project.name)
+                .or_else(|| config.targets.executable.iter().find(|exe| !exe.example))
+                .or_else(|| config.targets.executable.first())
+                .unwrap(),
         };
 
-        let exe_path = self.build_dir.join("bin").join(&exe_name);
+        // 실행 파일 경로
+        let exe_path = layout.executable_path(&main_exe.name);
 
         if !exe_path.exists() {
             return Err(BuildError::ExecutableNotFound(exe_path));
@@ -58,144 +147,274 @@ impl Runner {
         // 실행 명령 생성
         let mut cmd = Command::new(&exe_path);
 
-        // 인자 추가
+        // 인자 추가 (명령줄 인자 > 별칭 인자 > 설정의 기본 인자)
         if let Some(args_str) = args {
             for arg in args_str.split_whitespace() {
                 cmd.arg(arg);
             }
+        } else if let Some(alias_args) = run_alias.and_then(|a| a.args.as_ref()) {
+            cmd.args(alias_args);
+        } else if let Some(ref default_args) = config.run.args {
+            cmd.args(default_args);
         }
 
         // 환경 변수 설정: 공유 라이브러리 경로
-        if cfg!(target_os = "linux") {
-            let lib_path = self.build_dir.join("lib");
-            cmd.env("LD_LIBRARY_PATH", &lib_path);
-        } else if cfg!(target_os = "macos") {
-            let lib_path = self.build_dir.join("lib");
-            cmd.env("DYLD_LIBRARY_PATH", &lib_path);
-        } else if cfg!(target_os = "windows") {
-            let lib_path = self.build_dir.join("lib");
-            cmd.env(
-                "PATH",
-                format!(
-                    "{};{}",
-                    lib_path.display(),
-                    std::env::var("PATH").unwrap_or_default()
-                ),
-            );
+        self.apply_library_path(&mut cmd, &layout, config)?;
+
+        // 설정 파일의 실행 환경 적용
+        self.apply_environment(
+            &mut cmd,
+            config.run.env.as_ref(),
+            config.run.env_files.as_deref(),
+            config.run.cwd.as_deref(),
+        )?;
+
+        if let Some(alias_env) = run_alias.and_then(|a| a.env.as_ref()) {
+            cmd.envs(alias_env);
         }
 
-        // 프로그램 실행
-        cmd.stdin(Stdio::inherit())
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit());
+        run_interactive(&mut cmd)
+    }
 
-        info!("Executing: {:?}", cmd);
+    /// 예제 타겟 실행 (`cbuild examples <이름>`)
+    pub fn run_example(&self, name: &str, args: &[String]) -> BuildResult<()> {
+        let config = self.load_config()?;
+        let layout = self.layout();
 
-        match cmd.status() {
-            Ok(status) => {
-                if status.success() {
-                    println!("{}", "Program executed successfully".green());
-                } else {
-                    let code = status.code().unwrap_or(-1);
-                    println!("{} (code: {})", "Program exited with error".red(), code);
-                }
-                Ok(())
-            }
-            Err(e) => {
-                error!("Failed to execute program: {}", e);
-                Err(BuildError::IoError(e))
-            }
+        let example = config
+            .examples()
+            .find(|exe| exe.name == name)
+            .ok_or_else(|| BuildError::TargetNotFound(name.to_string()))?;
+
+        let exe_path = layout.executable_path(&example.name);
+        if !exe_path.exists() {
+            return Err(BuildError::ExecutableNotFound(exe_path));
         }
+
+        println!("{} {}", "Running".green().bold(), exe_path.display());
+
+        let mut cmd = Command::new(&exe_path);
+        cmd.args(args);
+        self.apply_library_path(&mut cmd, &layout, config)?;
+        self.apply_environment(
+            &mut cmd,
+            config.run.env.as_ref(),
+            config.run.env_files.as_deref(),
+            config.run.cwd.as_deref(),
+        )?;
+
+        run_interactive(&mut cmd)
     }
 
     pub fn run_tests(&self) -> BuildResult<()> {
         // 설정 로드
         let config = self.load_config()?;
+        let layout = self.layout();
 
         // 테스트 타겟 찾기
-        if config.targets.test.is_empty() {
-            println!("No test targets found.");
+        if config.targets.test.is_empty() && config.targets.golden.is_empty() {
+            println!(
+                "{}",
+                tr!("테스트 타겟이 없습니다.", "No test targets found.")
+            );
             return Ok(());
         }
 
-        println!("{}", "Running tests".blue().bold());
+        println!("{}", tr!("테스트 실행 중", "Running tests").blue().bold());
+        if let Some(ref test_runner) = config.build.test_runner {
+            println!(
+                "{}",
+                tr!("테스트 실행기: {}", "Test runner: {}", test_runner)
+            );
+        }
 
-        let test_dir = self.build_dir.join("bin").join("tests");
-        if !test_dir.exists() {
-            println!("Test directory not found: {}", test_dir.display());
+        let test_dir = layout.test_bin_dir();
+        if !test_dir.exists() && config.targets.golden.is_empty() {
+            println!(
+                "{}",
+                tr!(
+                    "테스트 디렉토리를 찾을 수 없습니다: {}",
+                    "Test directory not found: {}",
+                    test_dir.display()
+                )
+            );
             return Ok(());
         }
 
-        let mut failures = 0;
-        let mut success = 0;
-
-        for test in &config.targets.test {
-            let test_name = if cfg!(target_os = "windows") {
-                format!("{}.exe", test.name)
-            } else {
-                test.name.clone()
-            };
+        let total = config.targets.test.len() + config.targets.golden.len();
+        let mut suites = Vec::new();
+        let mut stopped = false;
 
-            let test_path = test_dir.join(&test_name);
+        for (index, test) in config.targets.test.iter().enumerate() {
+            let test_path = layout.test_path(&test.name);
 
             if !test_path.exists() {
                 println!(
                     "{} {}: {}",
                     "SKIP".yellow(),
                     test.name,
-                    "Test executable not found"
+                    tr!(
+                        "테스트 실행 파일을 찾을 수 없습니다",
+                        "Test executable not found"
+                    )
                 );
                 continue;
             }
 
+            // 누수 검사 방식 (`[test] leak_check`)
+            let (leak, leak_skipped) = if config.test.leak_check {
+                match leak_tool(config, &self.configuration, test) {
+                    Ok(tool) => (Some(tool), None),
+                    Err(reason) => {
+                        eprintln!(
+                            "{} {}",
+                            tr!("경고:", "warning:").yellow().bold(),
+                            tr!(
+                                "{}: 누수 검사를 건너뜁니다 ({})",
+                                "{}: skipping leak check ({})",
+                                test.name,
+                                reason
+                            )
+                        );
+                        (None, Some(reason))
+                    }
+                }
+            } else {
+                (None, None)
+            };
+
             // 환경 변수 설정: 공유 라이브러리 경로
-            let mut cmd = Command::new(&test_path);
-            if cfg!(target_os = "linux") {
-                let lib_path = self.build_dir.join("lib");
-                cmd.env("LD_LIBRARY_PATH", &lib_path);
-            } else if cfg!(target_os = "macos") {
-                let lib_path = self.build_dir.join("lib");
-                cmd.env("DYLD_LIBRARY_PATH", &lib_path);
-            } else if cfg!(target_os = "windows") {
-                let lib_path = self.build_dir.join("lib");
-                cmd.env(
-                    "PATH",
-                    format!(
-                        "{};{}",
-                        lib_path.display(),
-                        std::env::var("PATH").unwrap_or_default()
-                    ),
-                );
+            let mut cmd = test_command(config, &test_path, leak)?;
+            self.apply_library_path(&mut cmd, &layout, config)?;
+
+            // 테스트별 실행 환경 적용
+            self.apply_environment(
+                &mut cmd,
+                test.env.as_ref(),
+                test.env_files.as_deref(),
+                test.cwd.as_deref(),
+            )?;
+
+            if let Some(ref test_args) = test.args {
+                cmd.args(test_args);
             }
 
-            println!("Running test: {}", test.name);
+            println!("{}", tr!("테스트 실행: {}", "Running test: {}", test.name));
 
-            match cmd.status() {
-                Ok(status) => {
-                    if status.success() {
-                        println!("{} {}", "PASS".green(), test.name);
-                        success += 1;
-                    } else {
-                        println!(
-                            "{} {} (code: {})",
-                            "FAIL".red(),
-                            test.name,
-                            status.code().unwrap_or(-1)
-                        );
-                        failures += 1;
-                    }
+            let format = self.test_format.or(test.format).unwrap_or_default();
+            let mut suite = self.run_test_executable(&test.name, &mut cmd, format, leak);
+            // 건너뛴 누수 검사는 결과에 SKIP으로 남김
+            if let Some(reason) = leak_skipped {
+                suite.cases.push(TestCaseResult {
+                    name: "leak_check".to_string(),
+                    status: CaseStatus::Skipped,
+                    message: Some(reason),
+                });
+            }
+            self.report_suite(&mut suite, &config.test.quarantine);
+
+            stopped = self.should_stop(&suite, total - index - 1);
+            suites.push(suite);
+            if stopped {
+                break;
+            }
+        }
+
+        if !stopped {
+            for (index, golden) in config.targets.golden.iter().enumerate() {
+                println!(
+                    "{}",
+                    tr!(
+                        "골든 테스트 실행: {}",
+                        "Running golden test: {}",
+                        golden.name
+                    )
+                );
+
+                let mut suite = self.run_golden_test(golden, &layout)?;
+                self.report_suite(&mut suite, &config.test.quarantine);
+
+                stopped = self.should_stop(&suite, config.targets.golden.len() - index - 1);
+                suites.push(suite);
+                if stopped {
+                    break;
                 }
-                Err(e) => {
-                    println!("{} {}: {}", "ERROR".red(), test.name, e);
-                    failures += 1;
+            }
+        }
+
+        let failures: usize = suites.iter().map(|s| s.count(CaseStatus::Failed)).sum();
+        let success: usize = suites.iter().map(|s| s.count(CaseStatus::Passed)).sum();
+        let skipped: usize = suites.iter().map(|s| s.count(CaseStatus::Skipped)).sum();
+        let quarantined: usize = suites
+            .iter()
+            .map(|s| s.count(CaseStatus::Quarantined))
+            .sum();
+
+        let mut summary = tr!(
+            "성공 {}개, 실패 {}개",
+            "{} passed, {} failed",
+            success,
+            failures
+        );
+        if skipped > 0 {
+            summary.push_str(&tr!(", 건너뜀 {}개", ", {} skipped", skipped));
+        }
+        if quarantined > 0 {
+            summary.push_str(&tr!(
+                ", 격리된 실패 {}개",
+                ", {} quarantined failures",
+                quarantined
+            ));
+        }
+        println!("\n{}", tr!("테스트 결과: {}", "Test Results: {}", summary));
+
+        // 격리된 테스트의 실패는 빌드를 실패시키지 않고 따로 보고
+        if quarantined > 0 {
+            println!(
+                "{}",
+                tr!(
+                    "격리된 실패 (결과에 포함하지 않음):",
+                    "Quarantined failures (not counted):"
+                )
+                .yellow()
+            );
+            for suite in &suites {
+                for case in suite
+                    .cases
+                    .iter()
+                    .filter(|c| c.status == CaseStatus::Quarantined)
+                {
+                    println!("  {}", case_label(&suite.name, &case.name));
                 }
             }
         }
 
-        println!("\nTest Results: {} passed, {} failed", success, failures);
+        if let Err(e) = save_test_results(&layout, &suites) {
+            warn!(
+                "{}",
+                tr!(
+                    "테스트 결과를 저장할 수 없습니다: {}",
+                    "cannot save test results: {}",
+                    e
+                )
+            );
+        }
+
+        if let Some(ref junit_path) = self.junit_path {
+            write_junit(junit_path, &suites)?;
+            println!(
+                "{}",
+                tr!(
+                    "JUnit 보고서 저장: {}",
+                    "JUnit report written: {}",
+                    junit_path.display()
+                )
+            );
+        }
 
         if failures > 0 {
-            return Err(BuildError::CompilerError(format!(
+            return Err(BuildError::TestFailure(tr!(
+                "테스트 {}개 실패",
                 "{} tests failed",
                 failures
             )));
@@ -204,15 +423,713 @@ impl Runner {
         Ok(())
     }
 
+    /// 테스트 실행 파일 하나를 실행하고 결과를 케이스 단위로 정리
+    ///
+    /// 출력 형식이 `exit-code`이면 실행 파일 전체를 하나의 케이스로 보고,
+    /// 그 외에는 출력을 해석해 케이스별 결과를 만든다. 케이스가 모두 통과해도
+    /// 종료 코드가 0이 아니면(충돌 등) 실패 케이스를 하나 추가한다.
+    /// 누수 검사 중 누수가 발견되면 `leak_check` 실패 케이스로 보고한다.
+    fn run_test_executable(
+        &self,
+        name: &str,
+        cmd: &mut Command,
+        format: TestOutputFormat,
+        leak: Option<LeakTool>,
+    ) -> TestSuiteResult {
+        let started = Instant::now();
+        let single_case = |status: CaseStatus, message: Option<String>| TestCaseResult {
+            name: name.to_string(),
+            status,
+            message,
+        };
+
+        if format == TestOutputFormat::ExitCode && leak.is_none() {
+            let case = match cmd.status() {
+                Ok(status) if status.success() => single_case(CaseStatus::Passed, None),
+                Ok(status) => single_case(
+                    CaseStatus::Failed,
+                    Some(format!("exit code: {}", status.code().unwrap_or(-1))),
+                ),
+                Err(e) => single_case(CaseStatus::Failed, Some(e.to_string())),
+            };
+
+            return TestSuiteResult {
+                name: name.to_string(),
+                cases: vec![case],
+                duration: started.elapsed(),
+                output: String::new(),
+            };
+        }
+
+        let output = match cmd.output() {
+            Ok(output) => output,
+            Err(e) => {
+                return TestSuiteResult {
+                    name: name.to_string(),
+                    cases: vec![single_case(CaseStatus::Failed, Some(e.to_string()))],
+                    duration: started.elapsed(),
+                    output: String::new(),
+                };
+            }
+        };
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        print!("{}", stdout);
+        eprint!("{}", stderr);
+
+        // CMocka는 결과를 stderr에 출력하므로 두 출력을 모두 해석
+        let combined = format!("{}{}", stdout, stderr);
+        let mut cases = parse_test_output(format, &combined);
+
+        // 누수 검사 도구는 누수가 있으면 종료 코드를 바꾸므로 종료 코드 실패 대신 보고
+        let leak_summary = leak.and_then(|tool| parse_leak_summary(tool, &combined));
+        let has_failure = cases.iter().any(|c| c.status == CaseStatus::Failed);
+
+        if let Some(summary) = leak_summary {
+            cases.push(TestCaseResult {
+                name: "leak_check".to_string(),
+                status: CaseStatus::Failed,
+                message: Some(summary),
+            });
+        } else if !output.status.success() && !has_failure {
+            cases.push(single_case(
+                CaseStatus::Failed,
+                Some(format!("exit code: {}", output.status.code().unwrap_or(-1))),
+            ));
+        } else if cases.is_empty() && format == TestOutputFormat::ExitCode {
+            cases.push(single_case(CaseStatus::Passed, None));
+        } else if cases.is_empty() {
+            warn!(
+                "{}",
+                tr!(
+                    "{}: 출력에서 테스트 케이스를 찾지 못했습니다 ({:?})",
+                    "{}: no test cases found in output ({:?})",
+                    name,
+                    format
+                )
+            );
+            cases.push(single_case(CaseStatus::Passed, None));
+        }
+
+        TestSuiteResult {
+            name: name.to_string(),
+            cases,
+            duration: started.elapsed(),
+            output: combined,
+        }
+    }
+
+    /// `--fail-fast`일 때 실패한 테스트 이후 나머지 실행 중단 여부
+    fn should_stop(&self, suite: &TestSuiteResult, remaining: usize) -> bool {
+        if !self.fail_fast || suite.count(CaseStatus::Failed) == 0 {
+            return false;
+        }
+
+        if remaining > 0 {
+            println!(
+                "{} {}",
+                tr!("중단:", "Stopping:").yellow(),
+                tr!(
+                    "실패로 나머지 테스트 {}개를 건너뜁니다 (--fail-fast)",
+                    "skipping {} remaining tests after a failure (--fail-fast)",
+                    remaining
+                )
+            );
+        }
+        true
+    }
+
+    /// 골든 테스트 실행: 실행 파일의 출력을 기대 결과 파일과 비교
+    ///
+    /// `--update-golden`이면 비교 대신 현재 출력으로 기대 결과 파일을 갱신한다.
+    fn run_golden_test(
+        &self,
+        golden: &GoldenTarget,
+        layout: &BuildLayout,
+    ) -> BuildResult<TestSuiteResult> {
+        let config = self.load_config()?;
+        if !config
+            .targets
+            .executable
+            .iter()
+            .any(|exe| exe.name == golden.target)
+        {
+            return Err(BuildError::TargetNotFound(golden.target.clone()));
+        }
+
+        let started = Instant::now();
+        let result =
+            |status: CaseStatus, message: Option<String>, output: String| TestSuiteResult {
+                name: golden.name.clone(),
+                cases: vec![TestCaseResult {
+                    name: golden.name.clone(),
+                    status,
+                    message,
+                }],
+                duration: started.elapsed(),
+                output,
+            };
+
+        let exe_path = layout.executable_path(&golden.target);
+        if !exe_path.exists() {
+            return Err(BuildError::ExecutableNotFound(exe_path));
+        }
+
+        let mut cmd = test_command(config, &exe_path, None)?;
+        self.apply_library_path(&mut cmd, layout, config)?;
+        self.apply_environment(&mut cmd, golden.env.as_ref(), None, golden.cwd.as_deref())?;
+        if let Some(ref args) = golden.args {
+            cmd.args(args);
+        }
+
+        match golden.stdin {
+            Some(ref stdin) => {
+                let path = to_absolute_path(Path::new(stdin), &self.project_dir);
+                let file = std::fs::File::open(&path).map_err(BuildError::IoError)?;
+                cmd.stdin(Stdio::from(file));
+            }
+            None => {
+                cmd.stdin(Stdio::null());
+            }
+        }
+
+        let output = match cmd.output() {
+            Ok(output) => output,
+            Err(e) => {
+                return Ok(result(
+                    CaseStatus::Failed,
+                    Some(e.to_string()),
+                    String::new(),
+                ))
+            }
+        };
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+        let expected_code = golden.exit_code.unwrap_or(0);
+        let code = output.status.code().unwrap_or(-1);
+        if code != expected_code {
+            eprint!("{}", stderr);
+            return Ok(result(
+                CaseStatus::Failed,
+                Some(format!("exit code: {} (expected {})", code, expected_code)),
+                stderr,
+            ));
+        }
+
+        // 비교할 실제 출력 (표준 출력 또는 프로그램이 쓴 파일)
+        let actual = match golden.output_file {
+            Some(ref output_file) => {
+                let work_dir = golden
+                    .cwd
+                    .as_deref()
+                    .map(|cwd| to_absolute_path(Path::new(cwd), &self.project_dir))
+                    .unwrap_or_else(|| self.project_dir.clone());
+                let path = to_absolute_path(Path::new(output_file), &work_dir);
+                match std::fs::read(&path) {
+                    Ok(content) => content,
+                    Err(e) => {
+                        return Ok(result(
+                            CaseStatus::Failed,
+                            Some(tr!(
+                                "출력 파일을 읽을 수 없습니다 {}: {}",
+                                "cannot read output file {}: {}",
+                                path.display(),
+                                e
+                            )),
+                            stderr,
+                        ))
+                    }
+                }
+            }
+            None => output.stdout,
+        };
+        let actual = String::from_utf8_lossy(&actual).replace("\r\n", "\n");
+
+        let expected_path = to_absolute_path(Path::new(&golden.expected), &self.project_dir);
+
+        if self.update_golden {
+            if let Some(parent) = expected_path.parent() {
+                std::fs::create_dir_all(parent).map_err(BuildError::IoError)?;
+            }
+            std::fs::write(&expected_path, &actual).map_err(BuildError::IoError)?;
+            return Ok(result(
+                CaseStatus::Passed,
+                Some(tr!("갱신됨: {}", "updated: {}", expected_path.display())),
+                stderr,
+            ));
+        }
+
+        let expected = match std::fs::read_to_string(&expected_path) {
+            Ok(content) => content.replace("\r\n", "\n"),
+            Err(_) => {
+                return Ok(result(
+                    CaseStatus::Failed,
+                    Some(tr!(
+                        "기대 결과 파일이 없습니다: {} (--update-golden으로 생성)",
+                        "expected output file is missing: {} (create it with --update-golden)",
+                        expected_path.display()
+                    )),
+                    stderr,
+                ))
+            }
+        };
+
+        match first_difference(&expected, &actual) {
+            None => Ok(result(CaseStatus::Passed, None, stderr)),
+            Some(diff) => Ok(result(CaseStatus::Failed, Some(diff), stderr)),
+        }
+    }
+
+    /// 케이스별 결과 출력 및 격리 목록 적용
+    ///
+    /// `[test] quarantine`에 타겟 이름이나 `타겟::케이스`가 있으면 실패를
+    /// Quarantined로 바꿔 CI 주석 대신 경고로만 출력한다.
+    fn report_suite(&self, suite: &mut TestSuiteResult, quarantine: &[String]) {
+        for case in &mut suite.cases {
+            let label = case_label(&suite.name, &case.name);
+            let message = case.message.as_deref().unwrap_or("");
+
+            let quarantined = quarantine
+                .iter()
+                .any(|entry| *entry == suite.name || *entry == label);
+            if case.status == CaseStatus::Failed && quarantined {
+                case.status = CaseStatus::Quarantined;
+            }
+
+            let tag = match case.status {
+                CaseStatus::Passed => "PASS".green(),
+                CaseStatus::Skipped => "SKIP".yellow(),
+                CaseStatus::Quarantined => "FLAKY".yellow(),
+                CaseStatus::Failed => "FAIL".red(),
+            };
+            if message.is_empty() {
+                println!("{} {}", tag, label);
+            } else {
+                println!("{} {}: {}", tag, label, message);
+            }
+
+            if case.status == CaseStatus::Failed {
+                emit_error(self.annotations, &format!("Test {} failed", label), message);
+            }
+        }
+    }
+
+    /// 프로젝트 빌드 환경을 적용한 상태로 임의의 명령 실행
+    ///
+    /// build/bin을 PATH에, build/lib를 공유 라이브러리 탐색 경로에 추가하고
+    /// 모든 타겟의 포함/라이브러리 디렉토리를 환경 변수로 내보낸다.
+    /// 실행한 명령의 종료 코드를 반환한다.
+    pub fn exec(&self, command: &[String]) -> BuildResult<i32> {
+        let config = self.load_config()?;
+
+        let (program, args) = command.split_first().ok_or_else(|| {
+            BuildError::PathError(tr!(
+                "실행할 명령이 지정되지 않았습니다",
+                "no command given to execute"
+            ))
+        })?;
+
+        let layout = self.layout();
+        let bin_dir = layout.bin_dir();
+        let lib_dir = layout.lib_dir();
+
+        // 타겟에 선언된 포함/라이브러리 디렉토리 수집
+        let mut include_dirs = Vec::new();
+        let mut link_dirs = vec![lib_dir.clone()];
+
+        for exe in &config.targets.executable {
+            include_dirs.extend(exe.include_dirs.iter().flatten().cloned());
+            link_dirs.extend(
+                exe.link_dirs
+                    .iter()
+                    .flatten()
+                    .map(|d| to_absolute_path(Path::new(d), &self.project_dir)),
+            );
+        }
+        for lib in config
+            .targets
+            .static_lib
+            .iter()
+            .chain(&config.targets.shared_lib)
+        {
+            include_dirs.extend(lib.include_dirs.iter().flatten().cloned());
+        }
+        for interface in &config.targets.interface {
+            include_dirs.extend(interface.include_dirs.iter().flatten().cloned());
+        }
+        for test in &config.targets.test {
+            include_dirs.extend(test.include_dirs.iter().flatten().cloned());
+            link_dirs.extend(
+                test.link_dirs
+                    .iter()
+                    .flatten()
+                    .map(|d| to_absolute_path(Path::new(d), &self.project_dir)),
+            );
+        }
+
+        let include_dirs: Vec<PathBuf> = include_dirs
+            .iter()
+            .map(|d| to_absolute_path(Path::new(d), &self.project_dir))
+            .collect();
+        let include_dirs = deduplicate_paths(&include_dirs);
+        let link_dirs = deduplicate_paths(&link_dirs);
+
+        let mut cmd = Command::new(program);
+        cmd.args(args);
+
+        // Windows는 DLL도 PATH에서 찾으므로 PATH 하나에 모두 추가
+        match library_path_var() {
+            Some("PATH") => {
+                cmd.env(
+                    "PATH",
+                    prepend_search_path("PATH", &[bin_dir, lib_dir.clone()])?,
+                );
+            }
+            Some(var) => {
+                cmd.env("PATH", prepend_search_path("PATH", &[bin_dir])?);
+                cmd.env(
+                    var,
+                    prepend_search_path(var, std::slice::from_ref(&lib_dir))?,
+                );
+            }
+            None => {
+                cmd.env("PATH", prepend_search_path("PATH", &[bin_dir])?);
+            }
+        }
+
+        // 컴파일러가 인식하는 검색 경로 및 cbuild 전용 변수
+        cmd.env(
+            "C_INCLUDE_PATH",
+            prepend_search_path("C_INCLUDE_PATH", &include_dirs)?,
+        );
+        cmd.env(
+            "CPLUS_INCLUDE_PATH",
+            prepend_search_path("CPLUS_INCLUDE_PATH", &include_dirs)?,
+        );
+        cmd.env(
+            "LIBRARY_PATH",
+            prepend_search_path("LIBRARY_PATH", &link_dirs)?,
+        );
+        cmd.env("CBUILD_PROJECT_DIR", &self.project_dir);
+        cmd.env("CBUILD_BUILD_DIR", layout.build_dir());
+        cmd.env("CBUILD_COMPILER", &config.build.compiler);
+
+        // [run]의 환경 변수도 함께 적용
+        self.apply_environment(
+            &mut cmd,
+            config.run.env.as_ref(),
+            config.run.env_files.as_deref(),
+            None,
+        )?;
+
+        info!("Executing: {:?}", cmd);
+
+        let status = cmd.status().map_err(|e| {
+            error!(
+                "{}",
+                tr!("{} 실행 실패: {}", "Failed to execute {}: {}", program, e)
+            );
+            BuildError::IoError(e)
+        })?;
+
+        Ok(status.code().unwrap_or(-1))
+    }
+
+    /// 공유 라이브러리 탐색 경로 환경 변수 설정 (output_dir 하위 디렉토리 포함)
+    ///
+    /// 기존 값을 지우지 않고 앞에 추가한다. Windows는 DLL을 PATH에서 찾는다.
+    fn apply_library_path(
+        &self,
+        cmd: &mut Command,
+        layout: &BuildLayout,
+        config: &BuildConfig,
+    ) -> BuildResult<()> {
+        if let Some(var) = library_path_var() {
+            cmd.env(
+                var,
+                prepend_search_path(var, &layout.shared_lib_dirs(config))?,
+            );
+        }
+        Ok(())
+    }
+
+    /// 설정 파일에 정의된 환경 변수, .env 파일, 작업 디렉토리 적용
+    ///
+    /// .env 파일을 먼저 읽고 `env` 테이블의 값이 같은 키를 덮어쓴다.
+    fn apply_environment(
+        &self,
+        cmd: &mut Command,
+        env: Option<&BTreeMap<String, String>>,
+        env_files: Option<&[String]>,
+        cwd: Option<&str>,
+    ) -> BuildResult<()> {
+        for env_file in env_files.unwrap_or_default() {
+            let path = to_absolute_path(Path::new(env_file), &self.project_dir);
+            for (key, value) in load_env_file(&path)? {
+                cmd.env(key, value);
+            }
+        }
+
+        if let Some(env) = env {
+            cmd.envs(env);
+        }
+
+        if let Some(cwd) = cwd {
+            let dir = to_absolute_path(Path::new(cwd), &self.project_dir);
+            if !dir.is_dir() {
+                return Err(BuildError::PathError(tr!(
+                    "작업 디렉토리를 찾을 수 없습니다: {}",
+                    "working directory not found: {}",
+                    dir.display()
+                )));
+            }
+            cmd.current_dir(dir);
+        }
+
+        Ok(())
+    }
+
     fn load_config(&self) -> BuildResult<&BuildConfig> {
-        if self.config.is_none() {
-            let mut this = self as *const Self as *mut Self;
-            // SAFETY: We're temporarily mutating self to cache the config
-            unsafe {
-                (*this).config = Some(BuildConfig::from_file(&self.project_dir)?);
+        self.context.config()
+    }
+}
+
+/// 테스트 실행 명령 생성
+///
+/// `build.test_runner`가 있으면 그 명령(에뮬레이터 등)의 인자로 실행 파일을
+/// 넘기고, 없으면 실행 파일을 직접 실행한다. 누수 검사 명령도 같은 방식으로
+/// 실행기 뒤에 붙으며, 새니타이저 옵션 같은 환경 변수는 그대로 유지된다.
+fn test_command(
+    config: &BuildConfig,
+    program: &Path,
+    leak: Option<LeakTool>,
+) -> BuildResult<Command> {
+    let inner = match leak {
+        Some(tool) => leak_check_command(tool, program),
+        None => Command::new(program),
+    };
+    let Some(ref test_runner) = config.build.test_runner else {
+        return Ok(inner);
+    };
+
+    if test_runner.trim().is_empty() {
+        return Err(BuildError::ConfigParsingError(tr!(
+            "build.test_runner가 비어 있습니다",
+            "build.test_runner is empty"
+        )));
+    }
+
+    let mut cmd = tool_command(test_runner);
+    cmd.arg(inner.get_program()).args(inner.get_args());
+    for (key, value) in inner.get_envs() {
+        match value {
+            Some(value) => cmd.env(key, value),
+            None => cmd.env_remove(key),
+        };
+    }
+    Ok(cmd)
+}
+
+/// 출력용 테스트 케이스 이름 (실행 파일 단위 결과는 타겟 이름만)
+fn case_label(suite: &str, case: &str) -> String {
+    if suite == case {
+        suite.to_string()
+    } else {
+        format!("{}::{}", suite, case)
+    }
+}
+
+/// 기대 결과와 실제 출력의 첫 번째 차이를 줄 단위로 설명
+fn first_difference(expected: &str, actual: &str) -> Option<String> {
+    if expected == actual {
+        return None;
+    }
+
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut line = 1;
+
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(e), Some(a)) if e == a => line += 1,
+            (None, None) => {
+                // 줄 내용은 같고 마지막 줄바꿈만 다름
+                return Some(tr!(
+                    "출력 끝의 줄바꿈이 다릅니다",
+                    "trailing newline differs"
+                ));
+            }
+            (e, a) => {
+                return Some(tr!(
+                    "{}번째 줄이 다릅니다\n  expected: {}\n  actual:   {}",
+                    "line {} differs\n  expected: {}\n  actual:   {}",
+                    line,
+                    e.unwrap_or("<EOF>"),
+                    a.unwrap_or("<EOF>")
+                ));
+            }
+        }
+    }
+}
+
+/// 표준 입출력을 연결해 프로그램을 실행하고 종료 상태 출력
+fn run_interactive(cmd: &mut Command) -> BuildResult<()> {
+    cmd.stdin(Stdio::inherit())
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit());
+
+    info!("Executing: {:?}", cmd);
+
+    match cmd.status() {
+        Ok(status) => {
+            if status.success() {
+                println!(
+                    "{}",
+                    tr!(
+                        "프로그램이 정상 종료되었습니다",
+                        "Program executed successfully"
+                    )
+                    .green()
+                );
+            } else {
+                let code = status.code().unwrap_or(-1);
+                println!(
+                    "{}",
+                    tr!(
+                        "프로그램이 오류로 종료되었습니다 (코드: {})",
+                        "Program exited with error (code: {})",
+                        code
+                    )
+                    .red()
+                );
             }
+            Ok(())
+        }
+        Err(e) => {
+            error!(
+                "{}",
+                tr!("프로그램 실행 실패: {}", "Failed to execute program: {}", e)
+            );
+            Err(BuildError::IoError(e))
+        }
+    }
+}
+
+/// 실행할 때 공유 라이브러리를 찾는 환경 변수 (플랫폼에 없으면 None)
+fn library_path_var() -> Option<&'static str> {
+    if cfg!(target_os = "windows") {
+        Some("PATH")
+    } else if cfg!(target_os = "macos") {
+        Some("DYLD_LIBRARY_PATH")
+    } else if cfg!(target_os = "linux") {
+        Some("LD_LIBRARY_PATH")
+    } else {
+        None
+    }
+}
+
+/// 기존 환경 변수 값 앞에 경로 목록을 추가한 탐색 경로 생성
+fn prepend_search_path(var: &str, paths: &[PathBuf]) -> BuildResult<OsString> {
+    let mut entries: Vec<PathBuf> = paths.to_vec();
+
+    if let Some(existing) = std::env::var_os(var) {
+        entries.extend(std::env::split_paths(&existing));
+    }
+
+    std::env::join_paths(entries).map_err(|e| {
+        BuildError::PathError(tr!(
+            "{} 경로를 구성할 수 없습니다: {}",
+            "cannot build {} search path: {}",
+            var,
+            e
+        ))
+    })
+}
+
+/// .env 형식 파일 파싱 (KEY=VALUE, `#` 주석, 선택적 `export` 접두사와 따옴표 지원)
+fn load_env_file(path: &Path) -> BuildResult<Vec<(String, String)>> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        BuildError::PathError(tr!(
+            "env 파일을 읽을 수 없습니다 {}: {}",
+            "cannot read env file {}: {}",
+            path.display(),
+            e
+        ))
+    })?;
+
+    let mut vars = Vec::new();
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
         }
 
-        Ok(self.config.as_ref().unwrap())
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let Some((key, value)) = line.split_once('=') else {
+            warn!(
+                "{}",
+                tr!(
+                    "env 파일의 잘못된 줄 무시: {}",
+                    "ignoring malformed line in env file: {}",
+                    line
+                )
+            );
+            continue;
+        };
+
+        let value = value.trim();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+            .unwrap_or(value);
+
+        vars.push((key.trim().to_string(), value.to_string()));
+    }
+
+    Ok(vars)
+}
+
+#[cfg(all(test, windows))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn library_path_is_path_on_windows() {
+        assert_eq!(library_path_var(), Some("PATH"));
+    }
+
+    #[test]
+    fn prepend_search_path_uses_semicolons() {
+        // 다른 테스트와 겹치지 않는 변수 사용
+        let var = "CBUILD_TEST_PREPEND_SEARCH_PATH";
+        std::env::set_var(var, r"C:\tools;D:\Program Files\lib");
+        let joined = prepend_search_path(
+            var,
+            &[
+                PathBuf::from(r"C:\proj\build\debug\bin"),
+                PathBuf::from(r"C:\proj\build\debug\lib"),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            joined,
+            OsString::from(
+                r"C:\proj\build\debug\bin;C:\proj\build\debug\lib;C:\tools;D:\Program Files\lib"
+            )
+        );
+
+        // 경로 구분자 `;`가 들어간 경로는 따옴표로 감싸고, `"`가 들어간 경로는 오류
+        let joined =
+            prepend_search_path("CBUILD_TEST_UNSET_SEARCH_PATH", &[PathBuf::from(r"C:\a;b")])
+                .unwrap();
+        assert_eq!(joined, OsString::from(r#""C:\a;b""#));
+        assert!(prepend_search_path(
+            "CBUILD_TEST_UNSET_SEARCH_PATH",
+            &[PathBuf::from(r#"C:\a"b"#)]
+        )
+        .is_err());
     }
 }
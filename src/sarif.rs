@@ -0,0 +1,125 @@
+use crate::diagnostics::{Diagnostic, Severity};
+use crate::error::{BuildError, BuildResult};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde_json::{json, Value};
+use std::collections::BTreeSet;
+use std::path::Path;
+
+/// 진단 목록을 SARIF 2.1.0 형식으로 저장
+///
+/// 여러 번역 단위에서 같은 헤더의 경고가 반복되므로 위치와 메시지가 같은
+/// 진단은 한 번만 기록한다. 파일 경로는 `%SRCROOT%`(프로젝트 디렉토리) 기준이다.
+pub fn write_sarif(
+    path: &Path,
+    tool_name: &str,
+    diagnostics: &[Diagnostic],
+    project_dir: &Path,
+) -> BuildResult<()> {
+    let mut seen = BTreeSet::new();
+    let mut rules = BTreeSet::new();
+    let mut results = Vec::new();
+
+    for diagnostic in diagnostics {
+        let uri = artifact_uri(&diagnostic.file, project_dir);
+        let key = (
+            uri.clone(),
+            diagnostic.line,
+            diagnostic.column,
+            diagnostic.message.clone(),
+        );
+        if !seen.insert(key) {
+            continue;
+        }
+
+        let rule_id = rule_id(diagnostic);
+        rules.insert(rule_id.clone());
+
+        let mut region = serde_json::Map::new();
+        if let Some(line) = diagnostic.line {
+            region.insert("startLine".to_string(), json!(line));
+        }
+        if let Some(column) = diagnostic.column {
+            region.insert("startColumn".to_string(), json!(column));
+        }
+
+        results.push(json!({
+            "ruleId": rule_id,
+            "level": level(diagnostic.severity),
+            "message": { "text": diagnostic.message },
+            "locations": [{
+                "physicalLocation": {
+                    "artifactLocation": { "uri": uri, "uriBaseId": "%SRCROOT%" },
+                    "region": Value::Object(region),
+                }
+            }],
+        }));
+    }
+
+    let rules: Vec<Value> = rules.into_iter().map(|id| json!({ "id": id })).collect();
+    let root_uri = format!(
+        "file://{}/",
+        project_dir
+            .to_string_lossy()
+            .replace('\\', "/")
+            .trim_end_matches('/')
+    );
+
+    let sarif = json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": tool_name,
+                    "rules": rules,
+                }
+            },
+            "originalUriBaseIds": {
+                "%SRCROOT%": { "uri": root_uri }
+            },
+            "results": results,
+        }],
+    });
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(BuildError::IoError)?;
+    }
+
+    let content = serde_json::to_string_pretty(&sarif)
+        .map_err(|e| BuildError::ConfigParsingError(e.to_string()))?;
+    std::fs::write(path, content).map_err(BuildError::IoError)?;
+
+    Ok(())
+}
+
+/// 경고 옵션(`[-Wunused-variable]`)이 있으면 규칙 ID로 사용
+fn rule_id(diagnostic: &Diagnostic) -> String {
+    lazy_static! {
+        static ref OPTION_RE: Regex = Regex::new(r"\[(-W[^\]]+)\]$").unwrap();
+    }
+
+    match OPTION_RE.captures(&diagnostic.message) {
+        Some(cap) => cap[1].to_string(),
+        None => match diagnostic.severity {
+            Severity::Error => "compiler-error".to_string(),
+            Severity::Warning => "compiler-warning".to_string(),
+            Severity::Note => "compiler-note".to_string(),
+        },
+    }
+}
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Note => "note",
+    }
+}
+
+fn artifact_uri(file: &Path, project_dir: &Path) -> String {
+    file.strip_prefix(project_dir)
+        .unwrap_or(file)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
@@ -0,0 +1,300 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+/// git 태그에서 읽은 버전 (`v1.2.3`, `1.2`, `zlib-1.3.1`, `1.0.0-rc.1` 등)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub pre: Option<String>,
+}
+
+impl Version {
+    /// 버전 문자열 해석 (minor, patch가 없으면 0, `+` 뒤의 빌드 메타데이터는 무시)
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = match text.split_once('+') {
+            Some((version, build)) if !build.is_empty() => version,
+            Some(_) => return None,
+            None => text,
+        };
+        let (core, pre) = match text.split_once('-') {
+            Some((core, pre)) if !pre.is_empty() => (core, Some(pre.to_string())),
+            Some(_) => return None,
+            None => (text, None),
+        };
+        let mut numbers = core.split('.').map(|part| part.parse::<u64>().ok());
+        let major = numbers.next()??;
+        let minor = numbers.next().unwrap_or(Some(0))?;
+        let patch = numbers.next().unwrap_or(Some(0))?;
+        if numbers.next().is_some() {
+            return None;
+        }
+        Some(Version {
+            major,
+            minor,
+            patch,
+            pre,
+        })
+    }
+
+    /// 태그 이름에서 버전 찾기 (앞의 `v`나 `<이름>-` 같은 접두사는 무시)
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        tag.char_indices()
+            .filter(|&(index, c)| {
+                c.is_ascii_digit() && (index == 0 || tag[..index].ends_with(['v', 'V', '-', '_']))
+            })
+            .find_map(|(index, _)| Version::parse(&tag[index..]))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (&self.pre, &other.pre) {
+                // 정식 버전이 같은 번호의 사전 배포 버전보다 높음
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) => compare_pre(a, b),
+            })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if let Some(ref pre) = self.pre {
+            write!(f, "-{}", pre)?;
+        }
+        Ok(())
+    }
+}
+
+/// 사전 배포 식별자 비교 (점으로 나눈 부분마다 숫자는 숫자로, 나머지는 문자열로)
+fn compare_pre(a: &str, b: &str) -> Ordering {
+    let mut a = a.split('.');
+    let mut b = b.split('.');
+    loop {
+        let ordering = match (a.next(), b.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+            (Some(x), Some(y)) => match (x.parse::<u64>(), y.parse::<u64>()) {
+                (Ok(x), Ok(y)) => x.cmp(&y),
+                _ => x.cmp(y),
+            },
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Caret,
+    Tilde,
+    Exact,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+}
+
+/// 버전 조건 하나 (`^1.2`, `>=1.0` 등), 생략한 부분은 `parts`로 구분
+#[derive(Debug, Clone)]
+struct Comparator {
+    op: Op,
+    version: Version,
+    /// 적은 숫자 개수 (`^1` = 1, `~1.2` = 2)
+    parts: usize,
+}
+
+/// 쉼표로 구분한 버전 조건 (모두 만족해야 함, Cargo의 버전 조건 문법)
+///
+/// `^1.2`, `~1.2.3`, `=1.2.3`, `>=1.2, <2`, `*`을 지원한다. 사전 배포 버전은 조건에
+/// 같은 번호의 사전 배포 버전이 적혀 있을 때만 맞는다.
+#[derive(Debug, Clone)]
+pub struct VersionReq {
+    comparators: Vec<Comparator>,
+}
+
+impl VersionReq {
+    /// 버전 조건 해석 (잘못된 조건이면 None)
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        if text == "*" {
+            return Some(VersionReq {
+                comparators: Vec::new(),
+            });
+        }
+
+        let mut comparators = Vec::new();
+        for part in text.split(',') {
+            let part = part.trim();
+            let (op, rest) = [
+                (">=", Op::GreaterEq),
+                ("<=", Op::LessEq),
+                (">", Op::Greater),
+                ("<", Op::Less),
+                ("=", Op::Exact),
+                ("^", Op::Caret),
+                ("~", Op::Tilde),
+            ]
+            .into_iter()
+            .find_map(|(prefix, op)| part.strip_prefix(prefix).map(|rest| (op, rest.trim())))
+            .unwrap_or((Op::Caret, part));
+
+            let rest = rest.strip_prefix('v').unwrap_or(rest);
+            let core = rest.split(['-', '+']).next().unwrap_or(rest);
+            let parts = core.split('.').count();
+            comparators.push(Comparator {
+                op,
+                version: Version::parse(rest)?,
+                parts,
+            });
+        }
+        Some(VersionReq { comparators })
+    }
+
+    /// 버전 조건 문법으로 적은 값인지 (`1.2.3`처럼 연산자가 없으면 버전 표기로 보고 false)
+    pub fn is_requirement(text: &str) -> bool {
+        text.trim_start()
+            .starts_with(['^', '~', '=', '>', '<', '*'])
+    }
+
+    pub fn matches(&self, version: &Version) -> bool {
+        if version.pre.is_some() {
+            let allowed = self.comparators.iter().any(|c| {
+                c.version.pre.is_some()
+                    && (c.version.major, c.version.minor, c.version.patch)
+                        == (version.major, version.minor, version.patch)
+            });
+            if !allowed {
+                return false;
+            }
+        }
+        self.comparators.iter().all(|c| c.matches(version))
+    }
+}
+
+impl Comparator {
+    /// 조건에 적은 숫자까지만 비교 (`1.2`와 비교하면 1.2.x는 모두 Equal)
+    fn cmp_parts(&self, version: &Version) -> Ordering {
+        let v = &self.version;
+        match self.parts {
+            1 => version.major.cmp(&v.major),
+            2 => (version.major, version.minor).cmp(&(v.major, v.minor)),
+            _ => version.cmp(v),
+        }
+    }
+
+    fn matches(&self, version: &Version) -> bool {
+        let v = &self.version;
+        match self.op {
+            Op::Exact => self.cmp_parts(version) == Ordering::Equal,
+            // >1.2 -> >=1.3.0, <=1.2 -> <1.3.0 (생략한 부분은 모든 값과 같다고 봄)
+            Op::Greater => self.cmp_parts(version) == Ordering::Greater,
+            Op::GreaterEq => version >= v,
+            Op::Less => version < v,
+            Op::LessEq => self.cmp_parts(version) != Ordering::Greater,
+            Op::Tilde => {
+                version >= v
+                    && version.major == v.major
+                    && (self.parts == 1 || version.minor == v.minor)
+            }
+            Op::Caret => {
+                if version < v {
+                    return false;
+                }
+                // 0이 아닌 첫 숫자까지 같아야 함 (^1.2 -> 1.x, ^0.2 -> 0.2.x, ^0.0.3 -> 0.0.3)
+                if v.major > 0 || self.parts == 1 {
+                    version.major == v.major
+                } else if v.minor > 0 || self.parts == 2 {
+                    version.major == 0 && version.minor == v.minor
+                } else {
+                    version.major == 0 && version.minor == 0 && version.patch == v.patch
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(text: &str) -> Version {
+        Version::parse(text).unwrap()
+    }
+
+    fn req(text: &str) -> VersionReq {
+        VersionReq::parse(text).unwrap()
+    }
+
+    #[test]
+    fn parse_ignores_build_metadata() {
+        assert_eq!(version("1.2.3+build.5"), version("1.2.3"));
+        assert_eq!(version("1.0.0-rc.1+sha.abc").pre.as_deref(), Some("rc.1"));
+        assert_eq!(Version::from_tag("v2.0.1+20240101"), Some(version("2.0.1")));
+        assert!(Version::parse("1.2.3+").is_none());
+    }
+
+    #[test]
+    fn requirement_ignores_build_metadata() {
+        assert!(req("=1.2.3+build").matches(&version("1.2.3")));
+        assert!(req("^1.2+meta").matches(&version("1.4.0+other")));
+    }
+
+    #[test]
+    fn partial_greater_skips_whole_prefix() {
+        let greater = req(">1.2");
+        assert!(!greater.matches(&version("1.2.0")));
+        assert!(!greater.matches(&version("1.2.9")));
+        assert!(greater.matches(&version("1.3.0")));
+
+        let greater = req(">1");
+        assert!(!greater.matches(&version("1.9.9")));
+        assert!(greater.matches(&version("2.0.0")));
+
+        assert!(req(">1.2.3").matches(&version("1.2.4")));
+        assert!(!req(">1.2.3").matches(&version("1.2.3")));
+    }
+
+    #[test]
+    fn partial_less_eq_includes_whole_prefix() {
+        let less_eq = req("<=1.2");
+        assert!(less_eq.matches(&version("1.2.0")));
+        assert!(less_eq.matches(&version("1.2.9")));
+        assert!(!less_eq.matches(&version("1.3.0")));
+
+        assert!(req("<=1").matches(&version("1.9.9")));
+        assert!(!req("<=1").matches(&version("2.0.0")));
+        assert!(!req("<=1.2.3").matches(&version("1.2.4")));
+    }
+
+    #[test]
+    fn partial_bounds_fill_zeros() {
+        assert!(req(">=1.2").matches(&version("1.2.0")));
+        assert!(!req(">=1.2").matches(&version("1.1.9")));
+        assert!(req("<1.2").matches(&version("1.1.9")));
+        assert!(!req("<1.2").matches(&version("1.2.0")));
+    }
+
+    #[test]
+    fn range_with_partial_bounds() {
+        let range = req(">1.0, <=1.2");
+        assert!(!range.matches(&version("1.0.5")));
+        assert!(range.matches(&version("1.1.0")));
+        assert!(range.matches(&version("1.2.7")));
+        assert!(!range.matches(&version("1.3.0")));
+    }
+}
@@ -0,0 +1,142 @@
+use crate::config::{BuildConfig, SigningSettings};
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use crate::utils::is_tool_installed;
+use colored::Colorize;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// [signing]이 설정되어 있고 이 빌드 구성이 서명 대상인지 (기본: release만)
+pub fn is_enabled(config: &BuildConfig, configuration: &str) -> bool {
+    let signing = &config.signing;
+    let configured = signing.gpg_key.is_some()
+        || signing.codesign_identity.is_some()
+        || signing.signtool_args.is_some();
+    let selected = match signing.configurations {
+        Some(ref configurations) => configurations.iter().any(|c| c == configuration),
+        None => configuration == "release",
+    };
+    configured && selected
+}
+
+/// 현재 플랫폼의 코드 서명 도구가 설정되어 있는지 (macOS codesign, Windows signtool)
+pub fn signs_binaries(settings: &SigningSettings) -> bool {
+    (cfg!(target_os = "macos") && settings.codesign_identity.is_some())
+        || (cfg!(windows) && settings.signtool_args.is_some())
+}
+
+/// 실행 파일이나 공유 라이브러리에 코드 서명 (서명은 파일 안에 들어감)
+pub fn sign_binary(settings: &SigningSettings, path: &Path) -> BuildResult<()> {
+    let mut cmd = if cfg!(target_os = "macos") {
+        let Some(ref identity) = settings.codesign_identity else {
+            return Ok(());
+        };
+        let mut cmd = tool("codesign")?;
+        cmd.args(["--force", "--sign", identity]);
+        cmd.args(settings.codesign_args.iter().flatten());
+        cmd
+    } else if cfg!(windows) {
+        let Some(ref args) = settings.signtool_args else {
+            return Ok(());
+        };
+        let mut cmd = tool("signtool")?;
+        cmd.arg("sign").args(args);
+        cmd
+    } else {
+        return Ok(());
+    };
+
+    println!("{} {}", "Signing".green().bold(), path.display());
+    run(cmd.arg(path))
+}
+
+/// 패키지 파일 옆에 GPG 분리 서명(`<파일>.asc`) 생성
+pub fn sign_archive(settings: &SigningSettings, path: &Path) -> BuildResult<Option<PathBuf>> {
+    let Some(ref key) = settings.gpg_key else {
+        return Ok(None);
+    };
+
+    let signature = signature_path(path);
+    println!("{} {}", "Signing".green().bold(), path.display());
+    let mut cmd = tool("gpg")?;
+    cmd.args([
+        "--batch",
+        "--yes",
+        "--armor",
+        "--detach-sign",
+        "--local-user",
+    ])
+    .arg(key)
+    .arg("--output")
+    .arg(&signature)
+    .arg(path);
+    run(&mut cmd)?;
+    Ok(Some(signature))
+}
+
+/// 실행 파일이나 공유 라이브러리의 코드 서명 확인 (실패하면 이유)
+pub fn verify_binary(settings: &SigningSettings, path: &Path) -> Result<(), String> {
+    let mut cmd = if cfg!(target_os = "macos") && settings.codesign_identity.is_some() {
+        let mut cmd = tool("codesign").map_err(|e| e.to_string())?;
+        cmd.args(["--verify", "--strict"]);
+        cmd
+    } else if cfg!(windows) && settings.signtool_args.is_some() {
+        let mut cmd = tool("signtool").map_err(|e| e.to_string())?;
+        cmd.args(["verify", "/pa"]);
+        cmd
+    } else {
+        return Ok(());
+    };
+    check(cmd.arg(path))
+}
+
+/// 패키지 파일의 GPG 분리 서명 확인 (실패하면 이유)
+pub fn verify_archive(path: &Path) -> Result<(), String> {
+    let signature = signature_path(path);
+    if !signature.exists() {
+        return Err(tr!(
+            "서명 파일이 없습니다: {}",
+            "signature is missing: {}",
+            signature.display()
+        ));
+    }
+    let mut cmd = tool("gpg").map_err(|e| e.to_string())?;
+    cmd.args(["--batch", "--verify"]).arg(&signature).arg(path);
+    check(&mut cmd)
+}
+
+fn signature_path(path: &Path) -> PathBuf {
+    let mut name: OsString = path.as_os_str().to_os_string();
+    name.push(".asc");
+    PathBuf::from(name)
+}
+
+fn tool(name: &str) -> BuildResult<Command> {
+    if !is_tool_installed(name) {
+        return Err(BuildError::ToolchainError(tr!(
+            "[signing]에 필요한 {}을(를) 찾을 수 없습니다",
+            "{} is required by [signing] but was not found",
+            name
+        )));
+    }
+    Ok(Command::new(name))
+}
+
+fn run(cmd: &mut Command) -> BuildResult<()> {
+    check(cmd).map_err(BuildError::ToolchainError)
+}
+
+fn check(cmd: &mut Command) -> Result<(), String> {
+    let program = cmd.get_program().to_string_lossy().to_string();
+    let output = cmd.output().map_err(|e| format!("{}: {}", program, e))?;
+    if output.status.success() {
+        return Ok(());
+    }
+    Err(tr!(
+        "{} 실패: {}",
+        "{} failed: {}",
+        program,
+        String::from_utf8_lossy(&output.stderr).trim()
+    ))
+}
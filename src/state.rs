@@ -0,0 +1,310 @@
+use crate::error::{BuildError, BuildResult};
+use log::{info, warn};
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+/// 상태 파일 식별자와 형식 버전
+const STATE_MAGIC: &[u8; 4] = b"CBST";
+const STATE_VERSION: u32 = 1;
+
+const STATE_FILE: &str = "state.bin";
+const LEGACY_JSON_FILE: &str = "file_hashes.json";
+
+/// SHA-256 파일 해시
+pub type FileHash = [u8; 32];
+
+/// 증분 빌드 상태 (파일 해시, 헤더 의존성, 플래그 지문)
+///
+/// 수만 개 파일 규모에서도 빠르게 읽고 쓸 수 있도록 길이 접두사가 붙은
+/// 리틀 엔디언 바이너리 형식으로 `build/<cfg>/state.bin`에 저장한다.
+/// 이전 버전의 `file_hashes.json`은 처음 로드할 때 자동으로 변환된다.
+#[derive(Debug, Default, Clone)]
+pub struct IncrementalState {
+    /// 파일 경로 -> 내용 해시
+    pub file_hashes: HashMap<String, FileHash>,
+    /// 소스 파일 경로 -> 포함하는 헤더 경로 목록
+    pub dependencies: HashMap<String, Vec<String>>,
+    /// 키(타겟 등) -> 컴파일 플래그 지문
+    pub fingerprints: HashMap<String, String>,
+}
+
+impl IncrementalState {
+    /// 빌드 디렉토리에서 상태 로드 (없으면 빈 상태, 손상된 경우 경고 후 빈 상태)
+    pub fn load(build_dir: &Path) -> BuildResult<Self> {
+        let state_path = build_dir.join(STATE_FILE);
+
+        if state_path.exists() {
+            let data = std::fs::read(&state_path).map_err(BuildError::IoError)?;
+            return match decode(&data) {
+                Ok(state) => Ok(state),
+                Err(e) => {
+                    warn!(
+                        "증분 빌드 상태를 읽을 수 없어 전체 빌드합니다 ({}): {}",
+                        state_path.display(),
+                        e
+                    );
+                    Ok(IncrementalState::default())
+                }
+            };
+        }
+
+        let legacy_path = build_dir.join(LEGACY_JSON_FILE);
+        if legacy_path.exists() {
+            return Self::migrate_legacy(build_dir, &legacy_path);
+        }
+
+        Ok(IncrementalState::default())
+    }
+
+    /// 상태를 임시 파일에 쓴 뒤 교체해 중간에 중단되어도 손상되지 않게 저장
+    pub fn save(&self, build_dir: &Path) -> BuildResult<()> {
+        let state_path = build_dir.join(STATE_FILE);
+        let tmp_path = build_dir.join(format!("{}.tmp", STATE_FILE));
+
+        let mut file = std::fs::File::create(&tmp_path).map_err(BuildError::IoError)?;
+        file.write_all(&encode(self)).map_err(BuildError::IoError)?;
+        drop(file);
+
+        std::fs::rename(&tmp_path, &state_path).map_err(BuildError::IoError)?;
+        Ok(())
+    }
+
+    /// 기존 JSON 해시 파일(`{"경로": "16진수 해시"}`)을 바이너리 상태로 변환
+    fn migrate_legacy(build_dir: &Path, legacy_path: &PathBuf) -> BuildResult<Self> {
+        info!(
+            "증분 빌드 상태를 바이너리 형식으로 변환 중: {}",
+            legacy_path.display()
+        );
+
+        let content = std::fs::read_to_string(legacy_path).map_err(BuildError::IoError)?;
+        let hashes: HashMap<String, String> = serde_json::from_str(&content)
+            .map_err(|e| BuildError::ConfigParsingError(e.to_string()))?;
+
+        let mut state = IncrementalState::default();
+        for (path, hex) in hashes {
+            if let Some(hash) = decode_hex_hash(&hex) {
+                state.file_hashes.insert(path, hash);
+            }
+        }
+
+        state.save(build_dir)?;
+        std::fs::remove_file(legacy_path).map_err(BuildError::IoError)?;
+
+        Ok(state)
+    }
+}
+
+fn encode(state: &IncrementalState) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(STATE_MAGIC);
+    buf.extend_from_slice(&STATE_VERSION.to_le_bytes());
+
+    // 출력이 실행마다 같도록 키 순서로 정렬
+    let mut hashes: Vec<_> = state.file_hashes.iter().collect();
+    hashes.sort();
+    write_u32(&mut buf, hashes.len());
+    for (path, hash) in hashes {
+        write_str(&mut buf, path);
+        buf.extend_from_slice(hash);
+    }
+
+    let mut dependencies: Vec<_> = state.dependencies.iter().collect();
+    dependencies.sort();
+    write_u32(&mut buf, dependencies.len());
+    for (source, headers) in dependencies {
+        write_str(&mut buf, source);
+        write_u32(&mut buf, headers.len());
+        for header in headers {
+            write_str(&mut buf, header);
+        }
+    }
+
+    let mut fingerprints: Vec<_> = state.fingerprints.iter().collect();
+    fingerprints.sort();
+    write_u32(&mut buf, fingerprints.len());
+    for (key, value) in fingerprints {
+        write_str(&mut buf, key);
+        write_str(&mut buf, value);
+    }
+
+    buf
+}
+
+fn decode(data: &[u8]) -> Result<IncrementalState, std::io::Error> {
+    let mut reader = data;
+
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic)?;
+    if &magic != STATE_MAGIC {
+        return Err(invalid_data("상태 파일 형식이 아닙니다"));
+    }
+
+    let version = read_u32(&mut reader)?;
+    if version != STATE_VERSION {
+        return Err(invalid_data(&format!(
+            "지원하지 않는 상태 파일 버전: {}",
+            version
+        )));
+    }
+
+    let mut state = IncrementalState::default();
+
+    for _ in 0..read_u32(&mut reader)? {
+        let path = read_str(&mut reader)?;
+        let mut hash = [0u8; 32];
+        reader.read_exact(&mut hash)?;
+        state.file_hashes.insert(path, hash);
+    }
+
+    for _ in 0..read_u32(&mut reader)? {
+        let source = read_str(&mut reader)?;
+        let count = read_u32(&mut reader)?;
+        // 손상된 파일의 큰 개수로 메모리를 미리 잡지 않도록 남은 크기로 제한 (문자열마다 4바이트 이상)
+        let mut headers = Vec::with_capacity((count as usize).min(reader.len() / 4));
+        for _ in 0..count {
+            headers.push(read_str(&mut reader)?);
+        }
+        state.dependencies.insert(source, headers);
+    }
+
+    for _ in 0..read_u32(&mut reader)? {
+        let key = read_str(&mut reader)?;
+        let value = read_str(&mut reader)?;
+        state.fingerprints.insert(key, value);
+    }
+
+    Ok(state)
+}
+
+fn write_u32(buf: &mut Vec<u8>, value: usize) {
+    buf.extend_from_slice(&(value as u32).to_le_bytes());
+}
+
+fn write_str(buf: &mut Vec<u8>, value: &str) {
+    write_u32(buf, value.len());
+    buf.extend_from_slice(value.as_bytes());
+}
+
+fn read_u32(reader: &mut &[u8]) -> Result<u32, std::io::Error> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_str(reader: &mut &[u8]) -> Result<String, std::io::Error> {
+    let len = read_u32(reader)? as usize;
+    if len > reader.len() {
+        return Err(invalid_data("문자열 길이가 파일 크기를 넘습니다"));
+    }
+
+    let (bytes, rest) = reader.split_at(len);
+    *reader = rest;
+    String::from_utf8(bytes.to_vec()).map_err(|_| invalid_data("잘못된 UTF-8 문자열"))
+}
+
+fn invalid_data(message: &str) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn decode_hex_hash(hex: &str) -> Option<FileHash> {
+    if hex.len() != 64 || !hex.is_ascii() {
+        return None;
+    }
+
+    let mut hash = [0u8; 32];
+    for (i, byte) in hash.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok()?;
+    }
+    Some(hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_state() -> IncrementalState {
+        let mut state = IncrementalState::default();
+        state
+            .file_hashes
+            .insert("src/main.c".to_string(), [7u8; 32]);
+        state
+            .file_hashes
+            .insert("include/app.h".to_string(), [1u8; 32]);
+        state.dependencies.insert(
+            "src/main.c".to_string(),
+            vec!["include/app.h".to_string(), "include/한글.h".to_string()],
+        );
+        state
+            .fingerprints
+            .insert("exe:main".to_string(), "-O2 -g".to_string());
+        state
+    }
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let state = sample_state();
+        let decoded = decode(&encode(&state)).unwrap();
+        assert_eq!(decoded.file_hashes, state.file_hashes);
+        assert_eq!(decoded.dependencies, state.dependencies);
+        assert_eq!(decoded.fingerprints, state.fingerprints);
+    }
+
+    #[test]
+    fn encode_is_deterministic() {
+        let state = sample_state();
+        assert_eq!(encode(&state), encode(&state.clone()));
+    }
+
+    #[test]
+    fn truncated_input_is_an_error() {
+        let data = encode(&sample_state());
+        for len in 0..data.len() {
+            assert!(decode(&data[..len]).is_err(), "length {}", len);
+        }
+    }
+
+    #[test]
+    fn huge_header_count_is_an_error() {
+        let mut data = Vec::new();
+        data.extend_from_slice(STATE_MAGIC);
+        data.extend_from_slice(&STATE_VERSION.to_le_bytes());
+        write_u32(&mut data, 0);
+        write_u32(&mut data, 1);
+        write_str(&mut data, "src/main.c");
+        data.extend_from_slice(&u32::MAX.to_le_bytes());
+        assert!(decode(&data).is_err());
+    }
+
+    #[test]
+    fn wrong_magic_or_version_is_an_error() {
+        let mut data = encode(&sample_state());
+        data[0] = b'X';
+        assert!(decode(&data).is_err());
+
+        let mut data = encode(&sample_state());
+        data[4..8].copy_from_slice(&(STATE_VERSION + 1).to_le_bytes());
+        assert!(decode(&data).is_err());
+    }
+
+    #[test]
+    fn load_falls_back_to_empty_state_when_corrupt() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut data = encode(&sample_state());
+        data.truncate(data.len() - 3);
+        std::fs::write(dir.path().join(STATE_FILE), data).unwrap();
+
+        let state = IncrementalState::load(dir.path()).unwrap();
+        assert!(state.file_hashes.is_empty());
+        assert!(state.dependencies.is_empty());
+    }
+
+    #[test]
+    fn save_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let state = sample_state();
+        state.save(dir.path()).unwrap();
+        let loaded = IncrementalState::load(dir.path()).unwrap();
+        assert_eq!(loaded.file_hashes, state.file_hashes);
+    }
+}
@@ -0,0 +1,378 @@
+use crate::builder::Builder;
+use crate::context::ProjectContext;
+use crate::error::{BuildError, BuildResult};
+use crate::history::format_timestamp;
+use crate::i18n::tr;
+use crate::ide::include_dirs;
+use crate::parser::DependencyParser;
+use crate::utils::format_size;
+use crate::vcs::head_commit;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 추이에 표시할 이전 측정 수
+const HISTORY_ROWS: usize = 10;
+
+/// 타겟 하나의 크기 지표 (`cbuild stats --json`의 targets 항목)
+#[derive(Debug, Serialize, Default)]
+struct TargetStats {
+    /// 타겟 키 (exe:app, static:core 등), 합계는 "total"
+    target: String,
+    source_files: usize,
+    /// 소스 파일의 줄 수 (빈 줄 포함)
+    lines: usize,
+    /// 빌드된 객체 파일 크기의 합 (바이트)
+    object_size: u64,
+    /// 최종 결과물 크기 (아직 빌드하지 않았으면 None)
+    artifact_size: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+struct ProjectStats {
+    configuration: String,
+    targets: Vec<TargetStats>,
+    total: TargetStats,
+}
+
+/// `cbuild stats headers` 측정 한 번 (build/.history/header-stats.jsonl의 한 줄)
+#[derive(Debug, Serialize, Deserialize)]
+struct HeaderSnapshot {
+    /// 측정 시각 (UNIX 초)
+    timestamp: u64,
+    configuration: String,
+    git_commit: Option<String>,
+    translation_units: usize,
+    headers: usize,
+    /// 번역 단위 하나가 전이적으로 포함하는 프로젝트 헤더 수의 평균
+    average_includes: f64,
+    max_depth: usize,
+}
+
+/// 파일에서 시작하는 가장 긴 포함 경로 (파일 자신 포함)
+///
+/// 탐색 중인 파일을 다시 만나면 순환이므로 더 들어가지 않는다.
+fn deepest_chain(
+    file: &Path,
+    edges: &BTreeMap<PathBuf, Vec<PathBuf>>,
+    stack: &mut Vec<PathBuf>,
+    memo: &mut BTreeMap<PathBuf, Vec<PathBuf>>,
+) -> Vec<PathBuf> {
+    if let Some(chain) = memo.get(file) {
+        return chain.clone();
+    }
+
+    stack.push(file.to_path_buf());
+    let mut longest = Vec::new();
+    for header in edges.get(file).into_iter().flatten() {
+        if stack.contains(header) {
+            continue;
+        }
+        let chain = deepest_chain(header, edges, stack, memo);
+        if chain.len() > longest.len() {
+            longest = chain;
+        }
+    }
+    stack.pop();
+
+    let mut chain = vec![file.to_path_buf()];
+    chain.extend(longest);
+    memo.insert(file.to_path_buf(), chain.clone());
+    chain
+}
+
+/// `cbuild stats`: 타겟별 소스 파일 수, 줄 수, 객체 파일 크기, 결과물 크기 출력
+///
+/// 크기는 마지막 빌드의 출력 디렉토리에서 읽으므로 빌드하지 않은 파일은 0 또는 "-"로
+/// 표시한다. `json`이면 같은 내용을 JSON으로 출력한다.
+pub fn print_target_stats(
+    context: Arc<ProjectContext>,
+    configuration: &str,
+    out_dir: Option<PathBuf>,
+    json: bool,
+) -> BuildResult<()> {
+    let project_dir = context.project_dir();
+    let mut builder = Builder::with_context(context.clone(), configuration, 1)?;
+    builder.set_output_dir(out_dir);
+    let targets = builder.compile_info()?;
+    let layout = builder.layout();
+    let file_size = |path: &Path| std::fs::metadata(path).ok().map(|m| m.len());
+
+    let mut total = TargetStats {
+        target: "total".to_string(),
+        ..Default::default()
+    };
+    let mut stats = Vec::new();
+    for target in &targets {
+        let artifact = match target.target_type.as_str() {
+            "exe" => Some(layout.executable_path(&target.name)),
+            "static" => Some(layout.static_lib_path(&target.name)),
+            "shared" => Some(layout.shared_lib_path(&target.name)),
+            "test" => Some(layout.test_path(&target.name)),
+            _ => None,
+        };
+        let mut entry = TargetStats {
+            target: format!("{}:{}", target.target_type, target.name),
+            source_files: target.sources.len(),
+            artifact_size: artifact.as_deref().and_then(file_size),
+            ..Default::default()
+        };
+        for source in &target.sources {
+            let content = std::fs::read(source).map_err(BuildError::IoError)?;
+            entry.lines += content.split(|&b| b == b'\n').count()
+                - usize::from(content.is_empty() || content.ends_with(b"\n"));
+            entry.object_size += file_size(&layout.object_file(project_dir, source)).unwrap_or(0);
+        }
+
+        total.source_files += entry.source_files;
+        total.lines += entry.lines;
+        total.object_size += entry.object_size;
+        if let Some(size) = entry.artifact_size {
+            *total.artifact_size.get_or_insert(0) += size;
+        }
+        stats.push(entry);
+    }
+
+    if json {
+        let report = ProjectStats {
+            configuration: configuration.to_string(),
+            targets: stats,
+            total,
+        };
+        let json = serde_json::to_string_pretty(&report)
+            .map_err(|e| BuildError::ConfigParsingError(e.to_string()))?;
+        println!("{}", json);
+        return Ok(());
+    }
+
+    let width = stats
+        .iter()
+        .map(|entry| entry.target.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(tr!("타겟", "Target").chars().count());
+    println!(
+        "{:<width$}  {:>6}  {:>8}  {:>10}  {:>10}",
+        tr!("타겟", "Target"),
+        tr!("소스", "Files"),
+        tr!("줄", "Lines"),
+        tr!("객체", "Objects"),
+        tr!("결과물", "Artifact"),
+        width = width
+    );
+    for (index, entry) in stats.iter().chain([&total]).enumerate() {
+        let line = format!(
+            "{:<width$}  {:>6}  {:>8}  {:>10}  {:>10}",
+            if index == stats.len() {
+                tr!("합계", "Total")
+            } else {
+                entry.target.clone()
+            },
+            entry.source_files,
+            entry.lines,
+            format_size(entry.object_size),
+            entry.artifact_size.map_or("-".to_string(), format_size),
+            width = width
+        );
+        if index == stats.len() {
+            println!("{}", line.bold());
+        } else {
+            println!("{}", line);
+        }
+    }
+
+    Ok(())
+}
+
+/// `cbuild stats headers`: 헤더 포함 관계 지표 출력
+///
+/// `cbuild graph`와 같은 포함 관계에서 가장 많은 번역 단위가 포함하는 헤더,
+/// 가장 깊은 포함 경로, 번역 단위당 평균 포함 수를 구한다. 측정 결과는
+/// build/.history/header-stats.jsonl에 쌓아 이전 측정과의 추이를 함께 보여준다.
+pub fn print_header_stats(
+    context: Arc<ProjectContext>,
+    configuration: &str,
+    out_dir: Option<PathBuf>,
+    top: usize,
+) -> BuildResult<()> {
+    let project_dir = context.project_dir();
+    let mut builder = Builder::with_context(context.clone(), configuration, 1)?;
+    builder.set_output_dir(out_dir);
+    let targets = builder.compile_info()?;
+
+    let parser = DependencyParser::new();
+    let mut edges: BTreeMap<PathBuf, Vec<PathBuf>> = BTreeMap::new();
+    // 번역 단위 -> 전이적으로 포함하는 헤더 (여러 타겟에 속한 소스는 처음 타겟 기준)
+    let mut units: BTreeMap<PathBuf, BTreeSet<PathBuf>> = BTreeMap::new();
+
+    for target in &targets {
+        let search_dirs = include_dirs(target);
+
+        for source in &target.sources {
+            if units.contains_key(source) {
+                continue;
+            }
+            let headers = parser.parse_dependencies(source, &search_dirs)?;
+            units.insert(source.clone(), headers.into_iter().collect());
+
+            let mut pending = vec![source.clone()];
+            while let Some(file) = pending.pop() {
+                if edges.contains_key(&file) {
+                    continue;
+                }
+                let headers = parser.direct_dependencies(&file, &search_dirs)?;
+                pending.extend(headers.iter().cloned());
+                edges.insert(file, headers);
+            }
+        }
+    }
+
+    if units.is_empty() {
+        println!(
+            "{}",
+            tr!("분석할 소스가 없습니다.", "No sources to analyze.")
+        );
+        return Ok(());
+    }
+
+    let relative = |path: &Path| {
+        path.strip_prefix(project_dir)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    };
+
+    // 헤더별 포함하는 번역 단위 수
+    let mut fan_in: BTreeMap<&PathBuf, usize> = BTreeMap::new();
+    for headers in units.values() {
+        for header in headers {
+            *fan_in.entry(header).or_default() += 1;
+        }
+    }
+    let mut most_included: Vec<(&PathBuf, usize)> = fan_in.into_iter().collect();
+    most_included.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+    // 번역 단위별 가장 깊은 포함 경로
+    let mut memo = BTreeMap::new();
+    let mut chains: Vec<Vec<PathBuf>> = units
+        .keys()
+        .map(|source| deepest_chain(source, &edges, &mut Vec::new(), &mut memo))
+        .collect();
+    chains.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+
+    let total_includes: usize = units.values().map(|headers| headers.len()).sum();
+    let snapshot = HeaderSnapshot {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+        configuration: configuration.to_string(),
+        git_commit: head_commit(project_dir),
+        translation_units: units.len(),
+        headers: most_included.len(),
+        average_includes: total_includes as f64 / units.len() as f64,
+        max_depth: chains.first().map_or(0, |chain| chain.len() - 1),
+    };
+
+    println!(
+        "{}",
+        tr!("가장 많이 포함되는 헤더:", "Most included headers:").bold()
+    );
+    for (header, count) in most_included.iter().take(top) {
+        println!(
+            "  {:>5}  {:>5}%  {}",
+            count,
+            format!("{:.1}", *count as f64 * 100.0 / units.len() as f64),
+            relative(header)
+        );
+    }
+
+    println!(
+        "\n{}",
+        tr!("가장 깊은 포함 경로:", "Deepest include chains:").bold()
+    );
+    for chain in chains.iter().take(top).filter(|chain| chain.len() > 1) {
+        println!(
+            "  {:>3}  {}",
+            chain.len() - 1,
+            chain
+                .iter()
+                .map(|path| relative(path))
+                .collect::<Vec<_>>()
+                .join(" -> ")
+        );
+    }
+
+    println!(
+        "\n{}",
+        tr!(
+            "번역 단위 {}개, 헤더 {}개, 번역 단위당 평균 포함 {}개, 최대 깊이 {}",
+            "{} translation units, {} headers, {} includes per unit on average, max depth {}",
+            snapshot.translation_units,
+            snapshot.headers,
+            format!("{:.1}", snapshot.average_includes),
+            snapshot.max_depth
+        )
+    );
+
+    let previous = record_snapshot(&builder.layout().header_stats_path(), &snapshot)?;
+    if previous.is_empty() {
+        return Ok(());
+    }
+
+    println!("\n{}", tr!("추이:", "History:").bold());
+    println!(
+        "  {:<20}  {:<12}  {:>6}  {:>8}  {:>6}",
+        tr!("시각 (UTC)", "Time (UTC)"),
+        tr!("커밋", "Commit"),
+        "TU",
+        tr!("평균 포함", "Avg incl"),
+        tr!("깊이", "Depth")
+    );
+    let skip = previous.len().saturating_sub(HISTORY_ROWS - 1);
+    for entry in previous.iter().skip(skip).chain([&snapshot]) {
+        let commit = entry
+            .git_commit
+            .as_deref()
+            .map(|c| &c[..c.len().min(12)])
+            .unwrap_or("-");
+        println!(
+            "  {:<20}  {:<12}  {:>6}  {:>8.1}  {:>6}",
+            format_timestamp(entry.timestamp),
+            commit,
+            entry.translation_units,
+            entry.average_includes,
+            entry.max_depth
+        );
+    }
+
+    Ok(())
+}
+
+/// 같은 구성의 이전 측정을 읽고 이번 측정을 덧붙임 (읽을 수 없는 줄은 건너뜀)
+fn record_snapshot(path: &Path, snapshot: &HeaderSnapshot) -> BuildResult<Vec<HeaderSnapshot>> {
+    let previous: Vec<HeaderSnapshot> = std::fs::read_to_string(path)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| serde_json::from_str::<HeaderSnapshot>(line).ok())
+        .filter(|entry| entry.configuration == snapshot.configuration)
+        .collect();
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(BuildError::IoError)?;
+    }
+    let line = serde_json::to_string(snapshot)
+        .map_err(|e| BuildError::ConfigParsingError(e.to_string()))?;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(BuildError::IoError)?;
+    writeln!(file, "{}", line).map_err(BuildError::IoError)?;
+
+    Ok(previous)
+}
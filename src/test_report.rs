@@ -0,0 +1,493 @@
+use crate::error::{BuildError, BuildResult};
+use crate::utils::xml_escape;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+use std::path::Path;
+use std::time::Duration;
+
+/// 테스트 실행 파일의 출력 형식
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum TestOutputFormat {
+    /// 출력을 해석하지 않고 종료 코드로만 판정
+    #[default]
+    ExitCode,
+    /// Test Anything Protocol (`ok 1 - name`, `not ok 2 - name`)
+    Tap,
+    /// Unity (`file:line:test:PASS`)
+    Unity,
+    /// CMocka (`[       OK ] test`)
+    Cmocka,
+    /// greatest 상세 출력 (`-v`: `PASS test: ...`)
+    Greatest,
+}
+
+impl std::str::FromStr for TestOutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "exit-code" => Ok(TestOutputFormat::ExitCode),
+            "tap" => Ok(TestOutputFormat::Tap),
+            "unity" => Ok(TestOutputFormat::Unity),
+            "cmocka" => Ok(TestOutputFormat::Cmocka),
+            "greatest" => Ok(TestOutputFormat::Greatest),
+            _ => Err(format!(
+                "exit-code, tap, unity, cmocka, greatest 중 하나여야 합니다: {}",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseStatus {
+    Passed,
+    Failed,
+    Skipped,
+    /// 격리된(불안정한) 테스트의 실패. 빌드 실패로 보지 않는다.
+    Quarantined,
+}
+
+/// 테스트 케이스 하나의 결과
+#[derive(Debug, Clone)]
+pub struct TestCaseResult {
+    pub name: String,
+    pub status: CaseStatus,
+    pub message: Option<String>,
+}
+
+/// 테스트 실행 파일 하나의 결과 (JUnit testsuite)
+#[derive(Debug, Clone)]
+pub struct TestSuiteResult {
+    pub name: String,
+    pub cases: Vec<TestCaseResult>,
+    pub duration: Duration,
+    /// 실행 파일의 표준 출력과 오류 출력
+    pub output: String,
+}
+
+impl TestSuiteResult {
+    pub fn count(&self, status: CaseStatus) -> usize {
+        self.cases.iter().filter(|c| c.status == status).count()
+    }
+}
+
+/// 테스트 출력에서 케이스별 결과 추출
+pub fn parse_test_output(format: TestOutputFormat, output: &str) -> Vec<TestCaseResult> {
+    match format {
+        TestOutputFormat::ExitCode => Vec::new(),
+        TestOutputFormat::Tap => parse_tap(output),
+        TestOutputFormat::Unity => parse_unity(output),
+        TestOutputFormat::Cmocka => parse_cmocka(output),
+        TestOutputFormat::Greatest => parse_greatest(output),
+    }
+}
+
+fn parse_tap(output: &str) -> Vec<TestCaseResult> {
+    lazy_static! {
+        static ref TAP_RE: Regex =
+            Regex::new(r"^\s*(ok|not ok)\b\s*(\d+)?\s*(?:-\s*)?([^#]*?)\s*(?:#\s*(\w+)\s*(.*))?$")
+                .unwrap();
+    }
+
+    output
+        .lines()
+        .filter_map(|line| {
+            let cap = TAP_RE.captures(line)?;
+            let directive = cap.get(4).map(|d| d.as_str().to_uppercase());
+            let reason = cap.get(5).map(|r| r.as_str().to_string());
+
+            let name = match cap.get(3).map(|n| n.as_str()) {
+                Some(name) if !name.is_empty() => name.to_string(),
+                _ => format!("test {}", cap.get(2).map_or("?", |n| n.as_str())),
+            };
+
+            // SKIP은 건너뜀, TODO는 실패해도 실패로 보지 않음
+            let status = match (&cap[1], directive.as_deref()) {
+                (_, Some("SKIP")) => CaseStatus::Skipped,
+                ("not ok", Some("TODO")) => CaseStatus::Skipped,
+                ("ok", _) => CaseStatus::Passed,
+                _ => CaseStatus::Failed,
+            };
+
+            Some(TestCaseResult {
+                name,
+                status,
+                message: reason.filter(|r| !r.is_empty()),
+            })
+        })
+        .collect()
+}
+
+fn parse_unity(output: &str) -> Vec<TestCaseResult> {
+    lazy_static! {
+        static ref UNITY_RE: Regex =
+            Regex::new(r"^(.+?):(\d+):([A-Za-z_]\w*):(PASS|FAIL|IGNORE)(?::\s*(.*))?$").unwrap();
+    }
+
+    output
+        .lines()
+        .filter_map(|line| {
+            let cap = UNITY_RE.captures(line.trim_end())?;
+            let status = match &cap[4] {
+                "PASS" => CaseStatus::Passed,
+                "IGNORE" => CaseStatus::Skipped,
+                _ => CaseStatus::Failed,
+            };
+            // 통과한 케이스에는 위치 정보가 필요 없음
+            let message = match (status, cap.get(5)) {
+                (_, Some(m)) => Some(format!("{}:{}: {}", &cap[1], &cap[2], m.as_str())),
+                (CaseStatus::Failed, None) => Some(format!("{}:{}", &cap[1], &cap[2])),
+                _ => None,
+            };
+
+            Some(TestCaseResult {
+                name: cap[3].to_string(),
+                status,
+                message,
+            })
+        })
+        .collect()
+}
+
+fn parse_cmocka(output: &str) -> Vec<TestCaseResult> {
+    lazy_static! {
+        static ref CMOCKA_RE: Regex =
+            Regex::new(r"^\[\s*(OK|FAILED|SKIPPED|ERROR|RUN|LINE)\s*\]\s*(?:---\s*)?(.*)$")
+                .unwrap();
+    }
+
+    let mut cases = Vec::new();
+    // 케이스 결과 줄 앞에 출력되는 실패 메시지
+    let mut pending_message: Vec<String> = Vec::new();
+
+    for line in output.lines() {
+        let Some(cap) = CMOCKA_RE.captures(line.trim_end()) else {
+            continue;
+        };
+        let text = cap[2].trim().to_string();
+
+        let status = match &cap[1] {
+            "RUN" => {
+                pending_message.clear();
+                continue;
+            }
+            "ERROR" | "LINE" => {
+                pending_message.push(text);
+                continue;
+            }
+            "OK" => CaseStatus::Passed,
+            "SKIPPED" => CaseStatus::Skipped,
+            _ => CaseStatus::Failed,
+        };
+
+        // 요약 줄 ("[  FAILED  ] 1 test(s), listed below:") 제외
+        if text.is_empty() || text.contains("test(s)") {
+            continue;
+        }
+
+        let message = if status == CaseStatus::Failed && !pending_message.is_empty() {
+            Some(pending_message.join("\n"))
+        } else {
+            None
+        };
+        pending_message.clear();
+
+        // 실패 목록은 요약에서 한 번 더 출력되므로 중복 제거
+        if cases.iter().any(|c: &TestCaseResult| c.name == text) {
+            continue;
+        }
+
+        cases.push(TestCaseResult {
+            name: text,
+            status,
+            message,
+        });
+    }
+
+    cases
+}
+
+fn parse_greatest(output: &str) -> Vec<TestCaseResult> {
+    lazy_static! {
+        static ref GREATEST_RE: Regex =
+            Regex::new(r"^(PASS|FAIL|SKIP)\s+([A-Za-z_]\w*)(?::\s*(.*))?$").unwrap();
+    }
+
+    output
+        .lines()
+        .filter_map(|line| {
+            let cap = GREATEST_RE.captures(line.trim())?;
+            let status = match &cap[1] {
+                "PASS" => CaseStatus::Passed,
+                "SKIP" => CaseStatus::Skipped,
+                _ => CaseStatus::Failed,
+            };
+
+            Some(TestCaseResult {
+                name: cap[2].to_string(),
+                status,
+                message: cap
+                    .get(3)
+                    .map(|m| m.as_str().trim().to_string())
+                    .filter(|m| !m.is_empty()),
+            })
+        })
+        .collect()
+}
+
+/// 테스트 결과를 JUnit XML 보고서로 저장
+pub fn write_junit(path: &Path, suites: &[TestSuiteResult]) -> BuildResult<()> {
+    let total: usize = suites.iter().map(|s| s.cases.len()).sum();
+    let failures: usize = suites.iter().map(|s| s.count(CaseStatus::Failed)).sum();
+    let skipped: usize = suites.iter().map(skipped_count).sum();
+    let time: f64 = suites.iter().map(|s| s.duration.as_secs_f64()).sum();
+
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.3}\">",
+        total, failures, skipped, time
+    );
+
+    for suite in suites {
+        let _ = writeln!(
+            xml,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.3}\">",
+            xml_escape(&suite.name),
+            suite.cases.len(),
+            suite.count(CaseStatus::Failed),
+            skipped_count(suite),
+            suite.duration.as_secs_f64()
+        );
+
+        for case in &suite.cases {
+            let _ = write!(
+                xml,
+                "    <testcase classname=\"{}\" name=\"{}\"",
+                xml_escape(&suite.name),
+                xml_escape(&case.name)
+            );
+            let message = xml_escape(case.message.as_deref().unwrap_or(""));
+            match case.status {
+                CaseStatus::Passed => xml.push_str(" />\n"),
+                CaseStatus::Failed => {
+                    let _ = write!(
+                        xml,
+                        ">\n      <failure message=\"{}\">{}</failure>\n    </testcase>\n",
+                        message, message
+                    );
+                }
+                CaseStatus::Skipped => {
+                    let _ = write!(
+                        xml,
+                        ">\n      <skipped message=\"{}\" />\n    </testcase>\n",
+                        message
+                    );
+                }
+                CaseStatus::Quarantined => {
+                    let _ = write!(
+                        xml,
+                        ">\n      <skipped message=\"quarantined: {}\" />\n    </testcase>\n",
+                        message
+                    );
+                }
+            }
+        }
+
+        if !suite.output.is_empty() {
+            let _ = writeln!(
+                xml,
+                "    <system-out>{}</system-out>",
+                xml_escape(&suite.output)
+            );
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(BuildError::IoError)?;
+    }
+    std::fs::write(path, xml).map_err(BuildError::IoError)?;
+
+    Ok(())
+}
+
+/// JUnit에서는 격리된 실패를 건너뛴 테스트로 보고
+fn skipped_count(suite: &TestSuiteResult) -> usize {
+    suite.count(CaseStatus::Skipped) + suite.count(CaseStatus::Quarantined)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(cases: &[TestCaseResult]) -> Vec<(&str, CaseStatus, Option<&str>)> {
+        cases
+            .iter()
+            .map(|c| (c.name.as_str(), c.status, c.message.as_deref()))
+            .collect()
+    }
+
+    #[test]
+    fn tap_directives() {
+        let output = "\
+TAP version 13
+1..5
+ok 1 - parses empty input
+not ok 2 - handles overflow
+  ---
+  message: 'expected 0, got -1'
+  ...
+ok 3 - network lookup # SKIP no network
+not ok 4 - unicode names # TODO not implemented
+ok 5
+";
+        assert_eq!(
+            summary(&parse_tap(output)),
+            vec![
+                ("parses empty input", CaseStatus::Passed, None),
+                ("handles overflow", CaseStatus::Failed, None),
+                ("network lookup", CaseStatus::Skipped, Some("no network")),
+                (
+                    "unicode names",
+                    CaseStatus::Skipped,
+                    Some("not implemented")
+                ),
+                ("test 5", CaseStatus::Passed, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn unity_ignore_with_and_without_message() {
+        let output = "\
+test/test_math.c:20:test_add:PASS
+test/test_math.c:25:test_sub:FAIL: Expected 3 Was 4
+test/test_math.c:30:test_todo:IGNORE
+test/test_math.c:35:test_later:IGNORE: Not implemented
+
+-----------------------
+4 Tests 1 Failures 2 Ignored 
+FAIL
+";
+        assert_eq!(
+            summary(&parse_unity(output)),
+            vec![
+                ("test_add", CaseStatus::Passed, None),
+                (
+                    "test_sub",
+                    CaseStatus::Failed,
+                    Some("test/test_math.c:25: Expected 3 Was 4")
+                ),
+                ("test_todo", CaseStatus::Skipped, None),
+                (
+                    "test_later",
+                    CaseStatus::Skipped,
+                    Some("test/test_math.c:35: Not implemented")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn cmocka_skips_summary_and_duplicates() {
+        let output = "\
+[==========] Running 3 test(s).
+[ RUN      ] null_test_success
+[       OK ] null_test_success
+[ RUN      ] failing_test
+[  ERROR   ] --- 0x1 != 0x2
+[   LINE   ] --- tests/test_math.c:12: error: Failure!
+[  FAILED  ] failing_test
+[ RUN      ] skipped_test
+[  SKIPPED ] skipped_test
+[==========] 3 test(s) run.
+[  PASSED  ] 1 test(s).
+[  FAILED  ] 1 test(s), listed below:
+[  FAILED  ] failing_test
+
+ 1 FAILED TEST(S)
+";
+        assert_eq!(
+            summary(&parse_cmocka(output)),
+            vec![
+                ("null_test_success", CaseStatus::Passed, None),
+                (
+                    "failing_test",
+                    CaseStatus::Failed,
+                    Some("0x1 != 0x2\ntests/test_math.c:12: error: Failure!")
+                ),
+                ("skipped_test", CaseStatus::Skipped, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn greatest_verbose_output() {
+        let output = "\
+* Suite math_suite:
+PASS add_works: 
+FAIL sub_works: expected 3, got 4 (test_math.c:22)
+SKIP todo_case: not yet
+.
+3 tests - 1 passed, 1 failed, 1 skipped (12 ticks, 0.000 sec)
+";
+        assert_eq!(
+            summary(&parse_greatest(output)),
+            vec![
+                ("add_works", CaseStatus::Passed, None),
+                (
+                    "sub_works",
+                    CaseStatus::Failed,
+                    Some("expected 3, got 4 (test_math.c:22)")
+                ),
+                ("todo_case", CaseStatus::Skipped, Some("not yet")),
+            ]
+        );
+    }
+
+    #[test]
+    fn junit_escapes_names_and_messages() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("reports/junit.xml");
+        let suites = vec![TestSuiteResult {
+            name: "test_<parser>".to_string(),
+            cases: vec![
+                TestCaseResult {
+                    name: "a & b".to_string(),
+                    status: CaseStatus::Passed,
+                    message: None,
+                },
+                TestCaseResult {
+                    name: "quotes".to_string(),
+                    status: CaseStatus::Failed,
+                    message: Some("expected \"<x>\" & 'y'".to_string()),
+                },
+                TestCaseResult {
+                    name: "flaky".to_string(),
+                    status: CaseStatus::Quarantined,
+                    message: None,
+                },
+            ],
+            duration: Duration::from_millis(1500),
+            output: "\x1b[31ma < b\x1b[0m\n".to_string(),
+        }];
+
+        write_junit(&path, &suites).unwrap();
+        let xml = std::fs::read_to_string(&path).unwrap();
+
+        assert!(
+            xml.contains("<testsuites tests=\"3\" failures=\"1\" skipped=\"1\" time=\"1.500\">")
+        );
+        assert!(xml.contains("<testcase classname=\"test_&lt;parser&gt;\" name=\"a &amp; b\" />"));
+        assert!(xml.contains("<failure message=\"expected &quot;&lt;x&gt;&quot; &amp; 'y'\">"));
+        assert!(xml.contains("<skipped message=\"quarantined: \" />"));
+        assert!(xml.contains("<system-out>[31ma &lt; b[0m\n</system-out>"));
+    }
+}
@@ -0,0 +1,219 @@
+use crate::config::BuildConfig;
+use crate::config_edit::ConfigEditor;
+use crate::dependency::DependencyManager;
+use crate::error::{BuildError, BuildResult};
+use colored::Colorize;
+use log::{info, warn};
+use std::path::Path;
+
+/// `cbuild test init`으로 설정할 C 테스트 프레임워크
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestFramework {
+    Unity,
+    Cmocka,
+}
+
+impl std::str::FromStr for TestFramework {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "unity" => Ok(TestFramework::Unity),
+            "cmocka" => Ok(TestFramework::Cmocka),
+            _ => Err(format!("unity 또는 cmocka여야 합니다: {}", s)),
+        }
+    }
+}
+
+impl TestFramework {
+    fn name(self) -> &'static str {
+        match self {
+            TestFramework::Unity => "unity",
+            TestFramework::Cmocka => "cmocka",
+        }
+    }
+
+    /// cbuild.toml에 추가할 의존성과 테스트 타겟 설정
+    fn config_snippet(self, target_name: &str) -> String {
+        match self {
+            TestFramework::Unity => format!(
+                r#"
+[dev-dependencies.unity]
+version = "2.6.0"
+git = "https://github.com/ThrowTheSwitch/Unity.git"
+tag = "v2.6.0"
+
+[[targets.test]]
+name = "{}"
+src = ["test/test_*.c", "deps/unity/src/unity.c"]
+include_dirs = ["include", "deps/unity/src"]
+format = "unity"
+"#,
+                target_name
+            ),
+            TestFramework::Cmocka => format!(
+                r#"
+[dev-dependencies.cmocka]
+version = "1.1.7"
+git = "https://gitlab.com/cmocka/cmocka.git"
+tag = "cmocka-1.1.7"
+
+[[targets.test]]
+name = "{}"
+src = ["test/test_*.c", "deps/cmocka/src/cmocka.c"]
+include_dirs = ["include", "deps/cmocka/include"]
+defines = {{ HAVE_SIGNAL_H = "1", HAVE_STRINGS_H = "1", HAVE_INTTYPES_H = "1" }}
+format = "cmocka"
+"#,
+                target_name
+            ),
+        }
+    }
+
+    /// 예제 테스트와 러너 main 소스
+    fn sample_sources(self) -> [(&'static str, &'static str); 2] {
+        match self {
+            TestFramework::Unity => [
+                (
+                    "test/test_sample.c",
+                    r#"#include "unity.h"
+
+void test_addition(void) {
+    TEST_ASSERT_EQUAL_INT(4, 2 + 2);
+}
+
+void test_string_length(void) {
+    TEST_ASSERT_EQUAL_size_t(5, sizeof("hello") - 1);
+}
+"#,
+                ),
+                (
+                    "test/test_runner.c",
+                    r#"#include "unity.h"
+
+void test_addition(void);
+void test_string_length(void);
+
+void setUp(void) {}
+void tearDown(void) {}
+
+int main(void) {
+    UNITY_BEGIN();
+    RUN_TEST(test_addition);
+    RUN_TEST(test_string_length);
+    return UNITY_END();
+}
+"#,
+                ),
+            ],
+            TestFramework::Cmocka => [
+                (
+                    "test/test_sample.c",
+                    r#"#include <stdarg.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <setjmp.h>
+#include <cmocka.h>
+
+void test_addition(void **state) {
+    (void)state;
+    assert_int_equal(4, 2 + 2);
+}
+
+void test_string_length(void **state) {
+    (void)state;
+    assert_int_equal(5, sizeof("hello") - 1);
+}
+"#,
+                ),
+                (
+                    "test/test_runner.c",
+                    r#"#include <stdarg.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <setjmp.h>
+#include <cmocka.h>
+
+void test_addition(void **state);
+void test_string_length(void **state);
+
+int main(void) {
+    const struct CMUnitTest tests[] = {
+        cmocka_unit_test(test_addition),
+        cmocka_unit_test(test_string_length),
+    };
+    return cmocka_run_group_tests(tests, NULL, NULL);
+}
+"#,
+                ),
+            ],
+        }
+    }
+}
+
+/// 테스트 프레임워크를 의존성으로 추가하고 예제 테스트 타겟 생성
+///
+/// cbuild.toml 끝에 의존성과 `[[targets.test]]` 항목을 추가하고, 예제 테스트와
+/// 러너 main을 `test/`에 생성한 뒤 의존성을 내려받는다.
+pub fn init_test_harness(project_dir: &Path, framework: TestFramework) -> BuildResult<()> {
+    const TARGET_NAME: &str = "unit_tests";
+
+    let config = BuildConfig::from_file(project_dir)?;
+
+    if config.find_dependency(framework.name()).is_some() {
+        return Err(BuildError::ConfigParsingError(format!(
+            "의존성 {}이(가) 이미 설정되어 있습니다",
+            framework.name()
+        )));
+    }
+    if config.targets.test.iter().any(|t| t.name == TARGET_NAME) {
+        return Err(BuildError::ConfigParsingError(format!(
+            "테스트 타겟 {}이(가) 이미 있습니다",
+            TARGET_NAME
+        )));
+    }
+
+    let sources = framework.sample_sources();
+    for (path, _) in &sources {
+        if project_dir.join(path).exists() {
+            return Err(BuildError::PathError(format!(
+                "{}이(가) 이미 있습니다",
+                project_dir.join(path).display()
+            )));
+        }
+    }
+
+    // 예제 테스트 소스 생성
+    std::fs::create_dir_all(project_dir.join("test")).map_err(BuildError::IoError)?;
+    for (path, content) in &sources {
+        let path = project_dir.join(path);
+        std::fs::write(&path, content).map_err(BuildError::IoError)?;
+        println!("생성됨: {}", path.display());
+    }
+
+    // 설정 파일에 의존성과 테스트 타겟 추가 (기존 주석과 순서는 그대로, 이전 내용은 .bak)
+    let mut editor = ConfigEditor::open(project_dir)?;
+    editor.append(&framework.config_snippet(TARGET_NAME))?;
+    editor.save()?;
+    info!("cbuild.toml에 테스트 타겟 추가");
+
+    // 프레임워크 내려받기 (실패해도 설정은 유지)
+    let mut dep_manager = DependencyManager::new(project_dir);
+    if let Err(e) = dep_manager.install() {
+        warn!("{} 설치 실패: {}", framework.name(), e);
+        println!(
+            "{} 나중에 'cbuild dependencies'로 {}을(를) 설치하세요.",
+            "참고:".yellow(),
+            framework.name()
+        );
+    }
+
+    println!(
+        "{} {} 테스트 타겟 '{}'이(가) 추가되었습니다. 'cbuild test'로 실행하세요.",
+        "완료:".green().bold(),
+        framework.name(),
+        TARGET_NAME
+    );
+
+    Ok(())
+}
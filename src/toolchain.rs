@@ -0,0 +1,536 @@
+use crate::config::BuildConfig;
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use crate::tools;
+use crate::utils::{display_width, get_compiler_version, split_tool_command, tool_command};
+use colored::Colorize;
+use lazy_static::lazy_static;
+use log::{debug, info, warn};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::ffi::OsString;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+
+/// 컴파일러 종류
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum CompilerFamily {
+    Gcc,
+    Clang,
+    AppleClang,
+    Unknown,
+}
+
+impl std::fmt::Display for CompilerFamily {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            CompilerFamily::Gcc => "GCC",
+            CompilerFamily::Clang => "Clang",
+            CompilerFamily::AppleClang => "Apple Clang",
+            CompilerFamily::Unknown => "unknown",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// build/.toolchain.json에 저장되는 컴파일러 탐지 결과
+#[derive(Debug, Deserialize, Serialize, Clone)]
+struct ToolchainCache {
+    compiler: String,
+    path: PathBuf,
+    /// 컴파일러 실행 파일이 바뀌었는지 확인하기 위한 크기와 수정 시각
+    fingerprint: String,
+    family: CompilerFamily,
+    version: String,
+    version_line: String,
+    /// 플래그별 지원 여부
+    flags: BTreeMap<String, bool>,
+    /// 확인된 OpenMP 플래그 (사용할 수 없으면 기록하지 않고 다음 빌드에서 다시 확인)
+    #[serde(default)]
+    openmp: Option<OpenMpFlags>,
+}
+
+/// OpenMP로 컴파일·링크하는 데 필요한 플래그
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct OpenMpFlags {
+    pub compile: Vec<String>,
+    pub link: Vec<String>,
+}
+
+impl Default for OpenMpFlags {
+    /// 컴파일러를 탐지하지 않는 빌드(컨테이너 등)에서 사용하는 GCC/Clang 플래그
+    fn default() -> Self {
+        OpenMpFlags {
+            compile: vec!["-fopenmp".to_string()],
+            link: vec!["-fopenmp".to_string()],
+        }
+    }
+}
+
+/// 컴파일러 종류·버전과 플래그 지원 여부 탐지 결과
+///
+/// 플래그 지원 여부는 처음 사용할 때 빈 번역 단위를 컴파일해 확인하고
+/// 캐시 파일에 기록한다. 컴파일러 실행 파일이 바뀌면 캐시를 버린다.
+pub struct Toolchain {
+    cache: Mutex<ToolchainCache>,
+    cache_path: PathBuf,
+    /// 탐지 결과를 캐시 파일에 기록할지 (`--dry-run`에서는 읽기만 함)
+    persist: bool,
+    /// 탐지 결과를 캐시에서 재사용했는지
+    cache_hit: bool,
+    /// 이번 실행에서 이미 경고한 제외 플래그 (캐시된 결과도 한 번은 알림)
+    dropped: Mutex<BTreeSet<String>>,
+}
+
+impl Toolchain {
+    /// 컴파일러를 확인하고 캐시가 유효하면 재사용
+    ///
+    /// "zig cc"처럼 여러 단어로 된 컴파일러 명령은 첫 단어의 프로그램을 찾고
+    /// 나머지 단어를 모든 탐지 명령 앞에 붙인다.
+    pub fn probe(compiler: &str, cache_path: &Path) -> BuildResult<Toolchain> {
+        Self::probe_with(compiler, cache_path, true)
+    }
+
+    /// 캐시를 읽기만 하고 새 탐지 결과는 기록하지 않음 (`--dry-run`)
+    pub fn probe_read_only(compiler: &str, cache_path: &Path) -> BuildResult<Toolchain> {
+        Self::probe_with(compiler, cache_path, false)
+    }
+
+    fn probe_with(compiler: &str, cache_path: &Path, persist: bool) -> BuildResult<Toolchain> {
+        let path = which::which(split_tool_command(compiler).0)
+            .map_err(|_| BuildError::CompilerNotFound(compiler.to_string()))?;
+        let fingerprint = fingerprint(&path);
+
+        let cached = std::fs::read_to_string(cache_path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<ToolchainCache>(&content).ok())
+            .filter(|cache| {
+                cache.compiler == compiler && cache.path == path && cache.fingerprint == fingerprint
+            });
+
+        let cache_hit = cached.is_some();
+        let cache = match cached {
+            Some(cache) => {
+                debug!("툴체인 캐시 사용: {}", cache_path.display());
+                cache
+            }
+            None => {
+                let output = Command::new(&path)
+                    .args(split_tool_command(compiler).1)
+                    .arg("--version")
+                    .output()
+                    .map_err(|_| BuildError::CompilerNotFound(compiler.to_string()))?;
+                if !output.status.success() {
+                    return Err(BuildError::CompilerNotFound(compiler.to_string()));
+                }
+
+                let text = String::from_utf8_lossy(&output.stdout);
+                let version_line = text.lines().next().unwrap_or("").to_string();
+                let family = detect_family(&text, compiler);
+
+                let cache = ToolchainCache {
+                    compiler: compiler.to_string(),
+                    path,
+                    fingerprint,
+                    family,
+                    version: parse_version(&version_line, family),
+                    version_line,
+                    flags: BTreeMap::new(),
+                    openmp: None,
+                };
+                if persist {
+                    save_cache(cache_path, &cache);
+                }
+                cache
+            }
+        };
+
+        info!(
+            "컴파일러: {} ({} {})",
+            cache.compiler, cache.family, cache.version
+        );
+
+        Ok(Toolchain {
+            cache: Mutex::new(cache),
+            cache_path: cache_path.to_path_buf(),
+            persist,
+            cache_hit,
+            dropped: Mutex::new(BTreeSet::new()),
+        })
+    }
+
+    pub fn family(&self) -> CompilerFamily {
+        self.cache.lock().unwrap().family
+    }
+
+    /// 컴파일러 실행 파일 경로
+    pub fn path(&self) -> PathBuf {
+        self.cache.lock().unwrap().path.clone()
+    }
+
+    /// `--version` 출력의 첫 줄 (예: "gcc (Ubuntu 13.2.0-4ubuntu3) 13.2.0")
+    pub fn version_line(&self) -> String {
+        self.cache.lock().unwrap().version_line.clone()
+    }
+
+    /// 출력이 파이프로 캡처되어도 진단에 색을 입히는 플래그
+    pub fn color_diagnostics_flag(&self) -> Option<&'static str> {
+        match self.family() {
+            CompilerFamily::Gcc => Some("-fdiagnostics-color=always"),
+            CompilerFamily::Clang | CompilerFamily::AppleClang => Some("-fcolor-diagnostics"),
+            CompilerFamily::Unknown => None,
+        }
+    }
+
+    /// 컴파일러가 플래그를 지원하는지 확인 (결과는 캐시)
+    pub fn supports_flag(&self, flag: &str) -> bool {
+        let mut cache = self.cache.lock().unwrap();
+        if let Some(&supported) = cache.flags.get(flag) {
+            return supported;
+        }
+
+        // C 또는 C++ 중 하나에서 받아들이면 지원하는 것으로 봄
+        let supported = probe_flag(compiler_command(&cache), flag, "c")
+            || probe_flag(compiler_command(&cache), flag, "c++");
+
+        cache.flags.insert(flag.to_string(), supported);
+        if self.persist {
+            save_cache(&self.cache_path, &cache);
+        }
+        supported
+    }
+
+    /// OpenMP 플래그 확인 (결과는 캐시)
+    ///
+    /// `omp.h`를 포함하고 OpenMP 런타임 함수를 호출하는 프로그램을 실제로 링크해
+    /// 헤더와 런타임이 모두 있는지 확인한다. Apple clang은 `-fopenmp`를 직접 받지
+    /// 않으므로 `-Xpreprocessor -fopenmp`와 Homebrew libomp를 사용한다.
+    pub fn openmp_flags(&self) -> BuildResult<OpenMpFlags> {
+        let mut cache = self.cache.lock().unwrap();
+        if let Some(ref flags) = cache.openmp {
+            return Ok(flags.clone());
+        }
+
+        let candidates = match cache.family {
+            CompilerFamily::AppleClang => libomp_prefixes()
+                .into_iter()
+                .map(|prefix| OpenMpFlags {
+                    compile: vec![
+                        "-Xpreprocessor".to_string(),
+                        "-fopenmp".to_string(),
+                        format!("-I{}", prefix.join("include").display()),
+                    ],
+                    link: vec![
+                        format!("-L{}", prefix.join("lib").display()),
+                        "-lomp".to_string(),
+                    ],
+                })
+                .collect(),
+            _ => vec![OpenMpFlags::default()],
+        };
+
+        let flags = candidates
+            .into_iter()
+            .find(|flags| probe_openmp(compiler_command(&cache), flags))
+            .ok_or_else(|| BuildError::OpenMpNotFound(cache.compiler.clone()))?;
+
+        debug!("OpenMP 플래그: {:?}", flags);
+        cache.openmp = Some(flags.clone());
+        if self.persist {
+            save_cache(&self.cache_path, &cache);
+        }
+        Ok(flags)
+    }
+
+    /// 지원하지 않는 선택적 플래그(-std=, -f, -W, -m)를 제외 (플래그마다 실행당 한 번 경고)
+    pub fn filter_flags(&self, args: Vec<OsString>) -> Vec<OsString> {
+        args.into_iter()
+            .filter(|arg| match arg.to_str() {
+                Some(flag) if is_probeable(flag) => {
+                    let supported = self.supports_flag(flag);
+                    if !supported && self.dropped.lock().unwrap().insert(flag.to_string()) {
+                        eprintln!(
+                            "{} {}",
+                            tr!("경고:", "warning:").yellow().bold(),
+                            tr!(
+                                "컴파일러 {}이(가) 지원하지 않는 플래그를 제외합니다: {}",
+                                "compiler {} does not support {}, dropping it",
+                                self.cache.lock().unwrap().compiler,
+                                flag
+                            )
+                        );
+                    }
+                    supported
+                }
+                _ => true,
+            })
+            .collect()
+    }
+
+    /// 설정한 언어 표준(`build.c_standard` 등)을 컴파일러가 지원하는지 확인
+    ///
+    /// 표준은 다른 선택적 플래그와 달리 빼고 빌드하면 결과가 달라지므로 설정 오류로 본다.
+    pub fn check_standard(&self, setting: &str, standard: &str) -> BuildResult<()> {
+        if self.supports_flag(&format!("-std={}", standard)) {
+            return Ok(());
+        }
+        let cache = self.cache.lock().unwrap();
+        Err(BuildError::ConfigParsingError(tr!(
+            "{} = \"{}\"을(를) 컴파일러 {}({} {})가 지원하지 않습니다",
+            "{} = \"{}\" is not supported by compiler {} ({} {})",
+            setting,
+            standard,
+            cache.compiler,
+            cache.family,
+            cache.version
+        )))
+    }
+}
+
+/// 컴파일러 버전에 따라 지원 여부가 달라지는 플래그인지 확인
+///
+/// 포함 디렉토리, 매크로, 최적화 수준처럼 모든 컴파일러가 받아들이는 플래그와
+/// 링커/어셈블러 전달 플래그, `-Werror`는 검사하지 않는다.
+fn is_probeable(flag: &str) -> bool {
+    if flag == "-Werror" || ["-Wl,", "-Wa,", "-Wp,"].iter().any(|p| flag.starts_with(p)) {
+        return false;
+    }
+
+    flag.starts_with("-std=")
+        || flag.starts_with("-f")
+        || flag.starts_with("-W")
+        || flag.starts_with("-m")
+}
+
+/// 탐지에 사용할 컴파일러 명령 (여러 단어 명령의 앞 인자 포함)
+fn compiler_command(cache: &ToolchainCache) -> Command {
+    let mut cmd = Command::new(&cache.path);
+    cmd.args(split_tool_command(&cache.compiler).1);
+    cmd
+}
+
+/// 빈 번역 단위를 플래그와 함께 컴파일해 지원 여부 확인
+fn probe_flag(mut compiler: Command, flag: &str, language: &str) -> bool {
+    let child = compiler
+        .args(["-x", language, "-fsyntax-only", "-Werror", flag, "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+
+    let Ok(mut child) = child else {
+        return false;
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = writeln!(stdin, "int cbuild_probe;");
+    }
+
+    child.wait().map(|status| status.success()).unwrap_or(false)
+}
+
+/// OpenMP 프로그램을 컴파일·링크해 헤더와 런타임이 있는지 확인
+fn probe_openmp(mut compiler: Command, flags: &OpenMpFlags) -> bool {
+    let Ok(dir) = tempfile::tempdir() else {
+        return false;
+    };
+    let child = compiler
+        .args(["-x", "c"])
+        .args(&flags.compile)
+        .arg("-")
+        .arg("-o")
+        .arg(dir.path().join("openmp_probe"))
+        .args(&flags.link)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+
+    let Ok(mut child) = child else {
+        return false;
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = writeln!(
+            stdin,
+            "#include <omp.h>\nint main(void) {{ return omp_get_max_threads() > 0 ? 0 : 1; }}"
+        );
+    }
+
+    child.wait().map(|status| status.success()).unwrap_or(false)
+}
+
+/// Homebrew libomp 설치 위치 후보 (`brew --prefix libomp`, Apple Silicon, Intel 순)
+fn libomp_prefixes() -> Vec<PathBuf> {
+    let mut prefixes = Vec::new();
+    if let Ok(output) = Command::new("brew").args(["--prefix", "libomp"]).output() {
+        if output.status.success() {
+            let prefix = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            if !prefix.is_empty() {
+                prefixes.push(PathBuf::from(prefix));
+            }
+        }
+    }
+    for prefix in ["/opt/homebrew/opt/libomp", "/usr/local/opt/libomp"] {
+        let prefix = PathBuf::from(prefix);
+        if !prefixes.contains(&prefix) {
+            prefixes.push(prefix);
+        }
+    }
+    prefixes.retain(|prefix| prefix.join("include/omp.h").exists());
+    prefixes
+}
+
+fn detect_family(version_output: &str, compiler: &str) -> CompilerFamily {
+    if version_output.contains("Apple clang") {
+        CompilerFamily::AppleClang
+    } else if version_output.contains("clang") {
+        CompilerFamily::Clang
+    } else if version_output.contains("Free Software Foundation")
+        || compiler.contains("gcc")
+        || compiler.contains("g++")
+    {
+        CompilerFamily::Gcc
+    } else {
+        CompilerFamily::Unknown
+    }
+}
+
+/// `--version` 첫 줄에서 버전 번호 추출
+fn parse_version(line: &str, family: CompilerFamily) -> String {
+    lazy_static! {
+        static ref CLANG_VERSION_RE: Regex = Regex::new(r"version (\d+\.\d+(?:\.\d+)?)").unwrap();
+        static ref VERSION_RE: Regex = Regex::new(r"\d+\.\d+(?:\.\d+)?").unwrap();
+    }
+
+    let version = match family {
+        CompilerFamily::Clang | CompilerFamily::AppleClang => CLANG_VERSION_RE
+            .captures(line)
+            .map(|cap| cap[1].to_string()),
+        // GCC는 괄호 안 배포판 정보 뒤의 마지막 버전 번호
+        _ => VERSION_RE
+            .find_iter(line)
+            .last()
+            .map(|m| m.as_str().to_string()),
+    };
+
+    version.unwrap_or_else(|| "unknown".to_string())
+}
+
+fn fingerprint(path: &Path) -> String {
+    let Ok(metadata) = std::fs::metadata(path) else {
+        return String::new();
+    };
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0);
+
+    format!("{}-{}", metadata.len(), modified)
+}
+
+/// 캐시 저장 (실패해도 빌드에는 영향 없음)
+fn save_cache(path: &Path, cache: &ToolchainCache) {
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+
+    match serde_json::to_string_pretty(cache) {
+        Ok(content) => {
+            if let Err(e) = std::fs::write(path, content) {
+                warn!("툴체인 캐시를 저장할 수 없습니다: {}", e);
+            }
+        }
+        Err(e) => warn!("툴체인 캐시를 저장할 수 없습니다: {}", e),
+    }
+}
+
+/// 해석된 툴체인 정보 출력 (`build --verbose` 머리말, `cbuild toolchain show`)
+///
+/// 컴파일러 경로·버전, 아카이버, 링커, 호스트/타겟 트리플, 탐지 캐시 상태를 보여준다.
+pub fn print_report(config: &BuildConfig, toolchain: &Toolchain) {
+    let compiler = &config.build.compiler;
+    let version = get_compiler_version(compiler).unwrap_or_else(|| toolchain.version_line());
+    let host = query_compiler(compiler, "-dumpmachine")
+        .unwrap_or_else(|| format!("{}-{}", std::env::consts::ARCH, std::env::consts::OS));
+    let target = config.build.target.clone().unwrap_or_else(|| host.clone());
+
+    let archiver = tools::archiver(config);
+    let archiver_path = which::which(split_tool_command(archiver).0)
+        .map(|path| path.display().to_string())
+        .unwrap_or_else(|_| tr!("찾을 수 없음", "not found"));
+
+    // 컴파일러 드라이버가 호출하는 실제 링커 (-fuse-ld 등 추가 플래그 반영)
+    let mut ld_query = vec!["-print-prog-name=ld".to_string()];
+    ld_query.extend(
+        config
+            .build
+            .extra_flags
+            .iter()
+            .flatten()
+            .filter(|flag| flag.starts_with("-fuse-ld="))
+            .cloned(),
+    );
+    let linker = query_compiler_args(compiler, &ld_query)
+        .map(|ld| match which::which(&ld) {
+            Ok(path) => path.display().to_string(),
+            Err(_) => ld,
+        })
+        .map(|ld| format!("{} ({})", compiler, ld))
+        .unwrap_or_else(|| compiler.to_string());
+
+    let cache = if toolchain.cache_hit {
+        tr!("재사용", "hit")
+    } else {
+        tr!("새로 탐지", "miss")
+    };
+
+    let rows = [
+        (
+            tr!("컴파일러", "compiler"),
+            toolchain.path().display().to_string(),
+        ),
+        (
+            tr!("버전", "version"),
+            format!("{} ({})", version, toolchain.family()),
+        ),
+        (
+            tr!("아카이버", "archiver"),
+            format!("{} ({})", archiver, archiver_path),
+        ),
+        (tr!("링커", "linker"), linker),
+        (tr!("호스트", "host"), host),
+        (tr!("타겟", "target"), target),
+        (
+            tr!("캐시", "cache"),
+            format!("{} ({})", cache, toolchain.cache_path.display()),
+        ),
+    ];
+
+    println!("{}", tr!("툴체인:", "Toolchain:").blue().bold());
+    for (label, value) in rows {
+        let padding = 10usize.saturating_sub(display_width(&label));
+        println!("  {}{} {}", label.dimmed(), " ".repeat(padding), value);
+    }
+}
+
+/// 컴파일러에 질의 플래그를 주고 출력의 첫 줄을 얻음 (예: -dumpmachine)
+fn query_compiler(compiler: &str, flag: &str) -> Option<String> {
+    query_compiler_args(compiler, &[flag.to_string()])
+}
+
+fn query_compiler_args(compiler: &str, args: &[String]) -> Option<String> {
+    let output = tool_command(compiler).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .next()
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+}
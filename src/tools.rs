@@ -0,0 +1,95 @@
+use crate::config::BuildConfig;
+use crate::error::{BuildError, BuildResult};
+use crate::i18n::tr;
+use crate::utils;
+
+/// 정적 라이브러리를 만들 때 사용하는 기본 아카이버
+pub const ARCHIVER: &str = "ar";
+
+/// zig 툴체인에 포함된 아카이버
+pub const ZIG_ARCHIVER: &str = "zig ar";
+
+/// 설정의 아카이버 (build.archiver, 없으면 ar / compiler가 zig cc이면 zig ar)
+pub fn archiver(config: &BuildConfig) -> &str {
+    match config.build.archiver {
+        Some(ref archiver) => archiver,
+        None if is_zig(&config.build.compiler) => ZIG_ARCHIVER,
+        None => ARCHIVER,
+    }
+}
+
+/// zig cc/zig c++ 컴파일러 명령인지 확인
+pub fn is_zig(compiler: &str) -> bool {
+    utils::split_tool_command(compiler).0 == "zig"
+}
+
+/// 빌드 과정에서 실행하는 외부 도구의 역할
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolKind {
+    Compiler,
+    Archiver,
+    ContainerEngine,
+    /// PGO 프로파일 병합 도구 (llvm-profdata)
+    ProfileMerger,
+}
+
+impl std::fmt::Display for ToolKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            ToolKind::Compiler => tr!("컴파일러", "compiler"),
+            ToolKind::Archiver => tr!("정적 라이브러리 아카이버", "static library archiver"),
+            ToolKind::ContainerEngine => tr!("컨테이너 엔진", "container engine"),
+            ToolKind::ProfileMerger => tr!("프로파일 병합 도구", "profile merge tool"),
+        };
+        write!(f, "{}", description)
+    }
+}
+
+/// 빌드에 필요한 외부 도구
+#[derive(Debug, Clone)]
+pub struct RequiredTool {
+    pub program: String,
+    pub kind: ToolKind,
+}
+
+/// 설정된 타겟을 빌드하는 데 필요한 외부 도구 목록
+pub fn required_tools(config: &BuildConfig) -> Vec<RequiredTool> {
+    let mut tools = vec![RequiredTool {
+        program: config.build.compiler.clone(),
+        kind: ToolKind::Compiler,
+    }];
+
+    if !config.targets.static_lib.is_empty() {
+        tools.push(RequiredTool {
+            program: archiver(config).to_string(),
+            kind: ToolKind::Archiver,
+        });
+    }
+
+    tools
+}
+
+/// 필요한 도구가 모두 설치되어 있는지 확인
+///
+/// 컴파일을 시작하기 전에 확인해 링크 단계에서 뒤늦게 실패하지 않도록 하고,
+/// 없는 도구는 하나의 오류로 모아 보고한다.
+pub fn check_tools(config: &BuildConfig) -> BuildResult<()> {
+    check_with(required_tools(config), utils::is_tool_installed)
+}
+
+/// 주어진 확인 방법으로 도구 설치 여부를 검사 (컨테이너 안의 도구 확인 등)
+pub fn check_with(
+    tools: Vec<RequiredTool>,
+    is_installed: impl Fn(&str) -> bool,
+) -> BuildResult<()> {
+    let missing: Vec<RequiredTool> = tools
+        .into_iter()
+        .filter(|tool| !is_installed(&tool.program))
+        .collect();
+
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(BuildError::ToolsNotFound(missing))
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
-use std::ffi::OsStr;
-use std::path::{Path, PathBuf};
+use std::ffi::{OsStr, OsString};
+use std::path::{Component, Path, PathBuf, Prefix};
 
 /// 파일 확장자 가져오기
 pub fn get_extension(path: &Path) -> Option<String> {
@@ -12,12 +12,17 @@ pub fn get_extension(path: &Path) -> Option<String> {
 /// 소스 파일인지 확인
 pub fn is_source_file(path: &Path) -> bool {
     if let Some(ext) = get_extension(path) {
-        matches!(ext.as_str(), "c" | "cpp" | "cxx" | "cc")
+        matches!(ext.as_str(), "c" | "cpp" | "cxx" | "cc" | "m" | "mm")
     } else {
         false
     }
 }
 
+/// Objective-C(.m) 또는 Objective-C++(.mm) 소스인지 확인
+pub fn is_objc_source(path: &Path) -> bool {
+    matches!(get_extension(path).as_deref(), Some("m" | "mm"))
+}
+
 /// 헤더 파일인지 확인
 pub fn is_header_file(path: &Path) -> bool {
     if let Some(ext) = get_extension(path) {
@@ -60,18 +65,57 @@ pub fn to_absolute_path(path: &Path, base: &Path) -> PathBuf {
     }
 }
 
+/// `.`과 `..`를 경로 문자열만으로 정리 (파일 시스템은 확인하지 않음, 예: src/../include -> include)
+pub fn normalize_path(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !result.pop() {
+                    result.push("..");
+                }
+            }
+            other => result.push(other),
+        }
+    }
+    result
+}
+
+/// `from` 디렉토리에서 `to` 경로로 가는 상대 경로 계산 (예: bin/tests -> ../../lib)
+pub fn relative_path_between(from: &Path, to: &Path) -> PathBuf {
+    let from: Vec<_> = from.components().collect();
+    let to: Vec<_> = to.components().collect();
+
+    let common = from
+        .iter()
+        .zip(to.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut result = PathBuf::new();
+    for _ in common..from.len() {
+        result.push("..");
+    }
+    for component in &to[common..] {
+        result.push(component.as_os_str());
+    }
+
+    result
+}
+
+/// 심볼릭 링크와 `..`를 풀어낸 정규 경로 (존재하지 않으면 그대로)
+pub fn canonical_path(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
 /// 중복 경로 제거
 pub fn deduplicate_paths(paths: &[PathBuf]) -> Vec<PathBuf> {
     let mut seen = HashSet::new();
     let mut result = Vec::new();
 
     for path in paths {
-        let canonical = match path.canonicalize() {
-            Ok(p) => p,
-            Err(_) => path.clone(),
-        };
-
-        if seen.insert(canonical.clone()) {
+        if seen.insert(canonical_path(path)) {
             result.push(path.clone());
         }
     }
@@ -96,7 +140,7 @@ pub fn is_newer_than(path1: &Path, path2: &Path) -> bool {
         .unwrap_or_else(|_| std::time::SystemTime::now());
     let time2 = metadata2
         .modified()
-        .unwrap_or_else(|_| std::time::SystemTime::UNIX_EPOCH);
+        .unwrap_or(std::time::SystemTime::UNIX_EPOCH);
 
     time1 > time2
 }
@@ -121,25 +165,19 @@ pub fn derive_output_path(
 
 /// 디렉토리 내의 모든 파일을 재귀적으로 수집
 pub fn collect_files_with_extension(dir: &Path, extensions: &[&str]) -> Vec<PathBuf> {
-    let mut result = Vec::new();
-
-    if let Ok(entries) = std::fs::read_dir(dir) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-
-            if path.is_dir() {
-                // 재귀적으로 하위 디렉토리 처리
-                let mut sub_files = collect_files_with_extension(&path, extensions);
-                result.append(&mut sub_files);
-            } else if let Some(ext) = get_extension(&path) {
-                // 확장자 확인
-                if extensions.contains(&ext.as_str()) {
-                    result.push(path);
-                }
-            }
-        }
-    }
-
+    // 심볼릭 링크 디렉토리도 따라가되, 순환 링크는 walkdir가 오류로 알려 주므로 건너뜀
+    let mut result: Vec<PathBuf> = walkdir::WalkDir::new(dir)
+        .min_depth(1)
+        .follow_links(true)
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.into_path())
+        .filter(|path| get_extension(path).is_some_and(|ext| extensions.contains(&ext.as_str())))
+        .collect();
+
+    // 디렉토리 순회 순서는 파일 시스템마다 다르므로 경로 순으로 정렬
+    result.sort();
     result
 }
 
@@ -179,16 +217,52 @@ pub fn execute_command_and_capture_output(
         Ok(stdout)
     } else {
         let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-        Err(std::io::Error::new(
-            std::io::ErrorKind::Other,
-            format!("Command failed: {}", stderr),
-        ))
+        Err(std::io::Error::other(format!("Command failed: {}", stderr)))
     }
 }
 
+/// 긴 출력을 터미널이면 `$PAGER`(기본: less)로, 아니면 표준 출력으로 내보냄
+pub fn page_output(content: &[u8], use_pager: bool) -> Result<(), std::io::Error> {
+    use std::io::{IsTerminal, Write};
+
+    if use_pager && std::io::stdout().is_terminal() {
+        let pager = std::env::var("PAGER").unwrap_or_else(|_| "less".to_string());
+        let mut parts = pager.split_whitespace();
+        if let Some(program) = parts.next() {
+            if let Ok(mut child) = std::process::Command::new(program)
+                .args(parts)
+                .stdin(std::process::Stdio::piped())
+                .spawn()
+            {
+                if let Some(mut stdin) = child.stdin.take() {
+                    // 사용자가 페이저를 먼저 종료하면 쓰기 오류가 나므로 무시
+                    let _ = stdin.write_all(content);
+                }
+                child.wait()?;
+                return Ok(());
+            }
+            log::warn!("Failed to start pager: {}", pager);
+        }
+    }
+
+    std::io::stdout().write_all(content)
+}
+
+/// XML·HTML 속성과 본문에 넣을 문자열 이스케이프 (XML에서 허용하지 않는 제어 문자는 제거)
+pub fn xml_escape(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !c.is_control() || matches!(c, '\n' | '\t' | '\r'))
+        .collect::<String>()
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
 /// 대규모 C 프로젝트에서 일반적으로 사용되는 파일 확장자 목록
 pub fn get_common_source_extensions() -> Vec<&'static str> {
-    vec!["c", "cpp", "cxx", "cc"]
+    vec!["c", "cpp", "cxx", "cc", "m", "mm"]
 }
 
 pub fn get_common_header_extensions() -> Vec<&'static str> {
@@ -206,6 +280,21 @@ pub fn ensure_file_exists(path: &Path, default_content: &str) -> Result<(), std:
     Ok(())
 }
 
+/// 내용이 달라졌을 때만 파일을 기록 (수정 시간을 보존해 불필요한 재빌드 방지)
+pub fn write_if_changed(path: &Path, content: &str) -> Result<bool, std::io::Error> {
+    if let Ok(existing) = std::fs::read(path) {
+        if existing == content.as_bytes() {
+            return Ok(false);
+        }
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, content)?;
+    Ok(true)
+}
+
 /// 디렉토리가 존재하는지 확인하고 없으면 생성
 pub fn ensure_directory_exists(path: &Path) -> Result<(), std::io::Error> {
     if !path.exists() {
@@ -214,14 +303,32 @@ pub fn ensure_directory_exists(path: &Path) -> Result<(), std::io::Error> {
     Ok(())
 }
 
-/// 도구가 시스템에 설치되어 있는지 확인
+/// 도구가 시스템에 설치되어 있는지 확인 (여러 단어 명령은 첫 단어의 프로그램)
 pub fn is_tool_installed(tool: &str) -> bool {
-    which::which(tool).is_ok()
+    which::which(split_tool_command(tool).0).is_ok()
+}
+
+/// 여러 단어로 된 도구 명령(예: "zig cc")을 프로그램과 앞에 붙는 인자로 분리
+pub fn split_tool_command(command: &str) -> (&str, Vec<&str>) {
+    let mut words = command.split_whitespace();
+    let program = words.next().unwrap_or(command);
+    (program, words.collect())
+}
+
+/// 여러 단어로 된 도구 명령으로 실행할 명령 생성 (예: "zig cc" -> `zig cc ...`)
+pub fn tool_command(command: &str) -> std::process::Command {
+    let (program, args) = split_tool_command(command);
+    let mut cmd = std::process::Command::new(program);
+    cmd.args(args);
+    cmd
 }
 
 /// 컴파일러 버전 정보 가져오기
 pub fn get_compiler_version(compiler: &str) -> Option<String> {
-    match execute_command_and_capture_output(compiler, &["--version"], None) {
+    // "zig cc"처럼 여러 단어로 된 컴파일러 명령도 처리
+    let (program, mut args) = split_tool_command(compiler);
+    args.push("--version");
+    match execute_command_and_capture_output(program, &args, None) {
         Ok(output) => {
             let first_line = output.lines().next()?;
             Some(first_line.trim().to_string())
@@ -230,11 +337,248 @@ pub fn get_compiler_version(compiler: &str) -> Option<String> {
     }
 }
 
+/// 현재 프로세스의 CPU/IO 우선순위를 낮춤 (이후 생성되는 컴파일러/링커 프로세스에 상속됨)
+///
+/// Unix에서는 nice 값을 10으로, Linux에서는 IO 스케줄링 클래스를 idle로 설정하고
+/// Windows에서는 BELOW_NORMAL 우선순위 클래스를 사용한다.
+/// 스레드 풀이 생성되기 전에 호출해야 모든 작업 스레드에 적용된다.
+#[cfg(unix)]
+pub fn lower_process_priority() -> Result<(), std::io::Error> {
+    // SAFETY: 현재 프로세스의 우선순위만 변경한다.
+    let result = unsafe { libc::setpriority(libc::PRIO_PROCESS, 0, 10) };
+    if result != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        const IOPRIO_WHO_PROCESS: libc::c_int = 1;
+        const IOPRIO_CLASS_IDLE: libc::c_int = 3;
+        const IOPRIO_CLASS_SHIFT: libc::c_int = 13;
+
+        // SAFETY: ioprio_set은 현재 프로세스의 IO 우선순위만 변경한다.
+        let result = unsafe {
+            libc::syscall(
+                libc::SYS_ioprio_set,
+                IOPRIO_WHO_PROCESS,
+                0,
+                IOPRIO_CLASS_IDLE << IOPRIO_CLASS_SHIFT,
+            )
+        };
+        if result != 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(windows)]
+pub fn lower_process_priority() -> Result<(), std::io::Error> {
+    use std::ffi::c_void;
+
+    const BELOW_NORMAL_PRIORITY_CLASS: u32 = 0x0000_4000;
+
+    extern "system" {
+        fn GetCurrentProcess() -> *mut c_void;
+        fn SetPriorityClass(process: *mut c_void, priority_class: u32) -> i32;
+    }
+
+    // SAFETY: 현재 프로세스 의사 핸들에 대해 우선순위 클래스만 변경한다.
+    let result = unsafe { SetPriorityClass(GetCurrentProcess(), BELOW_NORMAL_PRIORITY_CLASS) };
+    if result == 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+
+    Ok(())
+}
+
+/// 사람이 읽기 쉬운 파일 크기 (예: 1.5 MiB)
+pub fn format_size(size: u64) -> String {
+    match size {
+        0..=1023 => format!("{} B", size),
+        1024..=1048575 => format!("{:.1} KiB", size as f64 / 1024.0),
+        1048576..=1073741823 => format!("{:.1} MiB", size as f64 / 1048576.0),
+        _ => format!("{:.1} GiB", size as f64 / 1073741824.0),
+    }
+}
+
 /// 파일 수정 시간 가져오기
 pub fn get_file_modification_time(path: &Path) -> Option<std::time::SystemTime> {
     std::fs::metadata(path).ok()?.modified().ok()
 }
 
+/// 실행 권한 부여 (유닉스 이외에서는 아무것도 하지 않음)
+#[cfg(unix)]
+pub fn make_executable(path: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))
+}
+
+#[cfg(not(unix))]
+pub fn make_executable(_path: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+/// `base_dir` 기준 glob 패턴에 맞는 경로 (읽을 수 없는 항목은 건너뜀)
+///
+/// 패턴 앞의 와일드카드가 없는 부분을 시작 디렉토리로 삼아 순회하며 나머지를 상대 경로와
+/// 비교하므로, 기준 디렉토리 경로의 `[`, `*` 등은 문자 그대로 취급하고 UTF-8이 아닌
+/// 경로도 처리한다. `follow_links`가 false면 심볼릭 링크 디렉토리 안으로 들어가지 않으며,
+/// true여도 상위 디렉토리를 가리키는 순환 링크는 다시 들어가지 않는다.
+pub fn glob_in(
+    base_dir: &Path,
+    pattern: &str,
+    follow_links: bool,
+) -> Result<Vec<PathBuf>, glob::PatternError> {
+    // 같은 cbuild.toml을 모든 OS에서 쓸 수 있도록 패턴의 `\`도 경로 구분자로 취급
+    let pattern = pattern.replace('\\', "/");
+
+    let mut root = base_dir.to_path_buf();
+    let mut rest: Vec<String> = Vec::new();
+    for component in Path::new(&pattern).components() {
+        let part = component.as_os_str().to_string_lossy();
+        if rest.is_empty() && !part.contains(['*', '?', '[']) {
+            // 절대 경로 패턴이면 루트나 드라이브가 기준 디렉토리를 대신함
+            root.push(component);
+        } else {
+            rest.push(part.into_owned());
+        }
+    }
+
+    if rest.is_empty() {
+        return Ok(if root.exists() {
+            vec![root]
+        } else {
+            Vec::new()
+        });
+    }
+
+    let matcher = glob::Pattern::new(&rest.join("/"))?;
+    let options = glob::MatchOptions {
+        require_literal_separator: true,
+        ..glob::MatchOptions::new()
+    };
+    let max_depth = if rest.iter().any(|part| part.contains("**")) {
+        usize::MAX
+    } else {
+        rest.len()
+    };
+
+    // 순환 링크는 walkdir가 오류로 알려 주므로 다른 읽기 오류처럼 건너뜀
+    let mut paths: Vec<PathBuf> = walkdir::WalkDir::new(&root)
+        .min_depth(1)
+        .max_depth(max_depth)
+        .follow_links(follow_links)
+        .into_iter()
+        .flatten()
+        .filter(|entry| {
+            entry
+                .path()
+                .strip_prefix(&root)
+                // UTF-8이 아닌 파일 이름도 `*` 등에 맞도록 손실 변환한 이름과 비교
+                .is_ok_and(|relative| matcher.matches_with(&relative.to_string_lossy(), options))
+        })
+        .map(|entry| entry.into_path())
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+/// Windows의 기본 경로 길이 제한 (MAX_PATH, 끝의 NUL 포함)
+const MAX_PATH: usize = 260;
+
+/// 외부 도구에 넘길 경로 (Windows에서 MAX_PATH를 넘는 절대 경로는 `\\?\` 확장 경로)
+///
+/// 표준 라이브러리의 파일 함수는 긴 경로를 스스로 처리하지만 컴파일러나 링커는 그렇지 않다.
+/// 확장 경로는 `/`와 `.`, `..`를 해석하지 않으므로 구성 요소를 정리해 `\`로 다시 잇는다.
+pub fn long_path(path: &Path) -> PathBuf {
+    if !cfg!(windows) || !path.is_absolute() || path.as_os_str().len() < MAX_PATH {
+        return path.to_path_buf();
+    }
+
+    let mut result = OsString::new();
+    let mut parts: Vec<&OsStr> = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::Prefix(prefix) => match prefix.kind() {
+                Prefix::Disk(drive) => result.push(format!("\\\\?\\{}:", drive as char)),
+                Prefix::UNC(server, share) => {
+                    result.push("\\\\?\\UNC\\");
+                    result.push(server);
+                    result.push("\\");
+                    result.push(share);
+                }
+                // 이미 확장 경로이거나 장치 경로
+                _ => return path.to_path_buf(),
+            },
+            Component::RootDir | Component::CurDir => {}
+            Component::ParentDir => {
+                parts.pop();
+            }
+            Component::Normal(part) => parts.push(part),
+        }
+    }
+    for part in parts {
+        result.push("\\");
+        result.push(part);
+    }
+    PathBuf::from(result)
+}
+
+/// `canonicalize`가 Windows에서 붙이는 `\\?\` 접두사를 뗀 경로
+///
+/// 접두사가 있는 경로와 없는 경로는 `strip_prefix`로 비교할 수 없으므로 비교 전에 맞춘다.
+/// 접두사를 떼면 MAX_PATH를 넘는 경로는 그대로 둔다.
+pub fn simplified_path(path: &Path) -> PathBuf {
+    let mut components = path.components();
+    let Some(Component::Prefix(prefix)) = components.next() else {
+        return path.to_path_buf();
+    };
+    let mut result = match prefix.kind() {
+        Prefix::VerbatimDisk(drive) => OsString::from(format!("{}:", drive as char)),
+        Prefix::VerbatimUNC(server, share) => {
+            let mut unc = OsString::from("\\\\");
+            unc.push(server);
+            unc.push("\\");
+            unc.push(share);
+            unc
+        }
+        _ => return path.to_path_buf(),
+    };
+    result.push(components.as_path());
+    if result.len() >= MAX_PATH {
+        return path.to_path_buf();
+    }
+    PathBuf::from(result)
+}
+
+/// `flag`에 경로를 이어 붙인 인자 (`-fprofile-use=<path>` 등, UTF-8이 아닌 경로도 보존)
+pub fn path_flag(flag: &str, path: &Path) -> OsString {
+    let mut arg = OsString::from(flag);
+    arg.push(path.as_os_str());
+    arg
+}
+
+/// 인자의 `{name}` 자리 표시자를 경로로 바꿈 (UTF-8이 아닌 경로도 보존)
+pub fn substitute_paths(arg: &str, paths: &[(&str, &Path)]) -> OsString {
+    let mut result = OsString::new();
+    let mut rest = arg;
+    'outer: while !rest.is_empty() {
+        for (name, path) in paths {
+            if let Some(after) = rest.strip_prefix(*name) {
+                result.push(path.as_os_str());
+                rest = after;
+                continue 'outer;
+            }
+        }
+        let len = rest.chars().next().map_or(1, char::len_utf8);
+        result.push(&rest[..len]);
+        rest = &rest[len..];
+    }
+    result
+}
+
 /// 파일을 새로운 위치로 복사, 필요한 디렉토리 생성
 pub fn copy_file_with_dirs(src: &Path, dst: &Path) -> Result<(), std::io::Error> {
     if let Some(parent) = dst.parent() {
@@ -286,3 +630,243 @@ pub fn get_platform_lib_prefix() -> &'static str {
 pub fn get_separator(length: usize) -> String {
     "=".repeat(length)
 }
+
+/// 터미널 표시 폭 (한글 등 전각 문자는 2칸)
+pub fn display_width(text: &str) -> usize {
+    text.chars()
+        .map(|c| match c as u32 {
+            0x1100..=0x115F
+            | 0x2E80..=0xA4CF
+            | 0xAC00..=0xD7A3
+            | 0xF900..=0xFAFF
+            | 0xFF00..=0xFF60 => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    /// `base` 아래에 빈 파일들을 만듦
+    fn touch(base: &Path, files: &[&str]) {
+        for file in files {
+            let path = base.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+    }
+
+    fn relative(base: &Path, paths: Vec<PathBuf>) -> Vec<String> {
+        paths
+            .iter()
+            .map(|p| {
+                p.strip_prefix(base)
+                    .unwrap()
+                    .to_string_lossy()
+                    .replace('\\', "/")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn glob_in_treats_base_dir_literally() {
+        let tmp = tempfile::tempdir().unwrap();
+        // 공백, 글로브 메타 문자, 한글이 들어간 프로젝트 디렉토리
+        let base = tmp.path().join("my project [v2] 프로젝트*");
+        touch(&base, &["src/main.c", "src/util.c", "src/util.h"]);
+
+        let found = glob_in(&base, "src/*.c", true).unwrap();
+        assert_eq!(relative(&base, found), ["src/main.c", "src/util.c"]);
+    }
+
+    #[test]
+    fn glob_in_matches_unicode_and_spaces_in_pattern() {
+        let tmp = tempfile::tempdir().unwrap();
+        let base = tmp.path();
+        touch(
+            base,
+            &["소스/메인 파일.c", "소스/other.c", "소스/하위/깊은.c"],
+        );
+
+        let found = glob_in(base, "소스/메인*.c", true).unwrap();
+        assert_eq!(relative(base, found), ["소스/메인 파일.c"]);
+
+        let found = glob_in(base, "소스/**/*.c", true).unwrap();
+        assert_eq!(
+            relative(base, found),
+            ["소스/other.c", "소스/메인 파일.c", "소스/하위/깊은.c"]
+        );
+    }
+
+    #[test]
+    fn glob_in_star_does_not_cross_directories() {
+        let tmp = tempfile::tempdir().unwrap();
+        let base = tmp.path();
+        touch(base, &["src/a.c", "src/sub/b.c"]);
+
+        let found = glob_in(base, "src/*.c", true).unwrap();
+        assert_eq!(relative(base, found), ["src/a.c"]);
+    }
+
+    #[test]
+    fn glob_in_accepts_backslash_separators_and_literal_paths() {
+        let tmp = tempfile::tempdir().unwrap();
+        let base = tmp.path();
+        touch(base, &["src/lib/x.c", "include/x.h"]);
+
+        let found = glob_in(base, "src\\lib\\*.c", true).unwrap();
+        assert_eq!(relative(base, found), ["src/lib/x.c"]);
+
+        let found = glob_in(base, "include/x.h", true).unwrap();
+        assert_eq!(relative(base, found), ["include/x.h"]);
+        assert!(glob_in(base, "include/missing.h", true).unwrap().is_empty());
+    }
+
+    #[test]
+    fn xml_escape_escapes_markup_and_drops_control_chars() {
+        assert_eq!(
+            xml_escape("a<b> & \"c\"\u{1b}[31m\n"),
+            "a&lt;b&gt; &amp; &quot;c&quot;[31m\n"
+        );
+    }
+
+    #[test]
+    fn path_flag_and_substitute_paths_keep_paths() {
+        let path = Path::new("/tmp/my dir/프로필.profdata");
+        assert_eq!(
+            path_flag("-fprofile-use=", path),
+            OsString::from("-fprofile-use=/tmp/my dir/프로필.profdata")
+        );
+        assert_eq!(
+            substitute_paths(
+                "{input}:{output}!",
+                &[
+                    ("{input}", Path::new("a b")),
+                    ("{output}", Path::new("출력"))
+                ]
+            ),
+            OsString::from("a b:출력!")
+        );
+        assert_eq!(
+            substitute_paths("{unknown}", &[("{input}", Path::new("x"))]),
+            OsString::from("{unknown}")
+        );
+    }
+
+    #[test]
+    fn normalize_and_relative_paths() {
+        assert_eq!(
+            normalize_path(Path::new("src/../include/./a.h")),
+            PathBuf::from("include/a.h")
+        );
+        assert_eq!(
+            relative_path_between(Path::new("/p/bin/tests"), Path::new("/p/lib")),
+            PathBuf::from("../../lib")
+        );
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn long_path_uses_extended_prefix() {
+        let long_dir = "d".repeat(120);
+        let path = PathBuf::from(format!(r"C:\proj/{0}\.\x\..\{0}\main.c", long_dir));
+        let expected = format!(r"\\?\C:\proj\{0}\{0}\main.c", long_dir);
+        assert_eq!(long_path(&path), PathBuf::from(expected));
+
+        let unc = PathBuf::from(format!(r"\\server\share\{0}\{0}\{0}\a.c", long_dir));
+        let expected = format!(r"\\?\UNC\server\share\{0}\{0}\{0}\a.c", long_dir);
+        assert_eq!(long_path(&unc), PathBuf::from(expected));
+
+        // 짧은 경로, 상대 경로, 이미 확장 경로인 경우는 그대로
+        let short = Path::new(r"C:\proj\src\main.c");
+        assert_eq!(long_path(short), short);
+        let relative = PathBuf::from(format!(r"src\{0}\{0}\{0}\a.c", long_dir));
+        assert_eq!(long_path(&relative), relative);
+        let verbatim = PathBuf::from(format!(r"\\?\C:\{0}\{0}\{0}\a.c", long_dir));
+        assert_eq!(long_path(&verbatim), verbatim);
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn simplified_path_strips_verbatim_prefix() {
+        assert_eq!(
+            simplified_path(Path::new(r"\\?\C:\proj\src")),
+            PathBuf::from(r"C:\proj\src")
+        );
+        assert_eq!(
+            simplified_path(Path::new(r"\\?\UNC\server\share\src")),
+            PathBuf::from(r"\\server\share\src")
+        );
+        assert_eq!(
+            simplified_path(Path::new(r"C:\proj")),
+            PathBuf::from(r"C:\proj")
+        );
+
+        // 접두사를 떼면 MAX_PATH를 넘는 경로는 유지
+        let long = PathBuf::from(format!(r"\\?\C:\{}", "d".repeat(300)));
+        assert_eq!(simplified_path(&long), long);
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn glob_in_returns_native_separators() {
+        let tmp = tempfile::tempdir().unwrap();
+        let base = tmp.path();
+        touch(base, &["src/lib/x.c"]);
+
+        for pattern in ["src/lib/*.c", r"src\lib\*.c", r"src\**\*.c"] {
+            let found = glob_in(base, pattern, true).unwrap();
+            assert_eq!(found, [base.join(r"src\lib\x.c")], "{}", pattern);
+        }
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn tools_are_found_through_path_and_pathext() {
+        let tmp = tempfile::tempdir().unwrap();
+        fs::write(tmp.path().join("cbuild-probe-tool.cmd"), "@echo off\r\n").unwrap();
+
+        // PATH를 바꾸는 테스트는 이것 하나뿐이며 기존 항목은 유지
+        let mut paths = vec![tmp.path().to_path_buf()];
+        paths.extend(std::env::split_paths(
+            &std::env::var_os("PATH").unwrap_or_default(),
+        ));
+        std::env::set_var("PATH", std::env::join_paths(paths).unwrap());
+
+        assert!(is_tool_installed("cbuild-probe-tool"));
+        assert!(is_tool_installed("cbuild-probe-tool.cmd"));
+        assert!(is_tool_installed("cbuild-probe-tool --version"));
+        assert!(!is_tool_installed("cbuild-missing-tool"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_paths_are_preserved() {
+        use std::os::unix::ffi::{OsStrExt, OsStringExt};
+
+        let tmp = tempfile::tempdir().unwrap();
+        let base = tmp.path().join(OsStr::from_bytes(b"proj-\xff\xfe"));
+        touch(&base, &["src/main.c"]);
+        fs::write(base.join(OsStr::from_bytes(b"src/bad-\xe9.c")), "").unwrap();
+
+        // UTF-8이 아닌 기준 디렉토리와 파일 이름도 찾음
+        let found = glob_in(&base, "src/*.c", true).unwrap();
+        assert_eq!(found.len(), 2);
+        assert!(found.iter().all(|p| p.starts_with(&base)));
+        assert!(found
+            .iter()
+            .any(|p| p.file_name().unwrap().as_bytes() == b"bad-\xe9.c"));
+
+        // 인자로 넘길 때 바이트가 그대로 유지됨
+        let flag = path_flag("-I", &base);
+        let mut expected = b"-I".to_vec();
+        expected.extend_from_slice(base.as_os_str().as_bytes());
+        assert_eq!(flag.into_vec(), expected);
+
+        let arg = substitute_paths("--out={output}", &[("{output}", &base)]);
+        assert!(arg.as_bytes().ends_with(b"proj-\xff\xfe"));
+    }
+}
@@ -0,0 +1,143 @@
+use crate::error::{BuildError, BuildResult};
+use log::debug;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// 지정한 git 참조 이후 변경된 파일 목록 (절대 경로)
+///
+/// 커밋된 변경뿐 아니라 작업 트리의 수정 사항과 추적되지 않는 새 파일도
+/// 포함한다. 삭제된 파일은 빌드할 수 없으므로 제외된다.
+pub fn changed_files_since(project_dir: &Path, git_ref: &str) -> BuildResult<Vec<PathBuf>> {
+    let toplevel = run_git(project_dir, &["rev-parse", "--show-toplevel"])?;
+    let repo_root = PathBuf::from(toplevel.trim());
+
+    // 참조와 작업 트리 비교 (커밋 + 스테이징 + 수정)
+    // -z: 한글 등 ASCII가 아닌 파일 이름을 따옴표와 8진수 이스케이프 없이 NUL로 구분해 받음
+    let diff = run_git_bytes(
+        project_dir,
+        &[
+            "diff",
+            "--name-only",
+            "-z",
+            "--diff-filter=d",
+            git_ref,
+            "--",
+        ],
+    )?;
+    // 아직 추가되지 않은 새 파일
+    let untracked = run_git_bytes(
+        project_dir,
+        &[
+            "ls-files",
+            "-z",
+            "--others",
+            "--exclude-standard",
+            "--full-name",
+        ],
+    )?;
+
+    let mut files: Vec<PathBuf> = diff
+        .split(|&b| b == 0)
+        .chain(untracked.split(|&b| b == 0))
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            let path = repo_root.join(path_from_bytes(name));
+            path.canonicalize().unwrap_or(path)
+        })
+        .collect();
+    files.sort();
+    files.dedup();
+
+    debug!("{} 이후 변경된 파일: {}개", git_ref, files.len());
+    Ok(files)
+}
+
+/// 현재 HEAD 커밋 해시 (git 저장소가 아니면 None)
+pub fn head_commit(project_dir: &Path) -> Option<String> {
+    run_git(project_dir, &["rev-parse", "HEAD"])
+        .ok()
+        .map(|out| out.trim().to_string())
+}
+
+/// git이 출력한 경로 바이트를 그대로 경로로 변환 (Unix에서는 UTF-8이 아니어도 보존)
+#[cfg(unix)]
+fn path_from_bytes(bytes: &[u8]) -> PathBuf {
+    use std::os::unix::ffi::OsStrExt;
+    PathBuf::from(std::ffi::OsStr::from_bytes(bytes))
+}
+
+#[cfg(not(unix))]
+fn path_from_bytes(bytes: &[u8]) -> PathBuf {
+    PathBuf::from(String::from_utf8_lossy(bytes).into_owned())
+}
+
+fn run_git(dir: &Path, args: &[&str]) -> BuildResult<String> {
+    run_git_bytes(dir, args).map(|out| String::from_utf8_lossy(&out).to_string())
+}
+
+fn run_git_bytes(dir: &Path, args: &[&str]) -> BuildResult<Vec<u8>> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .map_err(|e| BuildError::GitError(format!("git을 실행할 수 없습니다: {}", e)))?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err(BuildError::GitError(format!(
+            "git {} 실패: {}",
+            args.join(" "),
+            error.trim()
+        )));
+    }
+
+    Ok(output.stdout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {:?}", args);
+    }
+
+    #[test]
+    fn changed_files_keep_non_ascii_names() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        git(dir, &["init", "-q"]);
+        fs::write(dir.join("main.c"), "int main(void) { return 0; }\n").unwrap();
+        git(dir, &["add", "."]);
+        git(dir, &["commit", "-q", "-m", "init"]);
+
+        fs::create_dir(dir.join("소스")).unwrap();
+        fs::write(dir.join("소스/한글.c"), "").unwrap();
+        git(dir, &["add", "소스/한글.c"]);
+        fs::write(dir.join("새 파일.c"), "").unwrap();
+        fs::write(dir.join("main.c"), "int main(void) { return 1; }\n").unwrap();
+
+        let files = changed_files_since(dir, "HEAD").unwrap();
+        let root = dir.canonicalize().unwrap();
+        let names: Vec<_> = files
+            .iter()
+            .map(|file| file.strip_prefix(&root).unwrap().to_path_buf())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                PathBuf::from("main.c"),
+                PathBuf::from("새 파일.c"),
+                PathBuf::from("소스/한글.c"),
+            ]
+        );
+    }
+}